@@ -1,130 +1,421 @@
-use std::cmp::Ordering;
+use std::str::FromStr;
 
 use memory::{resolver::MemAddress, types::DataType};
-use parser::{types::{Variable, Operator}, expressions::id::{Access, Id}, statements::{Statement, Block, Program}, functions::Function};
+use parser::{types::{Variable, Operator}, expressions::{constant::Const, id::{Access, Id}}, statements::{Statement, Block, Program}, functions::{Function, FunctionParam, FunctionSignature}, Dimension};
 
-use crate::{node::{Node, AccessNode}, manager::Manager, quadruples::Quadruple};
+use crate::{
+    errors::{AtSpan, CompileError, CompileResult},
+    manager::Manager,
+    natives::NativeFunction,
+    node::{AccessNode, ExpressionNode, Node},
+    quadruples::{Instruction, Operand, QuadrupleHold},
+};
 
 pub mod expressions;
 pub mod statements;
 
 impl AccessNode for Variable {
     // TODO: Refactor to use Id
-    fn address(&self, manager: &mut Manager) -> MemAddress {
+    fn address(&self, manager: &mut Manager) -> CompileResult<MemAddress> {
         if let Some(var_entry) = manager.get_env().get_var(&self.id) {
-            return var_entry.address;
+            return Ok(var_entry.address);
         } else {
-            panic!("Cannot find id {} in scope", self.id);
+            return Err(CompileError::new(
+                format!("Cannot find id {} in scope", self.id).as_str(),
+                self.span,
+            ));
+        }
+    }
+}
+
+/// Declarations taking their type and dimension from their value.
+trait InferredVariable {
+    fn infer(&mut self, manager: &mut Manager) -> CompileResult<()>;
+
+    fn copy_value(&self, manager: &mut Manager) -> CompileResult<()>;
+}
+
+impl InferredVariable for Variable {
+    /// Sets the type and dimension of the declaration to the ones of its
+    /// value, the value is typed before the variable is declared.
+    fn infer(&mut self, manager: &mut Manager) -> CompileResult<()> {
+        let value = match &self.value {
+            Some(value) => value,
+            None => return Ok(()),
+        };
+
+        let data_type = value.data_type(manager)?;
+        let shape = value.dimensionality(manager)?;
+
+        if data_type == DataType::Void {
+            return Err(CompileError::new(
+                format!("Variable {} can't be declared with a void value", self.id).as_str(),
+                value.span(),
+            ));
+        } else if shape.contains(&0) && matches!(data_type, DataType::Struct(_)) {
+            return Err(CompileError::new(
+                format!(
+                    "The size of {} is only known at runtime, declare its type instead",
+                    self.id
+                )
+                .as_str(),
+                value.span(),
+            ));
         }
+
+        self.data_type = data_type;
+        self.dimension = if shape.contains(&0) {
+            Dimension::new_runtime(shape.len() as i8)
+        } else if shape.is_empty() {
+            Dimension::new_scalar()
+        } else {
+            let sizes = shape
+                .iter()
+                .map(|size| Const::new(size.to_string().as_str(), DataType::Int))
+                .collect();
+            Dimension::new(shape.len() as i8, sizes)
+                .map_err(CompileError::from)
+                .at(value.span())?
+        };
+
+        Ok(())
+    }
+
+    /// Allocates an array inferred from a value sized at runtime, taking
+    /// the lengths of the value, and copies its items.
+    fn copy_value(&self, manager: &mut Manager) -> CompileResult<()> {
+        let value = self.value.as_ref().unwrap();
+        let source = value.reduce(manager)?;
+
+        let shape = &self.dimension.shape;
+        let sizes = (0..shape.len())
+            .map(|axis| {
+                let length = Operand::Direct(manager.new_temp(&DataType::Int));
+                manager.emit(Instruction::len(
+                    source.clone(),
+                    axis,
+                    shape.len(),
+                    length.clone(),
+                ));
+                length
+            })
+            .collect();
+
+        let array = Operand::Direct(self.address(manager)?);
+        manager.emit(Instruction::new_array(
+            &self.data_type,
+            sizes,
+            array.clone(),
+        ));
+
+        let size = manager.array_size(&array, shape, 0);
+        manager.emit(Instruction::copy(source, size, array));
+
+        Ok(())
     }
 }
 
 impl Node for Variable {
-    fn generate(&mut self, manager: &mut Manager) -> () {
+    fn generate(&mut self, manager: &mut Manager) -> CompileResult<()> {
+        if self.inferred {
+            self.infer(manager)?;
+        }
+
         // Add variable to symbols table
-        let var_address =
-            manager
-                .get_env_mut()
-                .add_var(&self.id, &self.data_type, &self.dimension, false);
-
-        if self.dimension.size > 1 {
-            let array_address = manager
-                .get_env_mut()
-                .allocate_array(&self.data_type, &self.dimension);
-
-            manager.emit(Quadruple::operation(
-                Operator::Assign,
-                format!("&{}", array_address).as_str(),
-                "",
-                format!("{}", var_address).as_str(),
-            ));
+        manager
+            .get_env_mut()
+            .add_var(&self.id, &self.data_type, &self.dimension, false)
+            .at(self.span)?;
+        let symbol = manager.get_env().get_var(&self.id).cloned().unwrap();
 
-            manager.emit(Quadruple::operation(
-                Operator::Assign,
-                "END",
-                "",
-                format!("{}", array_address + self.dimension.size as MemAddress).as_str(),
-            ));
+        if self.inferred && self.dimension.is_runtime_sized() {
+            return self.copy_value(manager);
+        }
+
+        // The items are allocated once the sizes are known
+        let mut sizes = vec![];
+        if self.dimension.size <= 1 && self.dimension.is_runtime_sized() {
+            for length in self.dimension.lengths.iter() {
+                let length_type = length.data_type(manager)?;
+                if length_type != DataType::Int || !length.dimensionality(manager)?.is_empty() {
+                    return Err(CompileError::new(
+                        format!("Array sizes must be integers, found {:?}", length_type).as_str(),
+                        length.span(),
+                    ));
+                }
+
+                sizes.push(length.reduce(manager)?);
+            }
+        }
+
+        // Arrays of structs hold an array for each of their fields
+        for (_, leaf) in symbol.leaves() {
+            if self.dimension.size > 1 {
+                // The space of the items is reserved along with the variable
+                let array_address = leaf.point_address.unwrap();
+
+                manager.emit(Instruction::unary(
+                    Operator::Assign,
+                    Operand::AddressOf(array_address),
+                    Operand::Direct(leaf.address),
+                ));
+
+                manager.emit(Instruction::alloc(array_address, &self.dimension.shape));
+            } else if self.dimension.is_runtime_sized() {
+                manager.emit(Instruction::new_array(
+                    &leaf.data_type,
+                    sizes.clone(),
+                    Operand::Direct(leaf.address),
+                ));
+            }
         }
 
         if let Some(value) = &self.value {
             let mut assign = Statement::VarAssign(
-                Access::new(
-                    Id::new(self.id.as_str(), Some(self.data_type.clone())),
-                    vec![],
-                ),
+                Access {
+                    span: self.span,
+                    ..Access::new(
+                        Id::new(self.id.as_str(), Some(self.data_type.clone())),
+                        vec![],
+                    )
+                },
                 value.to_owned(),
             );
 
-            assign.generate(manager);
+            assign.generate(manager)?;
         }
+
+        Ok(())
     }
 }
 
 impl Node for Block {
-    fn generate(&mut self, manager: &mut Manager) -> () {
+    /// Generates every statement in the block. Errors are reported to the
+    /// manager per statement, so the remaining statements are still checked.
+    ///
+    /// Code emitted after the block is attributed back to the line of the
+    /// statement holding it.
+    fn generate(&mut self, manager: &mut Manager) -> CompileResult<()> {
+        let line = manager.current_line();
+
         for stmt in self.0.iter_mut() {
-            stmt.generate(manager);
+            manager.set_location(stmt.span());
+            if let Err(error) = stmt.generate(manager) {
+                manager.report(error);
+            }
         }
+
+        manager.set_line(line);
+
+        Ok(())
+    }
+}
+
+/// Allocates the global holding the values returned by a function, if it
+/// returns any.
+fn return_address(manager: &mut Manager, signature: &FunctionSignature) -> Option<MemAddress> {
+    match signature.data_type {
+        DataType::Void | DataType::Struct(_) => None,
+        // Arrays are returned as a pointer to their first item
+        _ if signature.dimension.dimensions > 0 => Some(manager.new_global(&DataType::Pointer)),
+        _ => Some(manager.new_global(&signature.data_type)),
     }
 }
 
 impl Node for Program {
-    fn generate(&mut self, manager: &mut Manager) -> () {
+    fn generate(&mut self, manager: &mut Manager) -> CompileResult<()> {
         let Program(statements) = self;
-        statements.sort_by(|a, b| match a {
-            Statement::FunctionDeclaration(_) => Ordering::Greater,
-            _ => match b {
-                Statement::FunctionDeclaration(_) => Ordering::Less,
-                _ => Ordering::Equal,
-            },
-        });
-
-        // Pre-declare function signatures
-        for stmt in statements.iter_mut().rev() {
-            match stmt {
-                Statement::FunctionDeclaration(func) => {
-                    let return_address = match func.signature.data_type {
-                        DataType::Void => None,
-                        _ => Some(manager.new_global(&func.signature.data_type)),
-                    };
-
-                    manager.new_func(&func.signature, 0, return_address, false);
-                    // TODO: improve undefined location
+
+        // Pre-declare structs, so they can be used by any function signature
+        for stmt in statements.iter() {
+            if let Statement::StructDeclaration(declaration) = stmt {
+                if let Err(error) = manager.new_struct(declaration) {
+                    manager.report(error);
+                }
+            }
+        }
+
+        // Pre-declare function signatures, so functions can be called
+        // before their declaration
+        let mut declared: Vec<bool> = vec![true; statements.len()];
+        for (index, stmt) in statements.iter_mut().enumerate() {
+            if let Statement::FunctionDeclaration(func) = stmt {
+                let return_address = return_address(manager, &func.signature);
+
+                // TODO: improve undefined location
+                if let Err(error) = manager.new_func(&func.signature, 0, return_address, false) {
+                    manager.report(error);
+                    declared[index] = false;
                 }
-                _ => break,
             }
         }
 
+        // Global functions go after the end of the program, so they can read
+        // any global variable. Nested functions are generated in place.
+        let (functions, statements): (Vec<_>, Vec<_>) = statements
+            .iter_mut()
+            .zip(declared)
+            .partition(|(stmt, _)| matches!(stmt, Statement::FunctionDeclaration(_)));
+
         let mut last_func_generated = false;
 
-        for stmt in statements.iter_mut() {
+        for (stmt, declared) in statements.into_iter().chain(functions) {
             if !last_func_generated {
                 match stmt {
                     Statement::FunctionDeclaration(_) => {
                         last_func_generated = true;
-                        manager.emit(Quadruple::end_program());
+                        manager.emit(Instruction::end_program());
                     }
                     _ => (),
                 }
             }
 
-            stmt.generate(manager);
+            if !declared {
+                continue;
+            }
+
+            manager.set_location(stmt.span());
+            if let Err(error) = stmt.generate(manager) {
+                manager.report(error);
+            }
         }
+
+        Ok(())
     }
 }
 
+/// Generation of the functions declared within blocks or other functions.
+trait NestedFunction {
+    fn generate_nested(&mut self, manager: &mut Manager) -> CompileResult<()>;
+}
+
 impl Node for Function {
-    fn generate(&mut self, manager: &mut Manager) -> () {
+    fn generate(&mut self, manager: &mut Manager) -> CompileResult<()> {
+        let env = manager.get_env().current_env();
+        if !env.is_global || !env.scopes.is_empty() {
+            return self.generate_nested(manager);
+        }
+
         let next_position = manager.get_next_pos();
 
         manager.update_func_position(&self.signature.id, next_position);
         manager.get_env_mut().switch(&self.signature.id);
 
-        self.block.generate(manager);
+        let block_result = self.block.generate(manager);
 
-        manager.emit(Quadruple::end_func());
+        manager.emit(Instruction::end_func());
 
         manager.get_env_mut().switch(&String::from("global"));
+
+        block_result
+    }
+}
+
+impl NestedFunction for Function {
+    /// Generates a function only visible within the block declaring it,
+    /// which can read the variables of the enclosing functions.
+    ///
+    /// The function is called through a closure stored in a variable of the
+    /// block, holding the values of the variables it captures once declared.
+    fn generate_nested(&mut self, manager: &mut Manager) -> CompileResult<()> {
+        let id = self.signature.id.clone();
+        let span = self.signature.span;
+        if NativeFunction::from_str(id.as_str()).is_ok() {
+            return Err(CompileError::new(
+                format!("A symbol with id {} has been already defined", id).as_str(),
+                span,
+            ));
+        }
+
+        let signature = FunctionSignature {
+            id: manager.nested_func_id(&id),
+            ..self.signature.clone()
+        };
+        let closure = manager
+            .get_env_mut()
+            .add_function(&id, &signature.id, &closure_type(&signature))
+            .at(span)?;
+
+        generate_closure(manager, &signature, Some(&id), &mut self.block, closure)
+    }
+}
+
+/// Type of the closures of a function, by the types of its parameters.
+fn closure_type(signature: &FunctionSignature) -> DataType {
+    DataType::Function(
+        signature
+            .params
+            .iter()
+            .map(|FunctionParam(param)| param.data_type.clone())
+            .collect(),
+        Box::new(signature.data_type.clone()),
+    )
+}
+
+/// Generates the block of a function within the current one, skipped over
+/// until it is called, and stores its closure once it is declared.
+///
+/// A named function calls itself through the closure it was called with.
+fn generate_closure(
+    manager: &mut Manager,
+    signature: &FunctionSignature,
+    name: Option<&String>,
+    block: &mut Block,
+    closure: MemAddress,
+) -> CompileResult<()> {
+    let parent = manager.get_env().current_env.clone();
+    let return_address = return_address(manager, signature);
+
+    let mut skip = QuadrupleHold::new(manager);
+    let position = manager.get_next_pos();
+
+    manager.get_env_mut().suspend();
+    if let Err(error) = manager.new_func(signature, position, return_address, true) {
+        manager.get_env_mut().resume();
+        return Err(error);
     }
+    manager
+        .get_env_mut()
+        .entries
+        .get_mut(&signature.id)
+        .unwrap()
+        .parent = Some(parent);
+
+    // Parameters shadow the function itself
+    let itself = match name {
+        Some(name) if manager.get_env().current_env().get(name).is_none() => manager
+            .get_env_mut()
+            .add_function(name, &signature.id, &closure_type(signature))
+            .ok(),
+        _ => None,
+    };
+
+    // Loops of the enclosing function can't be exited from the function
+    let unresolved = std::mem::take(&mut manager.unresolved);
+    let block_result = block.generate(manager);
+    manager.unresolved = unresolved;
+
+    manager.emit(Instruction::end_func());
+
+    let captures = manager.get_env().current_env().captures.clone();
+    manager.get_env_mut().resume();
+
+    let end_position = manager.get_next_pos();
+    skip.release(manager, Instruction::goto(end_position));
+
+    manager.update_func_captures(
+        &signature.id,
+        captures.iter().map(|(_, local)| *local).collect(),
+        itself,
+    );
+    manager.emit(Instruction::closure(
+        signature.id.as_str(),
+        captures
+            .iter()
+            .map(|(source, _)| Operand::Direct(*source))
+            .collect(),
+        Operand::Direct(closure),
+    ));
+
+    block_result
 }
@@ -1,33 +1,39 @@
-use std::iter::zip;
+use std::{iter::zip, str::FromStr};
 
-use memory::{
-    resolver::{MemAddress, MemoryResolver},
-    types::DataType,
-};
+use memory::{resolver::MemAddress, types::DataType};
 use parser::{
     expressions::{
         call::Call,
         constant::Const,
         id::{Access, Id},
+        lambda::Lambda,
         operation::Operation,
+        vector::Vector,
         Expression, Index,
     },
+    functions::{FunctionParam, FunctionSignature},
     semantics::SemanticRules,
+    span::Span,
+    statements::{Block, Statement},
     types::{Operator, OperatorType},
+    Dimension,
 };
 
+use super::{closure_type, generate_closure};
+
 use crate::{
+    errors::{AtSpan, CompileError, CompileResult},
     manager::Manager,
-    natives::NativeFunction,
+    natives::{NativeConstant, NativeFunction},
     node::{AccessNode, ExpressionNode, Node},
-    quadruples::Quadruple,
+    quadruples::{Instruction, Literal, Operand},
     symbols::SymbolEntry,
 };
 
 impl AccessNode for Expression {}
 
 impl ExpressionNode for Expression {
-    fn dimensionality(&self, manager: &mut Manager) -> Vec<usize> {
+    fn dimensionality(&self, manager: &mut Manager) -> CompileResult<Vec<usize>> {
         match &self {
             Expression::Const(constant) => constant.dimensionality(manager),
             Expression::Op(operation) => operation.dimensionality(manager),
@@ -36,22 +42,26 @@ impl ExpressionNode for Expression {
             Expression::Call(call) => call.dimensionality(manager),
             Expression::Not(expr) => expr.dimensionality(manager),
             Expression::Negative(expr) => expr.dimensionality(manager),
+            Expression::Vector(vector) => vector.dimensionality(manager),
+            Expression::Lambda(lambda) => lambda.dimensionality(manager),
         }
     }
 
-    fn data_type(&self, manager: &mut Manager) -> DataType {
+    fn data_type(&self, manager: &mut Manager) -> CompileResult<DataType> {
         match &self {
-            Expression::Const(constant) => constant.dtype.clone(),
+            Expression::Const(constant) => Ok(constant.dtype.clone()),
             Expression::Op(operation) => operation.data_type(manager),
-            Expression::Access(access) => access.id.data_type(manager),
+            Expression::Access(access) => access.data_type(manager),
             Expression::Id(id) => id.data_type(manager),
             Expression::Call(call) => call.data_type(manager),
             Expression::Not(expr) => expr.data_type(manager),
             Expression::Negative(expr) => expr.data_type(manager),
+            Expression::Vector(vector) => vector.data_type(manager),
+            Expression::Lambda(lambda) => lambda.data_type(manager),
         }
     }
 
-    fn reduce(&self, manager: &mut Manager) -> String {
+    fn reduce(&self, manager: &mut Manager) -> CompileResult<Operand> {
         match self {
             Expression::Const(constant) => constant.reduce(manager),
             Expression::Op(operation) => operation.reduce(manager),
@@ -59,213 +69,245 @@ impl ExpressionNode for Expression {
             Expression::Id(id) => id.reduce(manager),
             Expression::Call(call) => call.reduce(manager),
             Expression::Not(not) => {
-                let mut to_negate = not.reduce(manager);
-                let expr_type =
-                    MemoryResolver::get_type_from_address(to_negate.parse().unwrap()).unwrap();
-
-                if DataType::equivalent(expr_type, &DataType::Bool).is_err() {
-                    panic!("Expression can't be casted to boolean");
+                check_scalar(not, "!", manager)?;
+                let expr_type = not.data_type(manager)?;
+                let mut to_negate = not.reduce(manager)?;
+
+                if DataType::equivalent(&expr_type, &DataType::Bool).is_err() {
+                    return Err(CompileError::new(
+                        "Expression can't be casted to boolean",
+                        not.span(),
+                    ));
                 }
-                if *expr_type != DataType::Bool {
-                    to_negate = manager.emit_cast(&DataType::Bool, &to_negate);
+                if expr_type != DataType::Bool {
+                    to_negate = manager.emit_cast(&DataType::Bool, to_negate);
                 }
 
-                let dest = manager.new_temp(&DataType::Bool).to_string();
-                manager.emit(Quadruple::unary(
-                    Operator::Not,
-                    to_negate.as_str(),
-                    dest.as_str(),
-                ));
+                let dest = Operand::Direct(manager.new_temp(&DataType::Bool));
+                manager.emit(Instruction::unary(Operator::Not, to_negate, dest.clone()));
 
-                dest
+                Ok(dest)
             }
             Expression::Negative(expr) => {
-                let addr = expr.reduce(manager);
-                let expr_dt = expr.data_type(manager);
-                let new_addr = manager.new_temp(&expr_dt).to_string();
+                check_scalar(expr, "-", manager)?;
+                let addr = expr.reduce(manager)?;
+                let expr_dt = expr.data_type(manager)?;
+                let new_addr = Operand::Direct(manager.new_temp(&expr_dt));
 
-                manager.emit(Quadruple::unary(Operator::Neg, &addr, &new_addr));
+                manager.emit(Instruction::unary(Operator::Neg, addr, new_addr.clone()));
 
-                new_addr
+                Ok(new_addr)
             }
+            Expression::Vector(vector) => vector.reduce(manager),
+            Expression::Lambda(lambda) => lambda.reduce(manager),
         }
     }
 }
 
 impl Node for Expression {
-    fn generate(&mut self, manager: &mut Manager) -> () {
-        match self {
-            Expression::Const(constant) => constant.reduce(manager),
-            Expression::Op(operation) => operation.reduce(manager),
-            Expression::Access(access) => access.reduce(manager),
-            Expression::Id(id) => id.reduce(manager),
-            Expression::Call(call) => call.reduce(manager),
-            Expression::Not(not) => not.reduce(manager),
-            Expression::Negative(_) => todo!(),
-        };
+    fn generate(&mut self, manager: &mut Manager) -> CompileResult<()> {
+        self.reduce(manager)?;
+        Ok(())
+    }
+}
+
+/// Fails if the operand of an unary operator is an array, they only
+/// apply to scalar values.
+fn check_scalar(operand: &Expression, operator: &str, manager: &mut Manager) -> CompileResult<()> {
+    let dimensions = operand.dimensionality(manager)?;
+
+    if dimensions.is_empty() {
+        Ok(())
+    } else {
+        Err(CompileError::new(
+            format!(
+                "Operator {} can't be applied to an array with dimensions {:?}",
+                operator, dimensions
+            )
+            .as_str(),
+            operand.span(),
+        ))
     }
 }
 
 impl ExpressionNode for Index {
-    fn reduce(&self, manager: &mut Manager) -> String {
+    fn reduce(&self, manager: &mut Manager) -> CompileResult<Operand> {
         match self {
             Self::Simple(idx) => idx.reduce(manager),
+            // Slices are handled by the access to the array
+            Self::Range { .. } => Err(CompileError::new(
+                "Slices can only be taken from arrays",
+                Span::default(),
+            )),
         }
     }
 }
 
 trait Pipe {
-    fn resolve_pipe_type(&self, _: &mut Manager) -> DataType {
+    fn resolve_pipe_type(&self, _: &mut Manager) -> CompileResult<DataType> {
         todo!()
     }
 
-    fn resolve_pipe(&self, _: &mut Manager) -> Box<Expression> {
+    fn resolve_pipe(&self, _: &mut Manager) -> CompileResult<Box<Expression>> {
         todo!()
     }
 }
 
 impl Pipe for Operation {
-    fn resolve_pipe_type(&self, manager: &mut Manager) -> DataType {
-        let input_expr = self.left.to_owned();
-        let piped_fn = self.right.to_owned();
-
-        if let Expression::Access(access) = *piped_fn {
-            match *input_expr {
-                Expression::Op(op) => match op {
-                    Operation {
-                        operator: Operator::Pipe,
-                        left: _,
-                        right: _,
-                    } => {
-                        let call_param = op.resolve_pipe(manager);
-                        let call = Call::new(&access.id.id, vec![call_param]);
-                        call.data_type(manager)
-                    }
-                    _ => {
-                        let call = Call::new(&access.id.id, vec![Box::new(Expression::Op(op))]);
-                        call.data_type(manager)
-                    }
-                },
-                _ => panic!(),
-            }
-        } else {
-            panic!()
-        }
+    fn resolve_pipe_type(&self, manager: &mut Manager) -> CompileResult<DataType> {
+        let call = self.resolve_pipe(manager)?;
+        call.data_type(manager)
     }
 
-    fn resolve_pipe(&self, manager: &mut Manager) -> Box<Expression> {
+    fn resolve_pipe(&self, manager: &mut Manager) -> CompileResult<Box<Expression>> {
         let input_expr = self.left.to_owned();
         let piped_fn = self.right.to_owned();
 
-        if let Expression::Access(access) = *piped_fn {
-            match *input_expr {
-                Expression::Op(op) => match op {
-                    Operation {
-                        operator: Operator::Pipe,
-                        left: _,
-                        right: _,
-                    } => {
-                        let call_param = op.resolve_pipe(manager);
-                        let call = Call::new(&access.id.id, vec![call_param]);
-                        Box::new(Expression::Call(call))
-                    }
-                    _ => {
-                        let call = Call::new(&access.id.id, vec![Box::new(Expression::Op(op))]);
-                        Box::new(Expression::Call(call))
-                    }
-                },
-                _ => panic!(),
+        let (id, params) = match *piped_fn {
+            Expression::Access(access) => (access.id.id, vec![]),
+            // Calls take the piped value as their first parameter
+            Expression::Call(call) => (call.id, call.params),
+            piped_fn => {
+                return Err(CompileError::new(
+                    "The right side of a pipe must be a function id or call",
+                    piped_fn.span(),
+                ))
             }
-        } else {
-            piped_fn
-        }
+        };
+
+        let call_param = match *input_expr {
+            Expression::Op(
+                op @ Operation {
+                    operator: Operator::Pipe,
+                    ..
+                },
+            ) => op.resolve_pipe(manager)?,
+            input => Box::new(input),
+        };
+
+        Ok(Box::new(Expression::Call(Call {
+            span: self.span,
+            ..Call::new(&id, [vec![call_param], params].concat())
+        })))
     }
 }
 
 impl ExpressionNode for Operation {
-    fn dimensionality(&self, manager: &mut Manager) -> Vec<usize> {
+    fn dimensionality(&self, manager: &mut Manager) -> CompileResult<Vec<usize>> {
         if self.operator == Operator::Pipe {
-            let new_tree = self.resolve_pipe(manager);
+            let new_tree = self.resolve_pipe(manager)?;
             new_tree.dimensionality(manager)
+        } else if self.operator == Operator::MatMul {
+            // Rows of the left matrix by columns of the right one
+            let left_dims = self.left.dimensionality(manager)?;
+            let right_dims = self.right.dimensionality(manager)?;
+
+            Ok(vec![
+                left_dims.first().copied().unwrap_or(0),
+                right_dims.get(1).copied().unwrap_or(0),
+            ])
         } else {
-            self.left.dimensionality(manager)
+            let left_dims = self.left.dimensionality(manager)?;
+            let right_dims = self.right.dimensionality(manager)?;
+
+            // Scalars are applied to every item, known sizes take precedence
+            // over the ones only known at runtime
+            if left_dims.is_empty() {
+                Ok(right_dims)
+            } else if left_dims.len() != right_dims.len() {
+                Ok(left_dims)
+            } else {
+                Ok(zip(left_dims, right_dims)
+                    .map(|(left, right)| left.max(right))
+                    .collect())
+            }
         }
     }
 
-    fn data_type(&self, manager: &mut Manager) -> DataType {
+    fn data_type(&self, manager: &mut Manager) -> CompileResult<DataType> {
         match self.operator {
             Operator::Pipe => self.resolve_pipe_type(manager),
             _ => SemanticRules::match_type(
                 self.operator,
-                self.left.data_type(manager),
-                self.right.data_type(manager),
-            ),
+                self.left.data_type(manager)?,
+                self.right.data_type(manager)?,
+            )
+            .at(self.span),
         }
     }
 
-    fn reduce(&self, manager: &mut Manager) -> String {
+    fn reduce(&self, manager: &mut Manager) -> CompileResult<Operand> {
         if self.operator == Operator::Pipe {
-            let new_tree = self.resolve_pipe(manager);
+            let new_tree = self.resolve_pipe(manager)?;
             return new_tree.reduce(manager);
+        } else if self.operator == Operator::MatMul {
+            return self.reduce_matmul(manager);
         }
-        let left_dims = self.left.dimensionality(manager);
-        let right_dims = self.right.dimensionality(manager);
-        if left_dims != right_dims {
-            panic!(
-                "Can't operate items with dimensions {:#?} and {:#?}",
-                left_dims, right_dims
-            )
+        let left_dims = self.left.dimensionality(manager)?;
+        let right_dims = self.right.dimensionality(manager)?;
+        if !left_dims.is_empty()
+            && !right_dims.is_empty()
+            && !SemanticRules::match_shape(&left_dims, &right_dims)
+        {
+            return Err(CompileError::new(
+                format!(
+                    "Can't operate items with dimensions {:?} and {:?}",
+                    left_dims, right_dims
+                )
+                .as_str(),
+                self.span,
+            ));
         }
 
-        let mut left = self.left.reduce(manager);
-        let left_dt = self.left.data_type(manager);
-        let mut right = self.right.reduce(manager);
-        let right_dt = self.right.data_type(manager);
+        let dt = self.data_type(manager)?;
+        if !left_dims.is_empty() || !right_dims.is_empty() {
+            return self.reduce_elementwise(manager, &dt);
+        }
 
-        let dt = self.data_type(manager);
+        let mut left = self.left.reduce(manager)?;
+        let left_dt = self.left.data_type(manager)?;
+        let mut right = self.right.reduce(manager)?;
+        let right_dt = self.right.data_type(manager)?;
 
         match dt {
             DataType::Int | DataType::Float | DataType::String | DataType::Pointer => {
                 if left_dt != dt {
-                    let new_left = manager.new_temp(&dt).to_string();
-                    manager.emit(Quadruple::type_cast(&dt, left.as_str(), new_left.as_str()));
-
-                    left = new_left
+                    left = manager.emit_cast(&dt, left);
                 }
 
                 if right_dt != dt {
-                    let new_right = manager.new_temp(&dt).to_string();
-                    manager.emit(Quadruple::type_cast(
-                        &dt,
-                        right.as_str(),
-                        new_right.as_str(),
-                    ));
-
-                    right = new_right
+                    right = manager.emit_cast(&dt, right);
                 }
             }
             DataType::Bool => {
                 if self.operator.is_arithmetic() {
-                    panic!()
+                    return Err(CompileError::new(
+                        format!(
+                            "Operator {} can't produce a boolean value",
+                            self.operator.to_string()
+                        )
+                        .as_str(),
+                        self.span,
+                    ));
                 }
 
                 // TODO: Type casting compatibility validations
                 match self.operator.which() {
                     OperatorType::Boolean => {
                         if left_dt != DataType::Bool {
-                            left = manager.emit_cast(&DataType::Bool, left.as_str());
+                            left = manager.emit_cast(&DataType::Bool, left);
                         }
                         if right_dt != DataType::Bool {
-                            right = manager.emit_cast(&DataType::Bool, left.as_str());
+                            right = manager.emit_cast(&DataType::Bool, left.clone());
                         }
                     }
                     OperatorType::Comparison => {
                         if left_dt != right_dt {
                             let max_dt = DataType::max(&left_dt, &right_dt);
                             if max_dt != left_dt {
-                                left = manager.emit_cast(&max_dt, left.as_str());
+                                left = manager.emit_cast(&max_dt, left);
                             } else {
-                                right = manager.emit_cast(&max_dt, right.as_str());
+                                right = manager.emit_cast(&max_dt, right);
                             }
                         }
                     }
@@ -277,269 +319,1256 @@ impl ExpressionNode for Operation {
             _ => (),
         }
 
-        let tmp = manager.new_temp(&dt).to_string();
+        let tmp = Operand::Direct(manager.new_temp(&dt));
 
-        manager.emit(Quadruple::operation(
+        manager.emit(Instruction::operation(
             self.operator,
-            left.as_str(),
-            right.as_str(),
-            tmp.as_str(),
+            left,
+            right,
+            tmp.clone(),
         ));
 
-        return tmp;
+        return Ok(tmp);
+    }
+}
+
+pub trait Elementwise {
+    fn reduce_elementwise(&self, _: &mut Manager, _: &DataType) -> CompileResult<Operand> {
+        todo!()
+    }
+}
+
+impl Elementwise for Operation {
+    /// Applies the operation to each item of its array operands, scalar
+    /// operands are applied to every item. Returns the pointer to a new
+    /// array holding the results.
+    fn reduce_elementwise(
+        &self,
+        manager: &mut Manager,
+        data_type: &DataType,
+    ) -> CompileResult<Operand> {
+        if *data_type == DataType::Bool && self.operator.is_arithmetic() {
+            return Err(CompileError::new(
+                format!(
+                    "Operator {} can't produce a boolean value",
+                    self.operator.to_string()
+                )
+                .as_str(),
+                self.span,
+            ));
+        }
+
+        let shape = self.dimensionality(manager)?;
+        let left = self.left.reduce(manager)?;
+        let right = self.right.reduce(manager)?;
+
+        let dest = if shape.contains(&0) {
+            // Sizes only known at runtime are read from the operand holding them
+            let (expression, operand) = match self.left.dimensionality(manager)?.contains(&0) {
+                true => (&self.left, &left),
+                false => (&self.right, &right),
+            };
+            let (array, first_axis) = match expression.as_ref() {
+                Expression::Access(access) if !access.is_view() => (
+                    Operand::Direct(access.symbol(manager)?.address),
+                    access.indexing.len(),
+                ),
+                _ => (operand.clone(), 0),
+            };
+
+            let mut sizes = vec![];
+            for (axis, size) in shape.iter().enumerate() {
+                if *size > 0 {
+                    sizes.push(Operand::Direct(manager.new_constant(
+                        &DataType::Int,
+                        &Const::new(size.to_string().as_str(), DataType::Int),
+                    )));
+                } else {
+                    let length = Operand::Direct(manager.new_temp(&DataType::Int));
+                    manager.emit(Instruction::len(
+                        array.clone(),
+                        first_axis + axis,
+                        first_axis + shape.len(),
+                        length.clone(),
+                    ));
+                    sizes.push(length);
+                }
+            }
+
+            let pointer = Operand::Direct(manager.new_temp(&DataType::Pointer));
+            manager.emit(Instruction::new_array(data_type, sizes, pointer.clone()));
+            pointer
+        } else {
+            Operand::Direct(manager.new_temp_array(data_type, &shape).at(self.span)?)
+        };
+
+        manager.emit(Instruction::elementwise(
+            self.operator,
+            left,
+            right,
+            dest.clone(),
+        ));
+
+        Ok(dest)
+    }
+}
+
+pub trait MatrixProduct {
+    fn reduce_matmul(&self, _: &mut Manager) -> CompileResult<Operand> {
+        todo!()
+    }
+}
+
+impl MatrixProduct for Operation {
+    /// Multiplies two matrices, the columns of the left one must match the
+    /// rows of the right one. Returns the pointer to a new array holding the
+    /// product.
+    fn reduce_matmul(&self, manager: &mut Manager) -> CompileResult<Operand> {
+        let left_dims = self.left.dimensionality(manager)?;
+        let right_dims = self.right.dimensionality(manager)?;
+
+        if left_dims.len() != 2 || right_dims.len() != 2 {
+            return Err(CompileError::new(
+                format!(
+                    "Operator @ multiplies 2-dimensional arrays, found dimensions {:?} and {:?}",
+                    left_dims, right_dims
+                )
+                .as_str(),
+                self.span,
+            ));
+        } else if !SemanticRules::match_shape(&left_dims[1..], &right_dims[..1]) {
+            return Err(CompileError::new(
+                format!(
+                    "Can't multiply matrices with dimensions {:?} and {:?}",
+                    left_dims, right_dims
+                )
+                .as_str(),
+                self.span,
+            ));
+        }
+
+        self.data_type(manager)?;
+        let left = self.left.reduce(manager)?;
+        let right = self.right.reduce(manager)?;
+
+        let dest = Operand::Direct(manager.new_temp(&DataType::Pointer));
+        manager.emit(Instruction::operation(
+            Operator::MatMul,
+            left,
+            right,
+            dest.clone(),
+        ));
+
+        Ok(dest)
     }
 }
 
 impl ExpressionNode for Const {
-    fn reduce(&self, manager: &mut Manager) -> String {
+    fn reduce(&self, manager: &mut Manager) -> CompileResult<Operand> {
         let const_address = manager.new_constant(&self.dtype, self);
-        return const_address.to_string();
+        return Ok(Operand::Direct(const_address));
     }
 }
 
 impl AccessNode for Id {
-    fn address(&self, manager: &mut Manager) -> MemAddress {
+    fn address(&self, manager: &mut Manager) -> CompileResult<MemAddress> {
+        manager
+            .get_env_mut()
+            .capture(&self.id)
+            .at(Span::default())?;
         if let Some(var_entry) = manager.get_env_mut().get_var(&self.id) {
-            return var_entry.address;
+            return Ok(var_entry.address);
         } else {
-            panic!("Cannot find id {} in scope", self.id);
+            return Err(CompileError::new(
+                format!("Cannot find id {} in scope", self.id).as_str(),
+                Span::default(),
+            ));
         }
     }
 }
 
 impl ExpressionNode for Id {
-    fn dimensionality(&self, manager: &mut Manager) -> Vec<usize> {
+    fn dimensionality(&self, manager: &mut Manager) -> CompileResult<Vec<usize>> {
+        manager
+            .get_env_mut()
+            .capture(&self.id)
+            .at(Span::default())?;
         if let Some(id) = manager.get_env_mut().get_var(&self.id) {
-            return id.dimension.shape.clone();
+            return Ok(id.dimension.shape.clone());
         }
-        panic!("id {} is not defined", self.id);
+        Err(CompileError::new(
+            format!("id {} is not defined", self.id).as_str(),
+            Span::default(),
+        ))
     }
 
-    fn data_type(&self, manager: &mut Manager) -> DataType {
+    fn data_type(&self, manager: &mut Manager) -> CompileResult<DataType> {
         match &self.dtype {
-            Some(dtype) => dtype.clone(),
+            Some(dtype) => Ok(dtype.clone()),
             _ => {
+                manager
+                    .get_env_mut()
+                    .capture(&self.id)
+                    .at(Span::default())?;
                 if let Some(id) = manager.get_env_mut().get_var(&self.id) {
-                    return id.data_type.clone();
+                    return Ok(id.data_type.clone());
                 }
-                panic!("id {} is not defined", self.id);
+                Err(CompileError::new(
+                    format!("id {} is not defined", self.id).as_str(),
+                    Span::default(),
+                ))
             }
         }
     }
 
-    fn reduce(&self, manager: &mut Manager) -> String {
-        self.address(manager).to_string()
+    fn reduce(&self, manager: &mut Manager) -> CompileResult<Operand> {
+        Ok(Operand::Direct(self.address(manager)?))
     }
 }
 
 pub trait ImmutableVar {
-    fn is_immutable(&self, _: &mut Manager) -> bool {
+    fn is_immutable(&self, _: &mut Manager) -> CompileResult<bool> {
         todo!()
     }
 }
 
 impl ImmutableVar for Access {
-    fn is_immutable(&self, manager: &mut Manager) -> bool {
-        let id_var = manager.get_env_mut().get_var(&self.id.id).cloned();
+    fn is_immutable(&self, manager: &mut Manager) -> CompileResult<bool> {
+        Ok(self.symbol(manager)?.immutable)
+    }
+}
 
-        if let Some(id_var) = id_var {
-            id_var.immutable
-        } else {
-            panic!("Variable {} is not defined", self.id.id);
+pub trait AccessSymbol {
+    fn symbol(&self, _: &mut Manager) -> CompileResult<SymbolEntry> {
+        todo!()
+    }
+}
+
+impl AccessSymbol for Access {
+    /// Returns the entry of the accessed variable, or the entry of the
+    /// accessed field for struct variables.
+    fn symbol(&self, manager: &mut Manager) -> CompileResult<SymbolEntry> {
+        manager.get_env_mut().capture(&self.id.id).at(self.span)?;
+        match manager.get_env().get_var(&self.id.id) {
+            Some(symbol) => Ok(symbol.field(&self.fields).at(self.span)?.clone()),
+            None => Err(CompileError::new(
+                format!("Variable {} is not defined", self.id.id).as_str(),
+                self.span,
+            )),
         }
     }
 }
 
+pub trait FunctionValue {
+    fn function_value(&self, _: &mut Manager) -> CompileResult<Option<DataType>> {
+        todo!()
+    }
+}
+
+impl FunctionValue for Access {
+    /// Returns the type of the function accessed by its id, when a declared
+    /// function is used as a value instead of a variable.
+    fn function_value(&self, manager: &mut Manager) -> CompileResult<Option<DataType>> {
+        let id = &self.id.id;
+        if !self.indexing.is_empty() || !self.fields.is_empty() {
+            return Ok(None);
+        }
+
+        manager.get_env_mut().capture(id).at(self.span)?;
+        if manager.get_env().get_var(id).is_some() {
+            return Ok(None);
+        } else if NativeFunction::resolve(id, manager.get_procedures()).is_some() {
+            return Err(CompileError::new(
+                format!("Native function {} can't be used as a value", id).as_str(),
+                self.span,
+            ));
+        } else if manager.get_func(id).is_err() {
+            return Ok(None);
+        }
+
+        Ok(Some(manager.function_type(id).at(self.span)?))
+    }
+}
+
+/// Returns the native constant accessed by its id, unless a variable
+/// shadows it.
+fn native_constant(access: &Access, manager: &Manager) -> Option<NativeConstant> {
+    if !access.indexing.is_empty()
+        || !access.fields.is_empty()
+        || manager.get_env().get_var(&access.id.id).is_some()
+    {
+        return None;
+    }
+
+    NativeConstant::from_str(&access.id.id).ok()
+}
+
 impl ExpressionNode for Access {
-    fn dimensionality(&self, manager: &mut Manager) -> Vec<usize> {
-        let to_access_shape = self.id.dimensionality(manager);
+    fn dimensionality(&self, manager: &mut Manager) -> CompileResult<Vec<usize>> {
+        if self.function_value(manager)?.is_some() || native_constant(self, manager).is_some() {
+            return Ok(vec![]);
+        }
+
+        let to_access_shape = match self.fields.is_empty() {
+            true => self.id.dimensionality(manager).at(self.span)?,
+            false => self.symbol(manager)?.dimension.shape,
+        };
         let to_access_dims = to_access_shape.len();
         let indexing_dims = self.indexing.len();
 
         if indexing_dims > to_access_dims {
-            panic!();
+            return Err(CompileError::new(
+                format!(
+                    "Can't index {} with {} indices, it has {} dimensions",
+                    self.id.id, indexing_dims, to_access_dims
+                )
+                .as_str(),
+                self.span,
+            ));
         }
 
-        let slice_dims = to_access_dims - indexing_dims;
-
-        if indexing_dims >= to_access_dims {
-            vec![]
-        } else {
-            to_access_shape[(to_access_dims - slice_dims as usize)..]
-                .to_vec()
-                .clone()
+        // Sliced axes are kept, along with the ones left unindexed
+        let mut shape = vec![];
+        for (axis, index) in self.indexing.iter().enumerate() {
+            if let Index::Range { start, end, step } = index {
+                let size = slice_size(to_access_shape[axis], start, end, step)
+                    .map_err(|message| CompileError::new(message.as_str(), self.span))?;
+                shape.push(size);
+            }
         }
+        shape.extend_from_slice(&to_access_shape[indexing_dims..]);
+
+        Ok(shape)
     }
 
-    fn data_type(&self, manager: &mut Manager) -> DataType {
-        return self.id.data_type(manager);
+    fn data_type(&self, manager: &mut Manager) -> CompileResult<DataType> {
+        if let Some(data_type) = self.function_value(manager)? {
+            return Ok(data_type);
+        } else if native_constant(self, manager).is_some() {
+            return Ok(DataType::Float);
+        } else if !self.fields.is_empty() {
+            return Ok(self.symbol(manager)?.data_type);
+        }
+
+        return self.id.data_type(manager).at(self.span);
     }
 
-    fn reduce(&self, manager: &mut Manager) -> String {
-        let access_item: SymbolEntry;
-        let id_var = manager.get_env_mut().get_var(&self.id.id).cloned();
-        if let Some(entry) = id_var {
-            access_item = entry;
-        } else {
-            panic!("Item {} does not exist!", self.id.id);
+    fn reduce(&self, manager: &mut Manager) -> CompileResult<Operand> {
+        // Declared functions are used through a closure without captures
+        if let Some(data_type) = self.function_value(manager)? {
+            let closure = Operand::Direct(manager.new_temp(&data_type));
+            manager.emit(Instruction::closure(
+                self.id.id.as_str(),
+                vec![],
+                closure.clone(),
+            ));
+            return Ok(closure);
+        } else if let Some(constant) = native_constant(self, manager) {
+            let value = Const::new(constant.value().to_string().as_str(), DataType::Float);
+            return Ok(Operand::Direct(
+                manager.new_constant(&DataType::Float, &value),
+            ));
+        }
+
+        // Fields are stored apart, so the accessed field is indexed directly
+        let access_item = self.symbol(manager)?;
+
+        if let DataType::Struct(name) = &access_item.data_type {
+            return Err(CompileError::new(
+                format!(
+                    "Struct {} can't be used as a value, access one of its fields instead",
+                    name
+                )
+                .as_str(),
+                self.span,
+            ));
+        } else if let Some(procedure) = &access_item.procedure {
+            manager.function_type(procedure).at(self.span)?;
         }
 
         if self.indexing.len() == 0 {
-            return self.id.address(manager).to_string();
+            return Ok(Operand::Direct(access_item.address));
         } else if access_item.dimension.size == 1 {
-            panic!("Can't index scalar value {}", self.id.id);
+            return Err(CompileError::new(
+                format!("Can't index scalar value {}", self.id.id).as_str(),
+                self.span,
+            ));
+        }
+
+        if self.indexing.len() > access_item.dimension.dimensions as usize {
+            return Err(CompileError::new("Incompatible index!", self.span));
         }
 
-        // Address to values used to index the array
+        // Address to values used to index the array, slices are reduced along
+        // with their axis
         let indexing_addresses = self
             .indexing
             .iter()
-            .map(|index| index.reduce(manager))
-            .collect::<Vec<String>>();
-
-        if indexing_addresses.len() > access_item.dimension.dimensions as usize {
-            panic!("Incompatible index!");
-        }
-
-        let shape_cp = access_item.dimension.shape.clone();
-        let mut array_shape = shape_cp.iter();
-        let acc_tmp = manager.new_temp(&DataType::Pointer).to_string();
+            .map(|index| match index {
+                Index::Simple(index) => index.reduce(manager).map(Some),
+                Index::Range { .. } => Ok(None),
+            })
+            .collect::<CompileResult<Vec<Option<Operand>>>>()?;
+
+        let mut layout = ArrayLayout::new(&access_item.dimension, access_item.address);
+        let acc_tmp = Operand::Direct(manager.new_temp(&DataType::Pointer));
         let mut first_run = true;
 
-        zip(&indexing_addresses, &access_item.dimension.acc_size).for_each(|(index, dim_size)| {
-            if let Some(dim) = array_shape.next() {
-                manager.emit(Quadruple::verify(index.as_str(), dim.to_string().as_str()))
-            }
+        // Sizes of the sliced axes and the steps taken over them
+        let mut slices: Vec<(usize, Operand, Option<Operand>)> = vec![];
 
-            let dim_const = manager.new_constant(
-                &DataType::Pointer,
-                &Const::new(dim_size.to_string().as_str(), DataType::Pointer),
-            );
-            let dim_const = dim_const.to_string();
+        for (axis, (index, address)) in zip(&self.indexing, &indexing_addresses).enumerate() {
+            let index = match (index, address) {
+                (Index::Range { start, end, step }, None) => {
+                    let (start, size, step) = layout.slice(manager, axis, (start, end, step))?;
+                    slices.push((axis, size, step));
+                    start
+                }
+                (_, index) => {
+                    let index = index.clone().unwrap();
+                    match access_item.dimension.shape[axis] {
+                        0 => {
+                            let bound = layout.length(manager, axis);
+                            manager.emit(Instruction::verify_bound(index.clone(), bound));
+                        }
+                        size => manager.emit(Instruction::verify(index.clone(), size)),
+                    }
+                    index
+                }
+            };
+
+            let dim_const = layout.stride(manager, axis);
 
             if first_run {
-                manager.emit(Quadruple::operation(
+                manager.emit(Instruction::operation(
                     Operator::Mul,
-                    index.as_str(),
-                    dim_const.as_str(),
-                    acc_tmp.as_str(),
+                    index.clone(),
+                    dim_const,
+                    acc_tmp.clone(),
                 ));
                 first_run = false;
             } else {
-                let tmp = manager.new_temp(&DataType::Pointer);
-                let tmp_str = tmp.to_string();
+                let tmp = Operand::Direct(manager.new_temp(&DataType::Pointer));
 
-                manager.emit(Quadruple::operation(
+                manager.emit(Instruction::operation(
                     Operator::Mul,
-                    index.as_str(),
-                    dim_const.as_str(),
-                    tmp_str.as_str(),
+                    index.clone(),
+                    dim_const,
+                    tmp.clone(),
                 ));
 
-                manager.emit(Quadruple::operation(
+                manager.emit(Instruction::operation(
                     Operator::Add,
-                    acc_tmp.as_str(),
-                    tmp_str.as_str(),
-                    acc_tmp.as_str(),
+                    acc_tmp.clone(),
+                    tmp,
+                    acc_tmp.clone(),
                 ));
             }
-        });
+        }
 
         let access_tmp = manager.new_temp(&DataType::Pointer);
 
-        manager.emit(Quadruple::operation(
+        manager.emit(Instruction::operation(
             Operator::Add,
-            access_item.address.to_string().as_str(),
-            acc_tmp.as_str(),
-            access_tmp.to_string().as_str(),
+            Operand::Direct(access_item.address),
+            acc_tmp,
+            Operand::Direct(access_tmp),
         ));
 
-        if self.indexing.len() == access_item.dimension.dimensions as usize {
-            format!("*{}", access_tmp)
+        if !slices.is_empty() {
+            // Slices take every step-th item of their axis
+            let (mut sizes, mut strides) = (vec![], vec![]);
+            for (axis, size, step) in slices {
+                let stride = layout.stride(manager, axis);
+                let stride = match step {
+                    Some(step) => {
+                        let tmp = Operand::Direct(manager.new_temp(&DataType::Pointer));
+                        manager.emit(Instruction::operation(
+                            Operator::Mul,
+                            stride,
+                            step,
+                            tmp.clone(),
+                        ));
+                        tmp
+                    }
+                    None => stride,
+                };
+                sizes.push(size);
+                strides.push(stride);
+            }
+
+            for axis in self.indexing.len()..access_item.dimension.shape.len() {
+                sizes.push(layout.length(manager, axis));
+                strides.push(layout.stride(manager, axis));
+            }
+
+            let view = Operand::Direct(manager.new_temp(&DataType::Pointer));
+            manager.emit(Instruction::view(
+                Operand::Direct(access_tmp),
+                sizes,
+                strides,
+                view.clone(),
+            ));
+            Ok(view)
+        } else if self.indexing.len() == access_item.dimension.dimensions as usize {
+            Ok(Operand::Indirect(access_tmp))
+        } else {
+            Ok(Operand::Direct(access_tmp))
+        }
+    }
+}
+
+/// Number of items taken by the slice of an axis, 0 when its bounds are only
+/// known at runtime.
+fn slice_size(
+    length: usize,
+    start: &Option<Box<Expression>>,
+    end: &Option<Box<Expression>>,
+    step: &Option<Box<Expression>>,
+) -> Result<usize, String> {
+    let constant = |bound: &Option<Box<Expression>>, default: Option<usize>| match bound.as_deref()
+    {
+        None => default,
+        Some(Expression::Const(constant)) if constant.dtype == DataType::Int => {
+            constant.value.parse::<usize>().ok()
+        }
+        Some(_) => None,
+    };
+    let length = (length > 0).then_some(length);
+
+    match (
+        constant(start, Some(0)),
+        constant(end, length),
+        constant(step, Some(1)),
+    ) {
+        (_, _, Some(0)) => Err(String::from("The step of a slice must be positive")),
+        (Some(start), Some(end), _) if end <= start => {
+            Err(format!("Slice {}:{} takes no items", start, end))
+        }
+        (_, Some(end), _) if length.is_some_and(|length| end > length) => Err(format!(
+            "Slice end {} is out of bounds of an axis of size {}",
+            end,
+            length.unwrap()
+        )),
+        (Some(start), Some(end), Some(step)) => Ok((end - start).div_ceil(step)),
+        _ => Ok(0),
+    }
+}
+
+/// Bounds of the slice of an axis, `(start, end, step)`.
+type SliceBounds<'a> = (
+    &'a Option<Box<Expression>>,
+    &'a Option<Box<Expression>>,
+    &'a Option<Box<Expression>>,
+);
+
+/// Sizes and strides of the axes of an indexed array, the ones only known at
+/// runtime are read from the array once.
+struct ArrayLayout {
+    array: Operand,
+    shape: Vec<usize>,
+    acc_size: Vec<usize>,
+    lengths: Vec<Option<Operand>>,
+    strides: Vec<Option<Operand>>,
+}
+
+impl ArrayLayout {
+    fn new(dimension: &Dimension, address: MemAddress) -> ArrayLayout {
+        ArrayLayout {
+            array: Operand::Direct(address),
+            shape: dimension.shape.clone(),
+            acc_size: dimension.acc_size.clone(),
+            lengths: vec![None; dimension.shape.len()],
+            strides: vec![None; dimension.shape.len()],
+        }
+    }
+
+    fn constant(manager: &mut Manager, data_type: &DataType, value: usize) -> Operand {
+        Operand::Direct(manager.new_constant(
+            data_type,
+            &Const::new(value.to_string().as_str(), data_type.clone()),
+        ))
+    }
+
+    /// Size of an axis, read from the array if only known at runtime.
+    fn length(&mut self, manager: &mut Manager, axis: usize) -> Operand {
+        if let Some(length) = &self.lengths[axis] {
+            return length.clone();
+        }
+
+        let length = match self.shape[axis] {
+            0 => {
+                let length = Operand::Direct(manager.new_temp(&DataType::Int));
+                manager.emit(Instruction::len(
+                    self.array.clone(),
+                    axis,
+                    self.shape.len(),
+                    length.clone(),
+                ));
+                length
+            }
+            size => ArrayLayout::constant(manager, &DataType::Int, size),
+        };
+        self.lengths[axis] = Some(length.clone());
+        length
+    }
+
+    /// Number of items between consecutive indices of an axis.
+    fn stride(&mut self, manager: &mut Manager, axis: usize) -> Operand {
+        if let Some(stride) = &self.strides[axis] {
+            return stride.clone();
+        }
+
+        let stride = if self.shape[axis + 1..].contains(&0) {
+            let stride = Operand::Direct(manager.new_temp(&DataType::Pointer));
+            let first = self.length(manager, axis + 1);
+            manager.emit(Instruction::unary(Operator::Assign, first, stride.clone()));
+
+            for next_axis in axis + 2..self.shape.len() {
+                let next = self.length(manager, next_axis);
+                manager.emit(Instruction::operation(
+                    Operator::Mul,
+                    stride.clone(),
+                    next,
+                    stride.clone(),
+                ));
+            }
+
+            stride
         } else {
-            format!("{}", access_tmp)
+            ArrayLayout::constant(manager, &DataType::Pointer, self.acc_size[axis])
+        };
+
+        self.strides[axis] = Some(stride.clone());
+        stride
+    }
+
+    /// Emits the bounds of the slice of an axis, returning its start, its
+    /// size and its step when one is given.
+    fn slice(
+        &mut self,
+        manager: &mut Manager,
+        axis: usize,
+        (start, end, step): SliceBounds,
+    ) -> CompileResult<(Operand, Operand, Option<Operand>)> {
+        let length = self.length(manager, axis);
+
+        let start = match start {
+            Some(start) => {
+                let start = start.reduce(manager)?;
+                manager.emit(Instruction::verify_bound(start.clone(), length.clone()));
+                start
+            }
+            None => ArrayLayout::constant(manager, &DataType::Int, 0),
+        };
+
+        // The end can be right after the last item
+        let end = match end {
+            Some(end) => {
+                let end = end.reduce(manager)?;
+                let bound = Operand::Direct(manager.new_temp(&DataType::Int));
+                let one = ArrayLayout::constant(manager, &DataType::Int, 1);
+                manager.emit(Instruction::operation(
+                    Operator::Add,
+                    length,
+                    one,
+                    bound.clone(),
+                ));
+                manager.emit(Instruction::verify_bound(end.clone(), bound));
+                end
+            }
+            None => length,
+        };
+
+        let size = Operand::Direct(manager.new_temp(&DataType::Int));
+        manager.emit(Instruction::operation(
+            Operator::Sub,
+            end,
+            start.clone(),
+            size.clone(),
+        ));
+
+        let step = match step {
+            Some(step) => step.reduce(manager)?,
+            None => return Ok((start, size, None)),
+        };
+
+        // Rounds up the number of steps taken within the slice
+        let one = ArrayLayout::constant(manager, &DataType::Int, 1);
+        for (operator, value) in [
+            (Operator::Add, step.clone()),
+            (Operator::Sub, one),
+            (Operator::Div, step.clone()),
+        ] {
+            manager.emit(Instruction::operation(
+                operator,
+                size.clone(),
+                value,
+                size.clone(),
+            ));
         }
+
+        Ok((start, size, Some(step)))
+    }
+}
+
+impl ExpressionNode for Vector {
+    fn dimensionality(&self, manager: &mut Manager) -> CompileResult<Vec<usize>> {
+        let shape = self
+            .shape()
+            .map_err(|message| CompileError::new(message.as_str(), self.span))?;
+
+        for value in self.values() {
+            if !value.dimensionality(manager)?.is_empty() {
+                return Err(CompileError::new(
+                    "Items of array literals must be scalar values",
+                    value.span(),
+                ));
+            }
+        }
+
+        Ok(shape)
+    }
+
+    fn data_type(&self, manager: &mut Manager) -> CompileResult<DataType> {
+        let mut data_type: Option<DataType> = None;
+
+        for value in self.values() {
+            let value_type = value.data_type(manager)?;
+            data_type = match data_type {
+                None => Some(value_type),
+                Some(current) => match DataType::equivalent(&current, &value_type) {
+                    Ok(common) => Some(common),
+                    Err(_) => {
+                        return Err(CompileError::new(
+                            format!(
+                                "Array literal items of types {:?} and {:?} can't be mixed",
+                                current, value_type
+                            )
+                            .as_str(),
+                            value.span(),
+                        ))
+                    }
+                },
+            };
+        }
+
+        data_type.ok_or(CompileError::new(
+            "Empty array literals are not supported",
+            self.span,
+        ))
+    }
+
+    /// Stores the literal in a new temporal array, returns the pointer to
+    /// its first item.
+    fn reduce(&self, manager: &mut Manager) -> CompileResult<Operand> {
+        let shape = self.dimensionality(manager)?;
+        let data_type = self.data_type(manager)?;
+
+        let pointer = manager.new_temp_array(&data_type, &shape).at(self.span)?;
+        let pointer = Operand::Direct(pointer);
+        self.store(manager, &pointer, &data_type)?;
+
+        Ok(pointer)
+    }
+}
+
+pub trait StoreVector {
+    fn store(&self, _: &mut Manager, _: &Operand, _: &DataType) -> CompileResult<()> {
+        todo!()
+    }
+}
+
+impl StoreVector for Vector {
+    /// Stores the values of the literal into the items of an array, given
+    /// the pointer to its first item.
+    ///
+    /// Every value is evaluated before storing them, so values read from
+    /// the same array are not overwritten.
+    fn store(
+        &self,
+        manager: &mut Manager,
+        target: &Operand,
+        data_type: &DataType,
+    ) -> CompileResult<()> {
+        let mut operands = vec![];
+        for value in self.values() {
+            let value_type = value.data_type(manager)?;
+            if DataType::equivalent(&value_type, data_type).is_err() {
+                return Err(CompileError::new(
+                    format!(
+                        "Data type {:?} cannot be assigned to an array of {:?}.",
+                        value_type, data_type
+                    )
+                    .as_str(),
+                    value.span(),
+                ));
+            }
+
+            let mut operand = value.reduce(manager)?;
+            if value_type != *data_type {
+                operand = manager.emit_cast(data_type, operand);
+            } else if let Operand::Indirect(_) = operand {
+                let copy = Operand::Direct(manager.new_temp(data_type));
+                manager.emit(Instruction::unary(Operator::Assign, operand, copy.clone()));
+                operand = copy;
+            }
+
+            operands.push(operand);
+        }
+
+        for (index, operand) in operands.into_iter().enumerate() {
+            let offset = manager.new_constant(
+                &DataType::Pointer,
+                &Const::new(index.to_string().as_str(), DataType::Pointer),
+            );
+            let item = manager.new_temp(&DataType::Pointer);
+            manager.emit(Instruction::operation(
+                Operator::Add,
+                target.clone(),
+                Operand::Direct(offset),
+                Operand::Direct(item),
+            ));
+            manager.emit(Instruction::unary(
+                Operator::Assign,
+                operand,
+                Operand::Indirect(item),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+pub trait Callee {
+    fn callee(&self, _: &mut Manager) -> CompileResult<(String, Option<MemAddress>)> {
+        todo!()
+    }
+
+    fn value_callee(
+        &self,
+        _: &mut Manager,
+    ) -> CompileResult<Option<(MemAddress, Vec<DataType>, DataType)>> {
+        todo!()
+    }
+
+    fn reduce_value_call(
+        &self,
+        _: &mut Manager,
+        _: MemAddress,
+        _: &[DataType],
+        _: &DataType,
+    ) -> CompileResult<Operand> {
+        todo!()
+    }
+}
+
+impl Callee for Call {
+    /// Returns the id of the called procedure, along with the address of the
+    /// closure to call it through for nested functions.
+    fn callee(&self, manager: &mut Manager) -> CompileResult<(String, Option<MemAddress>)> {
+        manager.get_env_mut().capture(&self.id).at(self.span)?;
+
+        match manager.get_env().get_var(&self.id) {
+            Some(SymbolEntry {
+                procedure: Some(procedure),
+                address,
+                ..
+            }) => Ok((procedure.clone(), Some(*address))),
+            _ => Ok((self.id.clone(), None)),
+        }
+    }
+
+    /// Returns the address of the function value called, along with the
+    /// types of its parameters and return value, for variables holding
+    /// function values.
+    fn value_callee(
+        &self,
+        manager: &mut Manager,
+    ) -> CompileResult<Option<(MemAddress, Vec<DataType>, DataType)>> {
+        manager.get_env_mut().capture(&self.id).at(self.span)?;
+
+        match manager.get_env().get_var(&self.id) {
+            Some(SymbolEntry {
+                procedure: None,
+                data_type: DataType::Function(params, return_type),
+                address,
+                ..
+            }) => Ok(Some((*address, params.clone(), *return_type.clone()))),
+            _ => Ok(None),
+        }
+    }
+
+    /// Calls a function value, which only takes and returns scalars. The
+    /// returned value is stored by the call itself, since the function
+    /// called is only known at runtime.
+    fn reduce_value_call(
+        &self,
+        manager: &mut Manager,
+        closure: MemAddress,
+        params: &[DataType],
+        return_type: &DataType,
+    ) -> CompileResult<Operand> {
+        if self.params.len() != params.len() {
+            return Err(CompileError::new(
+                format!(
+                    "Function {} takes {} parameters, {} were provided",
+                    self.id,
+                    params.len(),
+                    self.params.len()
+                )
+                .as_str(),
+                self.span,
+            ));
+        }
+
+        manager.emit(Instruction::era(self.id.as_str()));
+
+        for (index, (param, def_param_data_type)) in zip(&self.params, params).enumerate() {
+            if !param.dimensionality(manager)?.is_empty() {
+                return Err(CompileError::new(
+                    format!("Function {} can't take arrays", self.id).as_str(),
+                    param.span(),
+                ));
+            }
+
+            let mut param_address = param.reduce(manager)?;
+            let param_data_type = param.data_type(manager)?;
+
+            if DataType::equivalent(&param_data_type, def_param_data_type).is_err() {
+                return Err(CompileError::new(
+                    format!(
+                        "Data type {:?} cannot be assigned to a parameter {:?}.",
+                        param_data_type, def_param_data_type
+                    )
+                    .as_str(),
+                    param.span(),
+                ));
+            }
+
+            if param_data_type != *def_param_data_type {
+                param_address = manager.emit_cast(def_param_data_type, param_address);
+            }
+
+            manager.emit(Instruction::param(param_address, index));
+        }
+
+        let return_value = match return_type {
+            DataType::Void => None,
+            _ => Some(Operand::Direct(manager.new_temp(return_type))),
+        };
+        manager.emit(Instruction::call(
+            Operand::Direct(closure),
+            return_value.clone(),
+        ));
+
+        Ok(return_value.unwrap_or(Operand::Const(Literal::Void)))
     }
 }
 
 impl ExpressionNode for Call {
-    fn data_type(&self, manager: &mut Manager) -> DataType {
-        if let Some(data_type) = NativeFunction::data_type(&self.id, manager) {
-            data_type
+    fn dimensionality(&self, manager: &mut Manager) -> CompileResult<Vec<usize>> {
+        if self.value_callee(manager)?.is_some() {
+            return Ok(vec![]);
+        } else if let Some(shape) = NativeFunction::dimensionality(self, manager)? {
+            return Ok(shape);
+        }
+
+        let (procedure, _) = self.callee(manager)?;
+        match manager.get_env().entries.get(&procedure) {
+            Some(entry) => Ok(entry.return_dimension.shape.clone()),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn data_type(&self, manager: &mut Manager) -> CompileResult<DataType> {
+        if let Some((_, _, return_type)) = self.value_callee(manager)? {
+            Ok(return_type)
+        } else if let Some(data_type) = NativeFunction::data_type(self, manager)? {
+            Ok(data_type)
         } else {
-            manager.get_func(&self.id).return_type.clone()
+            let (procedure, _) = self.callee(manager)?;
+            Ok(manager
+                .get_func(&procedure)
+                .at(self.span)?
+                .return_type
+                .clone())
         }
     }
 
-    fn reduce(&self, manager: &mut Manager) -> String {
-        if let Some(return_value) = NativeFunction::call_reduce(self, manager) {
-            return return_value;
+    fn reduce(&self, manager: &mut Manager) -> CompileResult<Operand> {
+        if let Some((closure, params, return_type)) = self.value_callee(manager)? {
+            return self.reduce_value_call(manager, closure, &params, &return_type);
+        } else if let Some(return_value) = NativeFunction::call_reduce(self, manager)? {
+            return Ok(return_value);
         }
 
-        let func = manager.get_func(&self.id).clone();
+        let (procedure, closure) = self.callee(manager)?;
+        let func = manager.get_func(&procedure).at(self.span)?.clone();
         let return_type = func.return_type.clone();
         let param_defintions = func.params.clone();
 
-        let target_params_len = param_defintions.len();
+        // Entries of the declared parameters, struct parameters are passed
+        // as one parameter for each of their fields
+        let param_symbols = match manager.get_env().entries.get(&procedure) {
+            Some(entry) => entry
+                .params
+                .iter()
+                .filter_map(|id| entry.symbols.get(id).cloned())
+                .collect::<Vec<SymbolEntry>>(),
+            None => vec![],
+        };
+
+        let target_params_len = param_symbols.len();
         if self.params.len() != target_params_len {
-            panic!(
-                "Params size do not match {} {} - {}",
-                self.id,
-                self.params.len(),
-                target_params_len
-            );
+            return Err(CompileError::new(
+                format!(
+                    "Function {} takes {} parameters, {} were provided",
+                    self.id,
+                    target_params_len,
+                    self.params.len()
+                )
+                .as_str(),
+                self.span,
+            ));
         }
 
-        manager.emit(Quadruple::era(self.id.as_str()));
+        manager.emit(Instruction::era(procedure.as_str()));
 
-        for (index, param) in self.params.iter().enumerate() {
-            let (_, def_param_data_type, _) = param_defintions.get(index).unwrap();
+        let mut index = 0;
+        for (param, param_symbol) in zip(&self.params, &param_symbols) {
+            if let DataType::Struct(_) = param_symbol.data_type {
+                let param_data_type = param.data_type(manager)?;
+                let access = match param.as_ref() {
+                    Expression::Access(access) if param_data_type == param_symbol.data_type => {
+                        access
+                    }
+                    _ => {
+                        return Err(CompileError::new(
+                            format!(
+                                "Data type {:?} cannot be assigned to a parameter {:?}.",
+                                param_data_type, param_symbol.data_type
+                            )
+                            .as_str(),
+                            param.span(),
+                        ))
+                    }
+                };
 
-            if def_param_data_type == &DataType::Pointer && param.dimensionality(manager).len() > 0
-            {
-                let param_address = param.reduce(manager);
-                manager.emit(Quadruple::param(param_address.as_str(), index));
+                for (path, _) in param_symbol.leaves() {
+                    let param_address = access.field(&path).reduce(manager)?;
+                    manager.emit(Instruction::param(param_address, index));
+                    index += 1;
+                }
                 continue;
             }
 
-            let mut param_address = param.reduce(manager);
-            let param_data_type = param.data_type(manager);
+            let (_, def_param_data_type, _) = param_defintions.get(index).unwrap();
+            let param_shape = param.dimensionality(manager)?;
+            let symbol_shape = &param_symbol.dimension.shape;
+
+            // Natives taking arrays accept any shape, checked when running
+            let is_native_array =
+                def_param_data_type == &DataType::Pointer && symbol_shape.is_empty();
+            if is_native_array && param_shape.is_empty() {
+                return Err(CompileError::new(
+                    format!("Function {} does not accept scalar values", self.id).as_str(),
+                    param.span(),
+                ));
+            } else if !is_native_array && !SemanticRules::match_shape(&param_shape, symbol_shape) {
+                return Err(CompileError::new(
+                    format!(
+                        "Can't pass item with dimensions {:?} to a parameter with dimensions {:?}",
+                        param_shape, symbol_shape
+                    )
+                    .as_str(),
+                    param.span(),
+                ));
+            }
 
-            assert!(
-                DataType::equivalent(&param_data_type, def_param_data_type).is_ok(),
-                "Data type {:?} cannot be assigned to a variable {:?}.",
-                param_data_type,
-                def_param_data_type
-            );
+            // Arrays are passed by reference, so their items are never cast
+            if !param_shape.is_empty() {
+                let param_data_type = param.data_type(manager)?;
+                if !symbol_shape.is_empty() && param_data_type != param_symbol.data_type {
+                    return Err(CompileError::new(
+                        format!(
+                            "Array of {:?} items cannot be assigned to a parameter of {:?} items.",
+                            param_data_type, param_symbol.data_type
+                        )
+                        .as_str(),
+                        param.span(),
+                    ));
+                }
 
-            // TODO: Refactor type casting instruction into func
-            if param_data_type != *def_param_data_type {
-                let value_temp = manager.new_temp(&def_param_data_type).to_string();
+                let param_address = param.reduce(manager)?;
+                manager.emit(Instruction::param(param_address, index));
+                index += 1;
+                continue;
+            }
 
-                manager.emit(Quadruple::type_cast(
-                    &def_param_data_type,
-                    param_address.as_str(),
-                    value_temp.as_str(),
+            let mut param_address = param.reduce(manager)?;
+            let param_data_type = param.data_type(manager)?;
+
+            if DataType::equivalent(&param_data_type, def_param_data_type).is_err() {
+                return Err(CompileError::new(
+                    format!(
+                        "Data type {:?} cannot be assigned to a parameter {:?}.",
+                        param_data_type, def_param_data_type
+                    )
+                    .as_str(),
+                    param.span(),
                 ));
+            }
 
-                param_address = value_temp.clone();
+            // TODO: Refactor type casting instruction into func
+            if param_data_type != *def_param_data_type {
+                param_address = manager.emit_cast(&def_param_data_type, param_address);
             }
 
-            manager.emit(Quadruple::param(param_address.as_str(), index));
+            manager.emit(Instruction::param(param_address, index));
+            index += 1;
         }
 
-        manager.emit(Quadruple::go_sub(self.id.as_str()));
+        // Natives taking an array are given its rank, so rows of arrays are
+        // read alone
+        let is_native = NativeFunction::resolve(&procedure, manager.get_procedures()).is_some();
+        if let (true, Some((_, DataType::Pointer, _)), Some(array)) =
+            (is_native, param_defintions.first(), self.params.first())
+        {
+            let rank = array.dimensionality(manager)?.len();
+            NativeFunction::emit_rank(rank, index, manager);
+        }
 
-        if let Some(func_return_address) = manager.get_func_return(&self.id) {
-            let return_value = manager.new_temp(&return_type).to_string();
-            manager.emit(Quadruple::unary(
+        match closure {
+            Some(closure) => manager.emit(Instruction::call(Operand::Direct(closure), None)),
+            None => manager.emit(Instruction::go_sub(procedure.as_str())),
+        }
+
+        let (return_shape, return_buffer) = match manager.get_env().entries.get(&procedure) {
+            Some(entry) => (entry.return_dimension.shape.clone(), entry.return_buffer),
+            None => (vec![], None),
+        };
+
+        if let Some(func_return_address) = manager.get_func_return(&procedure).at(self.span)? {
+            if return_buffer.is_some() {
+                // Copies the returned array before another call overwrites it
+                let return_value = Operand::Direct(
+                    manager
+                        .new_temp_array(&return_type, &return_shape)
+                        .at(self.span)?,
+                );
+                let size = manager.array_size(&return_value, &return_shape, 0);
+                manager.emit(Instruction::copy(
+                    Operand::Direct(func_return_address),
+                    size,
+                    return_value.clone(),
+                ));
+                return Ok(return_value);
+            } else if !return_shape.is_empty() {
+                // Arrays returned by reference are read through the pointer
+                let return_value = Operand::Direct(manager.new_temp(&DataType::Pointer));
+                manager.emit(Instruction::unary(
+                    Operator::Assign,
+                    Operand::Direct(func_return_address),
+                    return_value.clone(),
+                ));
+                return Ok(return_value);
+            }
+
+            let return_value = Operand::Direct(manager.new_temp(&return_type));
+            manager.emit(Instruction::unary(
                 Operator::Assign,
-                func_return_address.to_string().as_str(),
-                return_value.as_str(),
+                Operand::Direct(func_return_address),
+                return_value.clone(),
             ));
-            return_value
+            Ok(return_value)
         } else {
-            String::from("VOID")
+            Ok(Operand::Const(Literal::Void))
         }
     }
 }
+
+pub trait LambdaSignature {
+    fn signature(&self, _: &mut Manager) -> CompileResult<FunctionSignature> {
+        todo!()
+    }
+}
+
+impl LambdaSignature for Lambda {
+    /// Returns the signature of the function generated for a lambda, which
+    /// returns the type of its body.
+    ///
+    /// The body is typed within a function discarded afterwards, so it can
+    /// read the lambda parameters and the variables around it.
+    fn signature(&self, manager: &mut Manager) -> CompileResult<FunctionSignature> {
+        for FunctionParam(param) in self.params.iter() {
+            if param.dimension.dimensions > 0 || matches!(param.data_type, DataType::Struct(_)) {
+                return Err(CompileError::new(
+                    "Lambdas can't take arrays or structs",
+                    param.span,
+                ));
+            }
+        }
+
+        let mut signature = FunctionSignature {
+            id: manager.nested_func_id(&String::from("lambda")),
+            params: self.params.clone(),
+            data_type: DataType::Void,
+            dimension: Dimension::new_scalar(),
+            is_native: false,
+            span: self.span,
+        };
+
+        let parent = manager.get_env().current_env.clone();
+        manager.get_env_mut().suspend();
+        if let Err(error) = manager.new_func(&signature, 0, None, true) {
+            manager.get_env_mut().resume();
+            return Err(error);
+        }
+        manager
+            .get_env_mut()
+            .entries
+            .get_mut(&signature.id)
+            .unwrap()
+            .parent = Some(parent);
+
+        let body_type = self.body.data_type(manager);
+        let body_dims = self.body.dimensionality(manager);
+
+        manager.get_env_mut().resume();
+        manager.drop_func(&signature.id);
+
+        if !body_dims?.is_empty() {
+            return Err(CompileError::new(
+                "Lambdas can't return arrays",
+                self.body.span(),
+            ));
+        }
+
+        signature.data_type = body_type?;
+        Ok(signature)
+    }
+}
+
+impl ExpressionNode for Lambda {
+    fn dimensionality(&self, _: &mut Manager) -> CompileResult<Vec<usize>> {
+        Ok(vec![])
+    }
+
+    fn data_type(&self, manager: &mut Manager) -> CompileResult<DataType> {
+        Ok(closure_type(&self.signature(manager)?))
+    }
+
+    fn reduce(&self, manager: &mut Manager) -> CompileResult<Operand> {
+        let signature = self.signature(manager)?;
+        let closure = manager.new_temp(&closure_type(&signature));
+
+        let body = match signature.data_type {
+            DataType::Void => Statement::Expression(self.body.clone()),
+            _ => Statement::Return(self.body.clone()),
+        };
+        generate_closure(manager, &signature, None, &mut Block(vec![body]), closure)?;
+
+        Ok(Operand::Direct(closure))
+    }
+}
@@ -1,69 +1,126 @@
 use memory::types::DataType;
 use parser::{
-    expressions::constant::Const, semantics::ExitStatement, statements::Statement, types::Operator,
+    expressions::{constant::Const, Expression},
+    semantics::{ExitStatement, SemanticRules},
+    statements::Statement,
+    types::Operator,
     Dimension,
 };
 
 use crate::{
-    ast_nodes::expressions::ImmutableVar,
+    ast_nodes::expressions::{AccessSymbol, ImmutableVar, StoreVector},
+    errors::{AtSpan, CompileError, CompileResult},
     manager::Manager,
     node::{ExpressionNode, Node},
-    quadruples::{Quadruple, QuadrupleHold},
+    quadruples::{Instruction, Operand, QuadrupleHold},
 };
 
 impl Node for Statement {
-    fn generate(&mut self, manager: &mut Manager) -> () {
+    fn generate(&mut self, manager: &mut Manager) -> CompileResult<()> {
         match self {
-            Statement::VarDeclaration(var) => var.generate(manager),
+            Statement::VarDeclaration(var) => var.generate(manager)?,
             Statement::VarAssign(access, value) => {
                 // TODO: Generalize for assign and var declaration
                 // TODO: Generalize data type casting
-                let value_data_type = value.data_type(manager);
-                let access_data_type = access.data_type(manager);
-
-                let access_dims = access.dimensionality(manager);
-                let value_dims = value.dimensionality(manager);
-                if access_dims != value_dims {
-                    panic!(
-                        "Can't assign item {} with dimensions {:#?} to value with dimensions {:#?}",
-                        access.id.id, access_dims, value_dims
-                    )
-                }
-                assert!(
-                    DataType::equivalent(&access.data_type(manager), &value_data_type).is_ok(),
-                    "Data type {:?} cannot be assigned to a variable {:?}.",
-                    value_data_type,
-                    access_data_type
-                );
+                let value_data_type = value.data_type(manager)?;
+                let access_data_type = access.data_type(manager)?;
+
+                let access_dims = access.dimensionality(manager)?;
+                let value_dims = value.dimensionality(manager)?;
+                if !SemanticRules::match_shape(&access_dims, &value_dims) {
+                    return Err(CompileError::new(
+                        format!(
+                            "Can't assign item {} with dimensions {:?} to value with dimensions {:?}",
+                            access.id.id, access_dims, value_dims
+                        )
+                        .as_str(),
+                        access.span.join(&value.span()),
+                    ));
+                }
+                // Array items are copied as is, without casting them, while
+                // the values of array literals are cast as they are stored
+                let is_literal = matches!(value.as_ref(), Expression::Vector(_));
+                if DataType::equivalent(&access_data_type, &value_data_type).is_err()
+                    || (!access_dims.is_empty()
+                        && !is_literal
+                        && access_data_type != value_data_type)
+                {
+                    return Err(CompileError::new(
+                        format!(
+                            "Data type {:?} cannot be assigned to a variable {:?}.",
+                            value_data_type, access_data_type
+                        )
+                        .as_str(),
+                        value.span(),
+                    ));
+                }
 
-                // Get temporal variable for assignment R-value
-                let mut value_temp = value.reduce(manager);
+                if access.is_immutable(manager)? {
+                    return Err(CompileError::new(
+                        format!("Variable {} can't be mutated", access.id.id).as_str(),
+                        access.span,
+                    ));
+                }
 
-                if access_data_type != value_data_type {
-                    // Emits type casting operation quadruple on r-value type mismatch
-                    let prev_value_temp = value_temp.clone();
-                    value_temp = manager.new_temp(&access_data_type).to_string();
+                // Structs are assigned field by field
+                if let DataType::Struct(_) = access_data_type {
+                    let source = match value.as_ref() {
+                        Expression::Access(source) => source,
+                        _ => {
+                            return Err(CompileError::new(
+                                "Structs can only be assigned from other struct variables",
+                                value.span(),
+                            ))
+                        }
+                    };
+
+                    let symbol = access.symbol(manager)?;
+                    for (path, _) in symbol.leaves() {
+                        Statement::VarAssign(
+                            access.field(&path),
+                            Box::new(Expression::Access(source.field(&path))),
+                        )
+                        .generate(manager)?;
+                    }
+                    return Ok(());
+                }
+
+                // Array literals are stored directly into the items
+                if let Expression::Vector(vector) = value.as_ref() {
+                    let target = access.reduce(manager)?;
+                    return vector.store(manager, &target, &access_data_type);
+                }
 
-                    manager.emit(Quadruple::type_cast(
-                        &access_data_type,
-                        prev_value_temp.as_str(),
-                        value_temp.clone().as_str(),
-                    ))
+                // Whole arrays are assigned by copying their items
+                if !access_dims.is_empty() {
+                    let source = value.reduce(manager)?;
+                    let (size, target) = if access.is_view() {
+                        // Views know the sizes of their own axes
+                        let target = access.reduce(manager)?;
+                        (manager.array_size(&target, &access_dims, 0), target)
+                    } else {
+                        let array = Operand::Direct(access.symbol(manager)?.address);
+                        let size = manager.array_size(&array, &access_dims, access.indexing.len());
+                        (size, access.reduce(manager)?)
+                    };
+
+                    manager.emit(Instruction::copy(source, size, target));
+                    return Ok(());
                 }
 
-                if access.is_immutable(manager) {
-                    panic!("Variable {} can't be mutated", access.id.id);
+                // Get temporal variable for assignment R-value
+                let mut value_temp = value.reduce(manager)?;
+
+                if access_data_type != value_data_type {
+                    // Emits type casting operation quadruple on r-value type mismatch
+                    value_temp = manager.emit_cast(&access_data_type, value_temp);
                 }
 
-                let access = access.reduce(manager);
+                let access = access.reduce(manager)?;
 
-                manager.emit(Quadruple::unary(
-                    Operator::Assign,
-                    value_temp.as_str(),
-                    access.as_str(),
-                ));
+                manager.emit(Instruction::unary(Operator::Assign, value_temp, access));
             }
-            Statement::Expression(exp) => exp.generate(manager),
+            Statement::Expression(exp) => exp.generate(manager)?,
             Statement::If {
                 condition,
                 if_block,
@@ -77,16 +134,19 @@ impl Node for Statement {
                 //  4. [goto if condition was true, jumps after 5.]
                 //  5. [else-block instruction]
 
-                let mut condition_id = condition.reduce(manager);
-                let condition_dt = condition.data_type(manager);
+                let mut condition_id = condition.reduce(manager)?;
+                let condition_dt = condition.data_type(manager)?;
                 if condition_dt != DataType::Bool {
-                    condition_id = manager.emit_cast(&DataType::Bool, condition_id.as_str());
+                    condition_id = manager.emit_cast(&DataType::Bool, condition_id);
                 }
 
                 // goto instruction to skip if-true block
                 let mut goto_if_false_quad = QuadrupleHold::new(manager);
 
-                if_block.generate(manager);
+                manager.open_scope();
+                let block_result = if_block.generate(manager);
+                manager.close_scope();
+                block_result?;
 
                 if let Some(block) = else_block {
                     // goto instruction to skip else block if condition was true
@@ -96,19 +156,22 @@ impl Node for Statement {
                     let goto_false_jump = manager.get_next_pos();
                     goto_if_false_quad.release(
                         manager,
-                        Quadruple::goto_false(&condition_id, goto_false_jump),
+                        Instruction::goto_false(condition_id, goto_false_jump),
                     );
 
-                    block.generate(manager);
+                    manager.open_scope();
+                    let block_result = block.generate(manager);
+                    manager.close_scope();
+                    block_result?;
 
                     // Update goto to skip else block
                     let end_pos = manager.get_next_pos();
-                    goto_end_block.release(manager, Quadruple::goto(end_pos));
+                    goto_end_block.release(manager, Instruction::goto(end_pos));
                 } else {
                     // Update goto to skip if false
                     let end_pos = manager.get_next_pos();
                     goto_if_false_quad
-                        .release(manager, Quadruple::goto_false(&condition_id, end_pos));
+                        .release(manager, Instruction::goto_false(condition_id, end_pos));
                 }
             }
             Statement::For {
@@ -117,138 +180,254 @@ impl Node for Statement {
                 block,
             } => {
                 let (start, end, step) = range;
-                let start_dt = start.data_type(manager);
-                let end_dt = start.data_type(manager);
-                let step_dt = if let Some(step) = step {
-                    Some(step.data_type(manager))
-                } else {
-                    None
-                };
+                let start_dt = start.data_type(manager)?;
+                let end_dt = end.data_type(manager)?;
 
                 if DataType::equivalent(&start_dt, &DataType::Int).is_err() {
-                    panic!("For start expression is not numeric");
+                    return Err(CompileError::new(
+                        "For start expression is not numeric",
+                        start.span(),
+                    ));
                 }
                 if DataType::equivalent(&end_dt, &DataType::Int).is_err() {
-                    panic!("For end expression is not numeric");
+                    return Err(CompileError::new(
+                        "For end expression is not numeric",
+                        end.span(),
+                    ));
                 }
-                if let Some(step_dt) = step_dt {
-                    if DataType::equivalent(&step_dt, &DataType::Int).is_err() {
-                        panic!("For step expression is not numeric");
+                if let Some(step) = step {
+                    if DataType::equivalent(&step.data_type(manager)?, &DataType::Int).is_err() {
+                        return Err(CompileError::new(
+                            "For step expression is not numeric",
+                            step.span(),
+                        ));
                     }
                 }
 
-                let start = start.reduce(manager);
-                let end = end.reduce(manager);
+                let start_span = start.span();
+                let start = start.reduce(manager)?;
+                let end = end.reduce(manager)?;
                 let step = if let Some(step) = step {
-                    Some(step.reduce(manager))
+                    Some(step.reduce(manager)?)
                 } else {
                     None
                 };
 
+                // The iterator and the variables declared in the loop are
+                // freed once it ends
+                manager.open_scope();
                 let iterator_address = manager
                     .get_env_mut()
                     .add_var(iterator_id, &DataType::Int, &Dimension::new_scalar(), true)
-                    .to_string();
+                    .at(start_span);
+                let iterator_address = match iterator_address {
+                    Ok(address) => address,
+                    Err(error) => {
+                        manager.close_scope();
+                        return Err(error);
+                    }
+                };
+                let iterator = Operand::Direct(iterator_address);
 
-                manager.emit(Quadruple::unary(
+                manager.emit(Instruction::unary(
                     Operator::Assign,
-                    start.as_str(),
-                    iterator_address.as_str(),
+                    start,
+                    iterator.clone(),
                 ));
 
-                let check_tmp = manager.new_temp(&DataType::Bool).to_string();
+                let check_tmp = Operand::Direct(manager.new_temp(&DataType::Bool));
 
+                // Arrays allocated by an iteration are released before the next
                 let return_position = manager.get_next_pos();
+                manager.release_scope();
 
-                manager.emit(Quadruple::operation(
+                manager.emit(Instruction::operation(
                     Operator::LessThan,
-                    iterator_address.as_str(),
-                    end.as_str(),
-                    check_tmp.as_str(),
+                    iterator.clone(),
+                    end,
+                    check_tmp.clone(),
                 ));
 
                 let mut goto_false_hold = QuadrupleHold::new(manager);
 
-                block.generate(manager);
+                let block_result = block.generate(manager);
 
                 if let Some(step) = step {
-                    manager.emit(Quadruple::operation(
+                    manager.emit(Instruction::operation(
                         Operator::Add,
-                        iterator_address.as_str(),
-                        step.as_str(),
-                        iterator_address.as_str(),
+                        iterator.clone(),
+                        step,
+                        iterator.clone(),
                     ));
                 } else {
-                    let increment_tmp = manager
-                        .new_constant(&DataType::Int, &Const::new("1", DataType::Int))
-                        .to_string();
+                    let increment_tmp = Operand::Direct(
+                        manager.new_constant(&DataType::Int, &Const::new("1", DataType::Int)),
+                    );
 
-                    manager.emit(Quadruple::operation(
+                    manager.emit(Instruction::operation(
                         Operator::Add,
-                        iterator_address.as_str(),
-                        increment_tmp.as_str(),
-                        iterator_address.as_str(),
+                        iterator.clone(),
+                        increment_tmp,
+                        iterator.clone(),
                     ));
                 }
 
-                let to_start_pos_quadruple = Quadruple::goto(return_position);
-                manager.emit(Quadruple::goto(return_position.clone()));
+                let to_start_pos_quadruple = Instruction::goto(return_position);
+                manager.emit(Instruction::goto(return_position.clone()));
 
                 let end_pos = manager.get_next_pos();
 
-                let to_end_pos_quadruple = Quadruple::goto_false(check_tmp.as_str(), end_pos);
+                let to_end_pos_quadruple = Instruction::goto_false(check_tmp, end_pos);
                 goto_false_hold.release(manager, to_end_pos_quadruple.clone());
 
                 manager.resolve_context(&ExitStatement::Continue, to_start_pos_quadruple);
                 manager.resolve_context(&ExitStatement::Break, to_end_pos_quadruple);
 
-                manager.get_env_mut().del_var(iterator_id);
-                manager.emit(Quadruple::free(&iterator_address.as_str()));
+                manager.close_scope();
+
+                block_result?;
             }
             Statement::While { condition, block } => {
+                // The variables declared in the loop are freed once it ends,
+                // the arrays allocated by an iteration before the next
+                manager.open_scope();
                 let start_pos = manager.get_next_pos();
+                manager.release_scope();
 
                 // Temporal storing condition value
-                let mut condition_id = condition.reduce(manager);
-                let condition_dt = condition.data_type(manager);
-                if condition_dt != DataType::Bool {
-                    condition_id = manager.emit_cast(&DataType::Bool, condition_id.as_str());
-                }
+                let condition_id = (|| -> CompileResult<Operand> {
+                    let condition_id = condition.reduce(manager)?;
+                    if condition.data_type(manager)? != DataType::Bool {
+                        return Ok(manager.emit_cast(&DataType::Bool, condition_id));
+                    }
+                    Ok(condition_id)
+                })();
+                let condition_id = match condition_id {
+                    Ok(condition_id) => condition_id,
+                    Err(error) => {
+                        manager.close_scope();
+                        return Err(error);
+                    }
+                };
 
                 // Goto instruction to exit the loop
                 let mut goto_false_cond = QuadrupleHold::new(manager);
 
-                block.generate(manager);
+                let block_result = block.generate(manager);
 
                 // Emit instruction to return to condition evaluation
-                let to_start_pos_quadruple = Quadruple::goto(start_pos);
+                let to_start_pos_quadruple = Instruction::goto(start_pos);
                 manager.emit(to_start_pos_quadruple.clone());
 
                 let end_pos = manager.get_next_pos();
 
-                let to_end_pos_quadruple = Quadruple::goto(end_pos);
+                let to_end_pos_quadruple = Instruction::goto(end_pos);
 
                 // Emit instruction to return to condition evaluation
-                goto_false_cond.release(manager, Quadruple::goto_false(&condition_id, end_pos));
+                goto_false_cond.release(manager, Instruction::goto_false(condition_id, end_pos));
 
                 manager.resolve_context(&ExitStatement::Continue, to_start_pos_quadruple);
                 manager.resolve_context(&ExitStatement::Break, to_end_pos_quadruple);
+
+                manager.close_scope();
+
+                block_result?;
             }
-            Statement::FunctionDeclaration(func) => func.generate(manager),
+            Statement::FunctionDeclaration(func) => func.generate(manager)?,
+            // Structs are declared before generating the program
+            Statement::StructDeclaration(_) => (),
             Statement::Return(ret) => {
-                let mut return_item = ret.reduce(manager);
                 let context = manager.get_env().current_env();
-                assert_eq!(context.is_global, false);
+                if context.is_global {
+                    return Err(CompileError::new(
+                        "Can't return outside of a function",
+                        ret.span(),
+                    ));
+                }
                 let return_type = context.return_type.clone().unwrap();
-                if return_type != ret.data_type(manager) {
-                    return_item = manager.emit_cast(&return_type, return_item.as_str());
+                let return_shape = context.return_dimension.shape.clone();
+                let return_buffer = context.return_buffer;
+                let env_id = context.env_id.clone();
+
+                let ret_shape = ret.dimensionality(manager)?;
+                if !SemanticRules::match_shape(&return_shape, &ret_shape) {
+                    return Err(CompileError::new(
+                        format!(
+                            "Can't return a value with dimensions {:?} from a function returning dimensions {:?}",
+                            ret_shape, return_shape
+                        )
+                        .as_str(),
+                        ret.span(),
+                    ));
+                }
+
+                let ret_type = ret.data_type(manager)?;
+                if DataType::equivalent(&return_type, &ret_type).is_err()
+                    || (!return_shape.is_empty() && return_type != ret_type)
+                {
+                    return Err(CompileError::new(
+                        format!(
+                            "Can't return a {:?} value from a function returning {:?}",
+                            ret_type, return_type
+                        )
+                        .as_str(),
+                        ret.span(),
+                    ));
                 }
 
-                manager.emit(Quadruple::new_return(return_item.as_str()));
+                if let Some(buffer) = return_buffer {
+                    // Arrays returned by value are copied to the global buffer,
+                    // since the items of the function are dropped on return
+                    let value = ret.reduce(manager)?;
+                    let size = manager.array_size(&value, &return_shape, 0);
+                    manager.emit(Instruction::alloc(buffer, &return_shape));
+                    manager.emit(Instruction::copy(value, size, Operand::AddressOf(buffer)));
+                    manager.emit(Instruction::new_return(Operand::AddressOf(buffer)));
+                    return Ok(());
+                } else if !return_shape.is_empty() {
+                    // Only arrays outliving the call can be returned by reference
+                    let outlives_call = match ret.as_ref() {
+                        Expression::Access(access) if access.indexing.is_empty() => {
+                            let is_local =
+                                manager.get_env().current_env().get(&access.id.id).is_some();
+
+                            if is_local {
+                                let symbol = access.symbol(manager)?;
+                                manager
+                                    .get_func(&env_id)
+                                    .map(|func| {
+                                        func.params
+                                            .iter()
+                                            .any(|(address, _, _)| *address == symbol.address)
+                                    })
+                                    .unwrap_or(false)
+                            } else {
+                                true
+                            }
+                        }
+                        _ => false,
+                    };
+
+                    if !outlives_call {
+                        return Err(CompileError::new(
+                            "Arrays returned by reference must be array parameters or global arrays",
+                            ret.span(),
+                        ));
+                    }
+                }
+
+                let mut return_item = ret.reduce(manager)?;
+                if return_type != ret_type {
+                    return_item = manager.emit_cast(&return_type, return_item);
+                }
+
+                manager.emit(Instruction::new_return(return_item));
             }
             Statement::Break => manager.prepare_exit_stmt(&ExitStatement::Break),
             Statement::Continue => manager.prepare_exit_stmt(&ExitStatement::Continue),
-            Statement::VoidReturn => manager.emit(Quadruple::void_return()),
+            Statement::VoidReturn => manager.emit(Instruction::void_return()),
         }
+
+        Ok(())
     }
 }
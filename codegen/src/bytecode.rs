@@ -0,0 +1,912 @@
+//! Binary object file format for compiled programs (`.mobc`).
+//!
+//! Every number is stored little endian, strings are stored as their byte
+//! length (u32) followed by their UTF-8 bytes. The file is laid out as:
+//!
+//! ```text
+//! magic       b"MOBC"
+//! version     u16
+//! source      string
+//! constants   u32 count, then (address u32, constant) entries
+//! procedures  u32 count, then procedure entries
+//! code        u32 count, then (opcode u8, operands, line u32) entries
+//! ```
+//!
+//! The operands of each instruction depend on its opcode, e.g. a binary
+//! operation holds three operands while a function call holds the id of the
+//! function.
+
+use std::collections::HashMap;
+
+use memory::{
+    resolver::{MemAddress, MemoryResolver},
+    types::{DataType, FloatType, IntType},
+};
+use parser::types::Operator;
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::{
+    meta::ProgramMeta,
+    quadruples::{Instruction, Literal, Operand},
+    symbols::{FunctionEntry, ParamAddress},
+};
+
+pub const MAGIC: &[u8; 4] = b"MOBC";
+pub const VERSION: u16 = 1;
+
+/// Instruction codes of the virtual machine, displayed as their quadruple
+/// operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+#[repr(u8)]
+pub enum Opcode {
+    /// Placeholder of an instruction that was never resolved.
+    #[strum(serialize = "")]
+    Nop,
+    #[strum(serialize = "+")]
+    Add,
+    #[strum(serialize = "-")]
+    Sub,
+    #[strum(serialize = "*")]
+    Mul,
+    #[strum(serialize = "/")]
+    Div,
+    #[strum(serialize = ">")]
+    GreaterThan,
+    #[strum(serialize = ">=")]
+    GreaterOrEq,
+    #[strum(serialize = "<")]
+    LessThan,
+    #[strum(serialize = "<=")]
+    LessOrEq,
+    #[strum(serialize = "==")]
+    Eq,
+    #[strum(serialize = "!=")]
+    NotEq,
+    #[strum(serialize = "&&")]
+    And,
+    #[strum(serialize = "||")]
+    Or,
+    #[strum(serialize = "not")]
+    Not,
+    #[strum(serialize = "neg")]
+    Neg,
+    #[strum(serialize = "=")]
+    Assign,
+    #[strum(serialize = "cast")]
+    Cast,
+    #[strum(serialize = "ver")]
+    Verify,
+    #[strum(serialize = "goto")]
+    Goto,
+    #[strum(serialize = "gotoFalse")]
+    GotoFalse,
+    #[strum(serialize = "era")]
+    Era,
+    #[strum(serialize = "param")]
+    Param,
+    #[strum(serialize = "gosub")]
+    GoSub,
+    #[strum(serialize = "return")]
+    Return,
+    #[strum(serialize = "voidReturn")]
+    VoidReturn,
+    #[strum(serialize = "endFunc")]
+    EndFunc,
+    #[strum(serialize = "endProgram")]
+    EndProgram,
+    #[strum(serialize = "print")]
+    Print,
+    #[strum(serialize = "free")]
+    Free,
+    #[strum(serialize = "alloc")]
+    Alloc,
+    #[strum(serialize = "len")]
+    Len,
+    #[strum(serialize = "new")]
+    New,
+    #[strum(serialize = "copy")]
+    Copy,
+    #[strum(serialize = "view")]
+    View,
+    /// Followed by the opcode of the operator applied to each item.
+    #[strum(serialize = "elementwise")]
+    Elementwise,
+    #[strum(serialize = "@")]
+    MatMul,
+    #[strum(serialize = "call")]
+    Call,
+    #[strum(serialize = "closure")]
+    Closure,
+    #[strum(serialize = "mark")]
+    Mark,
+    #[strum(serialize = "release")]
+    Release,
+}
+
+impl Opcode {
+    fn from_byte(byte: u8) -> Option<Opcode> {
+        Opcode::iter().find(|opcode| *opcode as u8 == byte)
+    }
+
+    /// Opcode of the instructions applying an operator.
+    fn from_operator(operator: Operator) -> Option<Opcode> {
+        match operator {
+            Operator::Add => Some(Opcode::Add),
+            Operator::Sub => Some(Opcode::Sub),
+            Operator::Mul => Some(Opcode::Mul),
+            Operator::Div => Some(Opcode::Div),
+            Operator::MatMul => Some(Opcode::MatMul),
+            Operator::GreaterThan => Some(Opcode::GreaterThan),
+            Operator::GreaterOrEq => Some(Opcode::GreaterOrEq),
+            Operator::LessThan => Some(Opcode::LessThan),
+            Operator::LessOrEq => Some(Opcode::LessOrEq),
+            Operator::Eq => Some(Opcode::Eq),
+            Operator::NotEq => Some(Opcode::NotEq),
+            Operator::And => Some(Opcode::And),
+            Operator::Or => Some(Opcode::Or),
+            Operator::Not => Some(Opcode::Not),
+            Operator::Neg => Some(Opcode::Neg),
+            Operator::Assign => Some(Opcode::Assign),
+            Operator::Pipe | Operator::ForwardPipe => None,
+        }
+    }
+
+    fn operator(&self) -> Option<Operator> {
+        match self {
+            Opcode::Add => Some(Operator::Add),
+            Opcode::Sub => Some(Operator::Sub),
+            Opcode::Mul => Some(Operator::Mul),
+            Opcode::Div => Some(Operator::Div),
+            Opcode::MatMul => Some(Operator::MatMul),
+            Opcode::GreaterThan => Some(Operator::GreaterThan),
+            Opcode::GreaterOrEq => Some(Operator::GreaterOrEq),
+            Opcode::LessThan => Some(Operator::LessThan),
+            Opcode::LessOrEq => Some(Operator::LessOrEq),
+            Opcode::Eq => Some(Operator::Eq),
+            Opcode::NotEq => Some(Operator::NotEq),
+            Opcode::And => Some(Operator::And),
+            Opcode::Or => Some(Operator::Or),
+            Opcode::Not => Some(Operator::Not),
+            Opcode::Neg => Some(Operator::Neg),
+            Opcode::Assign => Some(Operator::Assign),
+            _ => None,
+        }
+    }
+}
+
+struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.bytes.extend(value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: usize) {
+        self.bytes.extend((value as u32).to_le_bytes());
+    }
+
+    fn address(&mut self, address: MemAddress) {
+        self.bytes.extend(address.to_le_bytes());
+    }
+
+    fn str(&mut self, value: &str) {
+        self.u32(value.len());
+        self.bytes.extend(value.as_bytes());
+    }
+
+    fn optional_address(&mut self, address: Option<MemAddress>) {
+        match address {
+            Some(address) => {
+                self.u8(1);
+                self.address(address);
+            }
+            None => self.u8(0),
+        }
+    }
+
+    fn data_type(&mut self, data_type: &DataType) {
+        match data_type {
+            DataType::Int => self.u8(0),
+            DataType::Float => self.u8(1),
+            DataType::Bool => self.u8(2),
+            DataType::String => self.u8(3),
+            DataType::DataFrame => self.u8(4),
+            DataType::Series => self.u8(5),
+            DataType::Iterable(inner) => {
+                self.u8(6);
+                self.data_type(inner);
+            }
+            DataType::Void => self.u8(7),
+            DataType::Function(params, inner) => {
+                self.u8(8);
+                self.u16(params.len() as u16);
+                for param in params.iter() {
+                    self.data_type(param);
+                }
+                self.data_type(inner);
+            }
+            DataType::Pointer => self.u8(9),
+            DataType::Struct(id) => {
+                self.u8(10);
+                self.str(id);
+            }
+        }
+    }
+
+    fn constant(&mut self, address: MemAddress, value: &str) -> Result<(), String> {
+        let invalid = || format!("Invalid constant {:?} at address {}", value, address);
+
+        self.address(address);
+        match MemoryResolver::get_type_from_address(address) {
+            Some(DataType::Int) => {
+                self.u8(0);
+                let value = value.parse::<IntType>().map_err(|_| invalid())?;
+                self.bytes.extend(value.to_le_bytes());
+            }
+            Some(DataType::Float) => {
+                self.u8(1);
+                let value = value.parse::<FloatType>().map_err(|_| invalid())?;
+                self.bytes.extend(value.to_le_bytes());
+            }
+            Some(DataType::Bool) => {
+                self.u8(2);
+                self.u8(value.parse::<bool>().map_err(|_| invalid())? as u8);
+            }
+            _ => {
+                self.u8(3);
+                self.str(value);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn procedure(&mut self, procedure: &FunctionEntry) {
+        self.str(procedure.id.as_str());
+        self.data_type(&procedure.return_type);
+        self.u32(procedure.procedure_address);
+        self.optional_address(procedure.return_address);
+        self.u16(procedure.params.len() as u16);
+        for (address, data_type, point_address) in procedure.params.iter() {
+            self.address(*address);
+            self.data_type(data_type);
+            self.optional_address(*point_address);
+        }
+        self.u16(procedure.captures.len() as u16);
+        for address in procedure.captures.iter() {
+            self.address(*address);
+        }
+        self.optional_address(procedure.closure_address);
+        self.u8(procedure.is_native as u8);
+    }
+
+    fn operand(&mut self, operand: &Operand) {
+        match operand {
+            Operand::Direct(address) => {
+                self.u8(0);
+                self.address(*address);
+            }
+            Operand::Indirect(address) => {
+                self.u8(1);
+                self.address(*address);
+            }
+            Operand::AddressOf(address) => {
+                self.u8(2);
+                self.address(*address);
+            }
+            Operand::Const(Literal::End) => self.u8(3),
+            Operand::Const(Literal::Int(value)) => {
+                self.u8(4);
+                self.bytes.extend(value.to_le_bytes());
+            }
+            Operand::Const(Literal::Text(text)) => {
+                self.u8(5);
+                self.str(text.as_str());
+            }
+            Operand::Const(Literal::Void) => self.u8(6),
+            Operand::Label(position) => {
+                self.u8(7);
+                self.u32(*position);
+            }
+        }
+    }
+
+    fn opcode(&mut self, operator: &Operator) -> Result<(), String> {
+        let opcode = Opcode::from_operator(*operator).ok_or(format!(
+            "Operator {} can't be encoded",
+            operator.to_string()
+        ))?;
+        self.u8(opcode as u8);
+        Ok(())
+    }
+
+    fn instruction(&mut self, instruction: &Instruction, line: usize) -> Result<(), String> {
+        match instruction {
+            Instruction::Empty => self.u8(Opcode::Nop as u8),
+            Instruction::Operation(operator, left, right, dest) => {
+                self.opcode(operator)?;
+                self.operand(left);
+                self.operand(right);
+                self.operand(dest);
+            }
+            Instruction::Unary(operator, value, dest) => {
+                self.opcode(operator)?;
+                self.operand(value);
+                self.operand(dest);
+            }
+            Instruction::Cast(data_type, value, dest) => {
+                self.u8(Opcode::Cast as u8);
+                self.data_type(data_type);
+                self.operand(value);
+                self.operand(dest);
+            }
+            Instruction::Verify(value, bound) => {
+                self.u8(Opcode::Verify as u8);
+                self.operand(value);
+                self.operand(bound);
+            }
+            Instruction::Goto(target) => {
+                self.u8(Opcode::Goto as u8);
+                self.operand(target);
+            }
+            Instruction::GotoFalse(check, target) => {
+                self.u8(Opcode::GotoFalse as u8);
+                self.operand(check);
+                self.operand(target);
+            }
+            Instruction::Era(id) => {
+                self.u8(Opcode::Era as u8);
+                self.str(id.as_str());
+            }
+            Instruction::Param(value, index) => {
+                self.u8(Opcode::Param as u8);
+                self.operand(value);
+                self.u32(*index);
+            }
+            Instruction::GoSub(id) => {
+                self.u8(Opcode::GoSub as u8);
+                self.str(id.as_str());
+            }
+            Instruction::Call(closure, result) => {
+                self.u8(Opcode::Call as u8);
+                self.operand(closure);
+                match result {
+                    Some(result) => {
+                        self.u8(1);
+                        self.operand(result);
+                    }
+                    None => self.u8(0),
+                }
+            }
+            Instruction::Closure(id, captured, dest) => {
+                self.u8(Opcode::Closure as u8);
+                self.str(id.as_str());
+                self.u16(captured.len() as u16);
+                for value in captured.iter() {
+                    self.operand(value);
+                }
+                self.operand(dest);
+            }
+            Instruction::Return(value) => {
+                self.u8(Opcode::Return as u8);
+                self.operand(value);
+            }
+            Instruction::VoidReturn => self.u8(Opcode::VoidReturn as u8),
+            Instruction::EndFunc => self.u8(Opcode::EndFunc as u8),
+            Instruction::EndProgram => self.u8(Opcode::EndProgram as u8),
+            Instruction::Print(value) => {
+                self.u8(Opcode::Print as u8);
+                self.operand(value);
+            }
+            Instruction::Free(address) => {
+                self.u8(Opcode::Free as u8);
+                self.operand(address);
+            }
+            Instruction::Mark(depth) => {
+                self.u8(Opcode::Mark as u8);
+                self.u16(*depth as u16);
+            }
+            Instruction::Release(depth) => {
+                self.u8(Opcode::Release as u8);
+                self.u16(*depth as u16);
+            }
+            Instruction::Alloc(address, shape) => {
+                self.u8(Opcode::Alloc as u8);
+                self.operand(address);
+                self.u16(shape.len() as u16);
+                for size in shape.iter() {
+                    self.u32(*size);
+                }
+            }
+            Instruction::Len(value, axis, rank, dest) => {
+                self.u8(Opcode::Len as u8);
+                self.operand(value);
+                self.u16(*axis as u16);
+                self.u16(*rank as u16);
+                self.operand(dest);
+            }
+            Instruction::New(data_type, sizes, dest) => {
+                self.u8(Opcode::New as u8);
+                self.data_type(data_type);
+                self.u16(sizes.len() as u16);
+                for size in sizes.iter() {
+                    self.operand(size);
+                }
+                self.operand(dest);
+            }
+            Instruction::View(source, sizes, strides, dest) => {
+                self.u8(Opcode::View as u8);
+                self.operand(source);
+                self.u16(sizes.len() as u16);
+                for operand in sizes.iter().chain(strides.iter()) {
+                    self.operand(operand);
+                }
+                self.operand(dest);
+            }
+            Instruction::Copy(source, count, dest) => {
+                self.u8(Opcode::Copy as u8);
+                self.operand(source);
+                self.operand(count);
+                self.operand(dest);
+            }
+            Instruction::Elementwise(operator, left, right, dest) => {
+                self.u8(Opcode::Elementwise as u8);
+                self.opcode(operator)?;
+                self.operand(left);
+                self.operand(right);
+                self.operand(dest);
+            }
+        }
+        self.u32(line);
+
+        Ok(())
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, size: usize) -> Result<&'a [u8], String> {
+        if self.position + size > self.bytes.len() {
+            return Err(String::from("Unexpected end of bytecode"));
+        }
+
+        let taken = &self.bytes[self.position..self.position + size];
+        self.position += size;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<usize, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()) as usize)
+    }
+
+    fn address(&mut self) -> Result<MemAddress, String> {
+        Ok(self.u32()? as MemAddress)
+    }
+
+    fn u64(&mut self) -> Result<[u8; 8], String> {
+        Ok(self.take(8)?.try_into().unwrap())
+    }
+
+    fn str(&mut self) -> Result<String, String> {
+        let size = self.u32()?;
+        String::from_utf8(self.take(size)?.to_vec())
+            .map_err(|_| String::from("Invalid string in bytecode"))
+    }
+
+    fn optional_address(&mut self) -> Result<Option<MemAddress>, String> {
+        match self.u8()? {
+            0 => Ok(None),
+            _ => Ok(Some(self.address()?)),
+        }
+    }
+
+    fn data_type(&mut self) -> Result<DataType, String> {
+        match self.u8()? {
+            0 => Ok(DataType::Int),
+            1 => Ok(DataType::Float),
+            2 => Ok(DataType::Bool),
+            3 => Ok(DataType::String),
+            4 => Ok(DataType::DataFrame),
+            5 => Ok(DataType::Series),
+            6 => Ok(DataType::Iterable(Box::new(self.data_type()?))),
+            7 => Ok(DataType::Void),
+            8 => {
+                let params = (0..self.u16()?)
+                    .map(|_| self.data_type())
+                    .collect::<Result<Vec<DataType>, String>>()?;
+                Ok(DataType::Function(params, Box::new(self.data_type()?)))
+            }
+            9 => Ok(DataType::Pointer),
+            10 => Ok(DataType::Struct(self.str()?)),
+            tag => Err(format!("Unknown data type tag {}", tag)),
+        }
+    }
+
+    fn constant(&mut self) -> Result<(MemAddress, String), String> {
+        let address = self.address()?;
+        let value = match self.u8()? {
+            0 => IntType::from_le_bytes(self.u64()?).to_string(),
+            1 => FloatType::from_le_bytes(self.u64()?).to_string(),
+            2 => (self.u8()? != 0).to_string(),
+            3 => self.str()?,
+            tag => return Err(format!("Unknown constant tag {}", tag)),
+        };
+
+        Ok((address, value))
+    }
+
+    fn procedure(&mut self) -> Result<FunctionEntry, String> {
+        let id = self.str()?;
+        let return_type = self.data_type()?;
+        let procedure_address = self.u32()?;
+        let return_address = self.optional_address()?;
+        let params = (0..self.u16()?)
+            .map(|_| Ok((self.address()?, self.data_type()?, self.optional_address()?)))
+            .collect::<Result<Vec<ParamAddress>, String>>()?;
+        let captures = (0..self.u16()?)
+            .map(|_| self.address())
+            .collect::<Result<Vec<MemAddress>, String>>()?;
+        let closure_address = self.optional_address()?;
+        let is_native = self.u8()? != 0;
+
+        Ok(FunctionEntry {
+            id,
+            return_type,
+            params,
+            procedure_address,
+            return_address,
+            captures,
+            closure_address,
+            is_native,
+        })
+    }
+
+    fn operand(&mut self) -> Result<Operand, String> {
+        match self.u8()? {
+            0 => Ok(Operand::Direct(self.address()?)),
+            1 => Ok(Operand::Indirect(self.address()?)),
+            2 => Ok(Operand::AddressOf(self.address()?)),
+            3 => Ok(Operand::Const(Literal::End)),
+            4 => Ok(Operand::Const(Literal::Int(IntType::from_le_bytes(
+                self.u64()?,
+            )))),
+            5 => Ok(Operand::Const(Literal::Text(self.str()?))),
+            6 => Ok(Operand::Const(Literal::Void)),
+            7 => Ok(Operand::Label(self.u32()?)),
+            tag => Err(format!("Unknown operand tag {}", tag)),
+        }
+    }
+
+    fn instruction(&mut self) -> Result<(Instruction, usize), String> {
+        let byte = self.u8()?;
+        let opcode = Opcode::from_byte(byte).ok_or(format!("Unknown opcode {:#04x}", byte))?;
+
+        let instruction = match opcode {
+            Opcode::Nop => Instruction::Empty,
+            Opcode::Not | Opcode::Neg | Opcode::Assign => {
+                Instruction::Unary(opcode.operator().unwrap(), self.operand()?, self.operand()?)
+            }
+            Opcode::Cast => Instruction::Cast(self.data_type()?, self.operand()?, self.operand()?),
+            Opcode::Verify => Instruction::Verify(self.operand()?, self.operand()?),
+            Opcode::Goto => Instruction::Goto(self.operand()?),
+            Opcode::GotoFalse => Instruction::GotoFalse(self.operand()?, self.operand()?),
+            Opcode::Era => Instruction::Era(self.str()?),
+            Opcode::Param => Instruction::Param(self.operand()?, self.u32()?),
+            Opcode::GoSub => Instruction::GoSub(self.str()?),
+            Opcode::Call => {
+                let closure = self.operand()?;
+                let result = match self.u8()? {
+                    0 => None,
+                    _ => Some(self.operand()?),
+                };
+                Instruction::Call(closure, result)
+            }
+            Opcode::Closure => {
+                let id = self.str()?;
+                let captured = (0..self.u16()?)
+                    .map(|_| self.operand())
+                    .collect::<Result<Vec<Operand>, String>>()?;
+                Instruction::Closure(id, captured, self.operand()?)
+            }
+            Opcode::Return => Instruction::Return(self.operand()?),
+            Opcode::VoidReturn => Instruction::VoidReturn,
+            Opcode::EndFunc => Instruction::EndFunc,
+            Opcode::EndProgram => Instruction::EndProgram,
+            Opcode::Print => Instruction::Print(self.operand()?),
+            Opcode::Free => Instruction::Free(self.operand()?),
+            Opcode::Mark => Instruction::Mark(self.u16()? as usize),
+            Opcode::Release => Instruction::Release(self.u16()? as usize),
+            Opcode::Alloc => {
+                let address = self.operand()?;
+                let shape = (0..self.u16()?)
+                    .map(|_| self.u32())
+                    .collect::<Result<Vec<usize>, String>>()?;
+                Instruction::Alloc(address, shape)
+            }
+            Opcode::Len => {
+                let value = self.operand()?;
+                let axis = self.u16()? as usize;
+                let rank = self.u16()? as usize;
+                Instruction::Len(value, axis, rank, self.operand()?)
+            }
+            Opcode::New => {
+                let data_type = self.data_type()?;
+                let sizes = (0..self.u16()?)
+                    .map(|_| self.operand())
+                    .collect::<Result<Vec<Operand>, String>>()?;
+                Instruction::New(data_type, sizes, self.operand()?)
+            }
+            Opcode::View => {
+                let source = self.operand()?;
+                let rank = self.u16()?;
+                let sizes = (0..rank)
+                    .map(|_| self.operand())
+                    .collect::<Result<Vec<Operand>, String>>()?;
+                let strides = (0..rank)
+                    .map(|_| self.operand())
+                    .collect::<Result<Vec<Operand>, String>>()?;
+                Instruction::View(source, sizes, strides, self.operand()?)
+            }
+            Opcode::Copy => Instruction::Copy(self.operand()?, self.operand()?, self.operand()?),
+            Opcode::Elementwise => {
+                let byte = self.u8()?;
+                let operator = Opcode::from_byte(byte)
+                    .and_then(|opcode| opcode.operator())
+                    .ok_or(format!("Unknown operator opcode {:#04x}", byte))?;
+                Instruction::Elementwise(
+                    operator,
+                    self.operand()?,
+                    self.operand()?,
+                    self.operand()?,
+                )
+            }
+            binary => Instruction::Operation(
+                binary.operator().unwrap(),
+                self.operand()?,
+                self.operand()?,
+                self.operand()?,
+            ),
+        };
+        let line = self.u32()?;
+
+        Ok((instruction, line))
+    }
+}
+
+/// Checks whether some file contents start with the bytecode header.
+pub fn is_bytecode(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+/// Serializes a program into the bytecode format.
+///
+/// Fails if the program holds instructions or constants that can't be
+/// encoded.
+pub fn write(meta: &ProgramMeta) -> Result<Vec<u8>, String> {
+    let mut writer = Writer { bytes: vec![] };
+
+    writer.bytes.extend(MAGIC);
+    writer.u16(VERSION);
+    writer.str(meta.source_path.as_str());
+
+    // Sorted so the same program always produces the same file
+    let mut constants = meta.constant_table.iter().collect::<Vec<_>>();
+    constants.sort_by_key(|(address, _)| **address);
+    writer.u32(constants.len());
+    for (address, value) in constants {
+        writer.constant(*address, value.as_str())?;
+    }
+
+    let mut procedures = meta.procedure_table.values().collect::<Vec<_>>();
+    procedures.sort_by(|a, b| a.id.cmp(&b.id));
+    writer.u32(procedures.len());
+    for procedure in procedures {
+        writer.procedure(procedure);
+    }
+
+    writer.u32(meta.quadruples.len());
+    for (position, instruction) in meta.quadruples.iter().enumerate() {
+        let line = meta.line_table.get(position).copied().unwrap_or(0);
+        writer.instruction(instruction, line)?;
+    }
+
+    Ok(writer.bytes)
+}
+
+/// Deserializes a program from the bytecode format.
+pub fn read(bytes: &[u8]) -> Result<ProgramMeta, String> {
+    if !is_bytecode(bytes) {
+        return Err(String::from("Not a Moris bytecode file"));
+    }
+
+    let mut reader = Reader {
+        bytes,
+        position: MAGIC.len(),
+    };
+
+    let version = reader.u16()?;
+    if version != VERSION {
+        return Err(format!(
+            "Unsupported bytecode version {}, expected version {}",
+            version, VERSION
+        ));
+    }
+
+    let source_path = reader.str()?;
+
+    let constant_table = (0..reader.u32()?)
+        .map(|_| reader.constant())
+        .collect::<Result<HashMap<MemAddress, String>, String>>()?;
+
+    let procedure_table = (0..reader.u32()?)
+        .map(|_| {
+            let procedure = reader.procedure()?;
+            Ok((procedure.id.clone(), procedure))
+        })
+        .collect::<Result<HashMap<String, FunctionEntry>, String>>()?;
+
+    let (quadruples, line_table) = (0..reader.u32()?)
+        .map(|_| reader.instruction())
+        .collect::<Result<Vec<(Instruction, usize)>, String>>()?
+        .into_iter()
+        .unzip();
+
+    Ok(ProgramMeta {
+        quadruples,
+        constant_table,
+        procedure_table,
+        source_path,
+        line_table,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use memory::{
+        resolver::{MemoryResolver, MemoryScope},
+        types::DataType,
+    };
+
+    use parser::types::Operator;
+
+    use super::{read, write, VERSION};
+    use crate::{
+        meta::ProgramMeta,
+        quadruples::{Instruction, Literal, Operand},
+        symbols::FunctionEntry,
+    };
+
+    #[test]
+    fn test_round_trip() {
+        let int_const = MemoryResolver::to_address(&MemoryScope::Constant, &DataType::Int, 0);
+        let str_const = MemoryResolver::to_address(&MemoryScope::Constant, &DataType::String, 0);
+        let global = MemoryResolver::to_address(&MemoryScope::Global, &DataType::Int, 0);
+
+        let meta = ProgramMeta {
+            quadruples: vec![
+                Instruction::unary(
+                    Operator::Assign,
+                    Operand::AddressOf(global),
+                    Operand::Const(Literal::End),
+                ),
+                Instruction::operation(
+                    Operator::Add,
+                    Operand::Direct(int_const),
+                    Operand::Direct(global),
+                    Operand::Indirect(global),
+                ),
+                Instruction::goto_false(Operand::Direct(global), 4),
+                Instruction::print(Operand::Const(Literal::Text(String::from("\n")))),
+                Instruction::era("fib"),
+                Instruction::param(Operand::Direct(global), 0),
+                Instruction::type_cast(
+                    &DataType::Float,
+                    Operand::Direct(global),
+                    Operand::Direct(global),
+                ),
+                Instruction::verify(Operand::Direct(global), 10),
+                Instruction::alloc(global, &[2, 5]),
+                Instruction::len(Operand::Direct(global), 1, 2, Operand::Direct(global)),
+                Instruction::new_array(
+                    &DataType::Float,
+                    vec![Operand::Direct(global), Operand::Indirect(global)],
+                    Operand::Direct(global),
+                ),
+                Instruction::verify_bound(Operand::Direct(global), Operand::Direct(global)),
+                Instruction::copy(
+                    Operand::Direct(global),
+                    Operand::Direct(int_const),
+                    Operand::Indirect(global),
+                ),
+                Instruction::elementwise(
+                    Operator::Mul,
+                    Operand::Direct(global),
+                    Operand::Direct(int_const),
+                    Operand::Direct(global),
+                ),
+                Instruction::operation(
+                    Operator::MatMul,
+                    Operand::Direct(global),
+                    Operand::Direct(global),
+                    Operand::Direct(global),
+                ),
+                Instruction::closure(
+                    "fib.step",
+                    vec![Operand::Direct(global), Operand::Direct(int_const)],
+                    Operand::Direct(global),
+                ),
+                Instruction::call(Operand::Direct(global), None),
+                Instruction::call(Operand::Direct(global), Some(Operand::Direct(global))),
+                Instruction::view(
+                    Operand::Direct(global),
+                    vec![Operand::Direct(global), Operand::Direct(int_const)],
+                    vec![Operand::Direct(int_const), Operand::Direct(global)],
+                    Operand::Direct(global),
+                ),
+            ],
+            constant_table: HashMap::from([
+                (int_const, String::from("42")),
+                (str_const, String::from("hello")),
+            ]),
+            procedure_table: HashMap::from([(
+                String::from("fib"),
+                FunctionEntry {
+                    id: String::from("fib"),
+                    return_type: DataType::Function(
+                        vec![DataType::Int, DataType::Bool],
+                        Box::new(DataType::Float),
+                    ),
+                    params: vec![(global, DataType::Int, None)],
+                    procedure_address: 3,
+                    return_address: Some(global),
+                    captures: vec![global],
+                    closure_address: Some(global),
+                    is_native: false,
+                },
+            )]),
+            source_path: String::from("fib.mo"),
+            line_table: vec![1, 1, 2, 0, 3, 3, 4, 4, 5, 6, 6, 7, 7, 8, 8, 9, 9, 9, 10],
+        };
+
+        let bytes = write(&meta).unwrap();
+        assert_eq!(&bytes[..4], b"MOBC");
+        assert_eq!(u16::from_le_bytes([bytes[4], bytes[5]]), VERSION);
+
+        let loaded = read(&bytes).unwrap();
+        assert_eq!(loaded.quadruples, meta.quadruples);
+        assert_eq!(loaded.constant_table, meta.constant_table);
+        assert_eq!(loaded.line_table, meta.line_table);
+        assert_eq!(loaded.source_path, meta.source_path);
+        assert_eq!(
+            format!("{:?}", loaded.procedure_table),
+            format!("{:?}", meta.procedure_table)
+        );
+    }
+
+    #[test]
+    fn test_invalid_files() {
+        assert!(read(b"\x80\x03}q").is_err());
+        assert!(read(b"MOBC\xff\x00").unwrap_err().contains("version"));
+        assert!(read(b"MOBC\x01\x00\x10").is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use core::panic;
-use std::collections::HashMap;
+use std::{collections::HashMap, ops::Range};
 
 use parser::{
     functions::{FunctionParam, FunctionSignature},
@@ -9,18 +9,62 @@ use parser::{
 use memory::{
     resolver::{MemAddress, MemoryScope},
     types::DataType,
-    virtual_allocator::VirtualAllocator,
+    virtual_allocator::{MemoryCounter, VirtualAllocator},
 };
 
-use crate::symbols::SymbolEntry;
+use crate::symbols::{StructEntry, SymbolEntry};
 
 /// Represents an Environment entry to represent call contexts / scopes.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct EnvEntry {
     pub is_global: bool,
     pub env_id: String,
     pub return_type: Option<DataType>,
+    /// Shape of the values returned by the function.
+    pub return_dimension: Dimension,
+    /// First item of the global array holding the returned value, for
+    /// functions returning arrays by value.
+    pub return_buffer: Option<MemAddress>,
+    /// Ids of the function parameters, in order.
+    pub params: Vec<String>,
     pub symbols: HashMap<String, SymbolEntry>,
+    /// Blocks nested within the environment currently open, innermost last.
+    pub scopes: Vec<BlockScope>,
+    /// Blocks already closed, the addresses of their variables may be
+    /// reused by later declarations.
+    pub released: Vec<ReleasedScope>,
+    /// Environment a nested function is declared within.
+    pub parent: Option<String>,
+    /// Values captured from the enclosing environments by a nested function,
+    /// as the address they are read from and the local receiving them.
+    pub captures: Vec<(MemAddress, MemAddress)>,
+}
+
+/// Variables declared within a block (conditions and loops), visible
+/// until the block ends.
+#[derive(Debug, Clone)]
+pub struct BlockScope {
+    pub symbols: HashMap<String, SymbolEntry>,
+    /// Allocation counters when the block started, restored once it ends.
+    counters: MemoryCounter,
+    /// Position of the first quadruple of the block.
+    start: usize,
+}
+
+/// Variables of a block already closed, along with the quadruples it spans.
+#[derive(Debug, Clone)]
+pub struct ReleasedScope {
+    pub symbols: Vec<SymbolEntry>,
+    pub quadruples: Range<usize>,
+}
+
+/// Environment set aside while generating a function nested within it.
+#[derive(Debug, Clone)]
+struct Suspended {
+    env_id: String,
+    scope: MemoryScope,
+    /// Allocation counters of the local scope, restored once resumed.
+    counters: MemoryCounter,
 }
 
 /// Manager for the current declaration environments.
@@ -28,12 +72,16 @@ pub struct EnvEntry {
 /// Keeps track of the current environment and the allocation of
 /// items within it. Also holds the data of multiple environments
 /// and allow switching betweent them.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Environment {
     pub current_env: String,
     pub entries: HashMap<String, EnvEntry>,
     pub current_scope: MemoryScope,
     pub allocator: VirtualAllocator,
+    /// Declared struct types, by id.
+    pub structs: HashMap<String, StructEntry>,
+    /// Environments being generated, outermost first.
+    suspended: Vec<Suspended>,
 }
 
 impl Environment {
@@ -46,11 +94,20 @@ impl Environment {
                     is_global: true,
                     env_id: String::from("global"),
                     return_type: None,
+                    return_dimension: Dimension::new_scalar(),
+                    return_buffer: None,
+                    params: vec![],
                     symbols: HashMap::new(),
+                    scopes: vec![],
+                    released: vec![],
+                    parent: None,
+                    captures: vec![],
                 },
             )]),
             allocator: VirtualAllocator::new(),
             current_scope: MemoryScope::Global,
+            structs: HashMap::new(),
+            suspended: vec![],
         };
     }
 
@@ -90,25 +147,34 @@ impl Environment {
                 let mut counters: HashMap<DataType, usize> = HashMap::new();
 
                 // TODO: Store initial counters
-                current_context.symbols.iter().for_each(|(_, entry)| {
-                    if entry.dimension.size > 1 {
-                        counters.insert(
-                            DataType::Pointer,
-                            counters.get(&DataType::Pointer).unwrap_or(&0) + 1,
-                        );
-                        counters.insert(
-                            entry.data_type.clone(),
-                            counters.get(&entry.data_type.clone()).unwrap_or(&0)
-                                + entry.dimension.size
-                                + 1,
-                        );
-                    } else {
-                        counters.insert(
-                            entry.data_type.clone(),
-                            counters.get(&entry.data_type.clone()).unwrap_or(&0) + 1,
-                        );
-                    }
-                });
+                // Struct fields are counted by the entries holding their values
+                current_context
+                    .symbols
+                    .values()
+                    .flat_map(|symbol| symbol.leaves())
+                    .for_each(|(_, entry)| {
+                        if entry.dimension.is_runtime_sized() {
+                            counters.insert(
+                                DataType::Pointer,
+                                counters.get(&DataType::Pointer).unwrap_or(&0) + 1,
+                            );
+                        } else if entry.dimension.size > 1 {
+                            counters.insert(
+                                DataType::Pointer,
+                                counters.get(&DataType::Pointer).unwrap_or(&0) + 1,
+                            );
+                            counters.insert(
+                                entry.data_type.clone(),
+                                counters.get(&entry.data_type.clone()).unwrap_or(&0)
+                                    + entry.dimension.size
+                                    + 1,
+                            );
+                        } else {
+                            let segment = entry.data_type.segment();
+                            counters
+                                .insert(segment.clone(), counters.get(&segment).unwrap_or(&0) + 1);
+                        }
+                    });
 
                 counters
                     .iter()
@@ -119,16 +185,37 @@ impl Environment {
         }
     }
 
+    /// Sets the current environment aside to generate a function declared
+    /// within it, see [Environment::resume].
+    pub fn suspend(&mut self) {
+        self.suspended.push(Suspended {
+            env_id: self.current_env.clone(),
+            scope: self.current_scope,
+            counters: self.allocator.get_counter(&MemoryScope::Local).clone(),
+        });
+    }
+
+    /// Switches back to the environment suspended last, along with the
+    /// allocation state of its locals.
+    pub fn resume(&mut self) {
+        let suspended = match self.suspended.pop() {
+            Some(suspended) => suspended,
+            None => panic!("There is no environment to resume!"),
+        };
+
+        self.current_env = suspended.env_id;
+        self.current_scope = suspended.scope;
+        *self.allocator.get_counter(&MemoryScope::Local) = suspended.counters;
+    }
+
     /// Creates a new environment to keep a function's local variables.
     ///
-    /// # Panics
-    ///
-    /// Panics if an environment with the same name (function id) has
-    /// been declared before.
-    pub fn from_function(&mut self, func: &FunctionSignature) {
+    /// Fails if an environment with the same name (function id) has
+    /// been declared before, or if two parameters share the same id.
+    pub fn from_function(&mut self, func: &FunctionSignature) -> Result<(), String> {
         let id = &func.id;
         if let Some(_) = self.entries.get(id) {
-            panic!("Environment {} already exist!", id);
+            return Err(format!("Environment {} already exist!", id));
         }
 
         self.current_env = id.clone();
@@ -144,74 +231,301 @@ impl Environment {
                 &variable.data_type,
                 &variable.dimension,
                 false,
-            );
+            )?;
         }
+
+        Ok(())
     }
 
-    /// Separates the space for an array of a given dimension
-    pub fn allocate_array(&mut self, data_type: &DataType, dimension: &Dimension) -> MemAddress {
-        let Dimension {
-            dimensions: _,
-            shape: _,
-            size,
-            acc_size: _,
-        } = dimension;
+    /// Separates the space for an array of a given dimension.
+    ///
+    /// Fails if the array doesn't fit in the memory of the current scope.
+    pub fn allocate_array(
+        &mut self,
+        data_type: &DataType,
+        dimension: &Dimension,
+    ) -> Result<MemAddress, String> {
+        self.allocator
+            .try_assign_location(&self.current_scope, data_type, dimension.size)
+    }
 
-        let address: u16;
+    /// Adds a new struct type to the declared structs.
+    ///
+    /// Fails if a struct with the same id has been declared before, if two
+    /// fields share the same id, or if a field has a struct type not declared
+    /// before it.
+    pub fn add_struct(
+        &mut self,
+        id: &String,
+        fields: Vec<(String, DataType)>,
+    ) -> Result<(), String> {
+        if self.structs.contains_key(id) {
+            return Err(format!("Struct {} was already defined!", id));
+        }
+
+        for (index, (field_id, data_type)) in fields.iter().enumerate() {
+            if fields[..index].iter().any(|(other, _)| other == field_id) {
+                return Err(format!(
+                    "Field {} of struct {} was already defined!",
+                    field_id, id
+                ));
+            }
+
+            match data_type {
+                DataType::Struct(name) if !self.structs.contains_key(name) => {
+                    return Err(format!("Struct {} is not defined", name));
+                }
+                DataType::Void => {
+                    return Err(format!("Field {} of struct {} can't be void", field_id, id));
+                }
+                _ => (),
+            }
+        }
 
-        address = self
-            .allocator
-            .assign_location(&self.current_scope, data_type, *size + 1); // Adds an extra space to mark end of array
+        self.structs.insert(
+            id.clone(),
+            StructEntry {
+                id: id.clone(),
+                fields,
+            },
+        );
 
-        address
+        Ok(())
     }
 
     /// Adds a new variable to the current declaration environment.
+    ///
+    /// Fails if the id has already been declared in the current environment.
     pub fn add_var(
         &mut self,
         id: &String,
         data_type: &DataType,
         dimension: &Dimension,
         immutable: bool,
-    ) -> MemAddress {
-        let Dimension {
-            dimensions: dim,
-            shape: _,
-            size: _,
-            acc_size: _,
-        } = dimension;
-
-        let address: u16;
-
-        if *dim > 0 {
-            address = self
-                .allocator
-                .assign_location(&self.current_scope, &DataType::Pointer, 1);
+    ) -> Result<MemAddress, String> {
+        let symbol = self.layout(id, data_type, dimension, immutable)?;
+        let address = symbol.address;
+
+        self.current_env_mut().add(symbol)?;
+
+        Ok(address)
+    }
+
+    /// Adds a variable holding the closure of a nested function, given the
+    /// id of its procedure and the type of its values.
+    pub fn add_function(
+        &mut self,
+        id: &String,
+        procedure_id: &String,
+        data_type: &DataType,
+    ) -> Result<MemAddress, String> {
+        let symbol = SymbolEntry {
+            procedure: Some(procedure_id.clone()),
+            ..self.layout(id, data_type, &Dimension::new_scalar(), true)?
+        };
+        let address = symbol.address;
+
+        self.current_env_mut().add(symbol)?;
+
+        Ok(address)
+    }
+
+    /// Makes a variable of the enclosing environments visible within the
+    /// nested function being generated.
+    ///
+    /// The value of the variable is copied into a local of the function when
+    /// its closure is created, the copy can't be mutated. Global variables
+    /// are accessed directly, unless they are declared within a block.
+    ///
+    /// Fails if the variable is an array, arrays can only be passed as
+    /// parameters.
+    pub fn capture(&mut self, id: &String) -> Result<(), String> {
+        self.visible(&self.current_env.clone(), id).map(|_| ())
+    }
+
+    /// Checks whether a variable can be accessed from an environment,
+    /// capturing it from the enclosing ones if needed.
+    fn visible(&mut self, env_id: &String, id: &String) -> Result<bool, String> {
+        let env = &self.entries[env_id];
+        if env.is_global {
+            return Ok(env
+                .scopes
+                .iter()
+                .any(|scope| scope.symbols.contains_key(id)));
+        } else if env.get(id).is_some() {
+            return Ok(true);
+        }
+
+        let parent = match &env.parent {
+            Some(parent) => parent.clone(),
+            None => return Ok(false),
+        };
+        if !self.visible(&parent, id)? {
+            return Ok(false);
+        }
 
-            let array_address = self.allocate_array(data_type, dimension);
+        let source = self.entries[&parent].get(id).cloned().unwrap();
+        if source
+            .leaves()
+            .iter()
+            .any(|(_, leaf)| leaf.dimension.dimensions > 0)
+        {
+            return Err(format!(
+                "Array {} can't be captured by a nested function, pass it as a parameter instead",
+                id
+            ));
+        }
+
+        // Suspended environments allocate from the counters they were left with
+        let suspended = self
+            .suspended
+            .iter()
+            .position(|suspended| suspended.env_id == *env_id);
+        let swap_counters = |env: &mut Environment| {
+            if let Some(index) = suspended {
+                std::mem::swap(
+                    env.allocator.get_counter(&MemoryScope::Local),
+                    &mut env.suspended[index].counters,
+                );
+            }
+        };
 
-            self.current_env_mut().add(SymbolEntry::new_vec(
+        swap_counters(self);
+        let copy = self.layout(id, &source.data_type, &source.dimension, true);
+        let allocated = self.allocator.get_counter(&MemoryScope::Local).clone();
+        swap_counters(self);
+        let copy = SymbolEntry {
+            procedure: source.procedure.clone(),
+            ..copy?
+        };
+
+        let env = self.entries.get_mut(env_id).unwrap();
+        // Blocks open in the function must not reclaim the copy once they end
+        for scope in env.scopes.iter_mut() {
+            scope.counters = allocated.clone();
+        }
+        env.captures.extend(
+            source
+                .leaves()
+                .iter()
+                .zip(copy.leaves())
+                .map(|((_, source), (_, copy))| (source.address, copy.address)),
+        );
+        env.symbols.insert(id.clone(), copy);
+
+        Ok(true)
+    }
+
+    /// Assigns the addresses of a new variable and returns its entry.
+    ///
+    /// Struct variables get a separate entry for each of their fields, so
+    /// every field is stored in the memory segment of its data type. The
+    /// fields of arrays of structs are arrays with the same dimensions.
+    fn layout(
+        &mut self,
+        id: &String,
+        data_type: &DataType,
+        dimension: &Dimension,
+        immutable: bool,
+    ) -> Result<SymbolEntry, String> {
+        if let DataType::Struct(name) = data_type {
+            let declaration = self
+                .structs
+                .get(name)
+                .cloned()
+                .ok_or(format!("Struct {} is not defined", name))?;
+
+            let fields = declaration
+                .fields
+                .iter()
+                .map(|(field_id, field_type)| {
+                    self.layout(field_id, field_type, dimension, immutable)
+                })
+                .collect::<Result<Vec<SymbolEntry>, String>>()?;
+
+            return Ok(SymbolEntry {
+                address: fields.first().map_or(0, |field| field.address),
+                fields,
+                ..SymbolEntry::new_var(
+                    id.clone(),
+                    data_type.clone(),
+                    0,
+                    dimension.clone(),
+                    immutable,
+                )
+            });
+        }
+
+        if let (DataType::Function(..), true) = (data_type, dimension.dimensions > 0) {
+            return Err(format!("Array {} can't hold functions", id));
+        }
+
+        if dimension.dimensions > 0 {
+            let address =
+                self.allocator
+                    .assign_location(&self.current_scope, &DataType::Pointer, 1);
+
+            // Arrays sized at runtime are allocated when declared
+            let array_address = match dimension.is_runtime_sized() {
+                true => None,
+                false => Some(self.allocate_array(data_type, dimension)?),
+            };
+
+            Ok(SymbolEntry::new_vec(
                 id.clone(),
                 data_type.clone(),
                 address,
                 dimension.clone(),
                 array_address,
-            ));
+            ))
         } else {
-            address = self
+            let address = self
                 .allocator
                 .assign_location(&self.current_scope, data_type, 1);
 
-            self.current_env_mut().add(SymbolEntry::new_var(
+            Ok(SymbolEntry::new_var(
                 id.clone(),
                 data_type.clone(),
                 address,
                 dimension.clone(),
                 immutable,
-            ));
+            ))
         }
+    }
+
+    /// Opens a block scope in the current environment starting at a
+    /// quadruple, the variables added from now on are only visible until the
+    /// scope is closed.
+    pub fn open_scope(&mut self, start: usize) {
+        let counters = self.allocator.get_counter(&self.current_scope).clone();
+
+        self.current_env_mut().scopes.push(BlockScope {
+            symbols: HashMap::new(),
+            counters,
+            start,
+        });
+    }
+
+    /// Closes the innermost block scope before a quadruple and returns the
+    /// variables declared within it, sorted by address.
+    ///
+    /// The addresses of the scope, including the temporals used within it,
+    /// are reclaimed to be used by the following declarations.
+    pub fn close_scope(&mut self, end: usize) -> Vec<SymbolEntry> {
+        let scope = match self.current_env_mut().scopes.pop() {
+            Some(scope) => scope,
+            None => panic!("There is no block scope to close!"),
+        };
+        *self.allocator.get_counter(&self.current_scope) = scope.counters;
+
+        let mut symbols = scope.symbols.into_values().collect::<Vec<SymbolEntry>>();
+        symbols.sort_by_key(|symbol| symbol.address);
+        self.current_env_mut().released.push(ReleasedScope {
+            symbols: symbols.clone(),
+            quadruples: scope.start..end,
+        });
 
-        address
+        symbols
     }
 
     /// Deletes a variable to the current declaration environment.
@@ -238,7 +552,14 @@ impl EnvEntry {
             is_global: false,
             env_id,
             return_type,
+            return_dimension: Dimension::new_scalar(),
+            return_buffer: None,
+            params: vec![],
             symbols: HashMap::new(),
+            scopes: vec![],
+            released: vec![],
+            parent: None,
+            captures: vec![],
         }
     }
 
@@ -248,23 +569,69 @@ impl EnvEntry {
             is_global: false,
             env_id: func.id.clone(),
             return_type: Some(func.data_type.clone()),
+            return_dimension: func.dimension.clone(),
+            return_buffer: None,
+            params: func
+                .params
+                .iter()
+                .map(|FunctionParam(variable)| variable.id.clone())
+                .collect(),
             symbols: HashMap::new(),
+            scopes: vec![],
+            released: vec![],
+            parent: None,
+            captures: vec![],
         }
     }
 
-    pub fn add(&mut self, symbol: SymbolEntry) {
+    /// Adds a symbol to the innermost open scope. Symbols may shadow the
+    /// ones declared in outer scopes.
+    pub fn add(&mut self, symbol: SymbolEntry) -> Result<(), String> {
         let id = symbol.id.clone();
-        if let Some(_) = &self.symbols.insert(id.clone(), symbol) {
-            panic!("{} was already defined!", id.clone());
+        let symbols = match self.scopes.last_mut() {
+            Some(scope) => &mut scope.symbols,
+            None => &mut self.symbols,
+        };
+
+        if symbols.contains_key(&id) {
+            return Err(format!("{} was already defined!", id));
         }
+
+        symbols.insert(id, symbol);
+        Ok(())
     }
 
     pub fn delete(&mut self, symbol_id: &String) {
-        self.symbols.remove(symbol_id);
+        match self.scopes.last_mut() {
+            Some(scope) => scope.symbols.remove(symbol_id),
+            None => self.symbols.remove(symbol_id),
+        };
     }
 
+    /// Returns the symbol visible with the given id, looking from the
+    /// innermost scope outwards.
     pub fn get(&self, id: &String) -> Option<&SymbolEntry> {
-        return self.symbols.get(id);
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.symbols.get(id))
+            .or_else(|| self.symbols.get(id))
+    }
+
+    /// Returns the variables of the closed blocks spanning a quadruple,
+    /// innermost blocks first.
+    pub fn block_symbols(&self, position: usize) -> Vec<&SymbolEntry> {
+        let mut scopes = self
+            .released
+            .iter()
+            .filter(|scope| scope.quadruples.contains(&position))
+            .collect::<Vec<&ReleasedScope>>();
+        scopes.sort_by_key(|scope| scope.quadruples.len());
+
+        scopes
+            .into_iter()
+            .flat_map(|scope| scope.symbols.iter())
+            .collect()
     }
 }
 
@@ -283,6 +650,8 @@ impl SymbolEntry {
             address,
             point_address: None,
             immutable,
+            fields: vec![],
+            procedure: None,
         }
     }
 
@@ -291,15 +660,17 @@ impl SymbolEntry {
         data_type: DataType,
         address: MemAddress,
         dimension: Dimension,
-        point_address: MemAddress,
+        point_address: Option<MemAddress>,
     ) -> SymbolEntry {
         SymbolEntry {
             id,
             data_type,
             dimension,
             address,
-            point_address: Some(point_address),
+            point_address,
             immutable: false,
+            fields: vec![],
+            procedure: None,
         }
     }
 }
@@ -0,0 +1,119 @@
+use std::fmt::{Display, Formatter};
+
+use parser::{span::Span, SyntaxError};
+
+/// Syntax or semantic error found while compiling a program, located
+/// by the span of the offending code.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompileError {
+    pub message: String,
+    pub span: Span,
+}
+
+pub type CompileResult<T> = Result<T, CompileError>;
+
+impl CompileError {
+    pub fn new(message: &str, span: Span) -> CompileError {
+        CompileError {
+            message: String::from(message),
+            span,
+        }
+    }
+
+    /// Formats the error as a diagnostic pointing to the offending line
+    /// of the source, e.g.
+    ///
+    /// ```text
+    /// error: Types Int and String are not compatible!
+    ///  --> main.mo:2:14
+    ///   |
+    /// 2 | let x: int = 5 + "a";
+    ///   |              ^^^^^^^
+    /// ```
+    pub fn render(&self, file_name: &str, source: &str) -> String {
+        let (line, column) = self.span.line_col(source);
+        let line_content = source.lines().nth(line - 1).unwrap_or("");
+        let gutter = " ".repeat(line.to_string().len());
+
+        let remaining = line_content.chars().count() + 1 - column;
+        let underline = source
+            .get(self.span.start..self.span.end)
+            .map(|item| item.chars().take_while(|c| *c != '\n').count())
+            .unwrap_or(1)
+            .clamp(1, remaining.max(1));
+
+        format!(
+            "error: {}\n{gutter}--> {}:{}:{}\n{gutter} |\n{} | {}\n{gutter} | {}{}\n",
+            self.message,
+            file_name,
+            line,
+            column,
+            line,
+            line_content,
+            " ".repeat(column - 1),
+            "^".repeat(underline),
+        )
+    }
+}
+
+impl Display for CompileError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "{}", self.message)
+    }
+}
+
+impl From<SyntaxError> for CompileError {
+    fn from(error: SyntaxError) -> Self {
+        CompileError {
+            message: error.message,
+            span: error.span,
+        }
+    }
+}
+
+/// Attaches a source span to errors raised by code that has no access
+/// to the location of the node being compiled.
+pub trait AtSpan<T> {
+    fn at(self, span: Span) -> CompileResult<T>;
+}
+
+impl<T> AtSpan<T> for Result<T, String> {
+    fn at(self, span: Span) -> CompileResult<T> {
+        self.map_err(|message| CompileError { message, span })
+    }
+}
+
+impl<T> AtSpan<T> for CompileResult<T> {
+    /// Only fills the span of errors that have not been located yet.
+    fn at(self, span: Span) -> CompileResult<T> {
+        self.map_err(|error| {
+            if error.span == Span::default() {
+                CompileError { span, ..error }
+            } else {
+                error
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parser::span::Span;
+
+    use super::CompileError;
+
+    #[test]
+    fn test_render() {
+        let source = "let x: int = 5;\nlet y: int = x + \"a\";\n";
+        let error = CompileError::new(
+            "Types Int and String are not compatible!",
+            Span::new(29, 36),
+        );
+
+        assert_eq!(
+            error.render("main.mo", source),
+            "error: Types Int and String are not compatible!\n \
+             --> main.mo:2:14\n  |\n2 | let y: int = x + \"a\";\n  |              ^^^^^^^\n"
+        );
+    }
+}
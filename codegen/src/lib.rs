@@ -1,33 +1,77 @@
+use std::fs;
+
+use errors::CompileError;
 use manager::Manager;
 use memory::types::DataType;
 use natives::NativeFunction;
 use node::Node;
-use parser::try_file;
+use parser::{parse_program, span::Span, statements::Program};
 
 pub mod ast_nodes;
+pub mod bytecode;
 pub mod env;
+pub mod errors;
 pub mod manager;
 pub mod meta;
 pub mod natives;
 pub mod node;
+pub mod optimizer;
 pub mod quadruples;
 pub mod symbols;
 
 /// Generates the code for an input file.
 ///
 /// All the program metadata and quadruples will be stored in the manager object.
-pub fn generate(path: &str, manager: &mut Manager) {
+/// Returns every syntax or semantic error found in the program.
+pub fn generate(path: &str, manager: &mut Manager) -> Result<(), Vec<CompileError>> {
+    let source = fs::read_to_string(path).map_err(|error| {
+        vec![CompileError::new(
+            format!("Can't read {}: {}", path, error).as_str(),
+            Span::default(),
+        )]
+    })?;
+
+    manager.set_source(path, source.as_str());
+    declare_natives(manager)?;
+
+    let program = parse_program(source.as_str()).map_err(|error| vec![error.into()])?;
+    generate_program(program, manager)
+}
+
+/// Adds the native functions to the manager procedure table.
+pub fn declare_natives(manager: &mut Manager) -> Result<(), Vec<CompileError>> {
     let native_functions = NativeFunction::get_function_definitions();
 
-    native_functions.iter().for_each(|func| {
+    for func in native_functions.iter() {
         let return_address = match func.data_type {
             DataType::Void => None,
+            _ if func.dimension.dimensions > 0 => Some(manager.new_global(&DataType::Pointer)),
             _ => Some(manager.new_global(&func.data_type)),
         };
 
-        manager.new_func(&func, 0, return_address, false);
-    });
+        manager
+            .new_func(&func, 0, return_address, false)
+            .map_err(|error| vec![error])?;
+    }
+
+    Ok(())
+}
+
+/// Generates the code for a parsed program, appending its quadruples to
+/// the ones already held by the manager.
+pub fn generate_program(
+    mut program: Program,
+    manager: &mut Manager,
+) -> Result<(), Vec<CompileError>> {
+    if let Err(error) = program.generate(manager) {
+        manager.report(error);
+    }
 
-    let mut test_program = try_file(path);
-    test_program.generate(manager);
+    if manager.errors.is_empty() {
+        Ok(())
+    } else {
+        let mut errors = manager.errors.clone();
+        errors.sort_by_key(|error| error.span.start);
+        Err(errors)
+    }
 }
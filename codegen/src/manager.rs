@@ -3,21 +3,28 @@ use memory::{
     resolver::{MemAddress, MemoryScope},
     types::DataType,
 };
-use std::{collections::HashMap, fmt::Debug, fs::File, path::PathBuf};
+use std::{collections::HashMap, fmt::Debug, fs, path::PathBuf};
 
 use crate::{
+    bytecode,
     env::Environment,
-    symbols::{FunctionEntry, ParamAddress},
+    errors::{AtSpan, CompileError, CompileResult},
+    symbols::{FunctionEntry, ParamAddress, SymbolEntry},
 };
 use parser::{
     expressions::constant::Const,
     functions::{FunctionParam, FunctionSignature},
     semantics::ExitStatement,
-    types::Variable,
+    span::Span,
+    structs::Struct,
+    types::{Operator, Variable},
     Dimension,
 };
 
-use super::{meta::ProgramMeta, quadruples::Quadruple};
+use super::{
+    meta::ProgramMeta,
+    quadruples::{Instruction, Operand},
+};
 
 /// Object to manage the code generation.
 ///
@@ -28,14 +35,21 @@ use super::{meta::ProgramMeta, quadruples::Quadruple};
 /// Serves as interface to emit quadruples from external
 /// code generation.
 ///
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Manager {
     pub env: Environment,
     instruction_counter: i32,
-    pub quadruples: Vec<Quadruple>,
+    pub quadruples: Vec<Instruction>,
     pub unresolved: HashMap<ExitStatement, Vec<usize>>,
     constant_table: HashMap<MemAddress, Const>,
     procedure_table: HashMap<String, FunctionEntry>,
+    pub errors: Vec<CompileError>,
+    pub source_path: String,
+    /// Source line of every emitted quadruple.
+    pub line_table: Vec<usize>,
+    /// Byte offsets where each source line starts.
+    line_offsets: Vec<usize>,
+    current_line: usize,
 }
 
 impl Manager {
@@ -47,6 +61,11 @@ impl Manager {
             unresolved: HashMap::new(),
             constant_table: HashMap::new(),
             procedure_table: HashMap::new(),
+            errors: vec![],
+            source_path: String::new(),
+            line_table: vec![],
+            line_offsets: vec![0],
+            current_line: 0,
         }
     }
 
@@ -58,12 +77,59 @@ impl Manager {
         self.unresolved = HashMap::new();
         self.constant_table = HashMap::new();
         self.procedure_table = HashMap::new();
+        self.errors = vec![];
+        self.source_path = String::new();
+        self.line_table = vec![];
+        self.line_offsets = vec![0];
+        self.current_line = 0;
+    }
+
+    /// Sets the source the program is generated from, so every emitted
+    /// quadruple can be mapped to its source line.
+    pub fn set_source(&mut self, path: &str, source: &str) {
+        self.source_path = String::from(path);
+        self.line_offsets = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(offset, _)| offset + 1))
+            .collect();
+    }
+
+    /// Returns the source line assigned to the next emitted quadruples.
+    pub fn current_line(&self) -> usize {
+        self.current_line
+    }
+
+    /// Sets the line of the code currently being generated. Empty spans
+    /// keep the previous location.
+    pub fn set_location(&mut self, span: Span) {
+        if span != Span::default() {
+            self.current_line = self
+                .line_offsets
+                .partition_point(|offset| *offset <= span.start);
+        }
+    }
+
+    pub fn set_line(&mut self, line: usize) {
+        self.current_line = line;
+    }
+
+    /// Records a compilation error so code generation can continue
+    /// and report every error found in a single pass.
+    pub fn report(&mut self, error: CompileError) {
+        self.errors.push(error);
     }
 
     /// Serializes and dumps the generated program data
-    /// (quadruples, constant and procedure tables) into an object file.
-    pub fn dump(&self, file_path: &PathBuf) {
-        let meta = ProgramMeta {
+    /// (quadruples, constant and procedure tables) into a bytecode object file.
+    pub fn dump(&self, file_path: &PathBuf) -> Result<(), String> {
+        let bytes = bytecode::write(&self.program_meta())?;
+
+        fs::write(file_path.as_os_str(), bytes)
+            .map_err(|error| format!("Can't write {}: {}", file_path.display(), error))
+    }
+
+    /// Returns the program data generated so far, as needed to execute it.
+    pub fn program_meta(&self) -> ProgramMeta {
+        ProgramMeta {
             quadruples: self.quadruples.clone(),
             constant_table: self
                 .constant_table
@@ -75,11 +141,9 @@ impl Manager {
                 .iter()
                 .map(|(k, v)| (k.clone(), v.clone()))
                 .collect::<HashMap<String, FunctionEntry>>(),
-        };
-
-        let mut buffer = File::create(file_path.as_os_str()).unwrap();
-
-        serde_pickle::to_writer(&mut buffer, &meta, Default::default()).unwrap();
+            source_path: self.source_path.clone(),
+            line_table: self.line_table.clone(),
+        }
     }
 
     pub fn get_env(&self) -> &Environment {
@@ -93,43 +157,88 @@ impl Manager {
     /// Adds a new function to the procedure table and adds the parameters as
     /// local variables to its corresponding environment (symbol table).
     ///
-    /// # Panics
-    ///
-    /// Panics if a function with the same id as func has been declared before
+    /// Fails if a function with the same id as func has been declared before
     pub fn new_func(
         &mut self,
         func: &FunctionSignature,
         location: usize,
         return_address: Option<MemAddress>,
         switch: bool,
-    ) {
-        if self.procedure_table.contains_key(&func.id)
+    ) -> CompileResult<()> {
+        // Functions of the program can shadow the native ones
+        let shadows_native = !func.is_native
+            && self
+                .procedure_table
+                .get(&func.id)
+                .is_some_and(|entry| entry.is_native);
+        if shadows_native {
+            self.procedure_table.remove(&func.id);
+            self.get_env_mut().drop_env(&func.id);
+        } else if self.procedure_table.contains_key(&func.id)
             || self.get_env_mut().entries.contains_key(&func.id)
         {
-            panic!("A symbol with id {} has been already defined", func.id)
+            return Err(CompileError::new(
+                format!("A symbol with id {} has been already defined", func.id).as_str(),
+                func.span,
+            ));
         }
 
-        self.get_env_mut().from_function(&func);
+        if let DataType::Struct(_) = func.data_type {
+            return Err(CompileError::new(
+                "Functions can't return structs",
+                func.span,
+            ));
+        }
 
-        // Assigns a local variable address to each of the function parameters
+        // Arrays of a fixed size are returned by value through a global array
+        let dimension = &func.dimension;
+        let mut return_buffer = None;
+        if dimension.dimensions > 0 {
+            if func.data_type == DataType::Void {
+                return Err(CompileError::new(
+                    "Void functions can't return arrays",
+                    func.span,
+                ));
+            } else if !dimension.lengths.is_empty() {
+                return Err(CompileError::new(
+                    "Sizes of returned arrays must be integer literals",
+                    func.span,
+                ));
+            } else if !dimension.is_runtime_sized() {
+                return_buffer = Some(
+                    self.env
+                        .allocator
+                        .try_assign_location(&MemoryScope::Global, &func.data_type, dimension.size)
+                        .at(func.span)?,
+                );
+            }
+        }
+
+        let declared = self.get_env_mut().from_function(&func);
+        if let Err(error) = declared {
+            self.get_env_mut().switch(&String::from("global"));
+            self.get_env_mut().drop_env(&func.id);
+            return Err(error).at(func.span);
+        }
+        self.get_env_mut()
+            .entries
+            .get_mut(&func.id)
+            .unwrap()
+            .return_buffer = return_buffer;
+
+        // Assigns a local variable address to each of the function parameters,
+        // structs are passed as one parameter for each of their fields
         let params: Vec<ParamAddress> = func
             .params
             .iter()
-            .map(
-                |FunctionParam(Variable {
-                     id,
-                     data_type,
-                     dimension: _,
-                     value: _,
-                 })| {
-                    let param_symbol = self.get_env_mut().get_var(id).unwrap();
-                    (
-                        param_symbol.address,
-                        data_type.clone(),
-                        param_symbol.point_address,
-                    )
-                },
-            )
+            .flat_map(|FunctionParam(Variable { id, .. })| {
+                let param_symbol = self.get_env().get_var(id).unwrap();
+                param_symbol
+                    .leaves()
+                    .into_iter()
+                    .map(|(_, leaf)| (leaf.address, leaf.data_type.clone(), leaf.point_address))
+                    .collect::<Vec<ParamAddress>>()
+            })
             .collect();
 
         self.procedure_table.insert(
@@ -140,6 +249,8 @@ impl Manager {
         if !switch {
             self.get_env_mut().switch(&String::from("global"));
         }
+
+        Ok(())
     }
 
     /// Updates the quadruple start position for a given function.
@@ -156,46 +267,184 @@ impl Manager {
         }
     }
 
-    /// Returns the address for the global variable holding a functions
-    /// return value.
-    ///
-    /// The manager assumes every function gets assigned a unique global
-    /// address to store a return value once it has been called.
+    /// Sets the locals receiving the values captured by the closures of a
+    /// nested function, along with the local holding the closure itself.
     ///
     /// # Panics
     ///
     /// Panics if the provided function id has not been declared before
     /// in the procedure table.
-    pub fn get_func_return(&self, func_id: &String) -> Option<MemAddress> {
-        if let Some(func) = self.procedure_table.get(func_id) {
-            func.return_address
+    pub fn update_func_captures(
+        &mut self,
+        func_id: &String,
+        captures: Vec<MemAddress>,
+        closure_address: Option<MemAddress>,
+    ) {
+        if let Some(func) = self.procedure_table.get_mut(func_id) {
+            func.captures = captures;
+            func.closure_address = closure_address;
         } else {
-            panic!("No funcion {}", func_id);
+            panic!("Can't find function {func_id}")
+        }
+    }
+
+    /// Returns an unused procedure id for a function nested within the
+    /// current environment, e.g. `outer.inner`.
+    pub fn nested_func_id(&self, id: &String) -> String {
+        let prefix = format!("{}.{}", self.env.current_env, id);
+        let mut procedure_id = prefix.clone();
+        let mut count = 1;
+
+        while self.procedure_table.contains_key(&procedure_id)
+            || self.env.entries.contains_key(&procedure_id)
+        {
+            count += 1;
+            procedure_id = format!("{}{}", prefix, count);
         }
+
+        procedure_id
+    }
+
+    /// Returns the address for the global variable holding a functions
+    /// return value.
+    ///
+    /// The manager assumes every function gets assigned a unique global
+    /// address to store a return value once it has been called.
+    ///
+    /// Fails if the provided function id has not been declared before
+    /// in the procedure table.
+    pub fn get_func_return(&self, func_id: &String) -> Result<Option<MemAddress>, String> {
+        Ok(self.get_func(func_id)?.return_address)
     }
 
-    pub fn get_func(&self, func_id: &String) -> &FunctionEntry {
+    /// Fails if the provided function id has not been declared before
+    /// in the procedure table.
+    pub fn get_func(&self, func_id: &String) -> Result<&FunctionEntry, String> {
         if let Some(func) = self.procedure_table.get(func_id) {
-            func
+            Ok(func)
         } else {
-            panic!("No funcion {}", func_id);
+            Err(format!("Function {} is not defined", func_id))
         }
     }
 
+    pub fn get_procedures(&self) -> &HashMap<String, FunctionEntry> {
+        &self.procedure_table
+    }
+
+    /// Removes a function from the procedure table along with its
+    /// environment.
     pub fn drop_func(&mut self, func_id: &String) {
-        self.get_env_mut().switch(&String::from("global"));
+        if self.get_env().current_env == *func_id {
+            self.get_env_mut().switch(&String::from("global"));
+        }
+        self.procedure_table.remove(func_id);
         self.get_env_mut().drop_env(func_id);
     }
 
+    /// Returns the type of the values holding a function.
+    ///
+    /// Fails if the function is not defined, or if it takes or returns
+    /// arrays or structs, since function values only keep the types of
+    /// their scalar parameters.
+    pub fn function_type(&self, func_id: &String) -> Result<DataType, String> {
+        let env = match self.env.entries.get(func_id) {
+            Some(env) => env,
+            None => return Err(format!("Function {} is not defined", func_id)),
+        };
+
+        let mut params = vec![];
+        for param in env.params.iter() {
+            match env.symbols.get(param) {
+                Some(symbol) if symbol.dimension.dimensions > 0 => {
+                    return Err(format!(
+                        "Function {} can't be used as a value, it takes arrays",
+                        func_id
+                    ))
+                }
+                Some(SymbolEntry {
+                    data_type: DataType::Struct(_),
+                    ..
+                }) => {
+                    return Err(format!(
+                        "Function {} can't be used as a value, it takes structs",
+                        func_id
+                    ))
+                }
+                Some(symbol) => params.push(symbol.data_type.clone()),
+                None => (),
+            }
+        }
+
+        if env.return_dimension.dimensions > 0 {
+            return Err(format!(
+                "Function {} can't be used as a value, it returns arrays",
+                func_id
+            ));
+        }
+
+        let return_type = env.return_type.clone().unwrap_or(DataType::Void);
+        Ok(DataType::Function(params, Box::new(return_type)))
+    }
+
+    /// Adds a new struct type that variables can be declared with.
+    ///
+    /// Fails if the struct has been declared before or if any of its fields
+    /// is not valid.
+    pub fn new_struct(&mut self, declaration: &Struct) -> CompileResult<()> {
+        let fields = declaration
+            .fields
+            .iter()
+            .map(|field| (field.id.clone(), field.data_type.clone()))
+            .collect();
+
+        self.get_env_mut()
+            .add_struct(&declaration.id, fields)
+            .at(declaration.span)
+    }
+
     pub fn new_variable(
         &mut self,
         id: &String,
         data_type: &DataType,
         dimension: &Dimension,
         immutable: bool,
-    ) {
+    ) -> Result<MemAddress, String> {
         self.get_env_mut()
-            .add_var(id, data_type, dimension, immutable);
+            .add_var(id, data_type, dimension, immutable)
+    }
+
+    /// Opens a block scope, see [Environment::open_scope], marking the heap
+    /// usage so the arrays allocated within it can be released.
+    pub fn open_scope(&mut self) {
+        self.env.open_scope(self.get_next_pos());
+        self.emit(Instruction::mark(self.scope_depth()));
+    }
+
+    /// Closes the innermost block scope, emitting the quadruples to free the
+    /// values and arrays of the variables declared within it, and the heap
+    /// arrays allocated while it was open.
+    pub fn close_scope(&mut self) {
+        let depth = self.scope_depth();
+        for symbol in self.env.close_scope(self.get_next_pos()) {
+            for (_, leaf) in symbol.leaves() {
+                self.emit(Instruction::free(leaf.address));
+                if let Some(array_address) = leaf.point_address {
+                    self.emit(Instruction::free(array_address));
+                }
+            }
+        }
+        self.emit(Instruction::release(depth));
+    }
+
+    /// Releases the heap arrays allocated since the innermost block scope
+    /// opened, loops release them before each iteration.
+    pub fn release_scope(&mut self) {
+        self.emit(Instruction::release(self.scope_depth()));
+    }
+
+    /// Depth of the innermost block scope of the current function.
+    fn scope_depth(&self) -> usize {
+        self.env.current_env().scopes.len().saturating_sub(1)
     }
 
     pub fn remove_variable(&mut self, id: &String) {
@@ -214,6 +463,64 @@ impl Manager {
             .assign_location(&self.env.current_scope, data_type, 1)
     }
 
+    /// Reserves a temporal array of a given shape, returns the address of
+    /// the pointer to its first item.
+    ///
+    /// Fails if the array doesn't fit in the memory of the current scope.
+    pub fn new_temp_array(
+        &mut self,
+        data_type: &DataType,
+        shape: &[usize],
+    ) -> Result<MemAddress, String> {
+        let start = self.env.allocator.try_assign_location(
+            &self.env.current_scope,
+            data_type,
+            shape.iter().product(),
+        )?;
+        let pointer = self.new_temp(&DataType::Pointer);
+
+        self.emit(Instruction::unary(
+            Operator::Assign,
+            Operand::AddressOf(start),
+            Operand::Direct(pointer),
+        ));
+        self.emit(Instruction::alloc(start, shape));
+
+        Ok(pointer)
+    }
+
+    /// Returns an operand holding the number of items of an array of a given
+    /// shape. Sizes only known at runtime are read from the array, its axes
+    /// are counted from `first_axis` onwards.
+    pub fn array_size(&mut self, array: &Operand, shape: &[usize], first_axis: usize) -> Operand {
+        let static_size: usize = shape.iter().filter(|size| **size > 0).product();
+        let mut size = Operand::Direct(self.new_constant(
+            &DataType::Int,
+            &Const::new(static_size.to_string().as_str(), DataType::Int),
+        ));
+
+        for (axis, _) in shape.iter().enumerate().filter(|(_, size)| **size == 0) {
+            let length = Operand::Direct(self.new_temp(&DataType::Int));
+            self.emit(Instruction::len(
+                array.clone(),
+                first_axis + axis,
+                first_axis + shape.len(),
+                length.clone(),
+            ));
+
+            let product = Operand::Direct(self.new_temp(&DataType::Int));
+            self.emit(Instruction::operation(
+                Operator::Mul,
+                size,
+                length,
+                product.clone(),
+            ));
+            size = product;
+        }
+
+        size
+    }
+
     pub fn new_constant(&mut self, data_type: &DataType, value: &Const) -> MemAddress {
         let address = self
             .env
@@ -225,15 +532,20 @@ impl Manager {
         address
     }
 
-    pub fn emit(&mut self, quadruple: Quadruple) {
-        self.quadruples.push(quadruple);
+    pub fn get_constant(&self, address: &MemAddress) -> Option<&Const> {
+        self.constant_table.get(address)
+    }
+
+    pub fn emit(&mut self, instruction: Instruction) {
+        self.quadruples.push(instruction);
+        self.line_table.push(self.current_line);
         self.instruction_counter += 1;
     }
 
     /// Shortcut to emit a type cast quadruple.
-    pub fn emit_cast(&mut self, target_dt: &DataType, target: &str) -> String {
-        let new = self.new_temp(target_dt).to_string();
-        self.emit(Quadruple::type_cast(target_dt, target, new.as_str()));
+    pub fn emit_cast(&mut self, target_dt: &DataType, target: Operand) -> Operand {
+        let new = Operand::Direct(self.new_temp(target_dt));
+        self.emit(Instruction::type_cast(target_dt, target, new.clone()));
         new
     }
 
@@ -241,7 +553,7 @@ impl Manager {
         return self.quadruples.len();
     }
 
-    pub fn update_instruction(&mut self, id: usize, quad: Quadruple) {
+    pub fn update_instruction(&mut self, id: usize, quad: Instruction) {
         if let Some(local) = self.quadruples.get_mut(id) {
             *local = quad;
         }
@@ -259,7 +571,7 @@ impl Manager {
     ///
     pub fn prepare_exit_stmt(&mut self, stmt_type: &ExitStatement) {
         let stmt_position = self.get_next_pos();
-        self.emit(Quadruple::new_empty());
+        self.emit(Instruction::new_empty());
 
         if let Some(context) = self.unresolved.get_mut(&stmt_type) {
             context.push(stmt_position);
@@ -273,7 +585,7 @@ impl Manager {
     /// Replaces all the quadruples matching an exit statement type with the same
     /// quadruple valuee.
     ///
-    pub fn resolve_context(&mut self, stmt_type: &ExitStatement, quadruple: Quadruple) {
+    pub fn resolve_context(&mut self, stmt_type: &ExitStatement, quadruple: Instruction) {
         let unresolved = self.unresolved.get(stmt_type).unwrap_or(&vec![]).clone();
 
         for ref_quadruple in unresolved {
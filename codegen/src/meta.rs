@@ -1,20 +1,352 @@
-use memory::resolver::MemAddress;
+use memory::{
+    resolver::{MemAddress, MemoryLayout, MemoryResolver, MemoryScope},
+    types::{DataType, IntType},
+};
+use parser::types::Operator;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
-use super::{symbols::FunctionEntry, quadruples::Quadruple};
+use super::{
+    natives::NativeFunction,
+    quadruples::{Instruction, Literal, Operand, Quadruple},
+    symbols::FunctionEntry,
+};
 
 /// Structure to dump the needed program's data for its 
 /// execution.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Debug, Clone)]
 pub struct ProgramMeta {
+    pub quadruples: Vec<Instruction>,
+    pub constant_table: HashMap<MemAddress, String>,
+    pub procedure_table: HashMap<String, FunctionEntry>,
+    /// Path of the source file the program was compiled from.
+    pub source_path: String,
+    /// Source line of every quadruple, 0 when unknown.
+    pub line_table: Vec<usize>,
+}
+
+/// Program data as stored in the pickle object files of previous versions,
+/// with the instructions in their textual form and addresses in the legacy
+/// memory layout.
+#[derive(Serialize, Deserialize)]
+pub struct LegacyProgramMeta {
     pub quadruples: Vec<Quadruple>,
     pub constant_table: HashMap<MemAddress, String>,
     pub procedure_table: HashMap<String, FunctionEntry>,
+    #[serde(default)]
+    pub source_path: String,
+    #[serde(default)]
+    pub line_table: Vec<usize>,
+}
+
+impl TryFrom<&ProgramMeta> for LegacyProgramMeta {
+    type Error = String;
+
+    fn try_from(meta: &ProgramMeta) -> Result<Self, Self::Error> {
+        let mut meta = meta.relocate(&MemoryResolver::LAYOUT, &MemoryLayout::LEGACY)?;
+        meta.remove_native_ranks();
+
+        Ok(LegacyProgramMeta {
+            quadruples: meta.quadruples.iter().map(Quadruple::from).collect(),
+            constant_table: meta.constant_table,
+            procedure_table: meta.procedure_table,
+            source_path: meta.source_path,
+            line_table: meta.line_table,
+        })
+    }
+}
+
+impl TryFrom<LegacyProgramMeta> for ProgramMeta {
+    type Error = String;
+
+    fn try_from(meta: LegacyProgramMeta) -> Result<Self, Self::Error> {
+        let mut meta = ProgramMeta {
+            quadruples: meta
+                .quadruples
+                .iter()
+                .map(Instruction::try_from)
+                .collect::<Result<Vec<Instruction>, String>>()?,
+            constant_table: meta.constant_table,
+            procedure_table: meta.procedure_table,
+            source_path: meta.source_path,
+            line_table: meta.line_table,
+        }
+        .relocate(&MemoryLayout::LEGACY, &MemoryResolver::LAYOUT)?;
+        meta.replace_end_markers();
+
+        // Programs of previous versions couldn't shadow native functions
+        for (id, procedure) in meta.procedure_table.iter_mut() {
+            procedure.is_native = NativeFunction::from_str(id).is_ok();
+        }
+        meta.add_native_ranks();
+
+        Ok(meta)
+    }
 }
 
 impl ProgramMeta {
     pub fn get_func(&self, id: &String) -> &FunctionEntry {
         self.procedure_table.get(id).unwrap()
     }
+
+    /// Returns the source line that generated the quadruple at the given
+    /// position, if known.
+    pub fn line(&self, position: usize) -> Option<usize> {
+        match self.line_table.get(position) {
+            Some(0) | None => None,
+            Some(line) => Some(*line),
+        }
+    }
+
+    /// Replaces the markers the programs of previous versions wrote at the
+    /// end of their arrays with the instructions reserving them.
+    ///
+    /// Arrays used to be declared by assigning their start address to a
+    /// pointer, right before marking their end.
+    pub fn replace_end_markers(&mut self) {
+        for position in 1..self.quadruples.len() {
+            let alloc = match (&self.quadruples[position - 1], &self.quadruples[position]) {
+                (
+                    Instruction::Unary(Operator::Assign, Operand::AddressOf(start), _),
+                    Instruction::Unary(
+                        Operator::Assign,
+                        Operand::Const(Literal::End),
+                        Operand::Direct(end),
+                    ),
+                ) if end > start => Instruction::alloc(*start, &[(end - start) as usize]),
+                _ => continue,
+            };
+
+            self.quadruples[position] = alloc;
+        }
+    }
+
+    /// Passes the rank of the arrays given to natives by the programs of
+    /// previous versions, which didn't pass it.
+    ///
+    /// Their arrays are reserved with a single axis, so the rank is always 1
+    /// and follows the last parameter of the call.
+    pub fn add_native_ranks(&mut self) {
+        let mut quadruples = vec![];
+        let mut lines = vec![];
+        let mut positions = vec![];
+        let mut last_param = None;
+        let rank = Operand::Direct(self.int_constant(1));
+        for (position, instruction) in self.quadruples.iter().enumerate() {
+            let line = self.line_table.get(position).copied().unwrap_or(0);
+            positions.push(quadruples.len());
+            match instruction {
+                Instruction::Param(_, index) => last_param = Some(*index),
+                Instruction::GoSub(id) if self.takes_rank(id) => {
+                    if let Some(index) = last_param {
+                        quadruples.push(Instruction::param(rank.clone(), index + 1));
+                        lines.push(line);
+                    }
+                }
+                _ => (),
+            }
+            quadruples.push(instruction.clone());
+            lines.push(line);
+        }
+
+        self.splice(quadruples, lines, positions);
+    }
+
+    /// Removes the ranks passed to natives, which the programs of previous
+    /// versions can't read.
+    pub fn remove_native_ranks(&mut self) {
+        let mut quadruples = vec![];
+        let mut lines = vec![];
+        let mut positions = vec![];
+        for (position, instruction) in self.quadruples.iter().enumerate() {
+            positions.push(quadruples.len());
+            let is_rank = matches!(
+                (instruction, self.quadruples.get(position + 1)),
+                (Instruction::Param(..), Some(Instruction::GoSub(id))) if self.takes_rank(id)
+            );
+            if !is_rank {
+                quadruples.push(instruction.clone());
+                lines.push(self.line_table.get(position).copied().unwrap_or(0));
+            }
+        }
+
+        self.splice(quadruples, lines, positions);
+    }
+
+    /// Returns the address of an int constant, adding it to the constant
+    /// table if the program doesn't have it.
+    fn int_constant(&mut self, value: IntType) -> MemAddress {
+        let constants = self.constant_table.iter().filter(|(address, _)| {
+            MemoryResolver::get_type_from_address(**address) == Some(&DataType::Int)
+        });
+        if let Some((address, _)) = constants
+            .clone()
+            .find(|(_, text)| text.parse() == Ok(value))
+        {
+            return *address;
+        }
+
+        let offset = constants
+            .map(|(address, _)| MemoryResolver::get_offset(*address).2 + 1)
+            .max()
+            .unwrap_or(0);
+        let address = MemoryResolver::to_address(&MemoryScope::Constant, &DataType::Int, offset);
+        self.constant_table.insert(address, value.to_string());
+        address
+    }
+
+    /// Whether a procedure is a native given the rank of its array.
+    fn takes_rank(&self, id: &str) -> bool {
+        NativeFunction::resolve(id, &self.procedure_table).is_some_and(|native| native.takes_rank())
+    }
+
+    /// Replaces the instructions of the program, moving the jump targets and
+    /// procedure entry points from their old positions to the new ones.
+    fn splice(
+        &mut self,
+        quadruples: Vec<Instruction>,
+        lines: Vec<usize>,
+        mut positions: Vec<usize>,
+    ) {
+        positions.push(quadruples.len());
+        let relocate = |target: usize| positions[target.min(positions.len() - 1)];
+
+        self.quadruples = quadruples
+            .into_iter()
+            .map(|instruction| match instruction {
+                Instruction::Goto(Operand::Label(target)) => Instruction::goto(relocate(target)),
+                Instruction::GotoFalse(check, Operand::Label(target)) => {
+                    Instruction::goto_false(check, relocate(target))
+                }
+                instruction => instruction,
+            })
+            .collect();
+        if !self.line_table.is_empty() {
+            self.line_table = lines;
+        }
+        for procedure in self.procedure_table.values_mut() {
+            if !procedure.is_native {
+                procedure.procedure_address = relocate(procedure.procedure_address);
+            }
+        }
+    }
+
+    /// Moves every address of the program from a memory layout to another.
+    ///
+    /// Fails if an address is not valid in the original layout or doesn't
+    /// fit in the new one.
+    pub fn relocate(&self, from: &MemoryLayout, to: &MemoryLayout) -> Result<ProgramMeta, String> {
+        let address = |address: MemAddress| {
+            from.translate(address, to)
+                .ok_or_else(|| format!("Address {} can't be relocated", address))
+        };
+        let optional = |value: Option<MemAddress>| value.map(address).transpose();
+        let operand = |operand: &Operand| -> Result<Operand, String> {
+            match operand {
+                Operand::Direct(value) => Ok(Operand::Direct(address(*value)?)),
+                Operand::Indirect(value) => Ok(Operand::Indirect(address(*value)?)),
+                Operand::AddressOf(value) => Ok(Operand::AddressOf(address(*value)?)),
+                operand => Ok(operand.clone()),
+            }
+        };
+
+        let quadruples = self
+            .quadruples
+            .iter()
+            .map(|instruction| {
+                Ok(match instruction {
+                    Instruction::Operation(operator, left, right, dest) => Instruction::Operation(
+                        *operator,
+                        operand(left)?,
+                        operand(right)?,
+                        operand(dest)?,
+                    ),
+                    Instruction::Unary(operator, value, dest) => {
+                        Instruction::Unary(*operator, operand(value)?, operand(dest)?)
+                    }
+                    Instruction::Cast(data_type, value, dest) => {
+                        Instruction::Cast(data_type.clone(), operand(value)?, operand(dest)?)
+                    }
+                    Instruction::Verify(value, bound) => {
+                        Instruction::Verify(operand(value)?, operand(bound)?)
+                    }
+                    Instruction::GotoFalse(check, target) => {
+                        Instruction::GotoFalse(operand(check)?, target.clone())
+                    }
+                    Instruction::Param(value, index) => Instruction::Param(operand(value)?, *index),
+                    Instruction::Return(value) => Instruction::Return(operand(value)?),
+                    Instruction::Print(value) => Instruction::Print(operand(value)?),
+                    Instruction::Free(value) => Instruction::Free(operand(value)?),
+                    Instruction::Alloc(value, shape) => {
+                        Instruction::Alloc(operand(value)?, shape.clone())
+                    }
+                    Instruction::Len(value, axis, rank, dest) => {
+                        Instruction::Len(operand(value)?, *axis, *rank, operand(dest)?)
+                    }
+                    Instruction::New(data_type, sizes, dest) => Instruction::New(
+                        data_type.clone(),
+                        sizes.iter().map(operand).collect::<Result<Vec<Operand>, String>>()?,
+                        operand(dest)?,
+                    ),
+                    Instruction::View(source, sizes, strides, dest) => Instruction::View(
+                        operand(source)?,
+                        sizes.iter().map(operand).collect::<Result<Vec<Operand>, String>>()?,
+                        strides.iter().map(operand).collect::<Result<Vec<Operand>, String>>()?,
+                        operand(dest)?,
+                    ),
+                    Instruction::Copy(source, count, dest) => {
+                        Instruction::Copy(operand(source)?, operand(count)?, operand(dest)?)
+                    }
+                    Instruction::Elementwise(operator, left, right, dest) => {
+                        Instruction::Elementwise(
+                            *operator,
+                            operand(left)?,
+                            operand(right)?,
+                            operand(dest)?,
+                        )
+                    }
+                    instruction => instruction.clone(),
+                })
+            })
+            .collect::<Result<Vec<Instruction>, String>>()?;
+
+        let constant_table = self
+            .constant_table
+            .iter()
+            .map(|(key, value)| Ok((address(*key)?, value.clone())))
+            .collect::<Result<HashMap<MemAddress, String>, String>>()?;
+
+        let procedure_table = self
+            .procedure_table
+            .iter()
+            .map(|(id, func)| {
+                let params = func
+                    .params
+                    .iter()
+                    .map(|(param, data_type, point_address)| {
+                        Ok((
+                            address(*param)?,
+                            data_type.clone(),
+                            optional(*point_address)?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+
+                let func = FunctionEntry {
+                    params,
+                    return_address: optional(func.return_address)?,
+                    ..func.clone()
+                };
+                Ok((id.clone(), func))
+            })
+            .collect::<Result<HashMap<String, FunctionEntry>, String>>()?;
+
+        Ok(ProgramMeta {
+            quadruples,
+            constant_table,
+            procedure_table,
+            source_path: self.source_path.clone(),
+            line_table: self.line_table.clone(),
+        })
+    }
 }
@@ -1,14 +1,24 @@
-use std::str::FromStr;
+use std::{collections::HashMap, iter::zip, str::FromStr};
 
-use memory::types::DataType;
+use memory::types::{DataType, FloatType};
 use parser::{
-    expressions::call::Call,
+    expressions::{call::Call, constant::Const, id::Access, Expression},
     functions::{FunctionParam, FunctionSignature},
+    semantics::SemanticRules,
+    span::Span,
     types::Operator,
+    Dimension,
 };
 use strum::{Display, EnumIter, EnumProperty, EnumString, EnumVariantNames, IntoEnumIterator};
 
-use crate::{manager::Manager, node::ExpressionNode, quadruples::Quadruple};
+use crate::{
+    ast_nodes::expressions::AccessSymbol,
+    errors::{AtSpan, CompileError, CompileResult},
+    manager::Manager,
+    node::ExpressionNode,
+    quadruples::{Instruction, Literal, Operand},
+    symbols::FunctionEntry,
+};
 
 /// Defines all the native functions whose implementation is in the side
 /// of the virtual machine
@@ -42,6 +52,62 @@ pub enum NativeFunction {
     Std,
     Sum,
     Var,
+    Len,
+    Transpose,
+    Dot,
+    Identity,
+    Inverse,
+    Det,
+    Solve,
+    Map,
+    Filter,
+    Reduce,
+    Substr,
+    Find,
+    Replace,
+    Split,
+    Join,
+    Upper,
+    Lower,
+    Trim,
+    StartsWith,
+    EndsWith,
+    ToInt,
+    ToFloat,
+    Format,
+    Abs,
+    Sqrt,
+    Pow,
+    Exp,
+    Log,
+    Ln,
+    Sin,
+    Cos,
+    Tan,
+    Floor,
+    Ceil,
+    Round,
+    Min,
+    Max,
+    Clamp,
+}
+
+/// Defines the constants available to every program, which can be shadowed
+/// by variables of the same name.
+#[derive(Debug, PartialEq, EnumString, Display)]
+pub enum NativeConstant {
+    #[strum(serialize = "PI")]
+    Pi,
+    E,
+}
+
+impl NativeConstant {
+    pub fn value(&self) -> FloatType {
+        match self {
+            NativeConstant::Pi => std::f64::consts::PI,
+            NativeConstant::E => std::f64::consts::E,
+        }
+    }
 }
 
 fn ptr_param(name: &str) -> FunctionParam {
@@ -64,20 +130,42 @@ fn df_param(name: &str) -> FunctionParam {
     FunctionParam::new_scalar(name, DataType::DataFrame)
 }
 
-fn series_param(name: &str) -> FunctionParam {
-    FunctionParam::new_scalar(name, DataType::Series)
-}
-
 impl NativeFunction {
-    pub fn data_type(id: &String, manager: &mut Manager) -> Option<DataType> {
-        if let Ok(function_id) = NativeFunction::from_str(id.as_str()) {
+    /// Returns the native function called by an id, unless the program
+    /// declared a function shadowing it.
+    pub fn resolve(
+        id: &str,
+        procedures: &HashMap<String, FunctionEntry>,
+    ) -> Option<NativeFunction> {
+        match procedures.get(id) {
+            Some(procedure) if !procedure.is_native => None,
+            _ => NativeFunction::from_str(id).ok(),
+        }
+    }
+
+    pub fn data_type(ctx: &Call, manager: &mut Manager) -> CompileResult<Option<DataType>> {
+        let id = &ctx.id;
+        if let Some(function_id) = NativeFunction::resolve(id, manager.get_procedures()) {
             if let Some(_) = function_id.get_str("check_params") {
-                Some(DataType::Void)
+                Ok(Some(DataType::Void))
+            } else if let NativeFunction::Map | NativeFunction::Filter | NativeFunction::Reduce =
+                function_id
+            {
+                let (_, return_type) =
+                    NativeFunction::check_function_param(&function_id, ctx, manager)?;
+                Ok(Some(return_type))
+            } else if function_id.is_math() {
+                let (return_type, _) =
+                    NativeFunction::check_math_params(&function_id, ctx, manager)?;
+                Ok(Some(return_type))
             } else {
-                Some(manager.get_func(&id).return_type.clone())
+                Ok(manager
+                    .get_func(&id)
+                    .ok()
+                    .map(|func| func.return_type.clone()))
             }
         } else {
-            None
+            Ok(None)
         }
     }
 
@@ -108,9 +196,7 @@ impl NativeFunction {
                     NativeFunction::Select => {
                         (DataType::Series, vec![df_param("df"), str_param("col")])
                     }
-                    NativeFunction::Scatter => {
-                        (DataType::Void, vec![series_param("x"), series_param("y")])
-                    }
+                    NativeFunction::Scatter => (DataType::Void, vec![]), // Params are checked in custom reduce
                     NativeFunction::SetCaption => (DataType::Void, vec![str_param("caption")]),
                     NativeFunction::SetXTitle => (DataType::Void, vec![str_param("title")]),
                     NativeFunction::SetYTitle => (DataType::Void, vec![str_param("title")]),
@@ -130,108 +216,960 @@ impl NativeFunction {
                     | NativeFunction::Std
                     | NativeFunction::Sum
                     | NativeFunction::Var => (DataType::Float, vec![]), // Params are checked in custom reduce
+                    NativeFunction::Len => (DataType::Int, vec![]),
+                    NativeFunction::Transpose | NativeFunction::Inverse | NativeFunction::Det => {
+                        (DataType::Float, vec![ptr_param("matrix")])
+                    }
+                    NativeFunction::Dot => (DataType::Float, vec![ptr_param("a"), ptr_param("b")]),
+                    NativeFunction::Identity => (DataType::Float, vec![int_param("size")]),
+                    NativeFunction::Solve => (
+                        DataType::Float,
+                        vec![ptr_param("matrix"), ptr_param("values")],
+                    ),
+                    NativeFunction::Map | NativeFunction::Filter | NativeFunction::Reduce => {
+                        (DataType::Pointer, vec![]) // Params are checked in custom reduce
+                    }
+                    NativeFunction::Substr => (
+                        DataType::String,
+                        vec![str_param("text"), int_param("start"), int_param("length")],
+                    ),
+                    NativeFunction::Find => {
+                        (DataType::Int, vec![str_param("text"), str_param("pattern")])
+                    }
+                    NativeFunction::Replace => (
+                        DataType::String,
+                        vec![str_param("text"), str_param("from"), str_param("to")],
+                    ),
+                    NativeFunction::Split => (
+                        DataType::String,
+                        vec![str_param("text"), str_param("separator")],
+                    ),
+                    NativeFunction::Join => (DataType::String, vec![]), // Params are checked in custom reduce
+                    NativeFunction::Upper | NativeFunction::Lower | NativeFunction::Trim => {
+                        (DataType::String, vec![str_param("text")])
+                    }
+                    NativeFunction::StartsWith => {
+                        (DataType::Bool, vec![str_param("text"), str_param("prefix")])
+                    }
+                    NativeFunction::EndsWith => {
+                        (DataType::Bool, vec![str_param("text"), str_param("suffix")])
+                    }
+                    NativeFunction::ToInt => (DataType::Int, vec![str_param("text")]),
+                    NativeFunction::ToFloat => (DataType::Float, vec![str_param("text")]),
+                    NativeFunction::Format => (DataType::String, vec![]), // Params are checked in custom reduce
+                    NativeFunction::Abs
+                    | NativeFunction::Sqrt
+                    | NativeFunction::Exp
+                    | NativeFunction::Log
+                    | NativeFunction::Ln
+                    | NativeFunction::Sin
+                    | NativeFunction::Cos
+                    | NativeFunction::Tan => (DataType::Float, vec![float_param("x")]),
+                    NativeFunction::Floor | NativeFunction::Ceil | NativeFunction::Round => {
+                        (DataType::Int, vec![float_param("x")])
+                    }
+                    NativeFunction::Pow => (
+                        DataType::Float,
+                        vec![float_param("base"), float_param("exponent")],
+                    ),
+                    NativeFunction::Min | NativeFunction::Max => {
+                        (DataType::Float, vec![float_param("a"), float_param("b")])
+                    }
+                    NativeFunction::Clamp => (
+                        DataType::Float,
+                        vec![float_param("x"), float_param("low"), float_param("high")],
+                    ),
                     _ => panic!(),
                 };
 
+                // Arrays are returned by reference, their sizes are only known at runtime
+                let dimension = match func {
+                    NativeFunction::Transpose
+                    | NativeFunction::Identity
+                    | NativeFunction::Inverse => Dimension::new_runtime(2),
+                    NativeFunction::Solve | NativeFunction::Split => Dimension::new_runtime(1),
+                    _ => Dimension::new_scalar(),
+                };
+
                 FunctionSignature {
                     id: func.to_string(),
                     params,
                     data_type,
+                    dimension,
                     is_native: true,
+                    span: Span::default(),
                 }
             })
             .collect::<Vec<FunctionSignature>>()
     }
 
+    /// Defines the shape of the arrays returned by native functions, as far
+    /// as it can be known from their parameters.
+    /// If None is returned, the shape declared by the function is used.
+    pub fn dimensionality(ctx: &Call, manager: &mut Manager) -> CompileResult<Option<Vec<usize>>> {
+        let function_id = match NativeFunction::resolve(&ctx.id, manager.get_procedures()) {
+            Some(function_id) => function_id,
+            None => return Ok(None),
+        };
+
+        let mut param_dims = vec![];
+        for param in ctx.params.iter() {
+            param_dims.push(param.dimensionality(manager)?);
+        }
+        let matrix = param_dims.first().filter(|dims| dims.len() == 2);
+
+        let shape = match function_id {
+            NativeFunction::Transpose => match matrix {
+                Some(dims) => vec![dims[1], dims[0]],
+                None => vec![0, 0],
+            },
+            NativeFunction::Inverse => matrix.cloned().unwrap_or(vec![0, 0]),
+            NativeFunction::Solve => vec![matrix.map(|dims| dims[0]).unwrap_or(0)],
+            // Series are mapped and filtered into a new Series
+            NativeFunction::Map => match ctx.params.first() {
+                Some(items) if items.data_type(manager)? != DataType::Series => {
+                    param_dims[0].clone()
+                }
+                _ => vec![],
+            },
+            NativeFunction::Filter => match ctx.params.first() {
+                Some(items) if items.data_type(manager)? != DataType::Series => vec![0],
+                _ => vec![],
+            },
+            NativeFunction::Reduce => vec![],
+            NativeFunction::Identity => match ctx.params.first().map(|param| param.as_ref()) {
+                Some(Expression::Const(size)) if size.dtype == DataType::Int => {
+                    let size = size.value.parse::<usize>().unwrap_or(0);
+                    vec![size, size]
+                }
+                _ => vec![0, 0],
+            },
+            _ if function_id.is_math() => {
+                NativeFunction::check_math_params(&function_id, ctx, manager)?.1
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(shape))
+    }
+
+    /// Checks the parameters of the linear algebra functions, each one
+    /// must be a numeric array of the expected number of dimensions.
+    fn check_matrix_params(
+        function_id: &NativeFunction,
+        ctx: &Call,
+        manager: &mut Manager,
+    ) -> CompileResult<()> {
+        let id = ctx.id.as_str();
+        let ranks: &[usize] = match function_id {
+            NativeFunction::Identity => &[0],
+            NativeFunction::Dot => &[1, 1],
+            NativeFunction::Solve => &[2, 1],
+            _ => &[2],
+        };
+
+        if ctx.params.len() != ranks.len() {
+            return Err(CompileError::new(
+                format!(
+                    "Function {id} takes {} parameters, {} were provided",
+                    ranks.len(),
+                    ctx.params.len()
+                )
+                .as_str(),
+                ctx.span,
+            ));
+        }
+
+        let mut param_dims = vec![];
+        for (param, rank) in zip(ctx.params.iter(), ranks) {
+            let dims = param.dimensionality(manager)?;
+            let data_type = param.data_type(manager)?;
+
+            if *rank == 0 {
+                if !dims.is_empty() || DataType::equivalent(&data_type, &DataType::Int).is_err() {
+                    return Err(CompileError::new(
+                        format!("Function {id} takes an integer size").as_str(),
+                        param.span(),
+                    ));
+                }
+            } else if dims.len() != *rank {
+                return Err(CompileError::new(
+                    format!(
+                        "Function {id} takes {rank}-dimensional arrays, found dimensions {:?}",
+                        dims
+                    )
+                    .as_str(),
+                    param.span(),
+                ));
+            } else if data_type != DataType::Int && data_type != DataType::Float {
+                return Err(CompileError::new(
+                    format!("Function {id} does not accept arrays of {:?}", data_type).as_str(),
+                    param.span(),
+                ));
+            }
+
+            param_dims.push(dims);
+        }
+
+        let shapes_match = match function_id {
+            NativeFunction::Dot => SemanticRules::match_shape(&param_dims[0], &param_dims[1]),
+            NativeFunction::Solve => {
+                SemanticRules::match_shape(&param_dims[0][..1], &param_dims[1])
+            }
+            _ => true,
+        };
+        if !shapes_match {
+            return Err(CompileError::new(
+                format!(
+                    "Function {id} can't operate arrays with dimensions {:?} and {:?}",
+                    param_dims[0], param_dims[1]
+                )
+                .as_str(),
+                ctx.span,
+            ));
+        }
+
+        if let NativeFunction::Inverse | NativeFunction::Det | NativeFunction::Solve = function_id {
+            let dims = &param_dims[0];
+            if !SemanticRules::match_shape(&dims[..1], &dims[1..]) {
+                return Err(CompileError::new(
+                    format!(
+                        "Function {id} takes a square matrix, found dimensions {:?}",
+                        dims
+                    )
+                    .as_str(),
+                    ctx.params[0].span(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the function is given the rank of its first parameter after
+    /// the rest of its parameters, see `emit_rank`.
+    pub fn takes_rank(&self) -> bool {
+        match self {
+            NativeFunction::Mean
+            | NativeFunction::Median
+            | NativeFunction::Std
+            | NativeFunction::Sum
+            | NativeFunction::Var
+            | NativeFunction::Map
+            | NativeFunction::Filter
+            | NativeFunction::Reduce => true,
+            function if function.is_math() => true,
+            function => NativeFunction::get_function_definitions()
+                .iter()
+                .find(|func| func.id == function.to_string())
+                .and_then(|func| func.params.first())
+                .is_some_and(|param| param.0.data_type == DataType::Pointer),
+        }
+    }
+
+    /// Whether the function operates numbers, it's applied item by item to
+    /// arrays and Series.
+    pub fn is_math(&self) -> bool {
+        matches!(
+            self,
+            NativeFunction::Abs
+                | NativeFunction::Sqrt
+                | NativeFunction::Pow
+                | NativeFunction::Exp
+                | NativeFunction::Log
+                | NativeFunction::Ln
+                | NativeFunction::Sin
+                | NativeFunction::Cos
+                | NativeFunction::Tan
+                | NativeFunction::Floor
+                | NativeFunction::Ceil
+                | NativeFunction::Round
+                | NativeFunction::Min
+                | NativeFunction::Max
+                | NativeFunction::Clamp
+        )
+    }
+
+    /// Checks the parameters of the math functions, each one can be a
+    /// number, an array of numbers or a Series. Arrays must have the same
+    /// shape and can't be operated along with Series.
+    ///
+    /// Returns the type and the shape of the result.
+    fn check_math_params(
+        function_id: &NativeFunction,
+        ctx: &Call,
+        manager: &mut Manager,
+    ) -> CompileResult<(DataType, Vec<usize>)> {
+        let id = ctx.id.as_str();
+        let params_len = manager.get_func(&ctx.id).at(ctx.span)?.params.len();
+        if ctx.params.len() != params_len {
+            return Err(CompileError::new(
+                format!(
+                    "Function {id} takes {params_len} parameters, {} were provided",
+                    ctx.params.len()
+                )
+                .as_str(),
+                ctx.span,
+            ));
+        }
+
+        let (mut shape, mut is_series, mut all_ints) = (vec![], false, true);
+        for param in ctx.params.iter() {
+            let data_type = param.data_type(manager)?;
+            match data_type {
+                DataType::Int | DataType::Float => (),
+                DataType::Series => is_series = true,
+                _ => {
+                    return Err(CompileError::new(
+                        format!("Function {id} takes numbers, arrays of numbers or Series")
+                            .as_str(),
+                        param.span(),
+                    ))
+                }
+            }
+            all_ints &= data_type == DataType::Int;
+
+            let dims = param.dimensionality(manager)?;
+            if dims.is_empty() {
+                continue;
+            } else if !shape.is_empty() && !SemanticRules::match_shape(&shape, &dims) {
+                return Err(CompileError::new(
+                    format!(
+                        "Function {id} can't operate arrays with dimensions {:?} and {:?}",
+                        shape, dims
+                    )
+                    .as_str(),
+                    ctx.span,
+                ));
+            }
+
+            // Sizes known at compile time are kept over the runtime ones
+            shape = match shape.is_empty() {
+                true => dims,
+                false => zip(&shape, &dims)
+                    .map(|(left, right)| *left.max(right))
+                    .collect(),
+            };
+        }
+
+        if is_series && !shape.is_empty() {
+            return Err(CompileError::new(
+                format!("Function {id} can't operate arrays along with Series").as_str(),
+                ctx.span,
+            ));
+        }
+
+        let data_type = match function_id {
+            _ if is_series => DataType::Series,
+            NativeFunction::Floor | NativeFunction::Ceil | NativeFunction::Round => DataType::Int,
+            NativeFunction::Abs
+            | NativeFunction::Min
+            | NativeFunction::Max
+            | NativeFunction::Clamp
+                if all_ints =>
+            {
+                DataType::Int
+            }
+            _ => DataType::Float,
+        };
+
+        Ok((data_type, shape))
+    }
+
+    /// Checks the parameters of the functions applying a function value to
+    /// the items of an array or Series: the items, the function and the
+    /// initial value for reduce.
+    ///
+    /// Returns the parameter types of the applied function along with the
+    /// type returned by the call.
+    fn check_function_param(
+        function_id: &NativeFunction,
+        ctx: &Call,
+        manager: &mut Manager,
+    ) -> CompileResult<(Vec<DataType>, DataType)> {
+        let id = ctx.id.as_str();
+        let (params_len, arity) = match function_id {
+            NativeFunction::Reduce => (3, 2),
+            _ => (2, 1),
+        };
+
+        if ctx.params.len() != params_len {
+            return Err(CompileError::new(
+                format!(
+                    "Function {id} takes {params_len} parameters, {} were provided",
+                    ctx.params.len()
+                )
+                .as_str(),
+                ctx.span,
+            ));
+        }
+
+        let items = &ctx.params[0];
+        let items_type = items.data_type(manager)?;
+        let is_series = items_type == DataType::Series;
+        if items.dimensionality(manager)?.is_empty() && !is_series {
+            return Err(CompileError::new(
+                format!("Function {id} takes an array or Series").as_str(),
+                items.span(),
+            ));
+        } else if let DataType::Struct(_) = items_type {
+            return Err(CompileError::new(
+                format!("Function {id} does not accept arrays of structs").as_str(),
+                items.span(),
+            ));
+        }
+
+        let function = &ctx.params[1];
+        let (params, return_type) = match function.data_type(manager)? {
+            DataType::Function(params, return_type) if params.len() == arity => {
+                (params, *return_type)
+            }
+            _ => {
+                return Err(CompileError::new(
+                    format!("Function {id} takes a function of {arity} parameters").as_str(),
+                    function.span(),
+                ))
+            }
+        };
+
+        // Items of a Series are checked as they are read
+        let item_param = params.last().unwrap();
+        if !is_series && DataType::equivalent(&items_type, item_param).is_err() {
+            return Err(CompileError::new(
+                format!(
+                    "Function {id} can't apply a function taking {:?} to items of {:?}",
+                    item_param, items_type
+                )
+                .as_str(),
+                function.span(),
+            ));
+        }
+
+        let call_type = match function_id {
+            NativeFunction::Map if return_type == DataType::Void => {
+                return Err(CompileError::new(
+                    format!("Function {id} takes a function returning a value").as_str(),
+                    function.span(),
+                ))
+            }
+            NativeFunction::Filter if return_type != DataType::Bool => {
+                return Err(CompileError::new(
+                    format!("Function {id} takes a function returning a bool").as_str(),
+                    function.span(),
+                ))
+            }
+            NativeFunction::Map | NativeFunction::Filter if is_series => DataType::Series,
+            NativeFunction::Map => return_type,
+            NativeFunction::Filter => items_type,
+            _ => {
+                // The accumulated value is passed back as the first parameter
+                let accumulated = &params[0];
+                let initial = &ctx.params[2];
+                let initial_type = initial.data_type(manager)?;
+                if !initial.dimensionality(manager)?.is_empty()
+                    || DataType::equivalent(&initial_type, accumulated).is_err()
+                    || DataType::equivalent(&return_type, accumulated).is_err()
+                {
+                    return Err(CompileError::new(
+                        format!(
+                            "Function {id} takes an initial value and a function returning values of {:?}",
+                            accumulated
+                        )
+                        .as_str(),
+                        initial.span(),
+                    ));
+                }
+                accumulated.clone()
+            }
+        };
+
+        Ok((params, call_type))
+    }
+
+    /// Formats the fields of a struct into a new string, nested structs are
+    /// formatted the same way.
+    fn format_struct(access: &Access, manager: &mut Manager) -> CompileResult<Operand> {
+        let symbol = access.symbol(manager)?;
+        if !access.dimensionality(manager)?.is_empty() {
+            return Err(CompileError::new(
+                "Can't print arrays of structs, print each of their items instead",
+                access.span,
+            ));
+        }
+
+        let text = |manager: &mut Manager, value: &str| {
+            Operand::Direct(
+                manager.new_constant(&DataType::String, &Const::new(value, DataType::String)),
+            )
+        };
+        let concat = |manager: &mut Manager, left: Operand, right: Operand| {
+            let dest = Operand::Direct(manager.new_temp(&DataType::String));
+            manager.emit(Instruction::operation(
+                Operator::Add,
+                left,
+                right,
+                dest.clone(),
+            ));
+            dest
+        };
+
+        let name = match &symbol.data_type {
+            DataType::Struct(name) => name.clone(),
+            _ => symbol.id.clone(),
+        };
+        let mut result = text(manager, format!("{} {{ ", name).as_str());
+
+        for (index, field) in symbol.fields.iter().enumerate() {
+            let separator = if index > 0 { ", " } else { "" };
+            let label = text(manager, format!("{}{}: ", separator, field.id).as_str());
+            result = concat(manager, result, label);
+
+            let field_access = access.field(std::slice::from_ref(&field.id));
+            let value = match field.data_type {
+                DataType::Struct(_) => NativeFunction::format_struct(&field_access, manager)?,
+                DataType::String => field_access.reduce(manager)?,
+                _ => {
+                    let value = field_access.reduce(manager)?;
+                    manager.emit_cast(&DataType::String, value)
+                }
+            };
+            result = concat(manager, result, value);
+        }
+
+        let end = text(manager, " }");
+        Ok(concat(manager, result, end))
+    }
+
+    /// Passes the rank of the arrays given to a native as the parameter at
+    /// an index, so natives given a row of an array read only that row.
+    pub fn emit_rank(rank: usize, index: usize, manager: &mut Manager) {
+        let rank = Const::new(rank.to_string().as_str(), DataType::Int);
+        let rank = manager.new_constant(&DataType::Int, &rank);
+        manager.emit(Instruction::param(Operand::Direct(rank), index));
+    }
+
     /// Defines custom reduce logic for Call nodes.
     /// If None is returned, the native function call will be treated as
     /// every other function.
-    pub fn call_reduce(ctx: &Call, manager: &mut Manager) -> Option<String> {
+    pub fn call_reduce(ctx: &Call, manager: &mut Manager) -> CompileResult<Option<Operand>> {
         let id = ctx.id.as_str();
-        if let Ok(function_id) = NativeFunction::from_str(id) {
+        if let Some(function_id) = NativeFunction::resolve(id, manager.get_procedures()) {
             match function_id {
                 NativeFunction::Print | NativeFunction::Println => {
-                    ctx.params.iter().for_each(|param| {
-                        let value = param.reduce(manager);
-                        manager.emit(Quadruple::new(
-                            NativeFunction::Print.to_string().as_str(),
-                            "",
-                            "",
-                            value.as_str(),
-                        ));
-                    });
+                    for param in ctx.params.iter() {
+                        let value = match (param.as_ref(), param.data_type(manager)?) {
+                            (Expression::Access(access), DataType::Struct(_)) => {
+                                NativeFunction::format_struct(access, manager)?
+                            }
+                            _ => param.reduce(manager)?,
+                        };
+                        manager.emit(Instruction::print(value));
+                    }
                     if function_id == NativeFunction::Println {
-                        manager.emit(Quadruple::new(
-                            NativeFunction::Print.to_string().as_str(),
-                            "",
-                            "",
-                            "\n",
-                        ));
+                        manager.emit(Instruction::print(Operand::Const(Literal::Text(
+                            String::from("\n"),
+                        ))));
                     }
-                    Some(String::from("VOID"))
+                    Ok(Some(Operand::Const(Literal::Void)))
                 }
                 NativeFunction::Read => {
-                    manager.emit(Quadruple::era(id));
+                    manager.emit(Instruction::era(id));
 
-                    ctx.params.iter().enumerate().for_each(|(index, param)| {
+                    for (index, param) in ctx.params.iter().enumerate() {
                         match **param {
-                            parser::expressions::Expression::Access(_) => (),
-                            _ => panic!("Can only read values from variables"),
+                            Expression::Access(_) => (),
+                            _ => {
+                                return Err(CompileError::new(
+                                    "Can only read values from variables",
+                                    param.span(),
+                                ))
+                            }
                         };
 
-                        let value_addr = param.reduce(manager);
+                        let value_addr = param.reduce(manager)?;
 
-                        manager.emit(Quadruple::param(value_addr.as_str(), index));
-                    });
+                        manager.emit(Instruction::param(value_addr, index));
+                    }
 
-                    manager.emit(Quadruple::go_sub(id));
+                    manager.emit(Instruction::go_sub(id));
 
-                    Some(String::from("VOID"))
+                    Ok(Some(Operand::Const(Literal::Void)))
                 }
                 NativeFunction::Mean
                 | NativeFunction::Median
                 | NativeFunction::Std
+                | NativeFunction::Sum
                 | NativeFunction::Var => {
-                    manager.emit(Quadruple::era(id));
+                    manager.emit(Instruction::era(id));
 
                     if ctx.params.len() != 1 {
-                        panic!(
-                            "Function {id} takes one parameter, {} were provided",
-                            ctx.params.len()
-                        );
+                        return Err(CompileError::new(
+                            format!(
+                                "Function {id} takes one parameter, {} were provided",
+                                ctx.params.len()
+                            )
+                            .as_str(),
+                            ctx.span,
+                        ));
                     }
 
                     let param = ctx.params.get(0).unwrap();
-                    let param_dt = param.data_type(manager);
+                    let param_dt = param.data_type(manager)?;
 
-                    if param_dt != DataType::Series && param.dimensionality(manager).len() == 0 {
-                        panic!("Function {id} does not accept scalar values");
+                    if param_dt != DataType::Series && param.dimensionality(manager)?.len() == 0 {
+                        return Err(CompileError::new(
+                            format!("Function {id} does not accept scalar values").as_str(),
+                            param.span(),
+                        ));
                     }
 
-                    let param_tmp = param.reduce(manager);
+                    let param_tmp = param.reduce(manager)?;
 
-                    manager.emit(Quadruple::param(param_tmp.as_str(), 0));
+                    manager.emit(Instruction::param(param_tmp, 0));
+                    NativeFunction::emit_rank(param.dimensionality(manager)?.len(), 1, manager);
 
-                    manager.emit(Quadruple::go_sub(id));
+                    manager.emit(Instruction::go_sub(id));
 
-                    if let Some(func_return_address) = manager.get_func_return(&String::from(id)) {
-                        let return_value = manager.new_temp(&DataType::Float).to_string();
+                    if let Some(func_return_address) =
+                        manager.get_func_return(&String::from(id)).at(ctx.span)?
+                    {
+                        let return_value = Operand::Direct(manager.new_temp(&DataType::Float));
 
-                        manager.emit(Quadruple::unary(
+                        manager.emit(Instruction::unary(
                             Operator::Assign,
-                            func_return_address.to_string().as_str(),
-                            return_value.as_str(),
+                            Operand::Direct(func_return_address),
+                            return_value.clone(),
                         ));
 
-                        Some(return_value)
+                        Ok(Some(return_value))
                     } else {
-                        None
+                        Ok(None)
+                    }
+                }
+                NativeFunction::Len => {
+                    if ctx.params.len() != 1 {
+                        return Err(CompileError::new(
+                            format!(
+                                "Function {id} takes one parameter, {} were provided",
+                                ctx.params.len()
+                            )
+                            .as_str(),
+                            ctx.span,
+                        ));
+                    }
+
+                    let param = &ctx.params[0];
+                    let param_dt = param.data_type(manager)?;
+
+                    if param.dimensionality(manager)?.is_empty()
+                        && param_dt != DataType::DataFrame
+                        && param_dt != DataType::Series
+                        && param_dt != DataType::String
+                    {
+                        return Err(CompileError::new(
+                            format!("Function {id} takes an array, string, DataFrame or Series")
+                                .as_str(),
+                            param.span(),
+                        ));
+                    }
+
+                    // Partially indexed arrays are arrays of a lower rank
+                    let rank = param.dimensionality(manager)?.len();
+
+                    // Arrays of structs take the length of the array of a field
+                    let value = match (param.as_ref(), param_dt) {
+                        (Expression::Access(access), DataType::Struct(_)) => {
+                            let (path, _) = access.symbol(manager)?.leaves().remove(0);
+                            access.field(&path).reduce(manager)?
+                        }
+                        _ => param.reduce(manager)?,
+                    };
+                    let length = Operand::Direct(manager.new_temp(&DataType::Int));
+                    manager.emit(Instruction::len(value, 0, rank, length.clone()));
+
+                    Ok(Some(length))
+                }
+                NativeFunction::Scatter => {
+                    if ctx.params.len() != 2 {
+                        return Err(CompileError::new(
+                            format!(
+                                "Function {id} takes 2 parameters, {} were provided",
+                                ctx.params.len()
+                            )
+                            .as_str(),
+                            ctx.span,
+                        ));
+                    }
+
+                    manager.emit(Instruction::era(id));
+
+                    // Points are read from Series or numeric arrays of one dimension
+                    for (index, param) in ctx.params.iter().enumerate() {
+                        let data_type = param.data_type(manager)?;
+                        let is_vector = param.dimensionality(manager)?.len() == 1
+                            && (data_type == DataType::Int || data_type == DataType::Float);
+
+                        if data_type != DataType::Series && !is_vector {
+                            return Err(CompileError::new(
+                                format!(
+                                    "Function {id} takes Series or one-dimensional arrays of numbers"
+                                )
+                                .as_str(),
+                                param.span(),
+                            ));
+                        }
+
+                        let value = param.reduce(manager)?;
+                        manager.emit(Instruction::param(value, index));
+                    }
+
+                    manager.emit(Instruction::go_sub(id));
+
+                    Ok(Some(Operand::Const(Literal::Void)))
+                }
+                NativeFunction::Transpose
+                | NativeFunction::Dot
+                | NativeFunction::Identity
+                | NativeFunction::Inverse
+                | NativeFunction::Det
+                | NativeFunction::Solve => {
+                    NativeFunction::check_matrix_params(&function_id, ctx, manager)?;
+
+                    manager.emit(Instruction::era(id));
+
+                    for (index, param) in ctx.params.iter().enumerate() {
+                        let mut value = param.reduce(manager)?;
+                        if param.dimensionality(manager)?.is_empty()
+                            && param.data_type(manager)? != DataType::Int
+                        {
+                            value = manager.emit_cast(&DataType::Int, value);
+                        }
+
+                        manager.emit(Instruction::param(value, index));
                     }
+
+                    manager.emit(Instruction::go_sub(id));
+
+                    // Arrays are read through the returned pointer
+                    let return_type = match function_id {
+                        NativeFunction::Dot | NativeFunction::Det => DataType::Float,
+                        _ => DataType::Pointer,
+                    };
+                    let func_return_address =
+                        manager.get_func_return(&String::from(id)).at(ctx.span)?;
+                    let return_value = Operand::Direct(manager.new_temp(&return_type));
+
+                    manager.emit(Instruction::unary(
+                        Operator::Assign,
+                        Operand::Direct(func_return_address.unwrap()),
+                        return_value.clone(),
+                    ));
+
+                    Ok(Some(return_value))
+                }
+                NativeFunction::Map | NativeFunction::Filter | NativeFunction::Reduce => {
+                    let (params, call_type) =
+                        NativeFunction::check_function_param(&function_id, ctx, manager)?;
+
+                    manager.emit(Instruction::era(id));
+
+                    for (index, param) in ctx.params.iter().enumerate() {
+                        let mut value = param.reduce(manager)?;
+                        let data_type = param.data_type(manager)?;
+                        if index == 2 && data_type != params[0] {
+                            value = manager.emit_cast(&params[0], value);
+                        }
+
+                        manager.emit(Instruction::param(value, index));
+                    }
+                    let rank = ctx.params[0].dimensionality(manager)?.len();
+                    NativeFunction::emit_rank(rank, ctx.params.len(), manager);
+
+                    manager.emit(Instruction::go_sub(id));
+
+                    // Arrays are read through the returned pointer
+                    let return_type = match function_id {
+                        NativeFunction::Reduce => call_type,
+                        _ if call_type == DataType::Series => call_type,
+                        _ => DataType::Pointer,
+                    };
+                    let func_return_address =
+                        manager.get_func_return(&String::from(id)).at(ctx.span)?;
+                    let return_value = Operand::Direct(manager.new_temp(&return_type));
+
+                    manager.emit(Instruction::unary(
+                        Operator::Assign,
+                        Operand::Direct(func_return_address.unwrap()),
+                        return_value.clone(),
+                    ));
+
+                    Ok(Some(return_value))
+                }
+                NativeFunction::Join => {
+                    if ctx.params.len() != 2 {
+                        return Err(CompileError::new(
+                            format!(
+                                "Function {id} takes 2 parameters, {} were provided",
+                                ctx.params.len()
+                            )
+                            .as_str(),
+                            ctx.span,
+                        ));
+                    }
+
+                    let (items, separator) = (&ctx.params[0], &ctx.params[1]);
+                    if items.data_type(manager)? != DataType::String
+                        || items.dimensionality(manager)?.len() != 1
+                    {
+                        return Err(CompileError::new(
+                            format!("Function {id} takes a one-dimensional array of strings")
+                                .as_str(),
+                            items.span(),
+                        ));
+                    }
+                    if separator.data_type(manager)? != DataType::String
+                        || !separator.dimensionality(manager)?.is_empty()
+                    {
+                        return Err(CompileError::new(
+                            "Strings can only be joined with a string separator",
+                            separator.span(),
+                        ));
+                    }
+
+                    manager.emit(Instruction::era(id));
+
+                    for (index, param) in ctx.params.iter().enumerate() {
+                        let value = param.reduce(manager)?;
+                        manager.emit(Instruction::param(value, index));
+                    }
+
+                    manager.emit(Instruction::go_sub(id));
+
+                    let func_return_address =
+                        manager.get_func_return(&String::from(id)).at(ctx.span)?;
+                    let return_value = Operand::Direct(manager.new_temp(&DataType::String));
+
+                    manager.emit(Instruction::unary(
+                        Operator::Assign,
+                        Operand::Direct(func_return_address.unwrap()),
+                        return_value.clone(),
+                    ));
+
+                    Ok(Some(return_value))
+                }
+                NativeFunction::Format => {
+                    let template = match ctx.params.first() {
+                        Some(template) => template,
+                        None => {
+                            return Err(CompileError::new(
+                                format!("Function {id} takes a template string").as_str(),
+                                ctx.span,
+                            ))
+                        }
+                    };
+                    if template.data_type(manager)? != DataType::String
+                        || !template.dimensionality(manager)?.is_empty()
+                    {
+                        return Err(CompileError::new(
+                            format!("The template of {id} must be a string").as_str(),
+                            template.span(),
+                        ));
+                    }
+
+                    // Constant templates are checked before running the program
+                    if let Expression::Const(Const {
+                        dtype: DataType::String,
+                        value,
+                        ..
+                    }) = template.as_ref()
+                    {
+                        let placeholders = value.matches("{}").count();
+                        if placeholders != ctx.params.len() - 1 {
+                            return Err(CompileError::new(
+                                format!(
+                                    "Template has {} placeholders, {} values were provided",
+                                    placeholders,
+                                    ctx.params.len() - 1
+                                )
+                                .as_str(),
+                                ctx.span,
+                            ));
+                        }
+                    }
+
+                    let mut values = vec![];
+                    for param in ctx.params.iter() {
+                        if !param.dimensionality(manager)?.is_empty() {
+                            return Err(CompileError::new(
+                                "Can't format arrays, format each of their items instead",
+                                param.span(),
+                            ));
+                        }
+
+                        values.push(match (param.as_ref(), param.data_type(manager)?) {
+                            (Expression::Access(access), DataType::Struct(_)) => {
+                                NativeFunction::format_struct(access, manager)?
+                            }
+                            _ => param.reduce(manager)?,
+                        });
+                    }
+
+                    manager.emit(Instruction::era(id));
+
+                    for (index, value) in values.into_iter().enumerate() {
+                        manager.emit(Instruction::param(value, index));
+                    }
+
+                    manager.emit(Instruction::go_sub(id));
+
+                    let func_return_address =
+                        manager.get_func_return(&String::from(id)).at(ctx.span)?;
+                    let return_value = Operand::Direct(manager.new_temp(&DataType::String));
+
+                    manager.emit(Instruction::unary(
+                        Operator::Assign,
+                        Operand::Direct(func_return_address.unwrap()),
+                        return_value.clone(),
+                    ));
+
+                    Ok(Some(return_value))
+                }
+                _ if function_id.is_math() => {
+                    let (data_type, shape) =
+                        NativeFunction::check_math_params(&function_id, ctx, manager)?;
+
+                    manager.emit(Instruction::era(id));
+
+                    for (index, param) in ctx.params.iter().enumerate() {
+                        let value = param.reduce(manager)?;
+                        manager.emit(Instruction::param(value, index));
+                    }
+
+                    // The rank goes last so rows of arrays are read alone
+                    NativeFunction::emit_rank(shape.len(), ctx.params.len(), manager);
+                    manager.emit(Instruction::go_sub(id));
+
+                    // Arrays are read through the returned pointer
+                    let return_type = match shape.is_empty() {
+                        true => data_type,
+                        false => DataType::Pointer,
+                    };
+                    let func_return_address =
+                        manager.get_func_return(&String::from(id)).at(ctx.span)?;
+                    let return_value = Operand::Direct(manager.new_temp(&return_type));
+
+                    manager.emit(Instruction::unary(
+                        Operator::Assign,
+                        Operand::Direct(func_return_address.unwrap()),
+                        return_value.clone(),
+                    ));
+
+                    Ok(Some(return_value))
                 }
-                _ => None,
+                _ => Ok(None),
             }
         } else {
-            None
+            Ok(None)
         }
     }
 }
@@ -1,11 +1,11 @@
 use memory::{resolver::MemAddress, types::DataType};
 
-use crate::manager::Manager;
+use crate::{errors::CompileResult, manager::Manager, quadruples::Operand};
 
 /// Trait for all nodes representing accessable items (variables).
 pub trait AccessNode {
     /// Returns the address of the current item
-    fn address(&self, _: &mut Manager) -> MemAddress {
+    fn address(&self, _: &mut Manager) -> CompileResult<MemAddress> {
         todo!();
     }
 }
@@ -14,20 +14,20 @@ pub trait AccessNode {
 pub trait ExpressionNode {
     /// Returns the dimensionality/shape of an item returned
     /// by a node (if applicable), e.g. expressions.
-    fn dimensionality(&self, _: &mut Manager) -> Vec<usize> {
-        vec![]
+    fn dimensionality(&self, _: &mut Manager) -> CompileResult<Vec<usize>> {
+        Ok(vec![])
     }
 
     /// Returns de data type of the node evaluated item,
     /// if applicable
-    fn data_type(&self, _: &mut Manager) -> DataType {
+    fn data_type(&self, _: &mut Manager) -> CompileResult<DataType> {
         todo!()
     }
 
     /// Generates the quadruples to execute the code of the
-    /// current node. Returns the address where the evaluation
+    /// current node. Returns the operand where the evaluation
     /// of the code from this node will be stored.
-    fn reduce(&self, _: &mut Manager) -> String;
+    fn reduce(&self, _: &mut Manager) -> CompileResult<Operand>;
 }
 
 /// Trait to represent nodes of an Abstract Syntax Tree.
@@ -39,5 +39,5 @@ pub trait ExpressionNode {
 pub trait Node {
     /// Generates the quadruples to execute the code of the
     /// current node.
-    fn generate(&mut self, manager: &mut Manager) -> ();
+    fn generate(&mut self, manager: &mut Manager) -> CompileResult<()>;
 }
@@ -0,0 +1,857 @@
+use std::collections::{HashMap, HashSet};
+
+use memory::{
+    resolver::{MemAddress, MemoryResolver, MemoryScope},
+    types::{DataType, FloatType, IntType},
+};
+use parser::{expressions::constant::Const, types::Operator};
+
+use crate::{
+    manager::Manager,
+    quadruples::{Instruction, Literal, Operand},
+};
+
+/// Maximum number of times the passes are repeated, each round may enable
+/// new optimizations on the code left by the previous one.
+const MAX_ROUNDS: usize = 10;
+
+/// Optimizes the quadruples generated by a manager.
+///
+/// Level 0 leaves the code untouched, any other level runs every pass.
+pub fn optimize(manager: &mut Manager, level: usize) {
+    if level > 0 {
+        Optimizer::new(manager).run();
+    }
+}
+
+/// Value known at compile time, as operated by the virtual machine.
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Int(IntType),
+    Float(FloatType),
+    Bool(bool),
+    String(String),
+    Pointer(MemAddress),
+}
+
+impl Value {
+    fn data_type(&self) -> DataType {
+        match self {
+            Value::Int(_) => DataType::Int,
+            Value::Float(_) => DataType::Float,
+            Value::Bool(_) => DataType::Bool,
+            Value::String(_) => DataType::String,
+            Value::Pointer(_) => DataType::Pointer,
+        }
+    }
+
+    /// Textual form of the value, as stored in the constant table.
+    fn text(&self) -> String {
+        match self {
+            Value::Int(value) => value.to_string(),
+            Value::Float(value) => value.to_string(),
+            Value::Bool(value) => value.to_string(),
+            Value::String(value) => value.clone(),
+            Value::Pointer(value) => value.to_string(),
+        }
+    }
+
+    fn parse(data_type: &DataType, text: &str) -> Option<Value> {
+        match data_type {
+            DataType::Int => text.parse().ok().map(Value::Int),
+            DataType::Float => text.parse().ok().map(Value::Float),
+            DataType::Bool => text.parse().ok().map(Value::Bool),
+            DataType::String => Some(Value::String(String::from(text))),
+            DataType::Pointer => text.parse().ok().map(Value::Pointer),
+            _ => None,
+        }
+    }
+
+    fn as_int(&self) -> Option<IntType> {
+        match self {
+            Value::Int(value) => Some(*value),
+            Value::Float(value) => Some(*value as IntType),
+            Value::Bool(value) => Some(*value as IntType),
+            Value::Pointer(value) => Some(*value as IntType),
+            Value::String(_) => None,
+        }
+    }
+
+    fn as_pointer(&self) -> Option<MemAddress> {
+        match self {
+            Value::Int(value) => Some(*value as MemAddress),
+            Value::Pointer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn arithmetic(
+        operator: Operator,
+        data_type: &DataType,
+        left: &Value,
+        right: &Value,
+    ) -> Option<Value> {
+        match data_type {
+            DataType::Int => {
+                let (left, right) = (left.as_int()?, right.as_int()?);
+                let result = match operator {
+                    Operator::Add => left.checked_add(right),
+                    Operator::Sub => left.checked_sub(right),
+                    Operator::Mul => left.checked_mul(right),
+                    Operator::Div => left.checked_div(right),
+                    _ => None,
+                };
+                result.map(Value::Int)
+            }
+            DataType::Float => match (left, right) {
+                (Value::Float(left), Value::Float(right)) => match operator {
+                    Operator::Add => Some(Value::Float(left + right)),
+                    Operator::Sub => Some(Value::Float(left - right)),
+                    Operator::Mul => Some(Value::Float(left * right)),
+                    Operator::Div if *right != 0.0 => Some(Value::Float(left / right)),
+                    _ => None,
+                },
+                _ => None,
+            },
+            DataType::Pointer => {
+                let (left, right) = (left.as_pointer()?, right.as_pointer()?);
+                let result = match operator {
+                    Operator::Add => left.checked_add(right),
+                    Operator::Sub => left.checked_sub(right),
+                    Operator::Mul => left.checked_mul(right),
+                    Operator::Div => left.checked_div(right),
+                    _ => None,
+                };
+                result.map(Value::Pointer)
+            }
+            DataType::String => match (left, right) {
+                (Value::String(left), Value::String(right)) => {
+                    Some(Value::String(format!("{}{}", left, right)))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Compares two values, by the type of the left operand address.
+    fn compare(
+        operator: Operator,
+        data_type: &DataType,
+        left: &Value,
+        right: &Value,
+    ) -> Option<Value> {
+        let ordering = match data_type {
+            DataType::Int => left.as_int()?.cmp(&right.as_int()?),
+            DataType::Float => match (left, right) {
+                (Value::Float(left), Value::Float(right)) => left.partial_cmp(right)?,
+                _ => return None,
+            },
+            DataType::String => match (left, right) {
+                (Value::String(left), Value::String(right)) => left.cmp(right),
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        let result = match operator {
+            Operator::GreaterThan => ordering.is_gt(),
+            Operator::GreaterOrEq => ordering.is_ge(),
+            Operator::LessThan => ordering.is_lt(),
+            Operator::LessOrEq => ordering.is_le(),
+            Operator::Eq => ordering.is_eq(),
+            Operator::NotEq => ordering.is_ne(),
+            _ => return None,
+        };
+
+        Some(Value::Bool(result))
+    }
+
+    fn cast(data_type: &DataType, value: &Value) -> Option<Value> {
+        match (data_type, value) {
+            (DataType::Float, Value::Bool(value)) => Some(Value::Float(*value as u8 as FloatType)),
+            (DataType::Float, Value::Int(value)) => Some(Value::Float(*value as FloatType)),
+            (DataType::Float, Value::Float(value)) => Some(Value::Float(*value)),
+            (DataType::Float, Value::Pointer(value)) => Some(Value::Float(*value as FloatType)),
+            (DataType::Int, value) => value.as_int().map(Value::Int),
+            (DataType::Bool, value) => value.as_int().map(|value| Value::Bool(value > 0)),
+            _ => None,
+        }
+    }
+}
+
+/// Where an instruction writes to.
+enum Write {
+    None,
+    Address(MemAddress),
+    /// The instruction may write to any address, like writes through a
+    /// pointer or function calls.
+    Any,
+}
+
+/// Optimization passes over the quadruples of a program.
+///
+/// Every pass keeps the observable behavior of the program: only
+/// temporal values are removed, named variables keep their values.
+pub struct Optimizer<'m> {
+    manager: &'m mut Manager,
+    /// Addresses of variables, parameters and return values.
+    variables: HashSet<MemAddress>,
+    /// Constants allocated for folded values.
+    constants: HashMap<(DataType, String), MemAddress>,
+}
+
+impl<'m> Optimizer<'m> {
+    pub fn new(manager: &'m mut Manager) -> Optimizer<'m> {
+        let mut variables = HashSet::new();
+
+        for env in manager.env.entries.values() {
+            // Struct fields are stored as variables of their own
+            variables.extend(
+                env.symbols
+                    .values()
+                    .chain(env.released.iter().flat_map(|scope| scope.symbols.iter()))
+                    .flat_map(|symbol| symbol.leaves())
+                    .map(|(_, symbol)| symbol.address),
+            );
+        }
+
+        for func in manager.get_procedures().values() {
+            variables.extend(func.return_address);
+            variables.extend(func.captures.iter().chain(func.closure_address.iter()));
+            for (address, _, point_address) in func.params.iter() {
+                variables.insert(*address);
+                variables.extend(point_address);
+            }
+        }
+
+        Optimizer {
+            manager,
+            variables,
+            constants: HashMap::new(),
+        }
+    }
+
+    /// Runs every pass until the code can't be further optimized.
+    pub fn run(&mut self) {
+        for _ in 0..MAX_ROUNDS {
+            let mut changed = self.propagate();
+            changed |= self.thread_jumps();
+            changed |= self.remove_unreachable();
+            changed |= self.coalesce_copies();
+            changed |= self.remove_dead_stores();
+
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    fn is_temp(&self, address: MemAddress) -> bool {
+        match MemoryResolver::get_scope_from_address(address) {
+            Some(MemoryScope::Global | MemoryScope::Local) => !self.variables.contains(&address),
+            _ => false,
+        }
+    }
+
+    fn same_type(left: MemAddress, right: MemAddress) -> bool {
+        match MemoryResolver::get_type_from_address(left) {
+            Some(data_type) => MemoryResolver::get_type_from_address(right) == Some(data_type),
+            None => false,
+        }
+    }
+
+    /// Returns the value of an operand, if known at compile time.
+    fn value(&self, operand: &Operand) -> Option<Value> {
+        match operand {
+            Operand::Direct(address) => match MemoryResolver::get_scope_from_address(*address) {
+                Some(MemoryScope::Constant) => {
+                    let data_type = MemoryResolver::get_type_from_address(*address)?;
+                    let constant = self.manager.get_constant(address)?;
+                    Value::parse(data_type, constant.value.as_str())
+                }
+                _ => None,
+            },
+            Operand::AddressOf(address) => Some(Value::Pointer(*address)),
+            Operand::Const(Literal::Int(value)) => Some(Value::Int(*value)),
+            Operand::Const(Literal::Text(text)) => Some(Value::String(text.clone())),
+            _ => None,
+        }
+    }
+
+    /// Returns the address of a constant holding the value.
+    fn constant(&mut self, value: &Value) -> MemAddress {
+        let data_type = value.data_type();
+        let key = (data_type.clone(), value.text());
+
+        if let Some(address) = self.constants.get(&key) {
+            return *address;
+        }
+
+        let address = self
+            .manager
+            .new_constant(&data_type, &Const::new(key.1.as_str(), data_type.clone()));
+        self.constants.insert(key, address);
+
+        address
+    }
+
+    /// Positions where execution may enter from somewhere else than the
+    /// previous instruction: jump targets, procedure entry points and the
+    /// instructions after jumps and calls.
+    fn leaders(&self) -> HashSet<usize> {
+        let mut leaders = HashSet::from([0]);
+
+        leaders.extend(
+            self.manager
+                .get_procedures()
+                .values()
+                .map(|func| func.procedure_address),
+        );
+
+        for (position, instruction) in self.manager.quadruples.iter().enumerate() {
+            match instruction {
+                Instruction::Goto(Operand::Label(target))
+                | Instruction::GotoFalse(_, Operand::Label(target)) => {
+                    leaders.insert(*target);
+                    leaders.insert(position + 1);
+                }
+                Instruction::GoSub(_)
+                | Instruction::Call(_, _)
+                | Instruction::Return(_)
+                | Instruction::VoidReturn
+                | Instruction::EndFunc
+                | Instruction::EndProgram => {
+                    leaders.insert(position + 1);
+                }
+                _ => (),
+            }
+        }
+
+        leaders
+    }
+
+    /// Operands an instruction reads as values. Arguments are excluded,
+    /// since calls receive the address of their parameters.
+    fn reads_mut(instruction: &mut Instruction) -> Vec<&mut Operand> {
+        match instruction {
+            Instruction::Operation(_, left, right, _) | Instruction::Verify(left, right) => {
+                vec![left, right]
+            }
+            Instruction::Unary(_, value, _)
+            | Instruction::Cast(_, value, _)
+            | Instruction::GotoFalse(value, _)
+            | Instruction::Return(value)
+            | Instruction::Print(value)
+            | Instruction::Len(value, _, _, _) => vec![value],
+            Instruction::New(_, values, _) | Instruction::Closure(_, values, _) => {
+                values.iter_mut().collect()
+            }
+            Instruction::View(source, sizes, strides, _) => std::iter::once(source)
+                .chain(sizes.iter_mut())
+                .chain(strides.iter_mut())
+                .collect(),
+            Instruction::Copy(source, count, dest) => vec![source, count, dest],
+            Instruction::Elementwise(_, left, right, dest) => vec![left, right, dest],
+            _ => vec![],
+        }
+    }
+
+    fn writes(instruction: &Instruction) -> Write {
+        match instruction {
+            Instruction::Operation(_, _, _, dest)
+            | Instruction::Unary(_, _, dest)
+            | Instruction::Cast(_, _, dest)
+            | Instruction::Len(_, _, _, dest)
+            | Instruction::New(_, _, dest)
+            | Instruction::View(_, _, _, dest)
+            | Instruction::Closure(_, _, dest)
+            | Instruction::Free(dest) => match dest {
+                Operand::Direct(address) => Write::Address(*address),
+                _ => Write::Any,
+            },
+            Instruction::GoSub(_)
+            | Instruction::Call(_, _)
+            | Instruction::Copy(_, _, _)
+            | Instruction::Elementwise(_, _, _, _) => Write::Any,
+            _ => Write::None,
+        }
+    }
+
+    /// Number of times each address is referenced by the program, other
+    /// than as the destination of a value.
+    fn uses(&self) -> HashMap<MemAddress, usize> {
+        let mut uses = HashMap::new();
+
+        for instruction in self.manager.quadruples.iter() {
+            // Destinations are only used when written through a pointer
+            let operands = match instruction {
+                Instruction::Operation(_, left, right, dest @ Operand::Indirect(_)) => {
+                    vec![left, right, dest]
+                }
+                Instruction::Operation(_, left, right, _) | Instruction::Verify(left, right) => {
+                    vec![left, right]
+                }
+                Instruction::Unary(_, value, dest @ Operand::Indirect(_))
+                | Instruction::Cast(_, value, dest @ Operand::Indirect(_)) => vec![value, dest],
+                Instruction::Unary(_, value, _)
+                | Instruction::Cast(_, value, _)
+                | Instruction::GotoFalse(value, _)
+                | Instruction::Param(value, _)
+                | Instruction::Return(value)
+                | Instruction::Print(value)
+                | Instruction::Len(value, _, _, _)
+                | Instruction::Call(value, _)
+                | Instruction::Free(value) => vec![value],
+                Instruction::New(_, values, _) | Instruction::Closure(_, values, _) => {
+                    values.iter().collect()
+                }
+                Instruction::View(source, sizes, strides, _) => std::iter::once(source)
+                    .chain(sizes.iter())
+                    .chain(strides.iter())
+                    .collect(),
+                Instruction::Copy(source, count, dest) => vec![source, count, dest],
+                Instruction::Elementwise(_, left, right, dest) => vec![left, right, dest],
+                _ => vec![],
+            };
+
+            for operand in operands {
+                if let Operand::Direct(address)
+                | Operand::Indirect(address)
+                | Operand::AddressOf(address) = operand
+                {
+                    *uses.entry(*address).or_insert(0) += 1;
+                }
+            }
+        }
+
+        uses
+    }
+
+    /// Evaluates an instruction whose operands are known at compile time,
+    /// returning an assignment of its result.
+    fn fold(&mut self, instruction: &Instruction) -> Option<Instruction> {
+        let (value, dest) = match instruction {
+            Instruction::Operation(operator, left, right, Operand::Direct(dest)) => {
+                let (left_value, right_value) = (self.value(left)?, self.value(right)?);
+                let value = match operator {
+                    Operator::Add | Operator::Sub | Operator::Mul | Operator::Div => {
+                        let data_type = MemoryResolver::get_type_from_address(*dest)?;
+                        Value::arithmetic(*operator, data_type, &left_value, &right_value)?
+                    }
+                    Operator::And | Operator::Or => match (left_value, right_value) {
+                        (Value::Bool(left), Value::Bool(right)) if *operator == Operator::And => {
+                            Value::Bool(left && right)
+                        }
+                        (Value::Bool(left), Value::Bool(right)) => Value::Bool(left || right),
+                        _ => return None,
+                    },
+                    _ => {
+                        // Comparisons are made by the type of the left address
+                        let address = match left {
+                            Operand::Direct(address) => *address,
+                            _ => return None,
+                        };
+                        let data_type = MemoryResolver::get_type_from_address(address)?;
+                        Value::compare(*operator, data_type, &left_value, &right_value)?
+                    }
+                };
+
+                (value, *dest)
+            }
+            Instruction::Unary(operator, value, Operand::Direct(dest)) => {
+                let value = match (operator, self.value(value)?) {
+                    (Operator::Not, Value::Bool(value)) => Value::Bool(!value),
+                    (Operator::Neg, Value::Int(value)) => Value::Int(value.checked_neg()?),
+                    (Operator::Neg, Value::Float(value)) => Value::Float(-value),
+                    _ => return None,
+                };
+
+                (value, *dest)
+            }
+            Instruction::Cast(data_type, value, Operand::Direct(dest)) => {
+                (Value::cast(data_type, &self.value(value)?)?, *dest)
+            }
+            _ => return None,
+        };
+
+        let constant = self.constant(&value);
+        Some(Instruction::unary(
+            Operator::Assign,
+            Operand::Direct(constant),
+            Operand::Direct(dest),
+        ))
+    }
+
+    /// Replaces the values copied between addresses by their origin and
+    /// folds the operations on constants, within each basic block.
+    fn propagate(&mut self) -> bool {
+        let leaders = self.leaders();
+        let mut changed = false;
+        // Copied address and the address holding its value
+        let mut copies: HashMap<MemAddress, MemAddress> = HashMap::new();
+
+        for position in 0..self.manager.quadruples.len() {
+            if leaders.contains(&position) {
+                copies.clear();
+            }
+
+            let mut instruction = self.manager.quadruples[position].clone();
+
+            for operand in Optimizer::reads_mut(&mut instruction) {
+                if let Operand::Direct(address) = operand {
+                    if let Some(origin) = copies.get(address) {
+                        *address = *origin;
+                    }
+                }
+            }
+
+            if let Some(folded) = self.fold(&instruction) {
+                instruction = folded;
+            }
+
+            match Optimizer::writes(&instruction) {
+                Write::Address(address) => {
+                    copies.remove(&address);
+                    copies.retain(|_, origin| *origin != address);
+                }
+                Write::Any => copies.clear(),
+                Write::None => (),
+            }
+
+            if let Instruction::Unary(
+                Operator::Assign,
+                Operand::Direct(origin),
+                Operand::Direct(dest),
+            ) = instruction
+            {
+                if origin != dest && Optimizer::same_type(origin, dest) {
+                    copies.insert(dest, origin);
+                }
+            }
+
+            if instruction != self.manager.quadruples[position] {
+                self.manager.update_instruction(position, instruction);
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    /// Follows a chain of unconditional jumps.
+    fn destination(&self, target: usize) -> usize {
+        let mut visited = HashSet::new();
+        let mut current = target;
+
+        while let Some(Instruction::Goto(Operand::Label(next))) =
+            self.manager.quadruples.get(current)
+        {
+            if !visited.insert(current) {
+                break;
+            }
+            current = *next;
+        }
+
+        current
+    }
+
+    /// Points jumps to the end of jump chains and resolves the conditional
+    /// jumps on constants. Jumps to the next instruction are removed.
+    fn thread_jumps(&mut self) -> bool {
+        let mut keep = vec![true; self.manager.quadruples.len()];
+        let mut changed = false;
+
+        for (position, kept) in keep.iter_mut().enumerate() {
+            let instruction = match &self.manager.quadruples[position] {
+                Instruction::Goto(Operand::Label(target)) => {
+                    Instruction::goto(self.destination(*target))
+                }
+                Instruction::GotoFalse(check, Operand::Label(target)) => match self.value(check) {
+                    Some(Value::Bool(true)) => {
+                        *kept = false;
+                        continue;
+                    }
+                    Some(Value::Bool(false)) => Instruction::goto(self.destination(*target)),
+                    _ => Instruction::goto_false(check.clone(), self.destination(*target)),
+                },
+                _ => continue,
+            };
+
+            match &instruction {
+                Instruction::Goto(Operand::Label(target))
+                | Instruction::GotoFalse(_, Operand::Label(target))
+                    if *target == position + 1 =>
+                {
+                    *kept = false;
+                }
+                _ => (),
+            }
+
+            if instruction != self.manager.quadruples[position] {
+                self.manager.update_instruction(position, instruction);
+                changed = true;
+            }
+        }
+
+        self.retain(&keep) || changed
+    }
+
+    /// Removes the instructions that can't be reached from the start of
+    /// the program or a procedure, like code after `endProgram` or `return`.
+    fn remove_unreachable(&mut self) -> bool {
+        let quadruples = &self.manager.quadruples;
+        let mut reachable = vec![false; quadruples.len()];
+        let mut pending = vec![0];
+        pending.extend(
+            self.manager
+                .get_procedures()
+                .values()
+                .map(|func| func.procedure_address),
+        );
+
+        while let Some(position) = pending.pop() {
+            if position >= quadruples.len() || reachable[position] {
+                continue;
+            }
+            reachable[position] = true;
+
+            match &quadruples[position] {
+                Instruction::Goto(Operand::Label(target)) => pending.push(*target),
+                Instruction::GotoFalse(_, Operand::Label(target)) => {
+                    pending.push(*target);
+                    pending.push(position + 1);
+                }
+                Instruction::Return(_)
+                | Instruction::VoidReturn
+                | Instruction::EndFunc
+                | Instruction::EndProgram => (),
+                _ => pending.push(position + 1),
+            }
+        }
+
+        self.retain(&reachable)
+    }
+
+    /// Writes results directly into the address they are copied to, when
+    /// the temporal holding them is not used anywhere else.
+    fn coalesce_copies(&mut self) -> bool {
+        let leaders = self.leaders();
+        let uses = self.uses();
+        let mut keep = vec![true; self.manager.quadruples.len()];
+
+        for position in 1..self.manager.quadruples.len() {
+            let (temp, dest) = match &self.manager.quadruples[position] {
+                Instruction::Unary(
+                    Operator::Assign,
+                    Operand::Direct(temp),
+                    Operand::Direct(dest),
+                ) => (*temp, *dest),
+                _ => continue,
+            };
+
+            if leaders.contains(&position)
+                || !keep[position - 1]
+                || !self.is_temp(temp)
+                || temp == dest
+                || !Optimizer::same_type(temp, dest)
+                || uses.get(&temp) != Some(&1)
+            {
+                continue;
+            }
+
+            let mut previous = self.manager.quadruples[position - 1].clone();
+            match &mut previous {
+                Instruction::Operation(_, _, _, result)
+                | Instruction::Unary(_, _, result)
+                | Instruction::Cast(_, _, result)
+                    if *result == Operand::Direct(temp) =>
+                {
+                    *result = Operand::Direct(dest);
+                }
+                _ => continue,
+            }
+
+            self.manager.update_instruction(position - 1, previous);
+            keep[position] = false;
+        }
+
+        self.retain(&keep)
+    }
+
+    /// Removes the instructions writing to temporals that are never used.
+    fn remove_dead_stores(&mut self) -> bool {
+        let uses = self.uses();
+
+        let keep = self
+            .manager
+            .quadruples
+            .iter()
+            .map(|instruction| match instruction {
+                // Divisions are kept, since they may fail
+                Instruction::Operation(Operator::Div, _, _, _) => true,
+                Instruction::Operation(_, _, _, Operand::Direct(dest))
+                | Instruction::Unary(_, _, Operand::Direct(dest))
+                | Instruction::Cast(_, _, Operand::Direct(dest)) => {
+                    !self.is_temp(*dest) || uses.contains_key(dest)
+                }
+                _ => true,
+            })
+            .collect::<Vec<bool>>();
+
+        self.retain(&keep)
+    }
+
+    /// Removes the instructions not marked to keep, remapping the jump
+    /// targets, procedure entry points and block scopes to the remaining
+    /// positions.
+    ///
+    /// Positions of removed instructions are mapped to the next kept one.
+    fn retain(&mut self, keep: &[bool]) -> bool {
+        if keep.iter().all(|kept| *kept) {
+            return false;
+        }
+
+        let mut positions = vec![0; keep.len() + 1];
+        let mut next = keep.iter().filter(|kept| **kept).count();
+        positions[keep.len()] = next;
+        for position in (0..keep.len()).rev() {
+            if keep[position] {
+                next -= 1;
+            }
+            positions[position] = next;
+        }
+
+        let relocate = |target: &usize| positions[(*target).min(keep.len())];
+
+        let quadruples = std::mem::take(&mut self.manager.quadruples);
+        let lines = std::mem::take(&mut self.manager.line_table);
+        for (position, instruction) in quadruples.into_iter().enumerate() {
+            if !keep[position] {
+                continue;
+            }
+
+            let instruction = match instruction {
+                Instruction::Goto(Operand::Label(target)) => Instruction::goto(relocate(&target)),
+                Instruction::GotoFalse(check, Operand::Label(target)) => {
+                    Instruction::goto_false(check, relocate(&target))
+                }
+                instruction => instruction,
+            };
+
+            self.manager.quadruples.push(instruction);
+            self.manager
+                .line_table
+                .push(lines.get(position).copied().unwrap_or(0));
+        }
+
+        let procedures = self
+            .manager
+            .get_procedures()
+            .iter()
+            .map(|(id, func)| (id.clone(), relocate(&func.procedure_address)))
+            .collect::<Vec<(String, usize)>>();
+        for (id, position) in procedures {
+            self.manager.update_func_position(&id, position);
+        }
+
+        for env in self.manager.env.entries.values_mut() {
+            for scope in env.released.iter_mut() {
+                scope.quadruples =
+                    relocate(&scope.quadruples.start)..relocate(&scope.quadruples.end);
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parser::{parse_program, types::Operator};
+
+    use crate::{
+        generate_program,
+        manager::Manager,
+        quadruples::{Instruction, Operand},
+    };
+
+    use super::optimize;
+
+    fn generate(source: &str, level: usize) -> Manager {
+        let mut manager = Manager::new();
+        manager.set_source("<test>", source);
+        let program = parse_program(source).unwrap();
+        generate_program(program, &mut manager).unwrap();
+        optimize(&mut manager, level);
+
+        manager
+    }
+
+    #[test]
+    fn test_constant_folding() {
+        let source = "let x: int = 10 - 1 * 2; let y: bool = 3 > 2; println(x, y);";
+        let plain = generate(source, 0);
+        let optimized = generate(source, 1);
+
+        assert!(optimized.quadruples.len() < plain.quadruples.len());
+        assert!(!optimized
+            .quadruples
+            .iter()
+            .any(|instruction| matches!(instruction, Instruction::Operation(..))));
+    }
+
+    #[test]
+    fn test_struct_fields_kept() {
+        let source = "struct Point { x: float, y: float } let p: Point; p.x = 1; p.y = 2;";
+        let optimized = generate(source, 1);
+        let point = optimized.env.get_var(&String::from("p")).unwrap().clone();
+
+        for (_, field) in point.leaves() {
+            assert!(optimized.quadruples.iter().any(|instruction| matches!(
+                instruction,
+                Instruction::Unary(Operator::Assign, _, Operand::Direct(dest))
+                    if *dest == field.address
+            )));
+        }
+    }
+
+    #[test]
+    fn test_jump_remap() {
+        let source = "
+            let x: int = 0;
+            while (x < 10) {
+                x = x + 1;
+            }
+            if (false) {
+                x = 5;
+            }
+            println(x);
+        ";
+        let optimized = generate(source, 1);
+        let quadruples = &optimized.quadruples;
+
+        assert_eq!(optimized.line_table.len(), quadruples.len());
+
+        // Only the loop condition is left, the branch on a constant is removed
+        let jumps = quadruples
+            .iter()
+            .enumerate()
+            .filter_map(|(position, instruction)| match instruction {
+                Instruction::Goto(Operand::Label(target))
+                | Instruction::GotoFalse(_, Operand::Label(target)) => Some((position, *target)),
+                _ => None,
+            })
+            .collect::<Vec<(usize, usize)>>();
+        assert_eq!(jumps.len(), 2);
+
+        let (condition, exit) = jumps[0];
+        let (back, start) = jumps[1];
+        assert_eq!(exit, back + 1);
+        assert!(start < condition);
+        assert!(matches!(
+            quadruples[condition - 1],
+            Instruction::Operation(Operator::LessThan, ..)
+        ));
+    }
+}
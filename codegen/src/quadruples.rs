@@ -1,104 +1,329 @@
 use core::panic;
-use std::fmt::{Debug, Error, Formatter};
+use std::fmt::{Debug, Display, Error, Formatter};
 
-use memory::types::DataType;
+use memory::{
+    resolver::MemAddress,
+    types::{DataType, IntType},
+};
 use serde::{Deserialize, Serialize};
 
 use parser::types::Operator;
 
 use crate::manager::Manager;
 
-#[derive(Serialize, Deserialize, Clone)]
-pub struct Quadruple(pub String, pub String, pub String, pub String);
+/// Value embedded in an instruction instead of being stored in memory.
+#[derive(Clone, PartialEq)]
+pub enum Literal {
+    /// Sentinel stored after the last element of an array by previous
+    /// versions, replaced by `Alloc` instructions when loaded.
+    End,
+    Int(IntType),
+    /// Text printed as is.
+    Text(String),
+    /// Result of a call to a void function, holds no value.
+    Void,
+}
 
-impl Quadruple {
-    pub fn new(fst: &str, snd: &str, thrd: &str, fth: &str) -> Quadruple {
-        Quadruple(
-            String::from(fst),
-            String::from(snd),
-            String::from(thrd),
-            String::from(fth),
-        )
+/// Typed operand of an instruction.
+#[derive(Clone, PartialEq)]
+pub enum Operand {
+    /// Value stored at an address.
+    Direct(MemAddress),
+    /// Value stored at the address held by a pointer (`*address`).
+    Indirect(MemAddress),
+    /// The address itself, as a pointer value (`&address`).
+    AddressOf(MemAddress),
+    Const(Literal),
+    /// Position of an instruction.
+    Label(usize),
+}
+
+impl Operand {
+    /// Decodes an operand from its quadruple notation.
+    ///
+    /// Anything that is not a memory reference is taken as literal text.
+    pub fn parse(operand: &str) -> Operand {
+        let address = |address: &str| address.parse::<MemAddress>().ok();
+
+        if operand == "END" {
+            Operand::Const(Literal::End)
+        } else if operand == "VOID" {
+            Operand::Const(Literal::Void)
+        } else if let Some(pointer) = operand.strip_prefix('*').and_then(address) {
+            Operand::Indirect(pointer)
+        } else if let Some(target) = operand.strip_prefix('&').and_then(address) {
+            Operand::AddressOf(target)
+        } else if let Some(direct) = address(operand) {
+            Operand::Direct(direct)
+        } else {
+            Operand::Const(Literal::Text(String::from(operand)))
+        }
     }
+}
 
-    pub fn free(addr: &str) -> Quadruple {
-        Quadruple(
-            String::from("free"),
-            String::new(),
-            String::new(),
-            String::from(addr),
-        )
+impl Display for Operand {
+    /// Formats the operand in its quadruple notation.
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match self {
+            Operand::Direct(address) => write!(fmt, "{}", address),
+            Operand::Indirect(address) => write!(fmt, "*{}", address),
+            Operand::AddressOf(address) => write!(fmt, "&{}", address),
+            Operand::Const(Literal::End) => write!(fmt, "END"),
+            Operand::Const(Literal::Int(value)) => write!(fmt, "{}", value),
+            Operand::Const(Literal::Text(text)) => write!(fmt, "{}", text),
+            Operand::Const(Literal::Void) => write!(fmt, "VOID"),
+            Operand::Label(position) => write!(fmt, "{}", position),
+        }
+    }
+}
+
+impl Debug for Operand {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match self {
+            Operand::Const(Literal::Text(text)) => write!(fmt, "{:?}", text),
+            operand => write!(fmt, "{}", operand),
+        }
     }
+}
 
-    pub fn param(param_address: &str, index: usize) -> Quadruple {
-        Quadruple::new("param", param_address, "", index.to_string().as_str())
+/// Instruction of the virtual machine, with typed operands.
+#[derive(Clone, PartialEq)]
+pub enum Instruction {
+    /// Placeholder for an instruction resolved later on, e.g. a jump whose
+    /// target is not known yet.
+    Empty,
+    /// Binary operation, `(operator, left, right, destination)`.
+    Operation(Operator, Operand, Operand, Operand),
+    /// Assignment or unary operation, `(operator, value, destination)`.
+    Unary(Operator, Operand, Operand),
+    /// Type conversion, `(target type, value, destination)`.
+    Cast(DataType, Operand, Operand),
+    /// Checks an index is within `0..bound`, `(index, bound)`.
+    Verify(Operand, Operand),
+    Goto(Operand),
+    /// Jumps if a condition is false, `(condition, target)`.
+    GotoFalse(Operand, Operand),
+    /// Prepares the call to a function.
+    Era(String),
+    /// Passes an argument to the function being called, `(value, index)`.
+    Param(Operand, usize),
+    GoSub(String),
+    /// Calls the function of the closure held by an operand, its captured
+    /// values are passed along with the arguments, `(closure, result)`.
+    /// Calls to function values store the returned value in the result.
+    Call(Operand, Option<Operand>),
+    /// Creates the closure of a nested function, holding the values it
+    /// captures, `(function id, captured values, destination)`.
+    Closure(String, Vec<Operand>, Operand),
+    Return(Operand),
+    VoidReturn,
+    EndFunc,
+    EndProgram,
+    Print(Operand),
+    Free(Operand),
+    /// Reserves the items of an array, `(start address, shape)`.
+    Alloc(Operand, Vec<usize>),
+    /// Records the heap usage when the block scope at a depth opens, the
+    /// marks of deeper blocks are dropped.
+    Mark(usize),
+    /// Releases the heap arrays allocated since the block scope at a depth
+    /// opened, keeping its mark so loops can release them on each iteration.
+    Release(usize),
+    /// Size of an axis of an array, or the length of a DataFrame or Series,
+    /// `(value, axis, rank, destination)`. The axis is counted among the last
+    /// `rank` axes of the array the value points to, since items of its outer
+    /// axes are arrays of a lower rank, or among all of them if it is 0.
+    Len(Operand, usize, usize, Operand),
+    /// Allocates an array whose size is known at runtime and stores the
+    /// address of its first item, `(data type, sizes, destination)`.
+    New(DataType, Vec<Operand>, Operand),
+    /// Creates a view over items of an array without copying them and stores
+    /// the address of its first item, `(source, sizes, strides,
+    /// destination)`. The view's axes take their items from the source every
+    /// `stride` items.
+    View(Operand, Vec<Operand>, Vec<Operand>, Operand),
+    /// Copies items between the arrays two pointers refer to,
+    /// `(source, count, destination)`.
+    Copy(Operand, Operand, Operand),
+    /// Applies an operator to each item of arrays of the same size, scalar
+    /// operands are applied to every item, `(operator, left, right,
+    /// destination)`.
+    Elementwise(Operator, Operand, Operand, Operand),
+}
+
+impl Instruction {
+    pub fn free(address: MemAddress) -> Instruction {
+        Instruction::Free(Operand::Direct(address))
     }
 
-    pub fn era(id: &str) -> Quadruple {
-        Quadruple::new("era", "", "", id)
+    pub fn mark(depth: usize) -> Instruction {
+        Instruction::Mark(depth)
     }
 
-    pub fn type_cast(data_type: &DataType, origin: &str, dest: &str) -> Quadruple {
-        Quadruple::new(format!("{:#?}", data_type).as_str(), origin, "", dest)
+    pub fn release(depth: usize) -> Instruction {
+        Instruction::Release(depth)
     }
 
-    pub fn unary(operator: Operator, left: &str, dest: &str) -> Quadruple {
-        let op = operator.to_string();
-        Quadruple::new(op, left, "", dest)
+    pub fn param(value: Operand, index: usize) -> Instruction {
+        Instruction::Param(value, index)
     }
 
-    pub fn operation(operator: Operator, left: &str, right: &str, dest: &str) -> Quadruple {
-        let op = operator.to_string();
-        Quadruple::new(op, left, right, dest)
+    pub fn era(id: &str) -> Instruction {
+        Instruction::Era(String::from(id))
     }
 
-    pub fn verify(value: &str, bound: &str) -> Quadruple {
-        Quadruple::new("ver", value, "", bound)
+    pub fn type_cast(data_type: &DataType, origin: Operand, dest: Operand) -> Instruction {
+        Instruction::Cast(data_type.clone(), origin, dest)
     }
 
-    pub fn go_sub(id: &str) -> Quadruple {
-        Quadruple::new("gosub", "", "", id)
+    pub fn unary(operator: Operator, value: Operand, dest: Operand) -> Instruction {
+        Instruction::Unary(operator, value, dest)
     }
 
-    pub fn goto_false(check: &str, position: usize) -> Quadruple {
-        Quadruple::new("gotoFalse", check, "", position.to_string().as_str())
+    pub fn operation(
+        operator: Operator,
+        left: Operand,
+        right: Operand,
+        dest: Operand,
+    ) -> Instruction {
+        Instruction::Operation(operator, left, right, dest)
     }
 
-    pub fn goto(position: usize) -> Quadruple {
-        Quadruple::new("goto", "", "", position.to_string().as_str())
+    pub fn verify(value: Operand, bound: usize) -> Instruction {
+        Instruction::Verify(value, Operand::Const(Literal::Int(bound as IntType)))
     }
 
-    pub fn new_empty() -> Quadruple {
-        Quadruple(String::new(), String::new(), String::new(), String::new())
+    /// Checks an index against a bound computed at runtime.
+    pub fn verify_bound(value: Operand, bound: Operand) -> Instruction {
+        Instruction::Verify(value, bound)
     }
 
-    pub fn new_return(id: &str) -> Quadruple {
-        Quadruple(
-            String::from("return"),
-            String::new(),
-            String::new(),
-            String::from(id),
-        )
+    pub fn go_sub(id: &str) -> Instruction {
+        Instruction::GoSub(String::from(id))
+    }
+
+    pub fn call(closure: Operand, result: Option<Operand>) -> Instruction {
+        Instruction::Call(closure, result)
+    }
+
+    pub fn closure(id: &str, captured: Vec<Operand>, dest: Operand) -> Instruction {
+        Instruction::Closure(String::from(id), captured, dest)
+    }
+
+    pub fn goto_false(check: Operand, position: usize) -> Instruction {
+        Instruction::GotoFalse(check, Operand::Label(position))
+    }
+
+    pub fn goto(position: usize) -> Instruction {
+        Instruction::Goto(Operand::Label(position))
+    }
+
+    pub fn new_empty() -> Instruction {
+        Instruction::Empty
+    }
+
+    pub fn new_return(value: Operand) -> Instruction {
+        Instruction::Return(value)
+    }
+
+    pub fn void_return() -> Instruction {
+        Instruction::VoidReturn
+    }
+
+    pub fn end_func() -> Instruction {
+        Instruction::EndFunc
     }
 
-    pub fn void_return() -> Quadruple {
-        Quadruple::new("voidReturn", "", "", "")
+    pub fn end_program() -> Instruction {
+        Instruction::EndProgram
     }
 
-    pub fn end_func() -> Quadruple {
-        Self::new_coded("endFunc")
+    pub fn print(value: Operand) -> Instruction {
+        Instruction::Print(value)
     }
 
-    pub fn end_program() -> Quadruple {
-        Self::new_coded("endProgram")
+    pub fn alloc(address: MemAddress, shape: &[usize]) -> Instruction {
+        Instruction::Alloc(Operand::Direct(address), shape.to_vec())
     }
 
-    fn new_coded(key: &str) -> Quadruple {
+    pub fn len(value: Operand, axis: usize, rank: usize, dest: Operand) -> Instruction {
+        Instruction::Len(value, axis, rank, dest)
+    }
+
+    pub fn new_array(data_type: &DataType, sizes: Vec<Operand>, dest: Operand) -> Instruction {
+        Instruction::New(data_type.clone(), sizes, dest)
+    }
+
+    pub fn view(
+        source: Operand,
+        sizes: Vec<Operand>,
+        strides: Vec<Operand>,
+        dest: Operand,
+    ) -> Instruction {
+        Instruction::View(source, sizes, strides, dest)
+    }
+
+    pub fn copy(source: Operand, count: Operand, dest: Operand) -> Instruction {
+        Instruction::Copy(source, count, dest)
+    }
+
+    pub fn elementwise(
+        operator: Operator,
+        left: Operand,
+        right: Operand,
+        dest: Operand,
+    ) -> Instruction {
+        Instruction::Elementwise(operator, left, right, dest)
+    }
+}
+
+impl Debug for Instruction {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        Quadruple::from(self).fmt(fmt)
+    }
+}
+
+/// Operators that can be encoded in an instruction.
+const OPERATORS: [Operator; 16] = [
+    Operator::Mul,
+    Operator::Div,
+    Operator::MatMul,
+    Operator::Add,
+    Operator::Sub,
+    Operator::And,
+    Operator::Or,
+    Operator::LessThan,
+    Operator::GreaterThan,
+    Operator::LessOrEq,
+    Operator::GreaterOrEq,
+    Operator::NotEq,
+    Operator::Eq,
+    Operator::Assign,
+    Operator::Not,
+    Operator::Neg,
+];
+
+/// Data types an instruction can cast values to.
+const CAST_TYPES: [DataType; 5] = [
+    DataType::Int,
+    DataType::Float,
+    DataType::Bool,
+    DataType::String,
+    DataType::Pointer,
+];
+
+/// Textual form of an instruction, as stored by the object files of
+/// previous versions.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Quadruple(pub String, pub String, pub String, pub String);
+
+impl Quadruple {
+    pub fn new(fst: &str, snd: &str, thrd: &str, fth: &str) -> Quadruple {
         Quadruple(
-            String::from(key),
-            String::new(),
-            String::new(),
-            String::new(),
+            String::from(fst),
+            String::from(snd),
+            String::from(thrd),
+            String::from(fth),
         )
     }
 
@@ -113,6 +338,218 @@ impl Quadruple {
     }
 }
 
+impl From<&Instruction> for Quadruple {
+    fn from(instruction: &Instruction) -> Self {
+        let text = |operand: &Operand| operand.to_string();
+
+        match instruction {
+            Instruction::Empty => Quadruple::new("", "", "", ""),
+            Instruction::Operation(operator, left, right, dest) => {
+                Quadruple::new(operator.to_string(), &text(left), &text(right), &text(dest))
+            }
+            Instruction::Unary(operator, value, dest) => {
+                Quadruple::new(operator.to_string(), &text(value), "", &text(dest))
+            }
+            Instruction::Cast(data_type, value, dest) => Quadruple::new(
+                format!("{:#?}", data_type).as_str(),
+                &text(value),
+                "",
+                &text(dest),
+            ),
+            Instruction::Verify(value, Operand::Const(Literal::Int(bound))) => {
+                Quadruple::new("ver", &text(value), "", bound.to_string().as_str())
+            }
+            Instruction::Verify(value, bound) => {
+                Quadruple::new("ver", &text(value), &text(bound), "")
+            }
+            Instruction::Goto(target) => Quadruple::new("goto", "", "", &text(target)),
+            Instruction::GotoFalse(check, target) => {
+                Quadruple::new("gotoFalse", &text(check), "", &text(target))
+            }
+            Instruction::Era(id) => Quadruple::new("era", "", "", id),
+            Instruction::Param(value, index) => {
+                Quadruple::new("param", &text(value), "", index.to_string().as_str())
+            }
+            Instruction::GoSub(id) => Quadruple::new("gosub", "", "", id),
+            Instruction::Call(closure, result) => Quadruple::new(
+                "call",
+                &text(closure),
+                "",
+                &result.as_ref().map(text).unwrap_or_default(),
+            ),
+            Instruction::Closure(id, captured, dest) => {
+                let captured = captured.iter().map(text).collect::<Vec<String>>();
+                Quadruple::new("closure", id, captured.join(",").as_str(), &text(dest))
+            }
+            Instruction::Return(value) => Quadruple::new("return", "", "", &text(value)),
+            Instruction::VoidReturn => Quadruple::new("voidReturn", "", "", ""),
+            Instruction::EndFunc => Quadruple::new("endFunc", "", "", ""),
+            Instruction::EndProgram => Quadruple::new("endProgram", "", "", ""),
+            Instruction::Print(value) => Quadruple::new("print", "", "", &text(value)),
+            Instruction::Free(address) => Quadruple::new("free", "", "", &text(address)),
+            Instruction::Mark(depth) => Quadruple::new("mark", "", "", depth.to_string().as_str()),
+            Instruction::Release(depth) => {
+                Quadruple::new("release", "", "", depth.to_string().as_str())
+            }
+            Instruction::Alloc(address, shape) => {
+                let shape = shape
+                    .iter()
+                    .map(|size| size.to_string())
+                    .collect::<Vec<String>>();
+                Quadruple::new("alloc", "", shape.join("x").as_str(), &text(address))
+            }
+            Instruction::Len(value, axis, rank, dest) => Quadruple::new(
+                "len",
+                &text(value),
+                format!("{}/{}", axis, rank).as_str(),
+                &text(dest),
+            ),
+            Instruction::New(data_type, sizes, dest) => {
+                let sizes = sizes.iter().map(text).collect::<Vec<String>>();
+                Quadruple::new(
+                    "new",
+                    format!("{:#?}", data_type).as_str(),
+                    sizes.join("x").as_str(),
+                    &text(dest),
+                )
+            }
+            Instruction::View(source, sizes, strides, dest) => {
+                let sizes = sizes.iter().map(text).collect::<Vec<String>>();
+                let strides = strides.iter().map(text).collect::<Vec<String>>();
+                Quadruple::new(
+                    "view",
+                    &text(source),
+                    format!("{}/{}", sizes.join("x"), strides.join("x")).as_str(),
+                    &text(dest),
+                )
+            }
+            Instruction::Copy(source, count, dest) => {
+                Quadruple::new("copy", &text(source), &text(count), &text(dest))
+            }
+            Instruction::Elementwise(operator, left, right, dest) => Quadruple::new(
+                format!(".{}", operator.to_string()).as_str(),
+                &text(left),
+                &text(right),
+                &text(dest),
+            ),
+        }
+    }
+}
+
+impl TryFrom<&Quadruple> for Instruction {
+    type Error = String;
+
+    /// Decodes an instruction from its textual form.
+    fn try_from(quadruple: &Quadruple) -> Result<Self, Self::Error> {
+        let Quadruple(operator, left, right, result) = quadruple;
+        let invalid = || format!("Invalid quadruple {:?}", quadruple);
+        let label = |position: &String| {
+            position
+                .parse::<usize>()
+                .map(Operand::Label)
+                .map_err(|_| invalid())
+        };
+        let shape = |shape: &String| {
+            shape
+                .split('x')
+                .map(|size| size.parse::<usize>().map_err(|_| invalid()))
+                .collect::<Result<Vec<usize>, String>>()
+        };
+        let dimensions = shape(right);
+        let axis = right
+            .split_once('/')
+            .and_then(|(axis, rank)| Some((axis.parse::<usize>().ok()?, rank.parse().ok()?)))
+            .ok_or_else(invalid);
+        let sizes = right
+            .split('x')
+            .map(Operand::parse)
+            .collect::<Vec<Operand>>();
+        let strided = right
+            .split_once('/')
+            .map(|(sizes, strides)| {
+                let operands = |text: &str| text.split('x').map(Operand::parse).collect();
+                (operands(sizes), operands(strides))
+            })
+            .ok_or_else(invalid);
+        let captured = right
+            .split(',')
+            .filter(|value| !value.is_empty())
+            .map(Operand::parse)
+            .collect::<Vec<Operand>>();
+        let closure_id = left.clone();
+        let data_type = CAST_TYPES
+            .iter()
+            .chain([DataType::DataFrame, DataType::Series].iter())
+            .find(|data_type| &format!("{:#?}", data_type) == left)
+            .cloned();
+        let (left, right, dest) = (
+            Operand::parse(left),
+            Operand::parse(right),
+            Operand::parse(result),
+        );
+
+        let instruction = match operator.as_str() {
+            "" => Instruction::Empty,
+            "ver" if result.is_empty() => Instruction::Verify(left, right),
+            "ver" => Instruction::Verify(
+                left,
+                Operand::Const(Literal::Int(result.parse().map_err(|_| invalid())?)),
+            ),
+            "goto" => Instruction::Goto(label(result)?),
+            "gotoFalse" => Instruction::GotoFalse(left, label(result)?),
+            "era" => Instruction::Era(result.clone()),
+            "param" => Instruction::Param(left, result.parse().map_err(|_| invalid())?),
+            "gosub" => Instruction::GoSub(result.clone()),
+            "call" if result.is_empty() => Instruction::Call(left, None),
+            "call" => Instruction::Call(left, Some(dest)),
+            "closure" => Instruction::Closure(closure_id, captured, dest),
+            "return" => Instruction::Return(dest),
+            "voidReturn" => Instruction::VoidReturn,
+            "endFunc" => Instruction::EndFunc,
+            "endProgram" => Instruction::EndProgram,
+            "print" => Instruction::Print(dest),
+            "free" => Instruction::Free(dest),
+            "mark" => Instruction::Mark(result.parse().map_err(|_| invalid())?),
+            "release" => Instruction::Release(result.parse().map_err(|_| invalid())?),
+            "alloc" => Instruction::Alloc(dest, dimensions?),
+            "len" => {
+                let (axis, rank) = axis?;
+                Instruction::Len(left, axis, rank, dest)
+            }
+            "new" => Instruction::New(data_type.ok_or_else(invalid)?, sizes, dest),
+            "view" => {
+                let (sizes, strides) = strided?;
+                Instruction::View(left, sizes, strides, dest)
+            }
+            "copy" => Instruction::Copy(left, right, dest),
+            operator if operator.len() > 1 && operator.starts_with('.') => {
+                match OPERATORS.iter().find(|op| op.to_string() == &operator[1..]) {
+                    Some(op) => Instruction::Elementwise(*op, left, right, dest),
+                    None => return Err(format!("Unknown instruction {}", operator)),
+                }
+            }
+            operator => {
+                if let Some(data_type) = CAST_TYPES
+                    .iter()
+                    .find(|data_type| format!("{:#?}", data_type) == operator)
+                {
+                    Instruction::Cast(data_type.clone(), left, dest)
+                } else {
+                    match OPERATORS.iter().find(|op| op.to_string() == operator) {
+                        Some(op @ (Operator::Assign | Operator::Not | Operator::Neg)) => {
+                            Instruction::Unary(*op, left, dest)
+                        }
+                        Some(op) => Instruction::Operation(*op, left, right, dest),
+                        None => return Err(format!("Unknown instruction {}", operator)),
+                    }
+                }
+            }
+        };
+
+        Ok(instruction)
+    }
+}
+
 impl Debug for Quadruple {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
         return write!(fmt, "{}\t{}\t{}\t{}\t", self.0, self.1, self.2, self.3);
@@ -131,7 +568,7 @@ pub struct QuadrupleHold {
 impl QuadrupleHold {
     pub fn new(manager: &mut Manager) -> QuadrupleHold {
         let position = manager.get_next_pos();
-        manager.emit(Quadruple::new_empty());
+        manager.emit(Instruction::new_empty());
 
         QuadrupleHold {
             position,
@@ -139,8 +576,120 @@ impl QuadrupleHold {
         }
     }
 
-    pub fn release(&mut self, manager: &mut Manager, value: Quadruple) {
+    pub fn release(&mut self, manager: &mut Manager, value: Instruction) {
         manager.update_instruction(self.position, value);
         self.released = true;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use memory::types::DataType;
+    use parser::types::Operator;
+
+    use super::{Instruction, Literal, Operand, Quadruple};
+
+    #[test]
+    fn test_textual_form() {
+        let instructions = vec![
+            Instruction::operation(
+                Operator::Mul,
+                Operand::Direct(1000),
+                Operand::Indirect(24005),
+                Operand::Direct(1001),
+            ),
+            Instruction::unary(
+                Operator::Assign,
+                Operand::AddressOf(12000),
+                Operand::Direct(1002),
+            ),
+            Instruction::unary(
+                Operator::Assign,
+                Operand::Const(Literal::End),
+                Operand::Direct(12010),
+            ),
+            Instruction::type_cast(
+                &DataType::Float,
+                Operand::Direct(1000),
+                Operand::Direct(5000),
+            ),
+            Instruction::verify(Operand::Direct(1000), 10),
+            Instruction::alloc(12000, &[2, 5]),
+            Instruction::len(Operand::Direct(12000), 1, 2, Operand::Direct(1003)),
+            Instruction::new_array(
+                &DataType::Float,
+                vec![Operand::Direct(1003), Operand::Indirect(12001)],
+                Operand::Direct(12020),
+            ),
+            Instruction::verify_bound(Operand::Direct(1000), Operand::Direct(1003)),
+            Instruction::copy(
+                Operand::Direct(12020),
+                Operand::Direct(1003),
+                Operand::Direct(12001),
+            ),
+            Instruction::elementwise(
+                Operator::GreaterThan,
+                Operand::Direct(12020),
+                Operand::Direct(24005),
+                Operand::Direct(12040),
+            ),
+            Instruction::operation(
+                Operator::MatMul,
+                Operand::Direct(12020),
+                Operand::Direct(12040),
+                Operand::Direct(12060),
+            ),
+            Instruction::goto_false(Operand::Direct(9000), 12),
+            Instruction::param(Operand::Direct(1000), 1),
+            Instruction::go_sub("fib"),
+            Instruction::closure(
+                "outer.inner",
+                vec![Operand::Direct(1000), Operand::Direct(5000)],
+                Operand::Direct(12003),
+            ),
+            Instruction::call(Operand::Direct(12003), None),
+            Instruction::call(Operand::Direct(12003), Some(Operand::Direct(1004))),
+            Instruction::view(
+                Operand::Direct(12020),
+                vec![Operand::Direct(1003), Operand::Direct(1005)],
+                vec![Operand::Direct(1004), Operand::Direct(1006)],
+                Operand::Direct(12060),
+            ),
+            Instruction::print(Operand::Const(Literal::Text(String::from("\n")))),
+            Instruction::new_empty(),
+        ];
+
+        let quadruples = instructions
+            .iter()
+            .map(Quadruple::from)
+            .collect::<Vec<Quadruple>>();
+        assert_eq!(format!("{:?}", quadruples[0]), "*\t1000\t*24005\t1001\t");
+        assert_eq!(format!("{:?}", quadruples[1]), "=\t&12000\t\t1002\t");
+        assert_eq!(format!("{:?}", quadruples[5]), "alloc\t\t2x5\t12000\t");
+        assert_eq!(format!("{:?}", quadruples[7]), "new\tFloat\t1003x*12001\t12020\t");
+        assert_eq!(format!("{:?}", quadruples[8]), "ver\t1000\t1003\t\t");
+        assert_eq!(format!("{:?}", quadruples[9]), "copy\t12020\t1003\t12001\t");
+        assert_eq!(format!("{:?}", quadruples[10]), ".>\t12020\t24005\t12040\t");
+        assert_eq!(format!("{:?}", quadruples[11]), "@\t12020\t12040\t12060\t");
+        assert_eq!(
+            format!("{:?}", quadruples[15]),
+            "closure\touter.inner\t1000,5000\t12003\t"
+        );
+        assert_eq!(format!("{:?}", quadruples[16]), "call\t12003\t\t\t");
+        assert_eq!(format!("{:?}", quadruples[17]), "call\t12003\t\t1004\t");
+        assert_eq!(
+            format!("{:?}", quadruples[18]),
+            "view\t12020\t1003x1005/1004x1006\t12060\t"
+        );
+
+        let decoded = quadruples
+            .iter()
+            .map(Instruction::try_from)
+            .collect::<Result<Vec<Instruction>, String>>()
+            .unwrap();
+        assert_eq!(decoded, instructions);
+
+        assert!(Instruction::try_from(&Quadruple::new("goto", "", "", "next")).is_err());
+        assert!(Instruction::try_from(&Quadruple::new("jump", "", "", "1")).is_err());
+    }
+}
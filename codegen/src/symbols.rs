@@ -14,6 +14,57 @@ pub struct SymbolEntry {
     pub data_type: DataType,
     pub immutable: bool,
     pub dimension: Dimension,
+    /// Entries of each field, for variables holding structs.
+    pub fields: Vec<SymbolEntry>,
+    /// Id of the nested function, for variables holding its closure.
+    pub procedure: Option<String>,
+}
+
+impl SymbolEntry {
+    /// Returns the entry of a field nested within a struct variable, given
+    /// the ids of the fields to access.
+    pub fn field(&self, path: &[String]) -> Result<&SymbolEntry, String> {
+        match path.split_first() {
+            None => Ok(self),
+            Some((id, path)) => match &self.data_type {
+                DataType::Struct(name) => self
+                    .fields
+                    .iter()
+                    .find(|field| field.id == *id)
+                    .ok_or(format!("Struct {} has no field {}", name, id))?
+                    .field(path),
+                _ => Err(format!(
+                    "{} is not a struct, can't access its field {}",
+                    self.id, id
+                )),
+            },
+        }
+    }
+
+    /// Returns the entries holding the values of the variable, along with
+    /// their field path. Variables other than structs are their own value.
+    pub fn leaves(&self) -> Vec<(Vec<String>, &SymbolEntry)> {
+        if self.fields.is_empty() {
+            return vec![(vec![], self)];
+        }
+
+        self.fields
+            .iter()
+            .flat_map(|field| {
+                field.leaves().into_iter().map(|(mut path, leaf)| {
+                    path.insert(0, field.id.clone());
+                    (path, leaf)
+                })
+            })
+            .collect()
+    }
+}
+
+/// Represents a struct declaration, by the data type of its fields
+#[derive(Debug, Clone)]
+pub struct StructEntry {
+    pub id: String,
+    pub fields: Vec<(String, DataType)>,
 }
 
 /// Represents a function entry
@@ -24,6 +75,18 @@ pub struct FunctionEntry {
     pub params: Vec<ParamAddress>,
     pub procedure_address: usize,
     pub return_address: Option<MemAddress>,
+    /// Locals receiving the values captured by the closures of a nested
+    /// function, in order.
+    #[serde(default)]
+    pub captures: Vec<MemAddress>,
+    /// Local holding the closure a nested function was called through, so
+    /// it can call itself.
+    #[serde(default)]
+    pub closure_address: Option<MemAddress>,
+    /// Whether the function is implemented by the virtual machine, programs
+    /// can declare functions shadowing the native ones.
+    #[serde(default)]
+    pub is_native: bool,
 }
 
 impl FunctionEntry {
@@ -39,6 +102,9 @@ impl FunctionEntry {
             return_type: func.data_type.to_owned(),
             params: params_mapped,
             return_address,
+            captures: vec![],
+            closure_address: None,
+            is_native: func.is_native,
         }
     }
 }
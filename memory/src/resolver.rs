@@ -4,34 +4,58 @@ use lazy_static::lazy_static;
 
 use super::types::DataType;
 
-pub type MemAddress = u16;
+pub type MemAddress = u32;
 
-lazy_static! {
-    pub static ref TYPE_OFFSETS: HashMap<DataType, MemAddress> = {
-        HashMap::from([
-            (DataType::Bool, MemoryResolver::DATA_TYPE_ALLOC_SIZE * 0),       // 0 - 1,999
-            (DataType::Float, MemoryResolver::DATA_TYPE_ALLOC_SIZE * 1),      // 2,000 - 3,999
-            (DataType::Int, MemoryResolver::DATA_TYPE_ALLOC_SIZE * 2),        // 4,000 - 5,999
-            (DataType::String, MemoryResolver::DATA_TYPE_ALLOC_SIZE * 3),     // 6,000 - 7,999
-            (DataType::DataFrame, MemoryResolver::DATA_TYPE_ALLOC_SIZE * 4),  // 8,000 - 9,999
-            (DataType::Series, MemoryResolver::DATA_TYPE_ALLOC_SIZE * 5),   // 8,000 - 9,999
-            (DataType::Pointer, MemoryResolver::DATA_TYPE_ALLOC_SIZE * 6),    // 10,000 - 12,000
-        ])
-    };
+/// Data types with a memory segment, in the order the segments are laid out
+/// within each scope.
+static TYPE_SEGMENTS: [DataType; 7] = [
+    DataType::Bool,
+    DataType::Float,
+    DataType::Int,
+    DataType::String,
+    DataType::DataFrame,
+    DataType::Series,
+    DataType::Pointer,
+];
 
-    pub static ref TYPE_OFFSETS_INV: HashMap<MemAddress, DataType> =
-        TYPE_OFFSETS.iter().map(|(data_type, offset)| (*offset, data_type.clone())).collect();
-
-    pub static ref SCOPE_OFFSETS: HashMap<MemoryScope, MemAddress> = {
-        HashMap::from([
-            (MemoryScope::Global, MemoryResolver::SEGMENT_SIZE * 1),      // (12,000 - 23,999)
-            (MemoryScope::Local, MemoryResolver::SEGMENT_SIZE * 2),       // (24,000 - 35,999)
-            (MemoryScope::Constant, MemoryResolver::SEGMENT_SIZE * 3),    // (36,000 - 39,999)
-        ])
-    };
+/// Scopes in the order their segments are laid out, the first segment is
+/// left unused so no valid address is 0.
+static SCOPE_SEGMENTS: [MemoryScope; 4] = [
+    MemoryScope::Global,
+    MemoryScope::Local,
+    MemoryScope::Constant,
+    MemoryScope::Heap,
+];
 
-    pub static ref SCOPE_OFFSETS_INV: HashMap<MemAddress, MemoryScope> =
-        SCOPE_OFFSETS.iter().map(|(scope, offset)| (*offset, *scope)).collect();
+lazy_static! {
+    pub static ref TYPE_OFFSETS: HashMap<DataType, MemAddress> = TYPE_SEGMENTS
+        .iter()
+        .enumerate()
+        .map(|(index, data_type)| {
+            (
+                data_type.clone(),
+                MemoryResolver::DATA_TYPE_ALLOC_SIZE * index as MemAddress,
+            )
+        })
+        .collect();
+    pub static ref TYPE_OFFSETS_INV: HashMap<MemAddress, DataType> = TYPE_OFFSETS
+        .iter()
+        .map(|(data_type, offset)| (*offset, data_type.clone()))
+        .collect();
+    pub static ref SCOPE_OFFSETS: HashMap<MemoryScope, MemAddress> = SCOPE_SEGMENTS
+        .iter()
+        .enumerate()
+        .map(|(index, scope)| {
+            (
+                *scope,
+                MemoryResolver::SEGMENT_SIZE * (index as MemAddress + 1),
+            )
+        })
+        .collect();
+    pub static ref SCOPE_OFFSETS_INV: HashMap<MemAddress, MemoryScope> = SCOPE_OFFSETS
+        .iter()
+        .map(|(scope, offset)| (*offset, *scope))
+        .collect();
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
@@ -39,65 +63,117 @@ pub enum MemoryScope {
     Global,
     Local,
     Constant,
+    /// Arrays allocated while the program runs.
+    Heap,
 }
-pub struct MemoryResolver {}
 
-impl MemoryResolver {
-    pub const DATA_TYPE_ALLOC_SIZE: MemAddress = 2_000;
-    pub const SEGMENT_SIZE: MemAddress = Self::DATA_TYPE_ALLOC_SIZE * 7; // 7 - Data types count
-    pub const GLOBAL_OFFSET: MemAddress = Self::SEGMENT_SIZE * 1;
-    pub const LOCAL_OFFSET: MemAddress = Self::SEGMENT_SIZE * 2;
-    pub const CONSTANT_OFFSET: MemAddress = Self::SEGMENT_SIZE * 3;
+/// Sizes of the segments the address space is split into.
+///
+/// Each scope gets a segment, split into a segment of `type_size` addresses
+/// per data type, so an address encodes its scope, type and offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryLayout {
+    pub type_size: MemAddress,
+}
 
-    pub fn get_scope_from_address(address: MemAddress) -> Option<&'static MemoryScope> {
-        let offset = address - (address % Self::SEGMENT_SIZE);
-        if let Some(scope) = SCOPE_OFFSETS_INV.get(&offset) {
-            Some(scope)
-        } else {
-            None
-        }
+impl MemoryLayout {
+    /// Layout used by the compiler and the virtual machine. It is fixed at
+    /// build time, programs can't choose another size.
+    pub const DEFAULT: MemoryLayout = MemoryLayout {
+        type_size: 10_000_000,
+    };
+
+    /// Layout of the object files of previous versions, with 16 bit addresses.
+    pub const LEGACY: MemoryLayout = MemoryLayout { type_size: 2_000 };
+
+    pub const fn new(type_size: MemAddress) -> MemoryLayout {
+        MemoryLayout { type_size }
     }
 
-    pub fn get_type_from_address(address: MemAddress) -> Option<&'static DataType> {
-        let type_offset = address % Self::SEGMENT_SIZE;
-        let step_offset = type_offset - (type_offset % Self::DATA_TYPE_ALLOC_SIZE);
-        if let Some(dtype) = TYPE_OFFSETS_INV.get(&step_offset) {
-            Some(dtype)
-        } else {
-            None
-        }
+    pub const fn segment_size(&self) -> MemAddress {
+        self.type_size * TYPE_SEGMENTS.len() as MemAddress
     }
 
-    fn get_scope_offset(scope: &MemoryScope) -> MemAddress {
-        if let Some(scope_offset) = SCOPE_OFFSETS.get(scope) {
-            *scope_offset
-        } else {
-            panic!("Scope not supported in memory: {:#?}", scope)
-        }
+    /// Highest address of the layout.
+    pub const fn max_address(&self) -> MemAddress {
+        self.segment_size() * (SCOPE_SEGMENTS.len() as MemAddress + 1) - 1
+    }
+
+    pub fn get_scope(&self, address: MemAddress) -> Option<&'static MemoryScope> {
+        let segment = (address / self.segment_size()) as usize;
+        SCOPE_SEGMENTS.get(segment.checked_sub(1)?)
     }
 
-    fn get_type_offset(scope: &MemoryScope, data_type: &DataType) -> MemAddress {
-        if let Some(type_offset) = TYPE_OFFSETS.get(data_type) {
-            Self::get_scope_offset(scope) + type_offset
-        } else {
-            panic!("Type not supported in memory: {:#?}", data_type)
+    pub fn get_type(&self, address: MemAddress) -> Option<&'static DataType> {
+        let segment = (address % self.segment_size()) / self.type_size;
+        TYPE_SEGMENTS.get(segment as usize)
+    }
+
+    pub fn get_offset(&self, address: MemAddress) -> Option<(MemoryScope, DataType, MemAddress)> {
+        let scope = self.get_scope(address)?;
+        let data_type = self.get_type(address)?;
+
+        Some((*scope, data_type.clone(), address % self.type_size))
+    }
+
+    /// Returns the address of an offset within the segment of a scope and
+    /// data type, None if it doesn't fit in the segment.
+    pub fn to_address(
+        &self,
+        scope: &MemoryScope,
+        data_type: &DataType,
+        offset: MemAddress,
+    ) -> Option<MemAddress> {
+        let scope_index = SCOPE_SEGMENTS.iter().position(|item| item == scope)?;
+        let type_index = TYPE_SEGMENTS.iter().position(|item| item == data_type)?;
+
+        if offset >= self.type_size {
+            return None;
         }
+
+        Some(
+            self.segment_size() * (scope_index as MemAddress + 1)
+                + self.type_size * type_index as MemAddress
+                + offset,
+        )
     }
 
-    fn is_within(scope: &MemoryScope, data_type: &DataType, address: MemAddress) -> bool {
-        let lower_bound = Self::get_type_offset(scope, data_type);
-        let upper_bound = lower_bound + Self::DATA_TYPE_ALLOC_SIZE;
+    /// Translates an address of this layout into the same location of
+    /// another layout.
+    pub fn translate(&self, address: MemAddress, layout: &MemoryLayout) -> Option<MemAddress> {
+        let (scope, data_type, offset) = self.get_offset(address)?;
+        layout.to_address(&scope, &data_type, offset)
+    }
+}
+
+pub struct MemoryResolver {}
+
+impl MemoryResolver {
+    /// The layout every address is resolved with. Object files store raw
+    /// addresses, so changing it makes the files written before unreadable.
+    pub const LAYOUT: MemoryLayout = MemoryLayout::DEFAULT;
+    pub const DATA_TYPE_ALLOC_SIZE: MemAddress = Self::LAYOUT.type_size;
+    pub const SEGMENT_SIZE: MemAddress = Self::LAYOUT.segment_size();
+    pub const GLOBAL_OFFSET: MemAddress = Self::SEGMENT_SIZE;
+    pub const LOCAL_OFFSET: MemAddress = Self::SEGMENT_SIZE * 2;
+    pub const CONSTANT_OFFSET: MemAddress = Self::SEGMENT_SIZE * 3;
+    pub const HEAP_OFFSET: MemAddress = Self::SEGMENT_SIZE * 4;
 
-        address >= lower_bound && address < upper_bound
+    pub fn get_scope_from_address(address: MemAddress) -> Option<&'static MemoryScope> {
+        Self::LAYOUT.get_scope(address)
+    }
+
+    pub fn get_type_from_address(address: MemAddress) -> Option<&'static DataType> {
+        Self::LAYOUT.get_type(address)
     }
 
     pub fn to_address(scope: &MemoryScope, data_type: &DataType, offset: MemAddress) -> MemAddress {
-        let type_offset = Self::get_type_offset(scope, data_type);
-        let address = type_offset + offset;
-        if Self::is_within(scope, data_type, address) {
-            address
-        } else {
-            panic!("Address is not within corresponding memory bounds")
+        match Self::LAYOUT.to_address(scope, data_type, offset) {
+            Some(address) => address,
+            None => panic!(
+                "Address is not within corresponding memory bounds: offset {} of {:?} {:?} memory",
+                offset, scope, data_type
+            ),
         }
     }
 
@@ -114,15 +190,10 @@ impl MemoryResolver {
     }
 
     pub fn get_offset(address: MemAddress) -> (MemoryScope, DataType, MemAddress) {
-        if let Some(scope) = Self::get_scope_from_address(address) {
-            if let Some(data_type) = Self::get_type_from_address(address) {
-                let item_offset = address % Self::DATA_TYPE_ALLOC_SIZE;
-
-                return (scope.clone(), data_type.clone(), item_offset);
-            }
+        match Self::LAYOUT.get_offset(address) {
+            Some(resolved) => resolved,
+            None => panic!("Cannot resolve address {address}"),
         }
-
-        panic!("Cannot resolve address {address}");
     }
 }
 
@@ -134,7 +205,7 @@ mod tests {
         types::DataType,
     };
 
-    use super::MemoryResolver;
+    use super::{MemoryLayout, MemoryResolver};
 
     #[test]
     fn test_offsets() {
@@ -157,4 +228,33 @@ mod tests {
             (MemoryScope::Local, DataType::DataFrame, 7)
         );
     }
+
+    #[test]
+    fn test_layouts() {
+        let legacy = MemoryLayout::LEGACY;
+        let last_constant = legacy.to_address(&MemoryScope::Constant, &DataType::Pointer, 1_999);
+        assert!(last_constant.unwrap() <= u16::MAX as MemAddress);
+        assert_eq!(
+            legacy.to_address(&MemoryScope::Local, &DataType::Int, 3),
+            Some(28_000 + 4_000 + 3)
+        );
+        assert_eq!(
+            legacy.to_address(&MemoryScope::Local, &DataType::Int, 2_000),
+            None
+        );
+
+        let address = MemoryResolver::to_address(&MemoryScope::Constant, &DataType::Float, 250_000);
+        assert_eq!(
+            MemoryResolver::get_offset(address),
+            (MemoryScope::Constant, DataType::Float, 250_000)
+        );
+        assert!(MemoryLayout::DEFAULT.max_address() > address);
+
+        let translated = legacy.translate(32_003, &MemoryLayout::DEFAULT).unwrap();
+        assert_eq!(
+            MemoryResolver::get_offset(translated),
+            (MemoryScope::Local, DataType::Int, 3)
+        );
+        assert_eq!(legacy.get_scope(1_999), None);
+    }
 }
@@ -13,8 +13,12 @@ pub enum DataType {
     Series,
     Iterable(Box<DataType>),
     Void,
-    Function(Box<DataType>),
+    /// Function values, by the types of their parameters and the type they
+    /// return.
+    Function(Vec<DataType>, Box<DataType>),
     Pointer,
+    /// User defined record, by the id of its declaration.
+    Struct(String),
 }
 
 impl DataType {
@@ -27,12 +31,22 @@ impl DataType {
             DataType::Series => 4,
             DataType::DataFrame => 5,
             DataType::Void => 6,
-            DataType::Function(_) => 7,
+            DataType::Function(..) => 7,
+            DataType::Struct(_) => 8,
             DataType::Iterable(_) => todo!(),
             _ => todo!(),
         }
     }
 
+    /// Type of the memory segment the values of this type are stored in,
+    /// functions are stored along with pointers.
+    pub fn segment(&self) -> DataType {
+        match self {
+            DataType::Function(..) => DataType::Pointer,
+            _ => self.clone(),
+        }
+    }
+
     pub fn max(left: &DataType, right: &DataType) -> DataType {
         if Self::hierarchy(&left) > Self::hierarchy(&right) {
             left.clone()
@@ -69,7 +83,14 @@ impl DataType {
                 _ => Err(()),
             },
             DataType::Void => Err(()),
-            DataType::Function(func) => Self::equivalent(func, right),
+            DataType::Function(..) => match right {
+                DataType::Function(..) if left == right => ok_ret,
+                _ => Err(()),
+            },
+            DataType::Struct(id) => match right {
+                DataType::Struct(other) if id == other => ok_ret,
+                _ => Err(()),
+            },
             DataType::Iterable(_) => todo!(),
             _ => todo!(),
         }
@@ -7,11 +7,12 @@ use super::{
 
 pub type MemoryCounter = HashMap<DataType, usize>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct VirtualAllocator {
     global_counters: MemoryCounter,
     local_counters: MemoryCounter,
     constant_counters: MemoryCounter,
+    heap_counters: MemoryCounter,
 }
 
 impl VirtualAllocator {
@@ -20,6 +21,7 @@ impl VirtualAllocator {
             global_counters: HashMap::new(),
             local_counters: HashMap::new(),
             constant_counters: HashMap::new(),
+            heap_counters: HashMap::new(),
         }
     }
 
@@ -28,13 +30,14 @@ impl VirtualAllocator {
             MemoryScope::Global => &mut self.global_counters,
             MemoryScope::Local => &mut self.local_counters,
             MemoryScope::Constant => &mut self.constant_counters,
+            MemoryScope::Heap => &mut self.heap_counters,
         }
     }
 
     pub fn update_counter(&mut self, scope: &MemoryScope, data_type: &DataType, size: usize) {
         let counter = self.get_counter(scope);
 
-        counter.insert(data_type.clone(), size);
+        counter.insert(data_type.segment(), size);
     }
 
     pub fn increase_counter_by(
@@ -44,13 +47,14 @@ impl VirtualAllocator {
         size: usize,
     ) -> usize {
         let counter = self.get_counter(scope);
+        let data_type = data_type.segment();
 
         if let Some(last) = counter.get(&data_type) {
             let next_offset = last.clone();
-            counter.insert(data_type.clone(), next_offset + size);
+            counter.insert(data_type, next_offset + size);
             next_offset
         } else {
-            counter.insert(data_type.clone(), size);
+            counter.insert(data_type, size);
             0
         }
     }
@@ -66,6 +70,33 @@ impl VirtualAllocator {
         size: usize,
     ) -> MemAddress {
         let start_address = self.increase_counter_by(scope, data_type, size);
-        MemoryResolver::to_address(scope, data_type, start_address as MemAddress)
+        MemoryResolver::to_address(scope, &data_type.segment(), start_address as MemAddress)
+    }
+
+    /// Assigns a location for `size` contiguous values.
+    ///
+    /// Fails without reserving any space if the values don't fit in the
+    /// memory segment of the scope and data type.
+    pub fn try_assign_location(
+        &mut self,
+        scope: &MemoryScope,
+        data_type: &DataType,
+        size: usize,
+    ) -> Result<MemAddress, String> {
+        let start = self
+            .get_counter(scope)
+            .get(&data_type.segment())
+            .copied()
+            .unwrap_or(0);
+        let available = MemoryResolver::DATA_TYPE_ALLOC_SIZE as usize;
+
+        if start + size > available {
+            return Err(format!(
+                "Not enough {:?} memory for {} {:?} values, {} of {} are in use",
+                scope, size, data_type, start, available
+            ));
+        }
+
+        Ok(self.assign_location(scope, data_type, size))
     }
 }
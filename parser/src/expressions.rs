@@ -1,14 +1,20 @@
+use crate::span::Span;
+
 use self::{
     call::Call,
     constant::Const,
     id::{Access, Id},
+    lambda::Lambda,
     operation::Operation,
+    vector::Vector,
 };
 
 pub mod call;
 pub mod constant;
 pub mod id;
+pub mod lambda;
 pub mod operation;
+pub mod vector;
 
 #[derive(Debug, Clone)]
 pub enum Expression {
@@ -19,9 +25,35 @@ pub enum Expression {
     Call(Call),
     Not(Box<Expression>),
     Negative(Box<Expression>),
+    Vector(Vector),
+    Lambda(Lambda),
+}
+
+impl Expression {
+    /// Returns the source span covered by the expression.
+    pub fn span(&self) -> Span {
+        match self {
+            Expression::Const(constant) => constant.span,
+            Expression::Op(operation) => operation.span,
+            Expression::Access(access) => access.span,
+            Expression::Id(_) => Span::default(),
+            Expression::Call(call) => call.span,
+            Expression::Not(expr) => expr.span(),
+            Expression::Negative(expr) => expr.span(),
+            Expression::Vector(vector) => vector.span,
+            Expression::Lambda(lambda) => lambda.span,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum Index {
     Simple(Box<Expression>),
+    /// Slice of an axis, taking every `step` item from `start` up to `end`.
+    /// Missing bounds take the whole axis one item at a time.
+    Range {
+        start: Option<Box<Expression>>,
+        end: Option<Box<Expression>>,
+        step: Option<Box<Expression>>,
+    },
 }
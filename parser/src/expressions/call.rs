@@ -1,9 +1,12 @@
+use crate::span::Span;
+
 use super::Expression;
 
 #[derive(Debug, Clone)]
 pub struct Call {
     pub id: String,
     pub params: Vec<Box<Expression>>,
+    pub span: Span,
 }
 
 impl Call {
@@ -11,6 +14,7 @@ impl Call {
         Call {
             id: String::from(id),
             params,
+            span: Span::default(),
         }
     }
 }
@@ -1,5 +1,7 @@
 use memory::types::{DataType, FloatType, IntType};
 
+use crate::span::Span;
+
 #[derive(Debug)]
 pub enum TypeConst {
     Bool(bool),
@@ -13,6 +15,7 @@ pub enum TypeConst {
 pub struct Const {
     pub value: String,
     pub dtype: DataType,
+    pub span: Span,
 }
 
 impl Const {
@@ -20,6 +23,7 @@ impl Const {
         Const {
             value: String::from(value),
             dtype,
+            span: Span::default(),
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::expressions::Index;
+use crate::{expressions::Index, span::Span};
 use memory::types::DataType;
 
 #[derive(Debug, Clone)]
@@ -11,6 +11,9 @@ pub struct Id {
 pub struct Access {
     pub id: Id,
     pub indexing: Vec<Index>,
+    /// Path of struct fields accessed after indexing the item.
+    pub fields: Vec<String>,
+    pub span: Span,
 }
 
 impl Id {
@@ -24,7 +27,27 @@ impl Id {
 
 impl Access {
     pub fn new(id: Id, indexing: Vec<Index>) -> Self {
-        Access { id, indexing }
+        Access {
+            id,
+            indexing,
+            fields: vec![],
+            span: Span::default(),
+        }
+    }
+
+    /// Whether any axis is sliced, the access is then a view of the array
+    /// instead of one of its items.
+    pub fn is_view(&self) -> bool {
+        self.indexing
+            .iter()
+            .any(|index| matches!(index, Index::Range { .. }))
+    }
+
+    /// Returns the access to a field nested within the accessed struct.
+    pub fn field(&self, path: &[String]) -> Access {
+        let mut access = self.clone();
+        access.fields.extend(path.iter().cloned());
+        access
     }
 }
 
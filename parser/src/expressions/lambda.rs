@@ -0,0 +1,22 @@
+use crate::{functions::FunctionParam, span::Span};
+
+use super::Expression;
+
+/// Anonymous function returning the value of an expression, e.g.
+/// `|x: int| x * 2`.
+#[derive(Debug, Clone)]
+pub struct Lambda {
+    pub params: Vec<FunctionParam>,
+    pub body: Box<Expression>,
+    pub span: Span,
+}
+
+impl Lambda {
+    pub fn new(params: Vec<FunctionParam>, body: Box<Expression>) -> Self {
+        Lambda {
+            params,
+            body,
+            span: Span::default(),
+        }
+    }
+}
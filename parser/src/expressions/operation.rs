@@ -1,4 +1,4 @@
-use crate::types::Operator;
+use crate::{span::Span, types::Operator};
 
 use super::Expression;
 
@@ -7,14 +7,17 @@ pub struct Operation {
     pub operator: Operator,
     pub left: Box<Expression>,
     pub right: Box<Expression>,
+    pub span: Span,
 }
 
 impl Operation {
     pub fn new(left: Box<Expression>, operator: Operator, right: Box<Expression>) -> Self {
+        let span = left.span().join(&right.span());
         Operation {
             operator,
             left,
             right,
+            span,
         }
     }
 }
@@ -0,0 +1,88 @@
+use crate::span::Span;
+
+use super::Expression;
+
+/// Array literal, each item is either a value or a nested row, e.g.
+/// `[[1, 2], [3, 4]]`.
+#[derive(Debug, Clone)]
+pub struct Vector {
+    pub items: Vec<Box<Expression>>,
+    pub span: Span,
+}
+
+impl Vector {
+    pub fn new(items: Vec<Box<Expression>>) -> Self {
+        Vector {
+            items,
+            span: Span::default(),
+        }
+    }
+
+    /// Returns the shape of the literal.
+    ///
+    /// Fails if its rows don't share the same shape.
+    pub fn shape(&self) -> Result<Vec<usize>, String> {
+        let mut shapes = self.items.iter().map(|item| match item.as_ref() {
+            Expression::Vector(row) => row.shape(),
+            _ => Ok(vec![]),
+        });
+
+        let row_shape = match shapes.next() {
+            Some(shape) => shape?,
+            None => return Ok(vec![0]),
+        };
+
+        for shape in shapes {
+            if shape? != row_shape {
+                return Err(String::from(
+                    "Rows of an array literal must have the same shape",
+                ));
+            }
+        }
+
+        Ok([vec![self.items.len()], row_shape].concat())
+    }
+
+    /// Returns the values of the literal in row major order.
+    pub fn values(&self) -> Vec<&Expression> {
+        self.items
+            .iter()
+            .flat_map(|item| match item.as_ref() {
+                Expression::Vector(row) => row.values(),
+                value => vec![value],
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use memory::types::DataType;
+
+    use super::*;
+    use crate::expressions::constant::Const;
+
+    fn int(value: &str) -> Box<Expression> {
+        Box::new(Expression::Const(Const::new(value, DataType::Int)))
+    }
+
+    fn row(items: Vec<Box<Expression>>) -> Box<Expression> {
+        Box::new(Expression::Vector(Vector::new(items)))
+    }
+
+    #[test]
+    fn test_shape() {
+        let matrix = Vector::new(vec![
+            row(vec![int("1"), int("2"), int("3")]),
+            row(vec![int("4"), int("5"), int("6")]),
+        ]);
+        assert_eq!(matrix.shape(), Ok(vec![2, 3]));
+        assert_eq!(matrix.values().len(), 6);
+
+        let ragged = Vector::new(vec![row(vec![int("1")]), row(vec![int("2"), int("3")])]);
+        assert!(ragged.shape().is_err());
+
+        let mixed = Vector::new(vec![row(vec![int("1")]), int("2")]);
+        assert!(mixed.shape().is_err());
+    }
+}
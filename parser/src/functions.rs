@@ -1,13 +1,17 @@
 use memory::types::DataType;
 
-use crate::{statements::Block, types::Variable, Dimension};
+use crate::{span::Span, statements::Block, types::Variable, Dimension};
 
 #[derive(Debug, Clone)]
 pub struct FunctionSignature {
     pub id: String,
     pub data_type: DataType,
+    /// Shape of the returned value, arrays with sizes only known at runtime
+    /// are returned by reference.
+    pub dimension: Dimension,
     pub params: Vec<FunctionParam>,
     pub is_native: bool,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone)]
@@ -19,7 +23,9 @@ impl FunctionParam {
             id: id.to_string(),
             data_type,
             dimension: Dimension::new_scalar(),
-            value: None
+            value: None,
+            span: Span::default(),
+            inferred: false,
         })
     }
 }
@@ -1,6 +1,9 @@
 // auto-generated: "lalrpop 0.19.8"
-// sha3: 5183cc923f4bf276530af8f5f1af05c01556eef3b54590c112628c356f0a8b90
-use crate::{Dimension};
+// sha3: 87aa9b79230cae5cdcd095c179cbc3cba9df17e6e2e854cbc3172688cddf72a4
+use crate::{Dimension, SyntaxError};
+use lalrpop_util::ParseError;
+use crate::lexer::Token;
+use crate::span::Span;
 use crate::types::{Operator, Variable};
 use memory::types::DataType;
 use crate::functions::{Function, FunctionParam, FunctionSignature};
@@ -9,7 +12,10 @@ use crate::expressions::constant::{Const};
 use crate::expressions::id::{Access, Id};
 use crate::expressions::operation::Operation;
 use crate::expressions::call::Call;
+use crate::expressions::vector::Vector;
+use crate::expressions::lambda::Lambda;
 use crate::statements::{Block, Program, Statement};
+use crate::structs::Struct;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
 #[allow(unused_imports)]
@@ -21,7 +27,10 @@ extern crate alloc;
 mod __parse__PProgram {
     #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
 
-    use crate::{Dimension};
+    use crate::{Dimension, SyntaxError};
+    use lalrpop_util::ParseError;
+    use crate::lexer::Token;
+    use crate::span::Span;
     use crate::types::{Operator, Variable};
     use memory::types::DataType;
     use crate::functions::{Function, FunctionParam, FunctionSignature};
@@ -30,421 +39,582 @@ mod __parse__PProgram {
     use crate::expressions::id::{Access, Id};
     use crate::expressions::operation::Operation;
     use crate::expressions::call::Call;
+    use crate::expressions::vector::Vector;
+    use crate::expressions::lambda::Lambda;
     use crate::statements::{Block, Program, Statement};
+    use crate::structs::Struct;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
     use self::__lalrpop_util::state_machine as __state_machine;
     extern crate core;
     extern crate alloc;
-    use self::__lalrpop_util::lexer::Token;
+    use super::__ToTriple;
     #[allow(dead_code)]
     pub(crate) enum __Symbol<'input>
      {
-        Variant0(&'input str),
-        Variant1(Box<Expression>),
-        Variant2(alloc::vec::Vec<Box<Expression>>),
-        Variant3(FunctionParam),
-        Variant4(alloc::vec::Vec<FunctionParam>),
-        Variant5(core::option::Option<Box<Expression>>),
-        Variant6(Block),
-        Variant7(core::option::Option<Block>),
-        Variant8(Const),
-        Variant9(Vec<Box<Expression>>),
-        Variant10(Vec<FunctionParam>),
-        Variant11(Statement),
-        Variant12(Dimension),
-        Variant13(core::option::Option<Dimension>),
-        Variant14(Operator),
-        Variant15(FunctionSignature),
-        Variant16(core::option::Option<FunctionParam>),
-        Variant17(Function),
-        Variant18(Call),
-        Variant19(alloc::vec::Vec<Statement>),
-        Variant20(DataType),
-        Variant21(Expression),
-        Variant22(Index),
-        Variant23(Program),
-        Variant24(core::option::Option<&'input str>),
-        Variant25(Variable),
-        Variant26(Vec<Index>),
-        Variant27(core::option::Option<Vec<Index>>),
-        Variant28(Access),
+        Variant0(Token<'input>),
+        Variant1(&'input str),
+        Variant2(String),
+        Variant3(Box<Expression>),
+        Variant4(alloc::vec::Vec<Box<Expression>>),
+        Variant5(FunctionParam),
+        Variant6(alloc::vec::Vec<FunctionParam>),
+        Variant7(Variable),
+        Variant8(alloc::vec::Vec<Variable>),
+        Variant9(DataType),
+        Variant10(alloc::vec::Vec<DataType>),
+        Variant11(core::option::Option<Box<Expression>>),
+        Variant12(core::option::Option<core::option::Option<Box<Expression>>>),
+        Variant13(alloc::vec::Vec<&'input str>),
+        Variant14(Block),
+        Variant15(core::option::Option<Block>),
+        Variant16(()),
+        Variant17(alloc::vec::Vec<()>),
+        Variant18(Index),
+        Variant19(alloc::vec::Vec<Index>),
+        Variant20(usize),
+        Variant21(Const),
+        Variant22(Vec<()>),
+        Variant23(Vec<Box<Expression>>),
+        Variant24(Vec<Index>),
+        Variant25(Vec<FunctionParam>),
+        Variant26(Vec<Variable>),
+        Variant27(Vec<DataType>),
+        Variant28(Statement),
+        Variant29(Dimension),
+        Variant30(core::option::Option<Dimension>),
+        Variant31(Operator),
+        Variant32(FunctionSignature),
+        Variant33(core::option::Option<FunctionParam>),
+        Variant34(Function),
+        Variant35(Call),
+        Variant36(alloc::vec::Vec<Statement>),
+        Variant37(Expression),
+        Variant38(Lambda),
+        Variant39(Program),
+        Variant40(Struct),
+        Variant41(core::option::Option<Variable>),
+        Variant42(core::option::Option<DataType>),
+        Variant43(core::option::Option<Operator>),
+        Variant44(core::option::Option<Vec<Index>>),
+        Variant45(Access),
+        Variant46(Vector),
     }
     const __ACTION: &[i16] = &[
         // State 0
-        0, 0, 0, 0, 0, 0, 72, 10, 0, 0, 73, 0, 0, 0, 74, 75, 76, 77, 0, 0, 11, 12, 0, 78, 0, 0, 0, 79, 13, 0, 0, 80, 0, 0, 0, 0, 0, 14, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 15,
+        0, 0, 0, 10, 94, 0, 0, 95, 0, 0, 0, 0, 96, 97, 98, 99, 0, 0, 11, 100, 0, 101, 12, 0, 0, 102, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 15, 0, 0, 104, 105, 106, 107, 0, 0, 0, 0, 0, 0, 0, 108,
         // State 1
-        0, 0, 0, -127, -127, 0, 0, 0, -127, -127, 0, -127, 0, -127, 0, 0, 0, 0, -127, -127, 0, 0, 0, 0, 0, 0, -127, 0, 0, -127, -127, 0, -127, -127, 84, -127, 0, 0, -127, -127, -127, 0, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -189, -189, 0, 0, 0, -189, -189, 0, -189, 0, 0, -189, 0, 0, 0, 0, -189, -189, 0, 0, 0, 0, 0, 0, -189, 0, 0, -189, -189, -189, 0, -189, -189, 109, -189, 0, 0, -189, -189, 0, 0, -189, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 72, 10, 0, 0, 73, 0, 0, 0, 74, 75, 76, 77, 0, 0, 11, 12, 0, 78, 0, 0, 0, 79, 13, 0, 0, 80, 0, 0, 0, 0, 0, 14, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 15,
+        0, 0, 0, 10, 94, 0, 0, 95, 0, 0, 0, 0, 96, 97, 98, 99, 0, 0, 11, 100, 0, 101, 12, 0, 0, 102, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 15, 0, 0, 104, 105, 106, 107, 0, 0, 0, 0, 0, 0, 0, 108,
         // State 4
-        0, 0, 0, 0, -96, 0, 0, 0, -96, -96, 0, 0, 0, 88, 0, 0, 0, 0, 89, 90, 0, 0, 0, 0, 0, 0, 91, 0, 0, 92, 0, 0, 93, -96, 0, -96, 0, 0, -96, -96, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -148, 0, 0, 0, -148, -148, 0, 0, 0, 0, 113, 0, 0, 0, 0, 114, 115, 0, 0, 0, 0, 0, 0, 116, 0, 0, 117, 0, 0, 0, 118, -148, 0, -148, 0, 0, -148, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 94, 0, 0, 0, -46, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 95, 0, -46, 0, 0, -46, -46, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 119, 0, 0, 0, -88, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 0, -88, 0, 0, -88, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, -123, -123, 0, 0, 0, -123, -123, 0, 96, 0, -123, 0, 0, 0, 0, -123, -123, 0, 0, 0, 0, 0, 0, -123, 0, 0, -123, 97, 0, -123, -123, 0, -123, 0, 0, -123, -123, -123, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -185, -185, 0, 0, 0, -185, -185, 0, 121, 0, 0, -185, 0, 0, 0, 0, -185, -185, 0, 0, 0, 0, 0, 0, -185, 0, 0, -185, 122, 123, 0, -185, -185, 0, -185, 0, 0, -185, -185, 0, 0, -185, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 0, 98, -105, 0, 0, 0, -105, -105, 0, 0, 0, -105, 0, 0, 0, 0, -105, -105, 0, 0, 0, 0, 0, 0, -105, 0, 0, -105, 0, 0, -105, -105, 0, -105, 0, 0, -105, -105, -105, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        124, -157, 0, 0, 0, -157, -157, 0, 0, 0, 0, -157, 0, 0, 0, 0, -157, -157, 0, 0, 0, 0, 0, 0, -157, 0, 0, -157, 0, 0, 0, -157, -157, 0, -157, 0, 0, -157, -157, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, -27, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        0, 0, 0, -150, -150, -150, 0, 0, -150, -150, 0, -150, 0, -150, 0, 0, 0, 0, -150, -150, 0, 0, 0, 0, 25, 0, -150, 0, 0, -150, -150, 0, -150, -150, -150, -150, 0, 0, -150, -150, -150, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -216, -216, -216, 0, 0, -216, -216, 0, -216, 137, 0, -216, 0, 0, 0, 0, -216, -216, 0, 0, 0, 0, 25, 0, -216, 0, 26, -216, -216, -216, 0, -216, -216, -216, -216, 0, 0, -216, -216, 0, 0, -216, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, -61, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 12
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 13
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 16
-        0, 0, 0, 0, 0, 0, 72, 10, 0, 0, 73, 0, 0, 0, 74, 75, 0, 77, 0, 0, 11, 12, 0, 78, 0, 0, 0, 79, 13, 0, 0, 80, 0, 0, 0, 0, 118, 14, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 15,
+        0, 0, 0, 10, 94, 0, 0, 95, 0, 0, 0, 0, 96, 97, 98, 99, 0, 0, 11, 100, 0, 101, 12, 0, 0, 102, 13, 0, 0, 0, 103, 0, 14, 0, 0, 150, 15, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 108,
         // State 17
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 19
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 20
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 21
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, -29, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 23
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -215, -215, -215, 0, 0, -215, -215, 0, -215, 137, 0, -215, 0, 0, 0, 0, -215, -215, 0, 0, 0, 0, 0, 0, -215, 0, 0, -215, -215, -215, 0, -215, -215, -215, -215, 0, 0, -215, -215, 0, 0, -215, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 24
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 36, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 25
-        0, 0, 0, 0, 0, 0, 72, 10, 0, 0, 73, 0, 0, 0, 74, 75, 0, 77, 0, 0, 11, 12, 0, 78, 0, 0, 0, 79, 13, 0, 0, 80, 0, 0, 0, 0, 135, 14, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 15,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, -61, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        0, 0, 0, -126, -126, 0, 0, 0, -126, -126, 0, -126, 0, -126, 0, 0, 0, 0, -126, -126, 0, 0, 0, 0, 0, 0, -126, 0, 0, -126, -126, 0, -126, -126, 84, -126, 0, 0, -126, -126, -126, 0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 27
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, -63, 0, 0, -63, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 28
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 29
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 94, 0, 0, 95, 0, 0, 0, 0, 96, 97, 98, 99, 0, 0, 11, 100, 0, 101, 12, 0, 0, 102, 13, 0, 0, 0, 103, 0, 14, 0, 0, 173, 15, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 108,
         // State 30
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 144, 145, 146, 147, 148, 0, 0,
+        -188, -188, 0, 0, 0, -188, -188, 0, -188, 0, 0, -188, 0, 0, 0, 0, -188, -188, 0, 0, 0, 0, 0, 0, -188, 0, 0, -188, -188, -188, 0, -188, -188, 109, -188, 0, 0, -188, -188, 0, 0, -188, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 31
-        0, 0, 0, 0, 0, 0, 72, 10, 0, 0, 73, 0, 0, 0, 74, 75, 0, 77, 0, 0, 11, 12, 0, 78, 0, 35, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 14, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 15,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 144, 145, 146, 147, 148, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 179, 180, 181, 182, 183, 0, 0,
         // State 33
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 34
-        0, 0, 0, 0, 0, 0, 72, 10, 0, 0, 73, 0, 0, 0, 74, 75, 0, 77, 0, 0, 11, 12, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 155, 14, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 15,
+        0, 0, 0, 10, 0, 36, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 35
-        0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 43, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, -134, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 36
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 144, 145, 146, 147, 148, 160, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 37
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 179, 180, 181, 182, 183, 0, 0,
         // State 38
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -107, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 40
-        0, 0, 0, 0, 0, 0, 72, 10, 0, 0, 73, 0, 0, 0, 74, 75, 0, 77, 0, 0, 11, 12, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 168, 14, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 15,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 41
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 50, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, -128, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, -133, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 43
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 44
-        0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0, 0, 0, 11, 0, 0, 78, 0, 0, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 209, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 45
-        0, 0, 0, 0, 0, 0, 72, 10, 0, 0, 73, 0, 0, 0, 74, 75, 0, 77, 0, 0, 11, 12, 0, 78, 0, 35, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 14, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 15,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 94, 0, 0, 95, 0, 0, 0, 0, 96, 97, 98, 99, 0, 0, 11, 100, 0, 101, 12, 17, 0, 102, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 15, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 108,
         // State 47
-        0, 0, 0, 0, 0, 0, 72, 10, 0, 0, 73, 0, 0, 0, 74, 75, 0, 77, 0, 0, 11, 12, 0, 78, 0, 35, 0, 0, 13, 0, 0, 80, 0, 0, 0, 0, 0, 14, 0, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 15,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, -87, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 48
-        0, 0, 0, -39, -39, 0, 0, 0, -39, -39, 0, -39, 0, -39, 0, 0, 0, 0, -39, -39, 0, 0, 0, 0, 0, 0, -39, 0, 0, -39, -39, 0, -39, -39, -39, -39, 0, 0, -39, -39, -39, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 178, 179, 180, 181, 182, 183, 0, 0,
         // State 49
-        0, 0, 0, 0, 0, 0, -112, -112, 0, 0, -112, 0, 0, 0, -112, -112, -112, -112, 0, 0, -112, -112, 0, -112, 0, 0, 0, -112, -112, 0, 0, -112, 0, 0, 0, 0, -112, -112, 0, 0, 0, -112, -112, -112, 0, 0, 0, 0, 0, 0, 0, -112,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, -127, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 50
-        0, 0, 0, -88, -88, 0, 0, 0, -88, -88, 0, -88, 0, -88, 0, 0, 0, 0, -88, -88, 0, 0, 0, 0, 0, 0, -88, 0, 0, -88, -88, 0, -88, -88, -88, -88, 0, 0, -88, -88, -88, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, -130, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 51
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 52
-        0, 0, 0, -38, -38, 0, 0, 0, -38, -38, 0, -38, 0, -38, 0, 0, 0, 0, -38, -38, 0, 0, 0, 0, 0, 0, -38, 0, 0, -38, -38, 0, -38, -38, -38, -38, 0, 0, -38, -38, -38, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 53
-        0, 0, 0, 0, 0, 0, -103, -103, 0, 0, -103, 0, 0, 0, -103, -103, -103, -103, 0, 0, -103, -103, 0, -103, 0, 0, 0, -103, -103, 0, 0, -103, 0, 0, 0, 0, -103, -103, 0, 0, 0, -103, -103, -103, 0, 0, 0, 0, 0, 0, 0, -103,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 179, 180, 181, 182, 183, 0, 0,
         // State 54
-        0, 0, 0, 0, 0, 0, -77, -77, 0, 0, -77, 0, 0, 0, -77, -77, -77, -77, 0, 0, -77, -77, 0, -77, 0, 0, 0, -77, -77, 0, 0, -77, 0, 0, 0, 0, 0, -77, 0, 0, 0, -77, -77, -77, 0, 0, 0, 0, 0, 0, 0, -77,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 55
-        0, 0, 0, -90, -90, 0, 0, 0, -90, -90, 0, -90, 0, -90, 0, 0, 0, 0, -90, -90, 0, 0, 0, 0, 0, 0, -90, 0, 0, -90, -90, 0, -90, -90, -90, -90, 0, 0, -90, -90, -90, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 56
-        0, 0, 0, 0, 0, 0, -82, -82, 0, 0, -82, 0, 0, 0, -82, -82, -82, -82, 0, 0, -82, -82, 0, -82, 0, 0, 0, -82, -82, 0, 0, -82, 0, 0, 0, 0, 0, -82, 0, 0, 0, -82, -82, -82, 0, 0, 0, 0, 0, 0, 0, -82,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 178, 179, 180, 181, 182, 183, 0, 0,
         // State 57
-        0, 0, 0, -36, -36, 0, 0, 0, -36, -36, 0, -36, 0, -36, 0, 0, 0, 0, -36, -36, 0, 0, 0, 0, 0, 0, -36, 0, 0, -36, -36, 0, -36, -36, -36, -36, 0, 0, -36, -36, -36, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 179, 180, 181, 182, 183, 234, 0,
         // State 58
-        0, 0, 0, -53, -53, 0, 0, 0, -53, -53, 0, -53, 0, -53, 0, 0, 0, 0, -53, -53, 0, 0, 0, 0, 0, 0, -53, 0, 0, -53, -53, 0, -53, -53, -53, -53, 0, 0, -53, -53, -53, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 59
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, -124, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 60
-        0, 0, 0, 0, -125, 0, 0, 0, -125, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -125, 0, -125, 0, 0, -125, -125, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 61
-        0, 0, 0, 0, 0, 0, -113, -113, 0, 0, -113, 0, 0, 0, -113, -113, -113, -113, 0, 0, -113, -113, 0, -113, 0, 0, 0, -113, -113, 0, 0, -113, 0, 0, 0, 0, -113, -113, 0, 0, 0, -113, -113, -113, 0, 0, 0, 0, 0, 0, 0, -113,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 62
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 63
-        0, 0, 0, 0, 0, 0, -114, -114, 0, 0, -114, 0, 0, 0, -114, -114, -114, -114, 0, 0, -114, -114, 0, -114, 0, 0, 0, -114, -114, 0, 0, -114, 0, 0, 0, 0, -114, -114, 0, 0, 0, -114, -114, -114, 0, 0, 0, 0, 0, 0, 0, -114,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 179, 180, 181, 182, 183, 234, 0,
         // State 64
-        0, 0, 0, 0, 0, 0, -78, -78, 0, 0, -78, 0, 0, 0, -78, -78, -78, -78, 0, 0, -78, -78, 0, -78, 0, 0, 0, -78, -78, 0, 0, -78, 0, 0, 0, 0, 0, -78, 0, 0, 0, -78, -78, -78, 0, 0, 0, 0, 0, 0, 0, -78,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 97, 0, 0, 0, 0, 11, 0, 0, 101, 12, 0, 0, 0, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 0, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 65
-        0, 0, 0, -37, -37, 0, 0, 0, -37, -37, 0, -37, 0, -37, 0, 0, 0, 0, -37, -37, 0, 0, 0, 0, 0, 0, -37, 0, 0, -37, -37, 0, -37, -37, -37, -37, 0, 0, -37, -37, -37, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 94, 0, 0, 95, 0, 0, 0, 0, 96, 97, 98, 99, 0, 0, 11, 100, 0, 101, 12, 17, 0, 102, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 15, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 108,
         // State 66
-        0, 0, 0, -129, -129, 0, 0, 0, -129, -129, 0, 0, 0, -129, 0, 0, 0, 0, -129, -129, 0, 0, 0, 0, 0, 0, -129, 0, 0, -129, 0, 0, -129, -129, 0, -129, 0, 0, -129, -129, -129, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 10, 94, 0, 0, 95, 0, 0, 0, 0, 96, 97, 98, 99, 0, 0, 11, 100, 0, 101, 12, 17, 0, 102, 13, 0, 0, 0, 103, 0, 14, 0, 0, 0, 15, 0, 0, 104, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 108,
         // State 67
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -81, -81, 0, 0, 0, -81, -81, 0, -81, 0, 0, -81, 0, 0, 0, 0, -81, -81, 0, 0, 0, 0, 0, 0, -81, 0, 0, -81, -81, -81, 0, -81, -81, -81, -81, 0, 0, -81, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 68
-        0, 0, 0, 0, 0, 0, -79, -79, 0, 0, -79, 0, 0, 0, -79, -79, -79, -79, 0, 0, -79, -79, 0, -79, 0, 0, 0, -79, -79, 0, 0, -79, 0, 0, 0, 0, 0, -79, 0, 0, 0, -79, -79, -79, 0, 0, 0, 0, 0, 0, 0, -79,
+        0, 0, 0, -168, -168, 0, 0, -168, 0, 0, 0, 0, -168, -168, -168, -168, 0, 0, -168, -168, 0, -168, -168, 0, 0, -168, -168, 0, 0, 0, -168, 0, -168, 0, 0, -168, -168, 0, 0, -168, -168, -168, -168, 0, 0, 0, 0, 0, 0, 0, -168,
         // State 69
-        0, 0, 0, -87, -87, 22, 0, 0, 0, 0, 0, -87, 0, -87, 0, 0, 0, 0, -87, -87, 0, 0, 0, 0, 0, 0, -87, 0, 0, -87, -87, 0, -87, -87, -87, 0, 0, 0, 0, 0, -87, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -137, -137, 0, 0, 0, -137, -137, 0, -137, 0, 0, -137, 0, 0, 0, 0, -137, -137, 0, 0, 0, 0, 0, 0, -137, 0, 0, -137, -137, -137, 0, -137, -137, -137, -137, 0, 0, -137, -137, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 70
-        0, 0, 0, 0, 0, 0, -104, -104, 0, 0, -104, 0, 0, 0, -104, -104, -104, -104, 0, 0, -104, -104, 0, -104, 0, 0, 0, -104, -104, 0, 0, -104, 0, 0, 0, 0, -104, -104, 0, 0, 0, -104, -104, -104, 0, 0, 0, 0, 0, 0, 0, -104,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 71
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -80, -80, 0, 0, 0, -80, -80, 0, -80, 0, 0, -80, 0, 0, 0, 0, -80, -80, 0, 0, 0, 0, 0, 0, -80, 0, 0, -80, -80, -80, 0, -80, -80, -80, -80, 0, 0, -80, -80, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 72
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -155, -155, 0, 0, -155, 0, 0, 0, 0, -155, -155, -155, -155, 0, 0, -155, -155, 0, -155, -155, 0, 0, -155, -155, 0, 0, 0, -155, 0, -155, 0, 0, -155, -155, 0, 0, -155, -155, -155, -155, 0, 0, 0, 0, 0, 0, 0, -155,
         // State 73
-        0, 0, 0, -23, -23, 0, 0, 0, -23, -23, 0, -23, 0, -23, 0, 0, 0, 0, -23, -23, 0, 0, 0, 0, 0, 0, -23, 0, 0, -23, -23, 0, -23, -23, -23, -23, 0, 0, -23, -23, -23, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -172, -172, 0, 0, -172, 0, 0, 0, 0, -172, -172, -172, -172, 0, 0, -172, -172, 0, -172, -172, 0, 0, -172, -172, 0, 0, 0, -172, 0, -172, 0, 0, -172, -172, 0, 0, -172, -172, -172, -172, 0, 0, 0, 0, 0, 0, 0, -172,
         // State 74
-        0, 0, 0, -56, -56, 0, 0, 0, -56, -56, 0, -56, 0, -56, 0, 0, 0, 0, -56, -56, 0, 0, 0, 0, 0, 0, -56, 0, 0, -56, -56, 0, -56, -56, -56, -56, 0, 0, -56, -56, -56, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -139, -139, 0, 0, 0, -139, -139, 0, -139, 0, 0, -139, 0, 0, 0, 0, -139, -139, 0, 0, 0, 0, 0, 0, -139, 0, 0, -139, -139, -139, 0, -139, -139, -139, -139, 0, 0, -139, -139, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 75
-        0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -119, -119, 0, 0, -119, 0, 0, 0, 0, -119, -119, -119, -119, 0, 0, -119, -119, 0, -119, -119, 0, 0, -119, -119, 0, 0, 0, -119, 0, -119, 0, 0, 0, -119, 0, 0, -119, -119, -119, -119, 0, 0, 0, 0, 0, 0, 0, -119,
         // State 76
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -78, -78, 0, 0, 0, -78, -78, 0, -78, 0, 0, -78, 0, 0, 0, 0, -78, -78, 0, 0, 0, 0, 0, 0, -78, 0, 0, -78, -78, -78, 0, -78, -78, -78, -78, 0, 0, -78, -78, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 77
-        0, 0, 0, -86, -86, 0, 0, 0, -86, -86, 0, -86, 0, -86, 0, 0, 0, 0, -86, -86, 0, 0, 0, 0, 0, 0, -86, 0, 0, -86, -86, 0, -86, -86, -86, -86, 0, 0, -86, -86, -86, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -97, -97, 0, 0, 0, -97, -97, 0, -97, 0, 0, -97, 0, 0, 0, 0, -97, -97, 0, 0, 0, 0, 0, 0, -97, 0, 0, -97, -97, -97, 0, -97, -97, -97, -97, 0, 0, -97, -97, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 78
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -89, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, -89, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 79
-        0, 0, 0, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0, 0, -137, -137, 0, 0, 0, 0, -137, 0, 0, -137, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 80
-        0, 0, 0, -120, -120, 0, 0, 0, -120, -120, 0, -120, 0, -120, 0, 0, 0, 0, -120, -120, 0, 0, 0, 0, 0, 0, -120, 0, 0, -120, -120, 0, -120, -120, -120, -120, 0, 0, -120, -120, -120, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -187, 0, 0, 0, -187, -187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -187, 0, -187, 0, 0, -187, -187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 81
-        0, 0, 0, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, -136, -136, 0, 0, 0, 0, -136, 0, 0, -136, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -169, -169, 0, 0, -169, 0, 0, 0, 0, -169, -169, -169, -169, 0, 0, -169, -169, 0, -169, -169, 0, 0, -169, -169, 0, 0, 0, -169, 0, -169, 0, 0, -169, -169, 0, 0, -169, -169, -169, -169, 0, 0, 0, 0, 0, 0, 0, -169,
         // State 82
-        0, 0, 0, -22, -22, 0, 0, 0, -22, -22, 0, -22, 0, -22, 0, 0, 0, 0, -22, -22, 0, 0, 0, 0, 0, 0, -22, 0, 0, -22, -22, 0, -22, -22, -22, -22, 0, 0, -22, -22, -22, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 83
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -170, -170, 0, 0, -170, 0, 0, 0, 0, -170, -170, -170, -170, 0, 0, -170, -170, 0, -170, -170, 0, 0, -170, -170, 0, 0, 0, -170, 0, -170, 0, 0, -170, -170, 0, 0, -170, -170, -170, -170, 0, 0, 0, 0, 0, 0, 0, -170,
         // State 84
-        0, 0, 0, 0, 0, 0, -64, -64, 0, 0, -64, 0, 0, 0, -64, -64, -64, -64, 0, 0, -64, -64, 0, -64, 0, 0, 0, -64, -64, 0, 0, -64, 0, 0, 0, 0, 0, -64, 0, 0, 0, -64, -64, -64, 0, 0, 0, 0, 0, 0, 0, -64,
+        0, 0, 0, -116, -116, 0, 0, -116, 0, 0, 0, 0, -116, -116, -116, -116, 0, 0, -116, -116, 0, -116, -116, 0, 0, -116, -116, 0, 0, 0, -116, 0, -116, 0, 0, 0, -116, 0, 0, -116, -116, -116, -116, 0, 0, 0, 0, 0, 0, 0, -116,
         // State 85
-        0, 0, 0, 0, 0, 0, -83, -83, 0, 0, -83, 0, 0, 0, -83, -83, -83, -83, 0, 0, -83, -83, 0, -83, 0, 0, 0, -83, -83, 0, 0, -83, 0, 0, 0, 0, 0, -83, 0, 0, 0, -83, -83, -83, 0, 0, 0, 0, 0, 0, 0, -83,
+        -79, -79, 0, 0, 0, -79, -79, 0, -79, 0, 0, -79, 0, 0, 0, 0, -79, -79, 0, 0, 0, 0, 0, 0, -79, 0, 0, -79, -79, -79, 0, -79, -79, -79, -79, 0, 0, -79, -79, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 86
-        0, 0, 0, 0, 0, 0, -115, -115, 0, 0, -115, 0, 0, 0, -115, -115, -115, -115, 0, 0, -115, -115, 0, -115, 0, 0, 0, -115, -115, 0, 0, -115, 0, 0, 0, 0, -115, -115, 0, 0, 0, -115, -115, -115, 0, 0, 0, 0, 0, 0, 0, -115,
+        0, 0, 0, -115, -115, 0, 0, -115, 0, 0, 0, 0, -115, -115, -115, -115, 0, 0, -115, -115, 0, -115, -115, 0, 0, -115, -115, 0, 0, 0, -115, 0, -115, 0, 0, 0, -115, 0, 0, -115, -115, -115, -115, 0, 0, 0, 0, 0, 0, 0, -115,
         // State 87
-        0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, -102, -102, 0, 0, 0, 0, -102, 0, 0, -102, 0, 0, 0, 0, -102, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, -102, -102, 0, 0, 0, 0, 0, 0, 0, 0,
+        -191, -191, 0, 0, 0, -191, -191, 0, 0, 0, 0, -191, 0, 0, 0, 0, -191, -191, 0, 0, 0, 0, 0, 0, -191, 0, 0, -191, 0, 0, 0, -191, -191, 0, -191, 0, 0, -191, -191, 0, 0, -191, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 88
-        0, 0, 0, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, -100, -100, 0, 0, 0, 0, -100, 0, 0, -100, 0, 0, 0, 0, -100, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, -100, -100, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 89
-        0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, -98, -98, 0, 0, 0, 0, -98, 0, 0, -98, 0, 0, 0, 0, -98, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, -98, -98, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -171, -171, 0, 0, -171, 0, 0, 0, 0, -171, -171, -171, -171, 0, 0, -171, -171, 0, -171, -171, 0, 0, -171, -171, 0, 0, 0, -171, 0, -171, 0, 0, -171, -171, 0, 0, -171, -171, -171, -171, 0, 0, 0, 0, 0, 0, 0, -171,
         // State 90
-        0, 0, 0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, -99, -99, 0, 0, 0, 0, -99, 0, 0, -99, 0, 0, 0, 0, -99, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, -99, -99, 0, 0, 0, 0, 0, 0, 0, 0,
+        -136, -136, 22, 0, 0, 0, 0, 0, -136, 0, 0, -136, 0, 0, 0, 0, -136, -136, 0, 0, 0, 0, 0, 0, -136, 0, 0, -136, -136, -136, 0, -136, -136, -136, 0, 0, 0, 0, -136, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 91
-        0, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, -97, -97, 0, 0, 0, 0, -97, 0, 0, -97, 0, 0, 0, 0, -97, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, -97, -97, 0, 0, 0, 0, 0, 0, 0, 0,
+        -140, -140, 0, 0, 0, -140, -140, 0, -140, 0, 0, -140, 0, 0, 0, 0, -140, -140, 0, 0, 0, 0, 0, 0, -140, 0, 0, -140, -140, -140, 0, -140, -140, -140, -140, 0, 0, -140, -140, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 92
-        0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, -101, -101, 0, 0, 0, 0, -101, 0, 0, -101, 0, 0, 0, 0, -101, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, -101, -101, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -156, -156, 0, 0, -156, 0, 0, 0, 0, -156, -156, -156, -156, 0, 0, -156, -156, 0, -156, -156, 0, 0, -156, -156, 0, 0, 0, -156, 0, -156, 0, 0, -156, -156, 0, 0, -156, -156, -156, -156, 0, 0, 0, 0, 0, 0, 0, -156,
         // State 93
-        0, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, -49, -49, 0, 0, 0, 0, -49, 0, 0, -49, 0, 0, 0, 0, -49, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, -49, -49, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 94
-        0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, -50, -50, 0, 0, 0, 0, -50, 0, 0, -50, 0, 0, 0, 0, -50, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, -50, -50, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 95
-        0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, -55, -55, 0, 0, 0, 0, -55, 0, 0, -55, 0, 0, 0, 0, -55, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, -55, -55, 0, 0, 0, 0, 0, 0, 0, 0,
+        -54, -54, 0, 0, 0, -54, -54, 0, -54, 0, 0, -54, 0, 0, 0, 0, -54, -54, 0, 0, 0, 0, 0, 0, -54, 0, 0, -54, -54, -54, 0, -54, -54, -54, -54, 0, 0, -54, -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 96
-        0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, -54, -54, 0, 0, 0, 0, -54, 0, 0, -54, 0, 0, 0, 0, -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, -54, -54, 0, 0, 0, 0, 0, 0, 0, 0,
+        -101, -101, 0, 0, 0, -101, -101, 0, -101, 0, 0, -101, 0, 0, 0, 0, -101, -101, 0, 0, 0, 0, 0, 0, -101, 0, 0, -101, -101, -101, 0, -101, -101, -101, -101, 0, 0, -101, -101, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 97
-        0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, -106, -106, 0, 0, 0, 0, -106, 0, 0, -106, 0, 0, 0, 0, -106, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, -106, -106, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 98
-        0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, -107, -107, 0, 0, 0, 0, -107, 0, 0, -107, 0, 0, 0, 0, -107, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, -107, -107, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 99
-        0, 0, 0, -52, -52, 0, 0, 0, -52, -52, 0, -52, 0, -52, 0, 0, 0, 0, -52, -52, 0, 0, 0, 0, 0, 0, -52, 0, 0, -52, -52, 0, -52, -52, -52, -52, 0, 0, -52, -52, -52, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 100
-        0, 0, 0, -87, -87, 0, 0, 0, -87, -87, 0, -87, 0, -87, 0, 0, 0, 0, -87, -87, 0, 0, 0, 0, 0, 0, -87, 0, 0, -87, -87, 0, -87, -87, -87, -87, 0, 0, -87, -87, -87, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -135, -135, 0, 0, 0, -135, -135, 0, -135, 0, 0, -135, 0, 0, 0, 0, -135, -135, 0, 0, 0, 0, 0, 0, -135, 0, 0, -135, -135, -135, 0, -135, -135, -135, -135, 0, 0, -135, -135, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 101
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 102
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -203, -203, 0, 0, 0, 0, -203, 0, 0, -203, -203, 0, 0, 0, -203, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -203, 0, 0, -203, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 103
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -178, -178, 0, 0, 0, -178, -178, 0, -178, 0, 0, -178, 0, 0, 0, 0, -178, -178, 0, 0, 0, 0, 0, 0, -178, 0, 0, -178, -178, -178, 0, -178, -178, -178, -178, 0, 0, -178, -178, 0, 0, -178, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 104
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 105
-        0, 0, 0, -149, -149, -149, 0, 0, -149, -149, 0, -149, 0, -149, 0, 0, 0, 0, -149, -149, 0, 0, 0, 0, 0, 0, -149, 0, 0, -149, -149, 0, -149, -149, -149, -149, 0, 0, -149, -149, -149, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -202, -202, 0, 0, 0, 0, -202, 0, 0, -202, -202, 0, 0, 0, -202, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -202, 0, 0, -202, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 106
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -53, -53, 0, 0, 0, -53, -53, 0, -53, 0, 0, -53, 0, 0, 0, 0, -53, -53, 0, 0, 0, 0, 0, 0, -53, 0, 0, -53, -53, -53, 0, -53, -53, -53, -53, 0, 0, -53, -53, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 107
-        0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 108
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 109
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -110, -110, 0, 0, -110, 0, 0, 0, 0, -110, -110, -110, -110, 0, 0, -110, -110, 0, -110, -110, 0, 0, -110, -110, 0, 0, 0, -110, 0, -110, 0, 0, -110, -110, 0, 0, -110, -110, -110, -110, 0, 0, 0, 0, 0, 0, 0, -110,
         // State 110
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -120, -120, 0, 0, -120, 0, 0, 0, 0, -120, -120, -120, -120, 0, 0, -120, -120, 0, -120, -120, 0, 0, -120, -120, 0, 0, 0, -120, 0, -120, 0, 0, 0, -120, 0, 0, -120, -120, -120, -120, 0, 0, 0, 0, 0, 0, 0, -120,
         // State 111
-        0, 0, 0, -51, -51, 0, 0, 0, -51, -51, 0, -51, 0, -51, 0, 0, 0, 0, -51, -51, 0, 0, 0, 0, 0, 0, -51, 0, 0, -51, -51, 0, -51, -51, -51, -51, 0, 0, -51, -51, -51, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -173, -173, 0, 0, -173, 0, 0, 0, 0, -173, -173, -173, -173, 0, 0, -173, -173, 0, -173, -173, 0, 0, -173, -173, 0, 0, 0, -173, 0, -173, 0, 0, -173, -173, 0, 0, -173, -173, -173, -173, 0, 0, 0, 0, 0, 0, 0, -173,
         // State 112
-        0, 0, 0, -24, -24, 0, 0, 0, -24, -24, 0, -24, 0, -24, 0, 0, 0, 0, -24, -24, 0, 0, 0, 0, 0, 0, -24, 0, 0, -24, -24, 0, -24, -24, -24, -24, 0, 0, -24, -24, -24, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -154, -154, 0, 0, 0, 0, -154, 0, 0, -154, -154, 0, 0, 0, -154, 0, 0, 0, -154, 0, 0, 0, 0, 0, 0, 0, 0, -154, 0, -154, -154, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 113
-        0, 0, 0, -84, -84, 0, 0, 0, -84, -84, 0, -84, 0, -84, 0, 0, 0, 0, -84, -84, 0, 0, 0, 0, 0, 0, -84, 0, 0, -84, -84, 0, -84, -84, -84, -84, 0, 0, -84, -84, -84, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -152, -152, 0, 0, 0, 0, -152, 0, 0, -152, -152, 0, 0, 0, -152, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, -152, 0, -152, -152, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 114
-        0, 0, 0, 0, 0, 0, -72, -72, 0, 0, -72, 0, 0, 0, -72, -72, 0, -72, 0, 0, -72, -72, 0, -72, 0, 0, 0, -72, -72, 0, 0, -72, 0, 0, 0, 0, -72, -72, 0, 0, 0, -72, -72, -72, 0, 0, 0, 0, 0, 0, 0, -72,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -150, -150, 0, 0, 0, 0, -150, 0, 0, -150, -150, 0, 0, 0, -150, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, -150, 0, -150, -150, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 115
-        0, 0, 0, 0, 0, 0, -68, -68, 0, 0, -68, 0, 0, 0, -68, -68, 0, -68, 0, 0, -68, -68, 0, -68, 0, 0, 0, -68, -68, 0, 0, -68, 0, 0, 0, 0, -68, -68, 0, 0, 0, -68, -68, -68, 0, 0, 0, 0, 0, 0, 0, -68,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -151, -151, 0, 0, 0, 0, -151, 0, 0, -151, -151, 0, 0, 0, -151, 0, 0, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, -151, 0, -151, -151, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 116
-        0, 0, 0, 0, 0, 0, -69, -69, 0, 0, -69, 0, 0, 0, -69, -69, 0, -69, 0, 0, -69, -69, 0, -69, 0, 0, 0, -69, -69, 0, 0, -69, 0, 0, 0, 0, -69, -69, 0, 0, 0, -69, -69, -69, 0, 0, 0, 0, 0, 0, 0, -69,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149, -149, 0, 0, 0, 0, -149, 0, 0, -149, -149, 0, 0, 0, -149, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, -149, -149, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 117
-        0, 0, 0, 0, 0, 0, -65, -65, 0, 0, -65, 0, 0, 0, -65, -65, -65, -65, 0, 0, -65, -65, 0, -65, 0, 0, 0, -65, -65, 0, 0, -65, 0, 0, 0, 0, 0, -65, 0, 0, 0, -65, -65, -65, 0, 0, 0, 0, 0, 0, 0, -65,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -153, -153, 0, 0, 0, 0, -153, 0, 0, -153, -153, 0, 0, 0, -153, 0, 0, 0, -153, 0, 0, 0, 0, 0, 0, 0, 0, -153, 0, -153, -153, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 118
-        0, 0, 0, 0, -95, 0, 0, 0, -95, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, -95, 0, 0, -95, -95, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, -92, 0, 0, 0, 0, -92, 0, 0, -92, -92, 0, 0, 0, -92, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, -92, -92, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 119
-        0, 0, 0, 0, -124, 0, 0, 0, -124, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, 0, -124, 0, 0, -124, -124, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, -93, 0, 0, 0, 0, -93, 0, 0, -93, -93, 0, 0, 0, -93, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, -93, -93, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 120
-        0, 0, 0, -128, -128, 0, 0, 0, -128, -128, 0, 0, 0, -128, 0, 0, 0, 0, -128, -128, 0, 0, 0, 0, 0, 0, -128, 0, 0, -128, 0, 0, -128, -128, 0, -128, 0, 0, -128, -128, -128, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, -99, 0, 0, 0, 0, -99, 0, 0, -99, -99, 0, 0, 0, -99, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, -99, 0, -99, -99, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 121
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, -100, 0, 0, 0, 0, -100, 0, 0, -100, -100, 0, 0, 0, -100, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, -100, 0, -100, -100, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 122
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, -98, 0, 0, 0, 0, -98, 0, 0, -98, -98, 0, 0, 0, -98, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, -98, -98, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 123
-        0, 0, 0, -67, -67, 0, 0, 0, -67, -67, 0, -67, 0, -67, 0, 0, 0, 0, -67, -67, 0, 0, 0, 0, 0, 0, -67, 0, 0, -67, -67, 0, -67, -67, -67, -67, 0, 0, -67, -67, -67, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -158, -158, 0, 0, 0, 0, -158, 0, 0, -158, -158, 0, 0, 0, -158, 0, 0, 0, -158, 0, 0, 0, 0, 0, 0, 0, 0, -158, 0, -158, -158, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 124
-        0, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, -4, -4, 0, 0, 0, 0, -4, 0, 0, -4, 0, 0, 0, 0, -4, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, -4, 0, -4, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -159, -159, 0, 0, 0, 0, -159, 0, 0, -159, -159, 0, 0, 0, -159, 0, 0, 0, -159, 0, 0, 0, 0, 0, 0, 0, 0, -159, 0, -159, -159, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 125
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -96, -96, 0, 0, 0, -96, -96, 0, -96, 0, 0, -96, 0, 0, 0, 0, -96, -96, 0, 0, 0, 0, 0, 0, -96, 0, 0, -96, -96, -96, 0, -96, -96, -96, -96, 0, 0, -96, -96, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 126
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -136, -136, 0, 0, 0, -136, -136, 0, -136, 0, 0, -136, 0, 0, 0, 0, -136, -136, 0, 0, 0, 0, 0, 0, -136, 0, 0, -136, -136, -136, 0, -136, -136, -136, -136, 0, 0, -136, -136, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 127
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 128
-        0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -64, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 129
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 130
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 141, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 131
-        0, 0, 0, -89, -89, 0, 0, 0, -89, -89, 0, -89, 0, -89, 0, 0, 0, 0, -89, -89, 0, 0, 0, 0, 0, 0, -89, 0, 0, -89, -89, 0, -89, -89, -89, -89, 0, 0, -89, -89, -89, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 132
-        0, 0, 0, 0, 0, 0, -111, -111, 0, 0, -111, 0, 0, 0, -111, -111, -111, -111, 0, 0, -111, -111, 0, -111, 0, 0, 0, -111, -111, 0, 0, -111, 0, 0, 0, 0, -111, -111, 0, 0, 0, -111, -111, -111, 0, 0, 0, 0, 0, 0, 0, -111,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 133
-        0, 0, 0, 0, 0, 0, -73, -73, 0, 0, -73, 0, 0, 0, -73, -73, 0, -73, 0, 0, -73, -73, 0, -73, 0, 0, 0, -73, -73, 0, 0, -73, 0, 0, 0, 0, -73, -73, 0, 0, 0, -73, -73, -73, 0, 0, 0, 0, 0, 0, 0, -73,
+        -218, -218, -218, 0, 0, -218, -218, 0, -218, 158, 0, -218, 0, 0, 0, 0, -218, -218, 0, 0, 0, 0, 0, 0, -218, 0, 0, -218, -218, -218, 0, -218, -218, -218, -218, 0, 0, -218, -218, 0, 0, -218, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 134
-        0, 0, 0, 0, 0, 0, -66, -66, 0, 0, -66, 0, 0, 0, -66, -66, -66, -66, 0, 0, -66, -66, 0, -66, 0, 0, 0, -66, -66, 0, 0, -66, 0, 0, 0, 0, 0, -66, 0, 0, 0, -66, -66, -66, 0, 0, 0, 0, 0, 0, 0, -66,
+        -58, -58, -58, 0, 0, -58, -58, 0, -58, -58, 0, -58, 0, 0, 0, 0, -58, -58, 0, 0, 0, 0, 35, 0, -58, 0, 0, -58, -58, -58, 0, -58, -58, -58, -58, 0, 0, -58, -58, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 135
-        0, 0, 0, 0, 0, 0, 0, -5, 0, 0, 0, 0, 0, 0, -5, -5, 0, 0, 0, 0, -5, 0, 0, -5, 0, 0, 0, 0, -5, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, -5, 0, -5, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0,
+        -212, -212, -212, 0, 0, -212, -212, 0, -212, -212, 0, -212, 0, 0, 0, 0, -212, -212, 0, 0, 0, 0, 0, 0, -212, 0, 0, -212, -212, -212, 0, -212, -212, -212, -212, 0, 0, -212, -212, 0, 0, -212, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 136
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 137
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 138
-        0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 139
-        0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 37, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 140
-        0, 0, 0, -145, -145, -145, 0, 0, -145, -145, 0, -145, 0, -145, 0, 0, 0, 0, -145, -145, 0, 0, 0, 0, 0, 0, -145, 0, 0, -145, -145, 0, -145, -145, -145, -145, 0, 0, -145, -145, -145, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 141
-        0, 0, 0, 0, 0, 0, -35, -35, 0, 0, -35, 0, 40, 0, -35, -35, -35, -35, 0, 0, -35, -35, 0, -35, 0, 0, 0, -35, -35, 0, 0, -35, 0, 0, 0, 0, -35, -35, 0, 0, 0, -35, -35, -35, 0, 0, 0, 0, 0, 0, 0, -35,
+        0, 0, 0, 0, 0, -142, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, -142, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 142
-        0, 0, 0, 0, 0, -132, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 143
-        0, 0, 0, 0, 0, -134, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 144
-        0, 0, 0, 0, 0, -131, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -94, -94, 0, 0, 0, -94, -94, 0, -94, 0, 0, -94, 0, 0, 0, 0, -94, -94, 0, 0, 0, 0, 0, 0, -94, 0, 0, -94, -94, -94, 0, -94, -94, -94, -94, 0, 0, -94, -94, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 145
-        0, 0, 0, 0, 0, -130, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -95, -95, 0, 0, 0, -95, -95, 0, -95, 0, 0, -95, 0, 0, 0, 0, -95, -95, 0, 0, 0, 0, 0, 0, -95, 0, 0, -95, -95, -95, 0, -95, -95, -95, -95, 0, 0, -95, -95, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 146
-        0, 0, 0, 0, 0, -135, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -55, -55, 0, 0, 0, -55, -55, 0, -55, 0, 0, -55, 0, 0, 0, 0, -55, -55, 0, 0, 0, 0, 0, 0, -55, 0, 0, -55, -55, -55, 0, -55, -55, -55, -55, 0, 0, -55, -55, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 147
-        0, 0, 0, 0, 0, -133, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -121, -121, 0, 0, 0, -121, -121, 0, -121, 0, 0, -121, 0, 0, 0, 0, -121, -121, 0, 0, 0, 0, 0, 0, -121, 0, 26, -121, -121, -121, 0, -121, -121, -121, -121, 0, 0, -121, -121, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 148
-        0, 0, 0, 0, 0, 0, -151, -151, 0, 0, -151, 0, 0, 0, -151, -151, -151, -151, 0, 0, -151, -151, 0, -151, 0, 0, 0, -151, -151, 0, 0, -151, 0, 0, 0, 0, -151, -151, 0, 0, 0, -151, -151, -151, 0, 0, 0, 0, 0, 0, 0, -151,
+        0, 0, 0, -176, -176, 0, 0, -176, 0, 0, 0, 0, -176, -176, -176, -176, 0, 0, -176, -176, 0, -176, -176, 0, 0, -176, -176, 0, 0, 0, -176, 0, -176, 0, 0, -176, -176, 0, 0, -176, 0, -176, -176, 0, 0, 0, 0, 0, 0, 0, -176,
         // State 149
-        0, 0, 0, 0, 0, 0, -20, -20, 0, 0, -20, 0, 0, 0, -20, -20, -20, -20, 0, 0, -20, -20, 0, -20, 0, 0, 0, -20, -20, 0, 0, -20, 0, 0, 0, 0, -20, -20, 0, 0, 0, -20, -20, -20, 0, 0, 0, 0, 0, 0, 0, -20,
+        0, 0, 0, -179, -179, 0, 0, -179, 0, 0, -179, 0, -179, -179, -179, -179, 0, 0, -179, -179, 0, -179, -179, 0, 0, -179, -179, 0, 0, 0, -179, 0, -179, 0, 0, -179, -179, 0, 0, -179, -179, -179, -179, 0, 0, 0, 0, 0, 0, 0, -179,
         // State 150
-        0, 0, 0, 0, 0, 0, -21, -21, 0, 0, -21, 0, 0, 0, -21, -21, -21, -21, 0, 0, -21, -21, 0, -21, 0, 0, 0, -21, -21, 0, 0, -21, 0, 0, 0, 0, -21, -21, 0, 0, 0, -21, -21, -21, 0, 0, 0, 0, 0, 0, 0, -21,
+        0, -147, 0, 0, 0, -147, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -147, 0, -147, 0, 0, -147, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 151
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -186, 0, 0, 0, -186, -186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -186, 0, -186, 0, 0, -186, -186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 152
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -190, -190, 0, 0, 0, -190, -190, 0, 0, 0, 0, -190, 0, 0, 0, 0, -190, -190, 0, 0, 0, 0, 0, 0, -190, 0, 0, -190, 0, 0, 0, -190, -190, 0, -190, 0, 0, -190, -190, 0, 0, -190, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 153
-        0, 0, 0, 0, 0, 0, -118, -118, 0, 0, -118, 0, 0, 0, -118, -118, 0, -118, 0, 0, -118, -118, 0, -118, 0, 0, 0, 0, -118, 0, 0, -118, 0, 0, 0, 0, -118, -118, 0, 0, 0, -118, -118, -118, 0, 0, 0, 0, 0, 0, 0, -118,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -206, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 154
-        0, 0, 0, 0, 0, 0, -121, -121, 0, 0, -121, 0, -121, 0, -121, -121, -121, -121, 0, 0, -121, -121, 0, -121, 0, 0, 0, -121, -121, 0, 0, -121, 0, 0, 0, 0, -121, -121, 0, 0, 0, -121, -121, -121, 0, 0, 0, 0, 0, 0, 0, -121,
+        0, 0, 0, -66, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 155
-        0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 156
-        0, 0, 0, 0, 0, 0, -144, -144, 0, 0, -144, 0, 0, 0, -144, -144, -144, -144, 0, 0, -144, -144, 0, -144, 0, 0, 0, -144, -144, 0, 0, -144, 0, 0, 0, 0, -144, -144, 0, 0, 0, -144, -144, -144, 0, 0, 0, 0, 0, 0, 0, -144,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 157
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 158
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -217, -217, -217, 0, 0, -217, -217, 0, -217, 158, 0, -217, 0, 0, 0, 0, -217, -217, 0, 0, 0, 0, 0, 0, -217, 0, 0, -217, -217, -217, 0, -217, -217, -217, -217, 0, 0, -217, -217, 0, 0, -217, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 159
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -35, -35, -35, 0, 0, -35, -35, 0, -35, -35, 0, -35, 0, 0, 0, 0, -35, -35, 0, 0, 0, 0, 0, 0, -35, 0, 0, -35, -35, -35, 0, -35, -35, -35, -35, 0, 0, -35, -35, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 160
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 161
-        0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 162
-        0, 0, 0, -146, -146, -146, 0, 0, -146, -146, 0, -146, 0, -146, 0, 0, 0, 0, -146, -146, 0, 0, 0, 0, 0, 0, -146, 0, 0, -146, -146, 0, -146, -146, -146, -146, 0, 0, -146, -146, -146, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 163
-        0, 0, 0, 0, 0, 0, -44, -44, 0, 0, -44, 0, 0, 0, -44, -44, -44, -44, 0, 0, -44, -44, 0, -44, 0, 0, 0, -44, -44, 0, 0, -44, 0, 0, 0, 0, -44, -44, 0, 0, 0, -44, -44, -44, 0, 0, 0, 0, 0, 0, 0, -44,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 164
-        0, 0, 0, 0, 0, 0, -34, -34, 0, 0, -34, 0, 0, 0, -34, -34, -34, -34, 0, 0, -34, -34, 0, -34, 0, 0, 0, -34, -34, 0, 0, -34, 0, 0, 0, 0, -34, -34, 0, 0, 0, -34, -34, -34, 0, 0, 0, 0, 0, 0, 0, -34,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 165
-        0, 0, 0, 0, 0, 0, -45, -45, 0, 0, -45, 0, 0, 0, -45, -45, -45, -45, 0, 0, -45, -45, 0, -45, 0, 0, 0, -45, -45, 0, 0, -45, 0, 0, 0, 0, -45, -45, 0, 0, 0, -45, -45, -45, 0, 0, 0, 0, 0, 0, 0, -45,
+        0, 0, 0, 0, 0, 0, 190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 166
-        0, 0, 0, 0, 0, 0, -119, -119, 0, 0, -119, 0, 0, 0, -119, -119, 0, -119, 0, 0, -119, -119, 0, -119, 0, 0, 0, 0, -119, 0, 0, -119, 0, 0, 0, 0, -119, -119, 0, 0, 0, -119, -119, -119, 0, 0, 0, 0, 0, 0, 0, -119,
+        -219, -219, 0, 0, 0, -219, -219, 0, -219, 0, 0, -219, 0, 0, 0, 0, -219, -219, 0, 0, 0, 0, 0, 0, -219, 0, 0, -219, -219, -219, 0, -219, -219, -219, -219, 0, 0, -219, -219, 0, 0, -219, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 167
-        0, 0, 0, 0, 0, 0, -122, -122, 0, 0, -122, 0, -122, 0, -122, -122, -122, -122, 0, 0, -122, -122, 0, -122, 0, 0, 0, -122, -122, 0, 0, -122, 0, 0, 0, 0, -122, -122, 0, 0, 0, -122, -122, -122, 0, 0, 0, 0, 0, 0, 0, -122,
+        0, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, -4, -4, 0, 0, 0, 0, -4, 0, 0, -4, -4, 0, 0, 0, -4, 0, 0, 0, -4, 0, -4, 0, -4, 0, 0, -4, 0, -4, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 168
-        0, 0, 0, 0, 0, 0, -143, -143, 0, 0, -143, 0, 0, 0, -143, -143, -143, -143, 0, 0, -143, -143, 0, -143, 0, 0, 0, -143, -143, 0, 0, -143, 0, 0, 0, 0, -143, -143, 0, 0, 0, -143, -143, -143, 0, 0, 0, 0, 0, 0, 0, -143,
+        -138, -138, 0, 0, 0, -138, -138, 0, -138, 0, 0, -138, 0, 0, 0, 0, -138, -138, 0, 0, 0, 0, 0, 0, -138, 0, 0, -138, -138, -138, 0, -138, -138, -138, -138, 0, 0, -138, -138, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 169
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -167, -167, 0, 0, -167, 0, 0, 0, 0, -167, -167, -167, -167, 0, 0, -167, -167, 0, -167, -167, 0, 0, -167, -167, 0, 0, 0, -167, 0, -167, 0, 0, -167, -167, 0, 0, -167, -167, -167, -167, 0, 0, 0, 0, 0, 0, 0, -167,
         // State 170
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 171
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -177, -177, 0, 0, -177, 0, 0, 0, 0, -177, -177, -177, -177, 0, 0, -177, -177, 0, -177, -177, 0, 0, -177, -177, 0, 0, 0, -177, 0, -177, 0, 0, -177, -177, 0, 0, -177, 0, -177, -177, 0, 0, 0, 0, 0, 0, 0, -177,
         // State 172
-        0, 0, 0, 0, 0, 0, -142, -142, 0, 0, -142, 0, 0, 0, -142, -142, -142, -142, 0, 0, -142, -142, 0, -142, 0, 0, 0, -142, -142, 0, 0, -142, 0, 0, 0, 0, -142, -142, 0, 0, 0, -142, -142, -142, 0, 0, 0, 0, 0, 0, 0, -142,
+        0, 0, 0, -180, -180, 0, 0, -180, 0, 0, -180, 0, -180, -180, -180, -180, 0, 0, -180, -180, 0, -180, -180, 0, 0, -180, -180, 0, 0, 0, -180, 0, -180, 0, 0, -180, -180, 0, 0, -180, -180, -180, -180, 0, 0, 0, 0, 0, 0, 0, -180,
         // State 173
-        0, 0, 0, 0, 0, -41, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 174
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -141, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0, -141, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 175
-        0, 0, 0, 0, 0, 0, -59, -59, 0, 0, -59, 0, 0, 0, -59, -59, -59, -59, 0, 0, -59, -59, 0, -59, 0, 0, 0, -59, -59, 0, 0, -59, 0, 0, 0, 0, -59, -59, 0, 0, 0, -59, -59, -59, 0, 0, 0, 0, 0, 0, 0, -59,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 176
-        0, 0, 0, 0, 0, 0, -141, -141, 0, 0, -141, 0, 0, 0, -141, -141, -141, -141, 0, 0, -141, -141, 0, -141, 0, 0, 0, -141, -141, 0, 0, -141, 0, 0, 0, 0, -141, -141, 0, 0, 0, -141, -141, -141, 0, 0, 0, 0, 0, 0, 0, -141,
+        0, 0, -198, -198, 0, 0, -198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -198, -198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -198, 0, -198, -198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 177
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -194, -194, 0, 0, -194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -194, -194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -194, 0, -194, -194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 178
-        0, 0, 0, 0, 0, -40, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -196, -196, 0, 0, -196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -196, -196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -196, 0, -196, -196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 179
-        0, 0, 0, 0, 0, 0, -58, -58, 0, 0, -58, 0, 0, 0, -58, -58, -58, -58, 0, 0, -58, -58, 0, -58, 0, 0, 0, -58, -58, 0, 0, -58, 0, 0, 0, 0, -58, -58, 0, 0, 0, -58, -58, -58, 0, 0, 0, 0, 0, 0, 0, -58,
+        0, 0, -193, -193, 0, 0, -193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -193, -193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -193, 0, -193, -193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 180
+        0, 0, -192, -192, 0, 0, -192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -192, -192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -192, 0, -192, -192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        0, 0, -197, -197, 0, 0, -197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -197, -197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -197, 0, -197, -197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 182
+        0, 0, -195, -195, 0, 0, -195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -195, -195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -195, 0, -195, -195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 183
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 201, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 184
+        -36, -36, -36, 0, 0, -36, -36, 0, -36, -36, 0, -36, 0, 0, 0, 0, -36, -36, 0, 0, 0, 0, 0, 0, -36, 0, 0, -36, -36, -36, 0, -36, -36, -36, -36, 0, 0, -36, -36, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 185
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 203, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 186
+        -47, -47, -47, 0, 0, -47, -47, 0, -47, -47, 0, -47, 0, 0, 0, 0, -47, -47, 0, 0, 0, 0, -47, 0, -47, 0, 0, -47, -47, -47, 0, -47, -47, -47, -47, 0, 0, -47, -47, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 187
+        0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 188
+        -111, -111, 0, 0, 0, -111, -111, 0, -111, 0, 0, -111, 0, 0, 0, 0, -111, -111, 0, 0, 0, 0, 0, 0, -111, 0, 0, -111, -111, -111, 0, -111, -111, -111, -111, 0, 0, -111, -111, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 189
+        0, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, -5, -5, 0, 0, 0, 0, -5, 0, 0, -5, -5, 0, 0, 0, -5, 0, 0, 0, -5, 0, -5, 0, -5, 0, 0, -5, 0, -5, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 190
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 207, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 191
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 211, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 192
+        0, 0, 0, 0, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 193
+        0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 194
+        0, 0, 0, -56, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 195
+        0, 0, -57, -57, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 196
+        0, 0, 0, -161, 0, 0, -161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 197
+        0, 0, -82, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 198
+        0, 0, 0, -160, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 199
+        0, 0, 0, -106, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 200
+        0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 201
+        0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 202
+        -48, -48, -48, 0, 0, -48, -48, 0, -48, -48, 0, -48, 0, 0, 0, 0, -48, -48, 0, 0, 0, 0, -48, 0, -48, 0, 0, -48, -48, -48, 0, -48, -48, -48, -48, 0, 0, -48, -48, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 203
+        0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 204
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 205
+        0, 0, 0, -77, -77, 0, 0, -77, 0, 0, 61, 0, -77, -77, -77, -77, 0, 0, -77, -77, 0, -77, -77, 0, 0, -77, -77, 0, 0, 0, -77, 0, -77, 0, 0, -77, -77, 0, 0, -77, -77, -77, -77, 0, 0, 0, 0, 0, 0, 0, -77,
+        // State 206
+        0, 0, 0, -211, -211, 0, 0, -211, 0, 0, 0, 0, -211, -211, -211, -211, 0, 0, -211, -211, 0, -211, -211, 0, 0, -211, -211, 0, 0, 0, -211, 0, -211, 0, 0, -211, -211, 0, 0, -211, -211, -211, -211, 0, 0, 0, 0, 0, 0, 0, -211,
+        // State 207
+        0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 222, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 208
+        0, 0, 0, -210, -210, 0, 0, -210, 0, 0, 0, 0, -210, -210, -210, -210, 0, 0, -210, -210, 0, -210, -210, 0, 0, -210, -210, 0, 0, 0, -210, 0, -210, 0, 0, -210, -210, 0, 0, -210, -210, -210, -210, 0, 0, 0, 0, 0, 0, 0, -210,
+        // State 209
+        0, 0, 0, 0, 0, 0, 224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 210
+        0, 0, 0, -181, -181, 0, 0, -181, 0, 0, 0, 0, -181, -181, -181, -181, 0, 0, -181, -181, 0, -181, -181, 0, 0, -181, -181, 0, 0, 0, -181, 0, -181, 0, 0, 0, -181, 0, 0, -181, -181, -181, -181, 0, 0, 0, 0, 0, 0, 0, -181,
+        // State 211
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 212
+        0, 0, 0, -220, -220, 0, 0, -220, 0, 0, 0, 0, -220, -220, -220, -220, 0, 0, -220, -220, 0, -220, -220, 0, 0, -220, -220, 0, 0, 0, -220, 0, -220, 0, 0, -220, -220, 0, 0, -220, -220, -220, -220, 0, 0, 0, 0, 0, 0, 0, -220,
+        // State 213
+        0, 0, 0, -51, -51, 0, 0, -51, 0, 0, 0, 0, -51, -51, -51, -51, 0, 0, -51, -51, 0, -51, -51, 0, 0, -51, -51, 0, 0, 0, -51, 0, -51, 0, 0, -51, -51, 0, 0, -51, -51, -51, -51, 0, 0, 0, 0, 0, 0, 0, -51,
+        // State 214
+        0, 0, 0, -52, -52, 0, 0, -52, 0, 0, 0, 0, -52, -52, -52, -52, 0, 0, -52, -52, 0, -52, -52, 0, 0, -52, -52, 0, 0, 0, -52, 0, -52, 0, 0, -52, -52, 0, 0, -52, -52, -52, -52, 0, 0, 0, 0, 0, 0, 0, -52,
+        // State 215
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 216
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 229, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 217
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 231, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 218
+        0, 0, 0, 0, 0, 0, 232, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 219
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 220
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 221
+        0, 0, 0, -209, -209, 0, 0, -209, 0, 0, 0, 0, -209, -209, -209, -209, 0, 0, -209, -209, 0, -209, -209, 0, 0, -209, -209, 0, 0, 0, -209, 0, -209, 0, 0, -209, -209, 0, 0, -209, -209, -209, -209, 0, 0, 0, 0, 0, 0, 0, -209,
+        // State 222
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 241, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 223
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 224
+        0, 0, 0, 0, 0, 0, -182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 225
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 242, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 226
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 227
+        0, 0, 0, -41, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 228
+        0, 0, -44, -44, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 229
+        0, 0, 0, 0, 0, 0, 244, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 230
+        0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 231
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, -19, -19, -19, -19, -19, -19, 0, 0,
+        // State 232
+        0, 0, -113, -113, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, -113, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 233
+        0, 0, -114, -114, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, -114, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 234
+        0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 235
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 236
+        0, 0, 0, -85, -85, 0, 0, -85, 0, 0, 0, 0, -85, -85, -85, -85, 0, 0, -85, -85, 0, -85, -85, 0, 0, -85, -85, 0, 0, 0, -85, 0, -85, 0, 0, -85, -85, 0, 0, -85, -85, -85, -85, 0, 0, 0, 0, 0, 0, 0, -85,
+        // State 237
+        0, 0, 0, -76, -76, 0, 0, -76, 0, 0, 0, 0, -76, -76, -76, -76, 0, 0, -76, -76, 0, -76, -76, 0, 0, -76, -76, 0, 0, 0, -76, 0, -76, 0, 0, -76, -76, 0, 0, -76, -76, -76, -76, 0, 0, 0, 0, 0, 0, 0, -76,
+        // State 238
+        0, 0, 0, -86, -86, 0, 0, -86, 0, 0, 0, 0, -86, -86, -86, -86, 0, 0, -86, -86, 0, -86, -86, 0, 0, -86, -86, 0, 0, 0, -86, 0, -86, 0, 0, -86, -86, 0, 0, -86, -86, -86, -86, 0, 0, 0, 0, 0, 0, 0, -86,
+        // State 239
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 246, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 240
+        0, 0, 0, -208, -208, 0, 0, -208, 0, 0, 0, 0, -208, -208, -208, -208, 0, 0, -208, -208, 0, -208, -208, 0, 0, -208, -208, 0, 0, 0, -208, 0, -208, 0, 0, -208, -208, 0, 0, -208, -208, -208, -208, 0, 0, 0, 0, 0, 0, 0, -208,
+        // State 241
+        0, 0, 0, -42, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 242
+        0, 0, -45, -45, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 243
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, -20, -20, -20, -20, -20, -20, 0, 0,
+        // State 244
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 245
+        0, 0, 0, -207, -207, 0, 0, -207, 0, 0, 0, 0, -207, -207, -207, -207, 0, 0, -207, -207, 0, -207, -207, 0, 0, -207, -207, 0, 0, 0, -207, 0, -207, 0, 0, -207, -207, 0, 0, -207, -207, -207, -207, 0, 0, 0, 0, 0, 0, 0, -207,
+        // State 246
+        0, 0, -199, -199, 0, 0, -199, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -199, -199, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -199, 0, -199, -199, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 247
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 248
+        0, 0, 0, -105, -105, 0, 0, -105, 0, 0, 0, 0, -105, -105, -105, -105, 0, 0, -105, -105, 0, -105, -105, 0, 0, -105, -105, 0, 0, 0, -105, 0, -105, 0, 0, -105, -105, 0, 0, -105, -105, -105, -105, 0, 0, 0, 0, 0, 0, 0, -105,
+        // State 249
+        0, 0, 0, -104, -104, 0, 0, -104, 0, 0, 0, 0, -104, -104, -104, -104, 0, 0, -104, -104, 0, -104, -104, 0, 0, -104, -104, 0, 0, 0, -104, 0, -104, 0, 0, -104, -104, 0, 0, -104, -104, -104, -104, 0, 0, 0, 0, 0, 0, 0, -104,
     ];
     fn __action(state: i16, integer: usize) -> i16 {
-        __ACTION[(state as usize) * 52 + integer]
+        __ACTION[(state as usize) * 51 + integer]
     }
     const __EOF_ACTION: &[i16] = &[
         // State 0
-        -109,
+        -165,
         // State 1
         0,
         // State 2
         0,
         // State 3
-        -110,
+        -166,
         // State 4
         0,
         // State 5
@@ -536,7 +706,7 @@ mod __parse__PProgram {
         // State 48
         0,
         // State 49
-        -112,
+        0,
         // State 50
         0,
         // State 51
@@ -544,13 +714,13 @@ mod __parse__PProgram {
         // State 52
         0,
         // State 53
-        -103,
+        0,
         // State 54
-        -77,
+        0,
         // State 55
         0,
         // State 56
-        -82,
+        0,
         // State 57
         0,
         // State 58
@@ -560,13 +730,13 @@ mod __parse__PProgram {
         // State 60
         0,
         // State 61
-        -113,
+        0,
         // State 62
-        -152,
+        0,
         // State 63
-        -114,
+        0,
         // State 64
-        -78,
+        0,
         // State 65
         0,
         // State 66
@@ -574,21 +744,21 @@ mod __parse__PProgram {
         // State 67
         0,
         // State 68
-        -79,
+        -168,
         // State 69
         0,
         // State 70
-        -104,
+        0,
         // State 71
         0,
         // State 72
-        0,
+        -155,
         // State 73
-        0,
+        -172,
         // State 74
         0,
         // State 75
-        0,
+        -119,
         // State 76
         0,
         // State 77
@@ -600,15 +770,15 @@ mod __parse__PProgram {
         // State 80
         0,
         // State 81
-        0,
+        -169,
         // State 82
-        0,
+        -221,
         // State 83
-        0,
+        -170,
         // State 84
-        -64,
+        -116,
         // State 85
-        -83,
+        0,
         // State 86
         -115,
         // State 87
@@ -616,13 +786,13 @@ mod __parse__PProgram {
         // State 88
         0,
         // State 89
-        0,
+        -171,
         // State 90
         0,
         // State 91
         0,
         // State 92
-        0,
+        -156,
         // State 93
         0,
         // State 94
@@ -656,11 +826,11 @@ mod __parse__PProgram {
         // State 108
         0,
         // State 109
-        0,
+        -110,
         // State 110
-        0,
+        -120,
         // State 111
-        0,
+        -173,
         // State 112
         0,
         // State 113
@@ -672,7 +842,7 @@ mod __parse__PProgram {
         // State 116
         0,
         // State 117
-        -65,
+        0,
         // State 118
         0,
         // State 119
@@ -702,11 +872,11 @@ mod __parse__PProgram {
         // State 131
         0,
         // State 132
-        -111,
+        0,
         // State 133
         0,
         // State 134
-        -66,
+        0,
         // State 135
         0,
         // State 136
@@ -720,7 +890,7 @@ mod __parse__PProgram {
         // State 140
         0,
         // State 141
-        -35,
+        0,
         // State 142
         0,
         // State 143
@@ -734,11 +904,11 @@ mod __parse__PProgram {
         // State 147
         0,
         // State 148
-        -151,
+        0,
         // State 149
-        -20,
+        -179,
         // State 150
-        -21,
+        0,
         // State 151
         0,
         // State 152
@@ -746,11 +916,11 @@ mod __parse__PProgram {
         // State 153
         0,
         // State 154
-        -121,
+        0,
         // State 155
         0,
         // State 156
-        -144,
+        0,
         // State 157
         0,
         // State 158
@@ -764,192 +934,372 @@ mod __parse__PProgram {
         // State 162
         0,
         // State 163
-        -44,
+        0,
         // State 164
-        -34,
+        0,
         // State 165
-        -45,
+        0,
         // State 166
         0,
         // State 167
-        -122,
+        0,
         // State 168
-        -143,
-        // State 169
         0,
+        // State 169
+        -167,
         // State 170
         0,
         // State 171
         0,
         // State 172
-        -142,
+        -180,
         // State 173
         0,
         // State 174
         0,
         // State 175
-        -59,
+        0,
         // State 176
-        -141,
+        0,
         // State 177
         0,
         // State 178
         0,
         // State 179
-        -58,
+        0,
+        // State 180
+        0,
+        // State 181
+        0,
+        // State 182
+        0,
+        // State 183
+        0,
+        // State 184
+        0,
+        // State 185
+        0,
+        // State 186
+        0,
+        // State 187
+        0,
+        // State 188
+        0,
+        // State 189
+        0,
+        // State 190
+        0,
+        // State 191
+        0,
+        // State 192
+        0,
+        // State 193
+        0,
+        // State 194
+        0,
+        // State 195
+        0,
+        // State 196
+        0,
+        // State 197
+        0,
+        // State 198
+        0,
+        // State 199
+        0,
+        // State 200
+        0,
+        // State 201
+        0,
+        // State 202
+        0,
+        // State 203
+        0,
+        // State 204
+        0,
+        // State 205
+        -77,
+        // State 206
+        -211,
+        // State 207
+        0,
+        // State 208
+        -210,
+        // State 209
+        0,
+        // State 210
+        -181,
+        // State 211
+        0,
+        // State 212
+        -220,
+        // State 213
+        -51,
+        // State 214
+        -52,
+        // State 215
+        0,
+        // State 216
+        0,
+        // State 217
+        0,
+        // State 218
+        0,
+        // State 219
+        0,
+        // State 220
+        0,
+        // State 221
+        -209,
+        // State 222
+        0,
+        // State 223
+        0,
+        // State 224
+        0,
+        // State 225
+        0,
+        // State 226
+        0,
+        // State 227
+        0,
+        // State 228
+        0,
+        // State 229
+        0,
+        // State 230
+        0,
+        // State 231
+        0,
+        // State 232
+        0,
+        // State 233
+        0,
+        // State 234
+        0,
+        // State 235
+        0,
+        // State 236
+        -85,
+        // State 237
+        -76,
+        // State 238
+        -86,
+        // State 239
+        0,
+        // State 240
+        -208,
+        // State 241
+        0,
+        // State 242
+        0,
+        // State 243
+        0,
+        // State 244
+        0,
+        // State 245
+        -207,
+        // State 246
+        0,
+        // State 247
+        0,
+        // State 248
+        -105,
+        // State 249
+        -104,
     ];
     fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 22,
-            5 => 27,
-            12 => match state {
-                45 => 175,
-                47 => 179,
-                _ => 148,
-            },
-            13 => 48,
-            14 => 111,
-            15 => 101,
-            16 => 102,
-            17 => 125,
-            18 => match state {
-                39 => 163,
-                _ => 49,
-            },
-            19 => 50,
+            2 => 27,
+            5 => 22,
+            8 => 45,
+            11 => 56,
             20 => match state {
-                37 => 160,
-                _ => 155,
-            },
-            22 => 164,
-            23 => match state {
-                9 => 103,
-                11 => 106,
-                12 => 108,
-                13 => 109,
-                14 => 110,
-                21 => 121,
-                22 => 122,
-                24 | 33 => 129,
-                28 => 139,
-                38 => 161,
-                41 => 169,
-                43 => 171,
-                44 => 174,
-                _ => 51,
-            },
-            25 => 18,
-            26 => match state {
-                19 => 26,
-                _ => 1,
+                23 => 158,
+                _ => 133,
             },
-            27 => 19,
-            28 => 52,
-            29 => 2,
-            30 => 53,
+            24 => 194,
+            26 => 195,
+            28 => 134,
             31 => match state {
-                27 => 136,
-                _ => 126,
+                65 => 248,
+                66 => 249,
+                _ => 212,
             },
-            33 => 54,
-            34 => 84,
-            35 => 55,
-            36 => match state {
-                25 => 133,
-                _ => 114,
+            32 => 67,
+            33 => 144,
+            34 => 196,
+            35 => 197,
+            36 => 135,
+            37 => 162,
+            38 => match state {
+                25 => 163,
+                _ => 137,
             },
-            38 => 25,
             39 => 127,
-            40 => 157,
-            41 => match state {
-                3 => 85,
-                _ => 56,
-            },
-            43 => 3,
-            44 => 112,
-            45 => match state {
-                33 => 152,
-                _ => 130,
+            40 => 191,
+            41 => 217,
+            42 => match state {
+                60 => 236,
+                _ => 68,
             },
-            46 => match state {
-                42 => 170,
-                46 => 177,
-                _ => 57,
+            43 => 69,
+            44 => match state {
+                44 => 207,
+                _ => 198,
             },
+            46 => 237,
             47 => match state {
-                8 => 99,
-                _ => 58,
+                54 => 225,
+                _ => 215,
             },
-            48 => 59,
-            49 => match state {
-                18 => 119,
-                _ => 60,
+            48 => match state {
+                11 | 25 => 138,
+                12 => 140,
+                13 => 141,
+                14 => 142,
+                21 => 153,
+                24 | 34 => 160,
+                26 => 164,
+                27 => 165,
+                28 => 170,
+                31 => 174,
+                35 => 187,
+                36 => 190,
+                40 => 201,
+                41 => 203,
+                42 => 204,
+                47 | 52 => 216,
+                49 => 219,
+                50 => 220,
+                51 => 222,
+                55 => 226,
+                58 => 234,
+                59 => 235,
+                61 => 239,
+                64 => 247,
+                _ => 70,
             },
-            50 => 17,
-            51 => 61,
-            52 => match state {
-                17 => 118,
-                _ => 4,
+            50 => 18,
+            51 => match state {
+                19 => 30,
+                _ => 1,
+            },
+            52 => 19,
+            53 => 71,
+            54 => 2,
+            55 => 72,
+            56 => match state {
+                22 => 154,
+                _ => 128,
             },
-            53 => 20,
-            54 => 15,
-            55 => 62,
-            56 => 63,
-            57 => match state {
-                0 | 3 => 64,
-                16 | 25 => 115,
-                34 => 153,
-                40 => 166,
-                _ => 149,
+            58 => 73,
+            59 => match state {
+                15 => 145,
+                _ => 74,
+            },
+            60 => match state {
+                33 => 183,
+                _ => 129,
             },
-            59 => 40,
-            60 => 65,
             61 => match state {
-                29 => 141,
-                39 => 165,
-                _ => 150,
+                63 => 246,
+                _ => 62,
             },
             62 => match state {
-                20 => 120,
-                _ => 66,
+                3 => 110,
+                _ => 75,
             },
-            63 => 5,
-            64 => 6,
-            65 => 7,
+            64 => 3,
+            65 => 146,
             66 => match state {
-                32 => 37,
-                36 => 158,
-                _ => 35,
+                34 => 185,
+                _ => 161,
             },
-            67 => 8,
-            69 => 67,
-            70 => match state {
-                16 | 25 => 116,
-                _ => 68,
+            67 => 76,
+            68 => match state {
+                8 => 125,
+                _ => 77,
             },
-            71 => 105,
-            73 => match state {
-                8..=9 | 11..=14 | 17..=22 | 24 | 28 | 33 | 38 | 41 | 43..=44 => 100,
-                _ => 69,
+            69 => 78,
+            70 => 79,
+            71 => match state {
+                18 => 151,
+                _ => 80,
             },
-            74 => 70,
+            72 => 17,
+            73 => 81,
+            74 => match state {
+                17 => 150,
+                _ => 4,
+            },
+            75 => 20,
+            76 => match state {
+                62 => 244,
+                _ => 199,
+            },
+            78 => 15,
+            79 => 82,
+            80 => 83,
+            81 => match state {
+                16 => 148,
+                29 => 171,
+                46 | 65..=66 => 213,
+                _ => 84,
+            },
+            83 => 29,
+            84 => 85,
+            85 => match state {
+                2 => 109,
+                43 => 205,
+                60 => 238,
+                _ => 214,
+            },
+            86 => 86,
+            87 => match state {
+                45 => 209,
+                _ => 192,
+            },
+            89 => match state {
+                20 => 152,
+                _ => 87,
+            },
+            90 => 5,
+            91 => 6,
+            92 => 7,
+            93 => match state {
+                32 => 39,
+                37 => 44,
+                48 => 218,
+                53 => 224,
+                56 => 229,
+                _ => 232,
+            },
+            95 => 8,
+            97 => 88,
+            98 => 89,
+            99 => 23,
+            101 => match state {
+                0 | 3 | 16 | 29 | 46 | 65..=66 => 90,
+                _ => 126,
+            },
+            102 => 91,
+            103 => 92,
             _ => 0,
         }
     }
     fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
         const __TERMINAL: &[&str] = &[
-            r###""for""###,
-            r###""if""###,
-            r###""while""###,
             r###"ADD"###,
             r###"AND"###,
             r###"ASSIGN"###,
+            r###"BAR"###,
             r###"BREAK"###,
-            r###"CALL_ID"###,
             r###"COLON"###,
             r###"COMMA"###,
             r###"CONTINUE"###,
             r###"DIV"###,
+            r###"DOT"###,
             r###"ELSE"###,
             r###"EQUAL"###,
             r###"FALSE"###,
@@ -968,6 +1318,7 @@ mod __parse__PProgram {
             r###"LET"###,
             r###"LPAREN"###,
             r###"LT"###,
+            r###"MATMUL"###,
             r###"MUL"###,
             r###"NOT"###,
             r###"NOTEQUAL"###,
@@ -976,10 +1327,10 @@ mod __parse__PProgram {
             r###"RBRACKET"###,
             r###"RCBRACKET"###,
             r###"RETURN"###,
-            r###"RLBRACKET"###,
             r###"RPAREN"###,
             r###"SEMICOLON"###,
             r###"STRING"###,
+            r###"STRUCT"###,
             r###"SUB"###,
             r###"TRUE"###,
             r###"T_BOOl"###,
@@ -1003,14 +1354,13 @@ mod __parse__PProgram {
     pub(crate) struct __StateMachine<'input>
     where 
     {
-        input: &'input str,
         __phantom: core::marker::PhantomData<(&'input ())>,
     }
     impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
     where 
     {
         type Location = usize;
-        type Error = &'static str;
+        type Error = SyntaxError;
         type Token = Token<'input>;
         type TokenIndex = usize;
         type Symbol = __Symbol<'input>;
@@ -1042,7 +1392,7 @@ mod __parse__PProgram {
 
         #[inline]
         fn error_action(&self, state: i16) -> i16 {
-            __action(state, 52 - 1)
+            __action(state, 51 - 1)
         }
 
         #[inline]
@@ -1084,7 +1434,6 @@ mod __parse__PProgram {
             symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
         ) -> Option<__state_machine::ParseResult<Self>> {
             __reduce(
-                self.input,
                 action,
                 start_location,
                 states,
@@ -1105,58 +1454,57 @@ mod __parse__PProgram {
     ) -> Option<usize>
     {
         match *__token {
-            Token(38, _) if true => Some(0),
-            Token(39, _) if true => Some(1),
-            Token(47, _) if true => Some(2),
-            Token(14, _) if true => Some(3),
-            Token(10, _) if true => Some(4),
-            Token(22, _) if true => Some(5),
-            Token(32, _) if true => Some(6),
-            Token(0, _) if true => Some(7),
-            Token(18, _) if true => Some(8),
-            Token(15, _) if true => Some(9),
-            Token(33, _) if true => Some(10),
-            Token(17, _) if true => Some(11),
-            Token(34, _) if true => Some(12),
-            Token(23, _) if true => Some(13),
-            Token(35, _) if true => Some(14),
-            Token(3, _) if true => Some(15),
-            Token(37, _) if true => Some(16),
-            Token(5, _) if true => Some(17),
-            Token(25, _) if true => Some(18),
-            Token(24, _) if true => Some(19),
-            Token(4, _) if true => Some(20),
-            Token(6, _) if true => Some(21),
-            Token(40, _) if true => Some(22),
-            Token(2, _) if true => Some(23),
-            Token(28, _) if true => Some(24),
-            Token(48, _) if true => Some(25),
-            Token(21, _) if true => Some(26),
-            Token(42, _) if true => Some(27),
-            Token(11, _) if true => Some(28),
-            Token(20, _) if true => Some(29),
-            Token(13, _) if true => Some(30),
-            Token(8, _) if true => Some(31),
-            Token(9, _) if true => Some(32),
-            Token(50, _) if true => Some(33),
-            Token(49, _) if true => Some(34),
-            Token(29, _) if true => Some(35),
-            Token(51, _) if true => Some(36),
-            Token(43, _) if true => Some(37),
-            Token(30, _) if true => Some(38),
-            Token(12, _) if true => Some(39),
-            Token(19, _) if true => Some(40),
-            Token(1, _) if true => Some(41),
-            Token(16, _) if true => Some(42),
-            Token(45, _) if true => Some(43),
-            Token(31, _) if true => Some(44),
-            Token(26, _) if true => Some(45),
-            Token(36, _) if true => Some(46),
-            Token(41, _) if true => Some(47),
-            Token(27, _) if true => Some(48),
-            Token(44, _) if true => Some(49),
-            Token(46, _) if true => Some(50),
-            Token(7, _) if true => Some(51),
+            Token::Add if true => Some(0),
+            Token::And if true => Some(1),
+            Token::Assign if true => Some(2),
+            Token::Bar if true => Some(3),
+            Token::Break if true => Some(4),
+            Token::Colon if true => Some(5),
+            Token::Comma if true => Some(6),
+            Token::Continue if true => Some(7),
+            Token::Div if true => Some(8),
+            Token::Dot if true => Some(9),
+            Token::Else if true => Some(10),
+            Token::Equal if true => Some(11),
+            Token::False if true => Some(12),
+            Token::Float(_) if true => Some(13),
+            Token::Fn if true => Some(14),
+            Token::For if true => Some(15),
+            Token::GreaterOrEq if true => Some(16),
+            Token::GreaterThan if true => Some(17),
+            Token::Id(_) if true => Some(18),
+            Token::If if true => Some(19),
+            Token::In if true => Some(20),
+            Token::Integer(_) if true => Some(21),
+            Token::LBracket if true => Some(22),
+            Token::LCBracket if true => Some(23),
+            Token::LessOrEq if true => Some(24),
+            Token::Let if true => Some(25),
+            Token::LParen if true => Some(26),
+            Token::LessThan if true => Some(27),
+            Token::MatMul if true => Some(28),
+            Token::Mul if true => Some(29),
+            Token::Not if true => Some(30),
+            Token::NotEqual if true => Some(31),
+            Token::Or if true => Some(32),
+            Token::Pipe if true => Some(33),
+            Token::RBracket if true => Some(34),
+            Token::RCBracket if true => Some(35),
+            Token::Return if true => Some(36),
+            Token::RParen if true => Some(37),
+            Token::Semicolon if true => Some(38),
+            Token::Str(_) if true => Some(39),
+            Token::Struct if true => Some(40),
+            Token::Sub if true => Some(41),
+            Token::True if true => Some(42),
+            Token::TBool if true => Some(43),
+            Token::TDataFrame if true => Some(44),
+            Token::TFloat if true => Some(45),
+            Token::TInt if true => Some(46),
+            Token::TSeries if true => Some(47),
+            Token::TStr if true => Some(48),
+            Token::TVoid if true => Some(49),
+            Token::While if true => Some(50),
             _ => None,
         }
     }
@@ -1169,23 +1517,25 @@ mod __parse__PProgram {
     ) -> __Symbol<'input>
     {
         match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 => match __token {
-                Token(38, __tok0) | Token(39, __tok0) | Token(47, __tok0) | Token(14, __tok0) | Token(10, __tok0) | Token(22, __tok0) | Token(32, __tok0) | Token(0, __tok0) | Token(18, __tok0) | Token(15, __tok0) | Token(33, __tok0) | Token(17, __tok0) | Token(34, __tok0) | Token(23, __tok0) | Token(35, __tok0) | Token(3, __tok0) | Token(37, __tok0) | Token(5, __tok0) | Token(25, __tok0) | Token(24, __tok0) | Token(4, __tok0) | Token(6, __tok0) | Token(40, __tok0) | Token(2, __tok0) | Token(28, __tok0) | Token(48, __tok0) | Token(21, __tok0) | Token(42, __tok0) | Token(11, __tok0) | Token(20, __tok0) | Token(13, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(50, __tok0) | Token(49, __tok0) | Token(29, __tok0) | Token(51, __tok0) | Token(43, __tok0) | Token(30, __tok0) | Token(12, __tok0) | Token(19, __tok0) | Token(1, __tok0) | Token(16, __tok0) | Token(45, __tok0) | Token(31, __tok0) | Token(26, __tok0) | Token(36, __tok0) | Token(41, __tok0) | Token(27, __tok0) | Token(44, __tok0) | Token(46, __tok0) | Token(7, __tok0) if true => __Symbol::Variant0(__tok0),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 14 | 15 | 16 | 17 | 19 | 20 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 => __Symbol::Variant0(__token),
+            13 | 18 | 21 => match __token {
+                Token::Float(__tok0) | Token::Id(__tok0) | Token::Integer(__tok0) if true => __Symbol::Variant1(__tok0),
+                _ => unreachable!(),
+            },
+            39 => match __token {
+                Token::Str(__tok0) if true => __Symbol::Variant2(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
         }
     }
     pub struct PProgramParser {
-        builder: __lalrpop_util::lexer::MatcherBuilder,
         _priv: (),
     }
 
     impl PProgramParser {
         pub fn new() -> PProgramParser {
-            let __builder = super::__intern_token::new_builder();
             PProgramParser {
-                builder: __builder,
                 _priv: (),
             }
         }
@@ -1193,15 +1543,17 @@ mod __parse__PProgram {
         #[allow(dead_code)]
         pub fn parse<
             'input,
+            __TOKEN: __ToTriple<'input, >,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
         >(
             &self,
-            input: &'input str,
-        ) -> Result<Program, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            __tokens0: __TOKENS,
+        ) -> Result<Program, __lalrpop_util::ParseError<usize, Token<'input>, SyntaxError>>
         {
-            let mut __tokens = self.builder.matcher(input);
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
             __state_machine::Parser::drive(
                 __StateMachine {
-                    input,
                     __phantom: core::marker::PhantomData::<(&())>,
                 },
                 __tokens,
@@ -1211,474 +1563,751 @@ mod __parse__PProgram {
     pub(crate) fn __reduce<
         'input,
     >(
-        input: &'input str,
         __action: i16,
         __lookahead_start: Option<&usize>,
         __states: &mut alloc::vec::Vec<i16>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<Program,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    ) -> Option<Result<Program,__lalrpop_util::ParseError<usize, Token<'input>, SyntaxError>>>
     {
         let (__pop_states, __nonterminal) = match __action {
             0 => {
-                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce0(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             1 => {
-                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce1(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             2 => {
-                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce2(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             3 => {
-                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce3(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             4 => {
-                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce4(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             5 => {
-                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce5(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             6 => {
-                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce6(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             7 => {
-                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce7(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             8 => {
-                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce8(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             9 => {
-                __reduce9(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce9(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             10 => {
-                __reduce10(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce10(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             11 => {
-                __reduce11(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce11(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             12 => {
-                __reduce12(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce12(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             13 => {
-                __reduce13(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce13(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             14 => {
-                __reduce14(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce14(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             15 => {
-                __reduce15(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce15(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             16 => {
-                __reduce16(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce16(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             17 => {
-                __reduce17(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce17(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             18 => {
-                __reduce18(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce18(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             19 => {
-                __reduce19(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce19(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             20 => {
-                __reduce20(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce20(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             21 => {
-                __reduce21(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce21(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             22 => {
-                __reduce22(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce22(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             23 => {
-                __reduce23(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce23(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             24 => {
-                __reduce24(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce24(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             25 => {
-                __reduce25(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce25(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             26 => {
-                __reduce26(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce26(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             27 => {
-                __reduce27(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce27(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             28 => {
-                __reduce28(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce28(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             29 => {
-                __reduce29(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce29(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             30 => {
-                __reduce30(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce30(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             31 => {
-                __reduce31(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce31(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             32 => {
-                __reduce32(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce32(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             33 => {
-                __reduce33(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce33(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             34 => {
-                __reduce34(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce34(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             35 => {
-                __reduce35(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce35(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             36 => {
-                __reduce36(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce36(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             37 => {
-                __reduce37(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce37(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             38 => {
-                __reduce38(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce38(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             39 => {
-                __reduce39(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce39(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             40 => {
-                __reduce40(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce40(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             41 => {
-                __reduce41(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce41(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             42 => {
-                __reduce42(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce42(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             43 => {
-                __reduce43(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce43(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             44 => {
-                __reduce44(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce44(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             45 => {
-                __reduce45(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce45(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             46 => {
-                __reduce46(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce46(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             47 => {
-                __reduce47(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce47(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             48 => {
-                __reduce48(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce48(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             49 => {
-                __reduce49(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce49(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             50 => {
-                __reduce50(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce50(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             51 => {
-                __reduce51(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce51(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             52 => {
-                __reduce52(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce52(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             53 => {
-                __reduce53(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce53(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             54 => {
-                __reduce54(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce54(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             55 => {
-                __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce55(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             56 => {
-                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce56(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             57 => {
-                __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce57(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             58 => {
-                __reduce58(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce58(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             59 => {
-                __reduce59(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce59(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             60 => {
-                __reduce60(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce60(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             61 => {
-                __reduce61(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce61(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             62 => {
-                __reduce62(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce62(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             63 => {
-                __reduce63(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce63(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             64 => {
-                __reduce64(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce64(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             65 => {
-                __reduce65(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce65(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             66 => {
-                __reduce66(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce66(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             67 => {
-                __reduce67(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce67(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             68 => {
-                __reduce68(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce68(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             69 => {
-                __reduce69(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce69(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             70 => {
-                __reduce70(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce70(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             71 => {
-                __reduce71(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce71(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             72 => {
-                __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce72(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce73(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             74 => {
-                __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce74(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             75 => {
-                __reduce75(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce75(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             76 => {
-                __reduce76(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce76(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             77 => {
-                __reduce77(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce77(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             78 => {
-                __reduce78(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce78(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             79 => {
-                __reduce79(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce79(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             80 => {
-                __reduce80(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce80(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             81 => {
-                __reduce81(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                // PDimension = PBrackets<PExpr> => ActionFn(23);
+                let __sym0 = __pop_Variant23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action23::<>(__sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+                (1, 44)
             }
             82 => {
-                __reduce82(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce82(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             83 => {
-                __reduce83(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce83(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             84 => {
-                __reduce84(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce84(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             85 => {
-                __reduce85(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce85(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             86 => {
-                __reduce86(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce86(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             87 => {
-                __reduce87(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce87(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             88 => {
-                __reduce88(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce88(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             89 => {
-                __reduce89(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce89(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             90 => {
-                __reduce90(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce90(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             91 => {
-                __reduce91(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce91(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             92 => {
-                __reduce92(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce92(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             93 => {
-                __reduce93(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce93(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             94 => {
-                __reduce94(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce94(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             95 => {
-                __reduce95(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce95(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             96 => {
-                __reduce96(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce96(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             97 => {
-                __reduce97(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce97(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             98 => {
-                __reduce98(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce98(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             99 => {
-                __reduce99(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce99(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             100 => {
-                __reduce100(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce100(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             101 => {
-                __reduce101(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce101(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             102 => {
-                __reduce102(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce102(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             103 => {
-                __reduce103(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce103(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             104 => {
-                __reduce104(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce104(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             105 => {
-                __reduce105(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce105(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             106 => {
-                __reduce106(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce106(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             107 => {
-                __reduce107(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce107(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             108 => {
-                __reduce108(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce108(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             109 => {
-                __reduce109(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce109(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             110 => {
-                __reduce110(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce110(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             111 => {
-                __reduce111(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce111(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             112 => {
-                __reduce112(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce112(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             113 => {
-                __reduce113(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce113(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             114 => {
-                __reduce114(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce114(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             115 => {
-                __reduce115(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce115(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             116 => {
-                __reduce116(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce116(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             117 => {
-                __reduce117(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce117(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             118 => {
-                __reduce118(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce118(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             119 => {
-                __reduce119(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce119(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             120 => {
-                __reduce120(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce120(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             121 => {
-                __reduce121(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce121(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             122 => {
-                __reduce122(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce122(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             123 => {
-                __reduce123(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce123(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             124 => {
-                __reduce124(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce124(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             125 => {
-                __reduce125(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce125(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             126 => {
-                __reduce126(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce126(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             127 => {
-                __reduce127(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce127(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             128 => {
-                __reduce128(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce128(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             129 => {
-                __reduce129(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce129(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             130 => {
-                __reduce130(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce130(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             131 => {
-                __reduce131(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce131(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             132 => {
-                __reduce132(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce132(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             133 => {
-                __reduce133(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce133(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             134 => {
-                __reduce134(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce134(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             135 => {
-                __reduce135(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce135(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             136 => {
-                __reduce136(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce136(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             137 => {
-                __reduce137(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce137(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             138 => {
-                __reduce138(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce138(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             139 => {
-                __reduce139(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce139(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             140 => {
-                __reduce140(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce140(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             141 => {
-                __reduce141(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce141(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             142 => {
-                __reduce142(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce142(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             143 => {
-                __reduce143(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce143(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             144 => {
-                __reduce144(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce144(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             145 => {
-                __reduce145(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce145(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             146 => {
-                __reduce146(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce146(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             147 => {
-                __reduce147(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce147(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             148 => {
-                __reduce148(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce148(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             149 => {
-                __reduce149(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce149(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             150 => {
-                __reduce150(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce150(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             151 => {
+                __reduce151(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            152 => {
+                __reduce152(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            153 => {
+                __reduce153(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            154 => {
+                __reduce154(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            155 => {
+                __reduce155(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            156 => {
+                __reduce156(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            157 => {
+                __reduce157(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            158 => {
+                __reduce158(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            159 => {
+                __reduce159(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            160 => {
+                __reduce160(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            161 => {
+                __reduce161(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            162 => {
+                __reduce162(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            163 => {
+                __reduce163(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            164 => {
+                __reduce164(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            165 => {
+                __reduce165(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            166 => {
+                __reduce166(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            167 => {
+                __reduce167(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            168 => {
+                __reduce168(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            169 => {
+                __reduce169(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            170 => {
+                __reduce170(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            171 => {
+                __reduce171(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            172 => {
+                __reduce172(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            173 => {
+                __reduce173(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            174 => {
+                __reduce174(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            175 => {
+                __reduce175(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            176 => {
+                __reduce176(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            177 => {
+                __reduce177(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            178 => {
+                __reduce178(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            179 => {
+                __reduce179(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            180 => {
+                __reduce180(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            181 => {
+                __reduce181(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            182 => {
+                __reduce182(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            183 => {
+                __reduce183(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            184 => {
+                __reduce184(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            185 => {
+                __reduce185(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            186 => {
+                __reduce186(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            187 => {
+                __reduce187(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            188 => {
+                __reduce188(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            189 => {
+                __reduce189(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            190 => {
+                __reduce190(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            191 => {
+                __reduce191(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            192 => {
+                __reduce192(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            193 => {
+                __reduce193(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            194 => {
+                __reduce194(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            195 => {
+                __reduce195(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            196 => {
+                __reduce196(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            197 => {
+                __reduce197(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            198 => {
+                __reduce198(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            199 => {
+                __reduce199(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            200 => {
+                __reduce200(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            201 => {
+                __reduce201(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            202 => {
+                __reduce202(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            203 => {
+                __reduce203(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            204 => {
+                __reduce204(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            205 => {
+                __reduce205(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            206 => {
+                // PVarDeclaration = LET, ID, COLON, PType, PDimension, ASSIGN, PExpr, SEMICOLON => ActionFn(280);
+                assert!(__symbols.len() >= 8);
+                let __sym7 = __pop_Variant0(__symbols);
+                let __sym6 = __pop_Variant3(__symbols);
+                let __sym5 = __pop_Variant0(__symbols);
+                let __sym4 = __pop_Variant29(__symbols);
+                let __sym3 = __pop_Variant9(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant1(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym7.2.clone();
+                let __nt = match super::__action280::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+                (8, 98)
+            }
+            207 => {
+                // PVarDeclaration = LET, ID, COLON, PType, ASSIGN, PExpr, SEMICOLON => ActionFn(281);
+                assert!(__symbols.len() >= 7);
+                let __sym6 = __pop_Variant0(__symbols);
+                let __sym5 = __pop_Variant3(__symbols);
+                let __sym4 = __pop_Variant0(__symbols);
+                let __sym3 = __pop_Variant9(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant1(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym6.2.clone();
+                let __nt = match super::__action281::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+                (7, 98)
+            }
+            208 => {
+                // PVarDeclaration = LET, ID, COLON, PType, PDimension, SEMICOLON => ActionFn(282);
+                assert!(__symbols.len() >= 6);
+                let __sym5 = __pop_Variant0(__symbols);
+                let __sym4 = __pop_Variant29(__symbols);
+                let __sym3 = __pop_Variant9(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant1(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = match super::__action282::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+                (6, 98)
+            }
+            209 => {
+                // PVarDeclaration = LET, ID, COLON, PType, SEMICOLON => ActionFn(283);
+                assert!(__symbols.len() >= 5);
+                let __sym4 = __pop_Variant0(__symbols);
+                let __sym3 = __pop_Variant9(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant1(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = match super::__action283::<>(__sym0, __sym1, __sym2, __sym3, __sym4) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+                (5, 98)
+            }
+            210 => {
+                __reduce210(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            211 => {
+                __reduce211(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            212 => {
+                __reduce212(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            213 => {
+                __reduce213(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            214 => {
+                __reduce214(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            215 => {
+                __reduce215(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            216 => {
+                __reduce216(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            217 => {
+                __reduce217(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            218 => {
+                __reduce218(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            219 => {
+                __reduce219(__lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            220 => {
                 // __PProgram = PProgram => ActionFn(0);
-                let __sym0 = __pop_Variant23(__symbols);
+                let __sym0 = __pop_Variant39(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, __sym0);
+                let __nt = super::__action0::<>(__sym0);
                 return Some(Ok(__nt));
             }
             _ => panic!("invalid action code {}", __action)
@@ -1694,223 +2323,322 @@ mod __parse__PProgram {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant28<
+    fn __pop_Variant16<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant45<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Access, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant45(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant6<
+    fn __pop_Variant14<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Block, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant1<
+    fn __pop_Variant3<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Box<Expression>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant18<
+    fn __pop_Variant35<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Call, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant35(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant8<
+    fn __pop_Variant21<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Const, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant20<
+    fn __pop_Variant9<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, DataType, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
+    fn __pop_Variant29<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Dimension, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant21<
+    fn __pop_Variant37<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Expression, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant37(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant17<
+    fn __pop_Variant34<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Function, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant3<
+    fn __pop_Variant5<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, FunctionParam, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant15<
+    fn __pop_Variant32<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, FunctionSignature, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant22<
+    fn __pop_Variant18<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Index, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant14<
+    fn __pop_Variant38<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Operator, usize)
+    ) -> (usize, Lambda, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant38(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant23<
+    fn __pop_Variant31<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Program, usize)
+    ) -> (usize, Operator, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant11<
+    fn __pop_Variant39<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Statement, usize)
+    ) -> (usize, Program, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant39(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant25<
+    fn __pop_Variant28<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Statement, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant40<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Struct, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant40(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant0<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Token<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant7<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Variable, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant9<
+    fn __pop_Variant22<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<()>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant23<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<Box<Expression>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant10<
+    fn __pop_Variant27<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<DataType>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant25<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<FunctionParam>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant26<
+    fn __pop_Variant24<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<Index>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant26<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<Variable>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant2<
+    fn __pop_Variant46<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Box<Expression>>, usize)
+    ) -> (usize, Vector, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant46(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant17<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<()>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -1918,5031 +2646,8050 @@ mod __parse__PProgram {
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<FunctionParam>, usize)
+    ) -> (usize, alloc::vec::Vec<Box<Expression>>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
+    fn __pop_Variant10<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<DataType>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<FunctionParam>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
     fn __pop_Variant19<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Statement>, usize)
+    ) -> (usize, alloc::vec::Vec<Index>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant7<
+    fn __pop_Variant36<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Statement>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant36(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Variable>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<&'input str>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant15<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, core::option::Option<Block>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant5<
+    fn __pop_Variant11<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, core::option::Option<Box<Expression>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
+    fn __pop_Variant42<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<DataType>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant42(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant30<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, core::option::Option<Dimension>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant16<
+    fn __pop_Variant33<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, core::option::Option<FunctionParam>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant27<
+    fn __pop_Variant43<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Operator>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant43(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant41<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Variable>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant41(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant44<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, core::option::Option<Vec<Index>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant44(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant24<
+    fn __pop_Variant12<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<&'input str>, usize)
+    ) -> (usize, core::option::Option<core::option::Option<Box<Expression>>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant0<
+    fn __pop_Variant20<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
     pub(crate) fn __reduce0<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<PExpr> COMMA) = PExpr, COMMA => ActionFn(119);
+        // (<PExpr> COMMA) = PExpr, COMMA => ActionFn(160);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action119::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action160::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (2, 0)
     }
     pub(crate) fn __reduce1<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<PExpr> COMMA)* =  => ActionFn(117);
+        // (<PExpr> COMMA)* =  => ActionFn(158);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action117::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action158::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (0, 1)
     }
     pub(crate) fn __reduce2<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<PExpr> COMMA)* = (<PExpr> COMMA)+ => ActionFn(118);
-        let __sym0 = __pop_Variant2(__symbols);
+        // (<PExpr> COMMA)* = (<PExpr> COMMA)+ => ActionFn(159);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action118::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action159::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (1, 1)
     }
     pub(crate) fn __reduce3<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<PExpr> COMMA)+ = PExpr, COMMA => ActionFn(135);
+        // (<PExpr> COMMA)+ = PExpr, COMMA => ActionFn(183);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action135::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action183::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (2, 2)
     }
     pub(crate) fn __reduce4<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<PExpr> COMMA)+ = (<PExpr> COMMA)+, PExpr, COMMA => ActionFn(136);
+        // (<PExpr> COMMA)+ = (<PExpr> COMMA)+, PExpr, COMMA => ActionFn(184);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action136::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action184::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (3, 2)
     }
     pub(crate) fn __reduce5<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<PFuncParam> COMMA) = PFuncParam, COMMA => ActionFn(124);
+        // (<PFuncParam> COMMA) = PFuncParam, COMMA => ActionFn(165);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action124::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        let __nt = super::__action165::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (2, 3)
     }
     pub(crate) fn __reduce6<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<PFuncParam> COMMA)* =  => ActionFn(122);
+        // (<PFuncParam> COMMA)* =  => ActionFn(163);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action122::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action163::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (0, 4)
     }
     pub(crate) fn __reduce7<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<PFuncParam> COMMA)* = (<PFuncParam> COMMA)+ => ActionFn(123);
-        let __sym0 = __pop_Variant4(__symbols);
+        // (<PFuncParam> COMMA)* = (<PFuncParam> COMMA)+ => ActionFn(164);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action123::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action164::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (1, 4)
     }
     pub(crate) fn __reduce8<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<PFuncParam> COMMA)+ = PFuncParam, COMMA => ActionFn(139);
+        // (<PFuncParam> COMMA)+ = PFuncParam, COMMA => ActionFn(187);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action139::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action187::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (2, 5)
     }
     pub(crate) fn __reduce9<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<PFuncParam> COMMA)+ = (<PFuncParam> COMMA)+, PFuncParam, COMMA => ActionFn(140);
+        // (<PFuncParam> COMMA)+ = (<PFuncParam> COMMA)+, PFuncParam, COMMA => ActionFn(188);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action140::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action188::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (3, 5)
     }
     pub(crate) fn __reduce10<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (ASSIGN <PExpr>) = ASSIGN, PExpr => ActionFn(112);
+        // (<PStructField> COMMA) = PStructField, COMMA => ActionFn(170);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action112::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action170::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
         (2, 6)
     }
     pub(crate) fn __reduce11<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (ASSIGN <PExpr>)? = ASSIGN, PExpr => ActionFn(143);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action143::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 7)
+        // (<PStructField> COMMA)* =  => ActionFn(168);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action168::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 7)
     }
     pub(crate) fn __reduce12<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (ASSIGN <PExpr>)? =  => ActionFn(111);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action111::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (0, 7)
+        // (<PStructField> COMMA)* = (<PStructField> COMMA)+ => ActionFn(169);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action169::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 7)
     }
     pub(crate) fn __reduce13<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (COLON <PExpr>) = COLON, PExpr => ActionFn(89);
+        // (<PStructField> COMMA)+ = PStructField, COMMA => ActionFn(191);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action89::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action191::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
         (2, 8)
     }
     pub(crate) fn __reduce14<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (COLON <PExpr>)? = COLON, PExpr => ActionFn(146);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<PStructField> COMMA)+ = (<PStructField> COMMA)+, PStructField, COMMA => ActionFn(192);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action146::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 9)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action192::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 8)
     }
     pub(crate) fn __reduce15<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (COLON <PExpr>)? =  => ActionFn(88);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action88::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (0, 9)
+        // (<PType> COMMA) = PType, COMMA => ActionFn(146);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action146::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 9)
     }
     pub(crate) fn __reduce16<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (ELSE <PElseBlock>) = ELSE, PElseBlock => ActionFn(94);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant6(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action94::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 10)
+        // (<PType> COMMA)* =  => ActionFn(144);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action144::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 10)
     }
     pub(crate) fn __reduce17<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (ELSE <PElseBlock>)? = ELSE, PElseBlock => ActionFn(149);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant6(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<PType> COMMA)* = (<PType> COMMA)+ => ActionFn(145);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action149::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (2, 11)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action145::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 10)
     }
     pub(crate) fn __reduce18<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (ELSE <PElseBlock>)? =  => ActionFn(93);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action93::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 11)
+        // (<PType> COMMA)+ = PType, COMMA => ActionFn(195);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action195::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 11)
     }
     pub(crate) fn __reduce19<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PBlock = PStatement => ActionFn(70);
-        let __sym0 = __pop_Variant11(__symbols);
+        // (<PType> COMMA)+ = (<PType> COMMA)+, PType, COMMA => ActionFn(196);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action70::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 12)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action196::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 11)
     }
     pub(crate) fn __reduce20<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PBlock = PStrictBlock => ActionFn(71);
-        let __sym0 = __pop_Variant6(__symbols);
+        // (ASSIGN <PExpr>) = ASSIGN, PExpr => ActionFn(129);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action71::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 12)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action129::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 12)
     }
     pub(crate) fn __reduce21<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PBool = TRUE => ActionFn(11);
+        // (ASSIGN <PExpr>)? = ASSIGN, PExpr => ActionFn(199);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action11::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 13)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action199::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 13)
     }
     pub(crate) fn __reduce22<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PBool = FALSE => ActionFn(12);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action12::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 13)
+        // (ASSIGN <PExpr>)? =  => ActionFn(128);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action128::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 13)
     }
     pub(crate) fn __reduce23<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PBoxed<PId> = PId => ActionFn(101);
-        let __sym0 = __pop_Variant21(__symbols);
+        // (COLON <PExpr>) = COLON, PExpr => ActionFn(101);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action101::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 14)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action101::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 14)
     }
     pub(crate) fn __reduce24<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PCallParams = PComma<PExpr> => ActionFn(54);
-        let __sym0 = __pop_Variant9(__symbols);
+        // (COLON <PExpr>)? = COLON, PExpr => ActionFn(202);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 15)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action202::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 15)
     }
     pub(crate) fn __reduce25<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PComma<PExpr> = PExpr => ActionFn(158);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action158::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 16)
+        // (COLON <PExpr>)? =  => ActionFn(100);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action100::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 15)
     }
     pub(crate) fn __reduce26<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PComma<PExpr> =  => ActionFn(159);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action159::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (0, 16)
+        // (COLON <PExpr?>) = COLON, PExpr => ActionFn(205);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action205::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 16)
     }
     pub(crate) fn __reduce27<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PComma<PExpr> = (<PExpr> COMMA)+, PExpr => ActionFn(160);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        // (COLON <PExpr?>) = COLON => ActionFn(206);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action160::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (2, 16)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action206::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 16)
     }
     pub(crate) fn __reduce28<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PComma<PExpr> = (<PExpr> COMMA)+ => ActionFn(161);
-        let __sym0 = __pop_Variant2(__symbols);
+        // (COLON <PExpr?>)? = COLON, PExpr => ActionFn(215);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action161::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 16)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action215::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 17)
     }
     pub(crate) fn __reduce29<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PComma<PFuncParam> = PFuncParam => ActionFn(162);
-        let __sym0 = __pop_Variant3(__symbols);
+        // (COLON <PExpr?>)? = COLON => ActionFn(216);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action162::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        let __nt = super::__action216::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (1, 17)
     }
     pub(crate) fn __reduce30<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PComma<PFuncParam> =  => ActionFn(163);
+        // (COLON <PExpr?>)? =  => ActionFn(135);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action163::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        let __nt = super::__action135::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (0, 17)
     }
     pub(crate) fn __reduce31<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PComma<PFuncParam> = (<PFuncParam> COMMA)+, PFuncParam => ActionFn(164);
+        // (DOT <ID>) = DOT, ID => ActionFn(123);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action164::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (2, 17)
+        let __nt = super::__action123::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (2, 18)
     }
     pub(crate) fn __reduce32<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PComma<PFuncParam> = (<PFuncParam> COMMA)+ => ActionFn(165);
-        let __sym0 = __pop_Variant4(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action165::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 17)
+        // (DOT <ID>)* =  => ActionFn(121);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action121::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (0, 19)
     }
     pub(crate) fn __reduce33<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PCondition = IF, PExpr, RPAREN, PStrictBlock, ELSE, PElseBlock => ActionFn(150);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant6(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant6(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (DOT <ID>)* = (DOT <ID>)+ => ActionFn(122);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action150::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (6, 18)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action122::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 19)
     }
     pub(crate) fn __reduce34<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PCondition = IF, PExpr, RPAREN, PStrictBlock => ActionFn(151);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant6(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
+        // (DOT <ID>)+ = DOT, ID => ActionFn(229);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant1(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action151::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (4, 18)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action229::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 20)
     }
     pub(crate) fn __reduce35<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PConst = PInt => ActionFn(14);
-        let __sym0 = __pop_Variant8(__symbols);
+        // (DOT <ID>)+ = (DOT <ID>)+, DOT, ID => ActionFn(230);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action14::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 19)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action230::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (3, 20)
     }
     pub(crate) fn __reduce36<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PConst = PStr => ActionFn(15);
-        let __sym0 = __pop_Variant8(__symbols);
+        // (ELSE <PElseBlock>) = ELSE, PElseBlock => ActionFn(106);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 19)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action106::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (2, 21)
     }
     pub(crate) fn __reduce37<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PConst = PFloat => ActionFn(16);
-        let __sym0 = __pop_Variant8(__symbols);
+        // (ELSE <PElseBlock>)? = ELSE, PElseBlock => ActionFn(233);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 19)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action233::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 22)
     }
     pub(crate) fn __reduce38<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PConst = PBool => ActionFn(17);
-        let __sym0 = __pop_Variant8(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 19)
+        // (ELSE <PElseBlock>)? =  => ActionFn(105);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action105::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (0, 22)
     }
     pub(crate) fn __reduce39<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PDimension = LBRACKET, PInt, RLBRACKET, PInt, RBRACKET => ActionFn(20);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant8(__symbols);
+        // (LBRACKET <PEmpty> RBRACKET) = LBRACKET, PEmpty, RBRACKET => ActionFn(152);
+        assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action20::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (5, 20)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action152::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (3, 23)
     }
     pub(crate) fn __reduce40<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PDimension = LBRACKET, PInt, RBRACKET => ActionFn(21);
+        // (LBRACKET <PEmpty> RBRACKET)+ = LBRACKET, PEmpty, RBRACKET => ActionFn(236);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action21::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (3, 20)
+        let __nt = super::__action236::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (3, 24)
     }
     pub(crate) fn __reduce41<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PDimension? = PDimension => ActionFn(113);
-        let __sym0 = __pop_Variant12(__symbols);
+        // (LBRACKET <PEmpty> RBRACKET)+ = (LBRACKET <PEmpty> RBRACKET)+, LBRACKET, PEmpty, RBRACKET => ActionFn(237);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant16(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant17(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action113::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 21)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action237::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (4, 24)
     }
     pub(crate) fn __reduce42<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PDimension? =  => ActionFn(114);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action114::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (0, 21)
+        // (LBRACKET <PExpr> RBRACKET) = LBRACKET, PExpr, RBRACKET => ActionFn(149);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action149::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 25)
     }
     pub(crate) fn __reduce43<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PElseBlock = PCondition => ActionFn(76);
-        let __sym0 = __pop_Variant11(__symbols);
+        // (LBRACKET <PExpr> RBRACKET)+ = LBRACKET, PExpr, RBRACKET => ActionFn(238);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action76::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 22)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action238::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 26)
     }
     pub(crate) fn __reduce44<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PElseBlock = PStrictBlock => ActionFn(77);
-        let __sym0 = __pop_Variant6(__symbols);
+        // (LBRACKET <PExpr> RBRACKET)+ = (LBRACKET <PExpr> RBRACKET)+, LBRACKET, PExpr, RBRACKET => ActionFn(239);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action77::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 22)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action239::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 26)
     }
     pub(crate) fn __reduce45<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PExpr = PTier<PExprOp, PLogicExpr> => ActionFn(40);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (LBRACKET <PIndexing> RBRACKET) = LBRACKET, PIndexing, RBRACKET => ActionFn(155);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action40::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 23)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action155::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (3, 27)
     }
     pub(crate) fn __reduce46<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PExpr? = PExpr => ActionFn(115);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (LBRACKET <PIndexing> RBRACKET)+ = LBRACKET, PIndexing, RBRACKET => ActionFn(240);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action115::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (1, 24)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action240::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (3, 28)
     }
     pub(crate) fn __reduce47<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PExpr? =  => ActionFn(116);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action116::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (0, 24)
+        // (LBRACKET <PIndexing> RBRACKET)+ = (LBRACKET <PIndexing> RBRACKET)+, LBRACKET, PIndexing, RBRACKET => ActionFn(241);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action241::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (4, 28)
     }
     pub(crate) fn __reduce48<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PExprOp = AND => ActionFn(27);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action27::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 25)
+        // @L =  => ActionFn(140);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action140::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 29)
     }
     pub(crate) fn __reduce49<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PExprOp = OR => ActionFn(28);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 25)
+        // @R =  => ActionFn(139);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action139::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 30)
     }
     pub(crate) fn __reduce50<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFactor = PFactor, PPipeOp, PBoxed<PId> => ActionFn(47);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant14(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PBlock = PStatement => ActionFn(82);
+        let __sym0 = __pop_Variant28(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action47::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 26)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action82::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 31)
     }
     pub(crate) fn __reduce51<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFactor = PUnaryOP, PItem => ActionFn(172);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PBlock = PStrictBlock => ActionFn(83);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action172::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 26)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action83::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 31)
     }
     pub(crate) fn __reduce52<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFactor = PItem => ActionFn(173);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PBool = TRUE => ActionFn(261);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action173::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 26)
+        let __nt = super::__action261::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 32)
     }
     pub(crate) fn __reduce53<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFactorOp = MUL => ActionFn(37);
+        // PBool = FALSE => ActionFn(262);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action37::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 27)
+        let __nt = super::__action262::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 32)
     }
     pub(crate) fn __reduce54<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFactorOp = DIV => ActionFn(38);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PBoxed<PId> = PId => ActionFn(114);
+        let __sym0 = __pop_Variant37(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action38::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 27)
+        let __nt = super::__action114::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 33)
     }
     pub(crate) fn __reduce55<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFloat = FLOAT => ActionFn(10);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PBrackets<PEmpty> = (LBRACKET <PEmpty> RBRACKET)+ => ActionFn(132);
+        let __sym0 = __pop_Variant17(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action10::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 28)
+        let __nt = super::__action132::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 34)
     }
     pub(crate) fn __reduce56<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFnSignature = FN, CALL_ID, PFunctionParams, RPAREN, COLON, PFunctionType => ActionFn(56);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant20(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PBrackets<PExpr> = (LBRACKET <PExpr> RBRACKET)+ => ActionFn(133);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action56::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (6, 29)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action133::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 35)
     }
     pub(crate) fn __reduce57<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PForLoop = FOR, ID, IN, PExpr, COLON, PExpr, COLON, PExpr, RPAREN, PBlock => ActionFn(147);
-        assert!(__symbols.len() >= 10);
-        let __sym9 = __pop_Variant6(__symbols);
-        let __sym8 = __pop_Variant0(__symbols);
-        let __sym7 = __pop_Variant1(__symbols);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant1(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PBrackets<PIndexing> = (LBRACKET <PIndexing> RBRACKET)+ => ActionFn(126);
+        let __sym0 = __pop_Variant19(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym9.2.clone();
-        let __nt = super::__action147::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8, __sym9);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (10, 30)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action126::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 36)
     }
     pub(crate) fn __reduce58<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PForLoop = FOR, ID, IN, PExpr, COLON, PExpr, RPAREN, PBlock => ActionFn(148);
-        assert!(__symbols.len() >= 8);
-        let __sym7 = __pop_Variant6(__symbols);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant1(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PCallParams = PComma<PExpr> => ActionFn(64);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym7.2.clone();
-        let __nt = super::__action148::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (8, 30)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action64::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 37)
     }
     pub(crate) fn __reduce59<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFuncParam = ID, COLON, PType, PDimension => ActionFn(152);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant20(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PComma<PExpr> = PExpr => ActionFn(207);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action152::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 31)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action207::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 38)
     }
     pub(crate) fn __reduce60<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFuncParam = ID, COLON, PType => ActionFn(153);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant20(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action153::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 31)
+        // PComma<PExpr> =  => ActionFn(208);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action208::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (0, 38)
     }
     pub(crate) fn __reduce61<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFuncParam? = PFuncParam => ActionFn(120);
-        let __sym0 = __pop_Variant3(__symbols);
+        // PComma<PExpr> = (<PExpr> COMMA)+, PExpr => ActionFn(209);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action120::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 32)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action209::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (2, 38)
     }
     pub(crate) fn __reduce62<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFuncParam? =  => ActionFn(121);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action121::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 32)
+        // PComma<PExpr> = (<PExpr> COMMA)+ => ActionFn(210);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action210::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 38)
     }
     pub(crate) fn __reduce63<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunction = PFnSignature, PFunctionBlock => ActionFn(60);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant6(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        // PComma<PFuncParam> = PFuncParam => ActionFn(284);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action60::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (2, 33)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action284::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 39)
     }
     pub(crate) fn __reduce64<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunctionBlock = LCBRACKET, RCBRACKET => ActionFn(166);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action166::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 34)
+        // PComma<PFuncParam> =  => ActionFn(285);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action285::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (0, 39)
     }
     pub(crate) fn __reduce65<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunctionBlock = LCBRACKET, PFunctionItem+, RCBRACKET => ActionFn(167);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant19(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PComma<PFuncParam> = (<PFuncParam> COMMA)+, PFuncParam => ActionFn(286);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action167::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 34)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action286::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (2, 39)
     }
     pub(crate) fn __reduce66<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunctionCall = CALL_ID, PCallParams, RPAREN => ActionFn(61);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant9(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PComma<PFuncParam> = (<PFuncParam> COMMA)+ => ActionFn(287);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action61::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (3, 35)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action287::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 39)
     }
     pub(crate) fn __reduce67<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunctionItem = PStatement => ActionFn(57);
-        let __sym0 = __pop_Variant11(__symbols);
+        // PComma<PStructField> = PStructField => ActionFn(296);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action57::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action296::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 40)
     }
     pub(crate) fn __reduce68<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunctionItem = PVarDeclaration => ActionFn(58);
-        let __sym0 = __pop_Variant25(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action58::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        // PComma<PStructField> =  => ActionFn(297);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action297::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (0, 40)
     }
     pub(crate) fn __reduce69<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunctionItem* =  => ActionFn(95);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action95::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (0, 37)
+        // PComma<PStructField> = (<PStructField> COMMA)+, PStructField => ActionFn(298);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action298::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (2, 40)
     }
     pub(crate) fn __reduce70<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunctionItem* = PFunctionItem+ => ActionFn(96);
-        let __sym0 = __pop_Variant19(__symbols);
+        // PComma<PStructField> = (<PStructField> COMMA)+ => ActionFn(299);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action96::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 37)
+        let __nt = super::__action299::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 40)
     }
     pub(crate) fn __reduce71<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunctionItem+ = PFunctionItem => ActionFn(125);
-        let __sym0 = __pop_Variant11(__symbols);
+        // PComma<PType> = PType => ActionFn(300);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action125::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 38)
+        let __nt = super::__action300::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 41)
     }
     pub(crate) fn __reduce72<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunctionItem+ = PFunctionItem+, PFunctionItem => ActionFn(126);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action126::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (2, 38)
+        // PComma<PType> =  => ActionFn(301);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action301::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (0, 41)
     }
     pub(crate) fn __reduce73<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunctionParams = PComma<PFuncParam> => ActionFn(55);
+        // PComma<PType> = (<PType> COMMA)+, PType => ActionFn(302);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant9(__symbols);
         let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action55::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 39)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action302::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (2, 41)
     }
     pub(crate) fn __reduce74<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunctionType = PType => ActionFn(7);
-        let __sym0 = __pop_Variant20(__symbols);
+        // PComma<PType> = (<PType> COMMA)+ => ActionFn(303);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 40)
+        let __nt = super::__action303::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 41)
     }
     pub(crate) fn __reduce75<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PFunctionType = T_VOID => ActionFn(8);
+        // PCondition = IF, LPAREN, PExpr, RPAREN, PStrictBlock, ELSE, PElseBlock => ActionFn(234);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant14(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant14(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 40)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action234::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (7, 42)
     }
     pub(crate) fn __reduce76<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PGlobalStatement = PFunction => ActionFn(67);
-        let __sym0 = __pop_Variant17(__symbols);
+        // PCondition = IF, LPAREN, PExpr, RPAREN, PStrictBlock => ActionFn(235);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant14(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action67::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 41)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action235::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (5, 42)
     }
     pub(crate) fn __reduce77<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PGlobalStatement = PStatement => ActionFn(68);
-        let __sym0 = __pop_Variant11(__symbols);
+        // PConst = PInt => ActionFn(16);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action68::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 41)
+        let __nt = super::__action16::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 43)
     }
     pub(crate) fn __reduce78<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PGlobalStatement = PVarDeclaration => ActionFn(69);
-        let __sym0 = __pop_Variant25(__symbols);
+        // PConst = PStr => ActionFn(17);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action69::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 41)
+        let __nt = super::__action17::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 43)
     }
     pub(crate) fn __reduce79<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PGlobalStatement* =  => ActionFn(85);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action85::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (0, 42)
-    }
-    pub(crate) fn __reduce80<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // PGlobalStatement* = PGlobalStatement+ => ActionFn(86);
-        let __sym0 = __pop_Variant19(__symbols);
+        // PConst = PFloat => ActionFn(18);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action86::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 42)
+        let __nt = super::__action18::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 43)
     }
-    pub(crate) fn __reduce81<
+    pub(crate) fn __reduce80<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PGlobalStatement+ = PGlobalStatement => ActionFn(129);
-        let __sym0 = __pop_Variant11(__symbols);
+        // PConst = PBool => ActionFn(19);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action129::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        let __nt = super::__action19::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
         (1, 43)
     }
     pub(crate) fn __reduce82<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PGlobalStatement+ = PGlobalStatement+, PGlobalStatement => ActionFn(130);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
+        // PDimension? = PDimension => ActionFn(130);
+        let __sym0 = __pop_Variant29(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action130::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (2, 43)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action130::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 45)
     }
     pub(crate) fn __reduce83<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PId = ID => ActionFn(18);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action18::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 44)
+        // PDimension? =  => ActionFn(131);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action131::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (0, 45)
     }
     pub(crate) fn __reduce84<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PIndexing = PExpr => ActionFn(19);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PElseBlock = PCondition => ActionFn(88);
+        let __sym0 = __pop_Variant28(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (1, 45)
+        let __nt = super::__action88::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 46)
     }
     pub(crate) fn __reduce85<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PInt = INTEGER => ActionFn(9);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PElseBlock = PStrictBlock => ActionFn(89);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action9::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        let __nt = super::__action89::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
         (1, 46)
     }
     pub(crate) fn __reduce86<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PItem = PVarReference => ActionFn(49);
-        let __sym0 = __pop_Variant28(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action49::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 47)
+        // PEmpty =  => ActionFn(26);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action26::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (0, 47)
     }
     pub(crate) fn __reduce87<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PItem = PConst => ActionFn(50);
-        let __sym0 = __pop_Variant8(__symbols);
+        // PExpr = PTier<PExprOp, PLogicExpr> => ActionFn(46);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action50::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 47)
+        let __nt = super::__action46::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 48)
     }
     pub(crate) fn __reduce88<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PItem = LPAREN, PExpr, RPAREN => ActionFn(51);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PExpr = PLambda => ActionFn(47);
+        let __sym0 = __pop_Variant38(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action51::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 47)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action47::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 48)
     }
     pub(crate) fn __reduce89<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PItem = PFunctionCall => ActionFn(52);
-        let __sym0 = __pop_Variant18(__symbols);
+        // PExpr? = PExpr => ActionFn(137);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 47)
+        let __nt = super::__action137::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 49)
     }
     pub(crate) fn __reduce90<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLineStatement = BREAK => ActionFn(72);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action72::<>(input, __sym0);
+        // PExpr? =  => ActionFn(138);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action138::<>(&__start, &__end);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 48)
+        (0, 49)
     }
     pub(crate) fn __reduce91<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLineStatement = CONTINUE => ActionFn(73);
+        // PExprOp = AND => ActionFn(32);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action73::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 48)
+        let __nt = super::__action32::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 50)
     }
     pub(crate) fn __reduce92<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLineStatement = PVarAssignment => ActionFn(74);
-        let __sym0 = __pop_Variant11(__symbols);
+        // PExprOp = OR => ActionFn(33);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action74::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 48)
+        let __nt = super::__action33::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 50)
     }
     pub(crate) fn __reduce93<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLineStatement = PExpr => ActionFn(75);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PFactor = PFactor, PPipeOp, PBoxed<PId> => ActionFn(54);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant31(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action75::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 48)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action54::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 51)
     }
     pub(crate) fn __reduce94<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLogicExpr = PMathExpr, PLogicOp, PMathExpr => ActionFn(41);
+        // PFactor = PFactor, PPipeOp, PFunctionCall => ActionFn(55);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant14(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant35(__symbols);
+        let __sym1 = __pop_Variant31(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action41::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 49)
+        let __nt = super::__action55::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 51)
     }
     pub(crate) fn __reduce95<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLogicExpr = PMathExpr => ActionFn(42);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PFactor = PUnaryOP, PItem => ActionFn(304);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant31(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action42::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 49)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action304::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 51)
     }
     pub(crate) fn __reduce96<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLogicOp = LT => ActionFn(29);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PFactor = PItem => ActionFn(305);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action29::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 50)
+        let __nt = super::__action305::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 51)
     }
     pub(crate) fn __reduce97<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLogicOp = GT => ActionFn(30);
+        // PFactorOp = MUL => ActionFn(42);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action30::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 50)
+        let __nt = super::__action42::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 52)
     }
     pub(crate) fn __reduce98<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLogicOp = LEQ => ActionFn(31);
+        // PFactorOp = DIV => ActionFn(43);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action31::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 50)
+        let __nt = super::__action43::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 52)
     }
     pub(crate) fn __reduce99<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLogicOp = GEQ => ActionFn(32);
+        // PFactorOp = MATMUL => ActionFn(44);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action32::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 50)
+        let __nt = super::__action44::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 52)
     }
     pub(crate) fn __reduce100<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLogicOp = NOTEQUAL => ActionFn(33);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PFloat = FLOAT => ActionFn(263);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action33::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 50)
+        let __nt = super::__action263::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 53)
     }
     pub(crate) fn __reduce101<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLogicOp = EQUAL => ActionFn(34);
+        // PFnSignature = FN, ID, LPAREN, PFunctionParams, RPAREN, COLON, PFunctionType, PParamDimension => ActionFn(290);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant29(__symbols);
+        let __sym6 = __pop_Variant9(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant25(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action34::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 50)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action290::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (8, 54)
     }
     pub(crate) fn __reduce102<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLoop = PForLoop => ActionFn(80);
-        let __sym0 = __pop_Variant11(__symbols);
+        // PFnSignature = FN, ID, LPAREN, PFunctionParams, RPAREN, COLON, PFunctionType => ActionFn(291);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant9(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant25(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action80::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 51)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action291::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (7, 54)
     }
     pub(crate) fn __reduce103<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PLoop = PWhileLoop => ActionFn(81);
-        let __sym0 = __pop_Variant11(__symbols);
+        // PForLoop = FOR, LPAREN, ID, IN, PExpr, COLON, PExpr, COLON, PExpr, RPAREN, PBlock => ActionFn(203);
+        assert!(__symbols.len() >= 11);
+        let __sym10 = __pop_Variant14(__symbols);
+        let __sym9 = __pop_Variant0(__symbols);
+        let __sym8 = __pop_Variant3(__symbols);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant3(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant3(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action81::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 51)
+        let __end = __sym10.2.clone();
+        let __nt = super::__action203::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8, __sym9, __sym10);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (11, 55)
     }
     pub(crate) fn __reduce104<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PMathExpr = PTier<PMathOp, PTerm> => ActionFn(43);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PForLoop = FOR, LPAREN, ID, IN, PExpr, COLON, PExpr, RPAREN, PBlock => ActionFn(204);
+        assert!(__symbols.len() >= 9);
+        let __sym8 = __pop_Variant14(__symbols);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant3(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant3(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action43::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 52)
+        let __end = __sym8.2.clone();
+        let __nt = super::__action204::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (9, 55)
     }
     pub(crate) fn __reduce105<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PMathOp = ADD => ActionFn(35);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PFuncParam = ID, COLON, PType, PParamDimension => ActionFn(292);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant29(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action35::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 53)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action292::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 56)
     }
     pub(crate) fn __reduce106<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PMathOp = SUB => ActionFn(36);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PFuncParam = ID, COLON, PType => ActionFn(293);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action36::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 53)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action293::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 56)
     }
     pub(crate) fn __reduce107<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PPipeOp = PIPE => ActionFn(39);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PFuncParam? = PFuncParam => ActionFn(161);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action39::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 54)
+        let __nt = super::__action161::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (1, 57)
     }
     pub(crate) fn __reduce108<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PProgram =  => ActionFn(168);
+        // PFuncParam? =  => ActionFn(162);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action168::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (0, 55)
+        let __nt = super::__action162::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (0, 57)
     }
     pub(crate) fn __reduce109<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PProgram = PGlobalStatement+ => ActionFn(169);
-        let __sym0 = __pop_Variant19(__symbols);
+        // PFunction = PFnSignature, PStrictBlock => ActionFn(67);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant32(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action169::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 55)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action67::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (2, 58)
     }
     pub(crate) fn __reduce110<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PReturnStatement = RETURN, PExpr, SEMICOLON => ActionFn(62);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PFunctionCall = ID, LPAREN, PCallParams, RPAREN => ActionFn(266);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant23(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action62::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (3, 56)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action266::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (4, 59)
     }
     pub(crate) fn __reduce111<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PStatement = PCondition => ActionFn(63);
-        let __sym0 = __pop_Variant11(__symbols);
+        // PFunctionParams = PComma<PFuncParam> => ActionFn(65);
+        let __sym0 = __pop_Variant25(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action63::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 57)
+        let __nt = super::__action65::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
     }
     pub(crate) fn __reduce112<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PStatement = PLoop => ActionFn(64);
-        let __sym0 = __pop_Variant11(__symbols);
+        // PFunctionType = PType => ActionFn(9);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action64::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 57)
+        let __nt = super::__action9::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 61)
     }
     pub(crate) fn __reduce113<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PStatement = PReturnStatement => ActionFn(65);
-        let __sym0 = __pop_Variant11(__symbols);
+        // PFunctionType = T_VOID => ActionFn(10);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action65::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 57)
+        let __nt = super::__action10::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 61)
     }
     pub(crate) fn __reduce114<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PStatement = PLineStatement, SEMICOLON => ActionFn(66);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
+        // PGlobalStatement = PStruct => ActionFn(80);
+        let __sym0 = __pop_Variant40(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action66::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (2, 57)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action80::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 62)
     }
     pub(crate) fn __reduce115<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PStatement* =  => ActionFn(90);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action90::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (0, 58)
+        // PGlobalStatement = PStatement => ActionFn(81);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action81::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 62)
     }
     pub(crate) fn __reduce116<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PStatement* = PStatement+ => ActionFn(91);
-        let __sym0 = __pop_Variant19(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action91::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 58)
+        // PGlobalStatement* =  => ActionFn(97);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action97::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (0, 63)
     }
     pub(crate) fn __reduce117<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PStatement+ = PStatement => ActionFn(127);
-        let __sym0 = __pop_Variant11(__symbols);
+        // PGlobalStatement* = PGlobalStatement+ => ActionFn(98);
+        let __sym0 = __pop_Variant36(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action127::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 59)
+        let __nt = super::__action98::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 63)
     }
     pub(crate) fn __reduce118<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PStatement+ = PStatement+, PStatement => ActionFn(128);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
+        // PGlobalStatement+ = PGlobalStatement => ActionFn(173);
+        let __sym0 = __pop_Variant28(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action128::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (2, 59)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action173::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 64)
     }
     pub(crate) fn __reduce119<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PStr = STRING => ActionFn(13);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PGlobalStatement+ = PGlobalStatement+, PGlobalStatement => ActionFn(174);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant28(__symbols);
+        let __sym0 = __pop_Variant36(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action13::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 60)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action174::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (2, 64)
     }
     pub(crate) fn __reduce120<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PStrictBlock = LCBRACKET, RCBRACKET => ActionFn(170);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PId = ID => ActionFn(267);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action170::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 61)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action267::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        (1, 65)
     }
     pub(crate) fn __reduce121<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PStrictBlock = LCBRACKET, PStatement+, RCBRACKET => ActionFn(171);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant19(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PIndexing = PExpr => ActionFn(21);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action171::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 61)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action21::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 66)
     }
     pub(crate) fn __reduce122<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PTerm = PTier<PFactorOp, PFactor> => ActionFn(44);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PIndexing = PExpr, COLON, PExpr, COLON, PExpr => ActionFn(217);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant3(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action44::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 62)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action217::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (5, 66)
     }
     pub(crate) fn __reduce123<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PTier<PExprOp, PLogicExpr> = PTier<PExprOp, PLogicExpr>, PExprOp, PLogicExpr => ActionFn(106);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant14(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PIndexing = PExpr, COLON, PExpr, COLON => ActionFn(218);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action106::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 63)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action218::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (4, 66)
     }
     pub(crate) fn __reduce124<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PTier<PExprOp, PLogicExpr> = PLogicExpr => ActionFn(107);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PIndexing = PExpr, COLON, PExpr => ActionFn(219);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action107::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 63)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action219::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (3, 66)
     }
     pub(crate) fn __reduce125<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PTier<PFactorOp, PFactor> = PTier<PFactorOp, PFactor>, PFactorOp, PFactor => ActionFn(102);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant14(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PIndexing = PExpr, COLON, COLON, PExpr => ActionFn(220);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action102::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 64)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action220::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (4, 66)
     }
     pub(crate) fn __reduce126<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PTier<PFactorOp, PFactor> = PFactor => ActionFn(103);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PIndexing = PExpr, COLON, COLON => ActionFn(221);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action103::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 64)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action221::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (3, 66)
     }
     pub(crate) fn __reduce127<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PTier<PMathOp, PTerm> = PTier<PMathOp, PTerm>, PMathOp, PTerm => ActionFn(104);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant14(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PIndexing = PExpr, COLON => ActionFn(222);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action104::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 65)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action222::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (2, 66)
     }
     pub(crate) fn __reduce128<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PTier<PMathOp, PTerm> = PTerm => ActionFn(105);
-        let __sym0 = __pop_Variant1(__symbols);
+        // PIndexing = COLON, PExpr, COLON, PExpr => ActionFn(223);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action105::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 65)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action223::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (4, 66)
     }
     pub(crate) fn __reduce129<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PType = T_INT => ActionFn(1);
+        // PIndexing = COLON, PExpr, COLON => ActionFn(224);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action1::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 66)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action224::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (3, 66)
     }
     pub(crate) fn __reduce130<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PType = T_FLOAT => ActionFn(2);
+        // PIndexing = COLON, PExpr => ActionFn(225);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action2::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 66)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action225::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (2, 66)
     }
     pub(crate) fn __reduce131<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PType = T_BOOl => ActionFn(3);
+        // PIndexing = COLON, COLON, PExpr => ActionFn(226);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action3::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 66)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action226::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (3, 66)
     }
     pub(crate) fn __reduce132<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PType = T_STR => ActionFn(4);
+        // PIndexing = COLON, COLON => ActionFn(227);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action4::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 66)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action227::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (2, 66)
     }
     pub(crate) fn __reduce133<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PType = T_DATAFRAME => ActionFn(5);
+        // PIndexing = COLON => ActionFn(228);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        let __nt = super::__action228::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
         (1, 66)
     }
     pub(crate) fn __reduce134<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PType = T_SERIES => ActionFn(6);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PInt = INTEGER => ActionFn(268);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action6::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 66)
+        let __nt = super::__action268::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 67)
     }
     pub(crate) fn __reduce135<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PUnaryOP = SUB => ActionFn(45);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PItem = PVarReference => ActionFn(57);
+        let __sym0 = __pop_Variant45(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action45::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant0(__nt), __end));
-        (1, 67)
+        let __nt = super::__action57::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 68)
     }
     pub(crate) fn __reduce136<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PUnaryOP = NOT => ActionFn(46);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PItem = PConst => ActionFn(58);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action46::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant0(__nt), __end));
-        (1, 67)
+        let __nt = super::__action58::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 68)
     }
     pub(crate) fn __reduce137<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PUnaryOP? = PUnaryOP => ActionFn(99);
+        // PItem = LPAREN, PExpr, RPAREN => ActionFn(59);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action99::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 68)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action59::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 68)
     }
     pub(crate) fn __reduce138<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PUnaryOP? =  => ActionFn(100);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action100::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (0, 68)
+        // PItem = PFunctionCall => ActionFn(60);
+        let __sym0 = __pop_Variant35(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action60::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 68)
     }
     pub(crate) fn __reduce139<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PVarAssignment = PVarReference, ASSIGN, PExpr => ActionFn(26);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant28(__symbols);
+        // PItem = PVector => ActionFn(61);
+        let __sym0 = __pop_Variant46(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action26::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (3, 69)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action61::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 68)
     }
     pub(crate) fn __reduce140<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PVarDeclaration = LET, ID, COLON, PType, PDimension, ASSIGN, PExpr, SEMICOLON => ActionFn(154);
-        assert!(__symbols.len() >= 8);
-        let __sym7 = __pop_Variant0(__symbols);
-        let __sym6 = __pop_Variant1(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant20(__symbols);
+        // PLambda = BAR, PFunctionParams, BAR, PExpr => ActionFn(269);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant3(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant25(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym7.2.clone();
-        let __nt = super::__action154::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (8, 70)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action269::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
+        (4, 69)
     }
     pub(crate) fn __reduce141<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PVarDeclaration = LET, ID, COLON, PType, ASSIGN, PExpr, SEMICOLON => ActionFn(155);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant1(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant20(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // PLambda = OR, PExpr => ActionFn(270);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action155::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (7, 70)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action270::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
+        (2, 69)
     }
     pub(crate) fn __reduce142<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PVarDeclaration = LET, ID, COLON, PType, PDimension, SEMICOLON => ActionFn(156);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant20(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // PLineStatement = BREAK => ActionFn(84);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action156::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (6, 70)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action84::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 70)
     }
     pub(crate) fn __reduce143<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PVarDeclaration = LET, ID, COLON, PType, SEMICOLON => ActionFn(157);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant20(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // PLineStatement = CONTINUE => ActionFn(85);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action157::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (5, 70)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action85::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 70)
     }
     pub(crate) fn __reduce144<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PVarRefIndex = LBRACKET, PIndexing, RBRACKET => ActionFn(23);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant22(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PLineStatement = PVarAssignment => ActionFn(86);
+        let __sym0 = __pop_Variant28(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action23::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (3, 71)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action86::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 70)
     }
     pub(crate) fn __reduce145<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PVarRefIndex = LBRACKET, PIndexing, RLBRACKET, PIndexing, RBRACKET => ActionFn(24);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant22(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant22(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PLineStatement = PExpr => ActionFn(87);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action24::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (5, 71)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action87::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 70)
     }
     pub(crate) fn __reduce146<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PVarRefIndex? = PVarRefIndex => ActionFn(108);
-        let __sym0 = __pop_Variant26(__symbols);
+        // PLogicExpr = PMathExpr, PLogicOp, PMathExpr => ActionFn(48);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant31(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action108::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (1, 72)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action48::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 71)
     }
     pub(crate) fn __reduce147<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PVarRefIndex? =  => ActionFn(109);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action109::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (0, 72)
+        // PLogicExpr = PMathExpr => ActionFn(49);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action49::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 71)
     }
     pub(crate) fn __reduce148<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PVarReference = ID, PVarRefIndex => ActionFn(174);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant26(__symbols);
+        // PLogicOp = LT => ActionFn(34);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action174::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (2, 73)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action34::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 72)
     }
     pub(crate) fn __reduce149<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PVarReference = ID => ActionFn(175);
+        // PLogicOp = GT => ActionFn(35);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action175::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (1, 73)
+        let __nt = super::__action35::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 72)
     }
     pub(crate) fn __reduce150<
         'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PWhileLoop = WHILE, PExpr, RPAREN, PBlock => ActionFn(83);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant6(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
+        // PLogicOp = LEQ => ActionFn(36);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action83::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (4, 74)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action36::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 72)
     }
-}
-pub use self::__parse__PProgram::PProgramParser;
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __intern_token {
-    #![allow(unused_imports)]
-    use crate::{Dimension};
-    use crate::types::{Operator, Variable};
-    use memory::types::DataType;
-    use crate::functions::{Function, FunctionParam, FunctionSignature};
-    use crate::expressions::{Expression, Index};
-    use crate::expressions::constant::{Const};
-    use crate::expressions::id::{Access, Id};
-    use crate::expressions::operation::Operation;
-    use crate::expressions::call::Call;
-    use crate::statements::{Block, Program, Statement};
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
-    extern crate alloc;
-    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
-        let __strs: &[(&str, bool)] = &[
-            ("^([A-Z_a-z]+[0-9A-Z_a-z]*\\()", false),
-            ("^(\"[\0-!\\#-\u{10ffff}]*\")", false),
-            ("^([0-9]+)", false),
-            ("^([0-9]+\\.[0-9]+)", false),
-            ("^([A-Z_a-z]+[0-9A-Z_a-z]*)", false),
-            ("^(for[\t-\r \u{85}\u{a0}\u{1680}\u{2000}-\u{200a}\u{2028}-\u{2029}\u{202f}\u{205f}\u{3000}]*\\()", false),
-            ("^(if[\t-\r \u{85}\u{a0}\u{1680}\u{2000}-\u{200a}\u{2028}-\u{2029}\u{202f}\u{205f}\u{3000}]*\\()", false),
-            ("^(while[\t-\r \u{85}\u{a0}\u{1680}\u{2000}-\u{200a}\u{2028}-\u{2029}\u{202f}\u{205f}\u{3000}]*\\()", false),
-            ("^(!)", false),
-            ("^(!=)", false),
-            ("^(\\&\\&)", false),
-            ("^(\\()", false),
-            ("^(\\))", false),
-            ("^(\\*)", false),
-            ("^(\\+)", false),
-            ("^(,)", false),
-            ("^(\\-)", false),
-            ("^(/)", false),
-            ("^(:)", false),
-            ("^(;)", false),
-            ("^(<)", false),
-            ("^(<=)", false),
-            ("^(=)", false),
-            ("^(==)", false),
-            ("^(>)", false),
-            ("^(>=)", false),
-            ("^(DataFrame)", false),
-            ("^(Series)", false),
-            ("^(\\[)", false),
-            ("^(\\])", false),
-            ("^(\\]\\[)", false),
-            ("^(bool)", false),
-            ("^(break)", false),
-            ("^(continue)", false),
-            ("^(else)", false),
-            ("^(false)", false),
-            ("^(float)", false),
-            ("^(fn)", false),
-            ("^(for)", false),
-            ("^(if)", false),
-            ("^(in)", false),
-            ("^(int)", false),
-            ("^(let)", false),
-            ("^(return)", false),
-            ("^(str)", false),
-            ("^(true)", false),
-            ("^(void)", false),
-            ("^(while)", false),
-            ("^(\\{)", false),
-            ("^(\\|>)", false),
-            ("^(\\|\\|)", false),
-            ("^(\\})", false),
-            (r"^(\s*)", true),
-        ];
-        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
+    pub(crate) fn __reduce151<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PLogicOp = GEQ => ActionFn(37);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action37::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 72)
+    }
+    pub(crate) fn __reduce152<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PLogicOp = NOTEQUAL => ActionFn(38);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action38::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 72)
+    }
+    pub(crate) fn __reduce153<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PLogicOp = EQUAL => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action39::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 72)
+    }
+    pub(crate) fn __reduce154<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PLoop = PForLoop => ActionFn(92);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action92::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 73)
+    }
+    pub(crate) fn __reduce155<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PLoop = PWhileLoop => ActionFn(93);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action93::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 73)
+    }
+    pub(crate) fn __reduce156<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PMathExpr = PTier<PMathOp, PTerm> => ActionFn(50);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action50::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 74)
+    }
+    pub(crate) fn __reduce157<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PMathOp = ADD => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action40::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 75)
+    }
+    pub(crate) fn __reduce158<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PMathOp = SUB => ActionFn(41);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action41::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 75)
+    }
+    pub(crate) fn __reduce159<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PParamDimension = PDimension => ActionFn(24);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action24::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 76)
+    }
+    pub(crate) fn __reduce160<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PParamDimension = PBrackets<PEmpty> => ActionFn(25);
+        let __sym0 = __pop_Variant22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action25::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 76)
+    }
+    pub(crate) fn __reduce161<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PParamDimension? = PParamDimension => ActionFn(109);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action109::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 77)
+    }
+    pub(crate) fn __reduce162<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PParamDimension? =  => ActionFn(110);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action110::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (0, 77)
+    }
+    pub(crate) fn __reduce163<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PPipeOp = PIPE => ActionFn(45);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action45::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 78)
+    }
+    pub(crate) fn __reduce164<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PProgram =  => ActionFn(288);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action288::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
+        (0, 79)
+    }
+    pub(crate) fn __reduce165<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PProgram = PGlobalStatement+ => ActionFn(289);
+        let __sym0 = __pop_Variant36(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action289::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
+        (1, 79)
+    }
+    pub(crate) fn __reduce166<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PReturnStatement = RETURN, PExpr, SEMICOLON => ActionFn(71);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action71::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (3, 80)
+    }
+    pub(crate) fn __reduce167<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStatement = PCondition => ActionFn(72);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action72::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 81)
+    }
+    pub(crate) fn __reduce168<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStatement = PLoop => ActionFn(73);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action73::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 81)
+    }
+    pub(crate) fn __reduce169<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStatement = PReturnStatement => ActionFn(74);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action74::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 81)
+    }
+    pub(crate) fn __reduce170<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStatement = PVarDeclaration => ActionFn(75);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action75::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 81)
+    }
+    pub(crate) fn __reduce171<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStatement = PFunction => ActionFn(76);
+        let __sym0 = __pop_Variant34(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action76::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 81)
+    }
+    pub(crate) fn __reduce172<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStatement = PLineStatement, SEMICOLON => ActionFn(77);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action77::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (2, 81)
+    }
+    pub(crate) fn __reduce173<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStatement* =  => ActionFn(102);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action102::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (0, 82)
+    }
+    pub(crate) fn __reduce174<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStatement* = PStatement+ => ActionFn(103);
+        let __sym0 = __pop_Variant36(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action103::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 82)
+    }
+    pub(crate) fn __reduce175<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStatement+ = PStatement => ActionFn(171);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action171::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 83)
+    }
+    pub(crate) fn __reduce176<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStatement+ = PStatement+, PStatement => ActionFn(172);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant28(__symbols);
+        let __sym0 = __pop_Variant36(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action172::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (2, 83)
+    }
+    pub(crate) fn __reduce177<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStr = STRING => ActionFn(271);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action271::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 84)
+    }
+    pub(crate) fn __reduce178<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStrictBlock = LCBRACKET, RCBRACKET => ActionFn(294);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action294::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (2, 85)
+    }
+    pub(crate) fn __reduce179<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStrictBlock = LCBRACKET, PStatement+, RCBRACKET => ActionFn(295);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant36(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action295::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (3, 85)
+    }
+    pub(crate) fn __reduce180<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStruct = STRUCT, ID, LCBRACKET, PComma<PStructField>, RCBRACKET => ActionFn(272);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant26(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action272::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (5, 86)
+    }
+    pub(crate) fn __reduce181<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStructField = ID, COLON, PType => ActionFn(273);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action273::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 87)
+    }
+    pub(crate) fn __reduce182<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStructField? = PStructField => ActionFn(166);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action166::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant41(__nt), __end));
+        (1, 88)
+    }
+    pub(crate) fn __reduce183<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PStructField? =  => ActionFn(167);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action167::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant41(__nt), __end));
+        (0, 88)
+    }
+    pub(crate) fn __reduce184<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PTerm = PTier<PFactorOp, PFactor> => ActionFn(51);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action51::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 89)
+    }
+    pub(crate) fn __reduce185<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PTier<PExprOp, PLogicExpr> = PTier<PExprOp, PLogicExpr>, PExprOp, PLogicExpr => ActionFn(119);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant31(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action119::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 90)
+    }
+    pub(crate) fn __reduce186<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PTier<PExprOp, PLogicExpr> = PLogicExpr => ActionFn(120);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action120::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 90)
+    }
+    pub(crate) fn __reduce187<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PTier<PFactorOp, PFactor> = PTier<PFactorOp, PFactor>, PFactorOp, PFactor => ActionFn(115);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant31(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action115::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 91)
+    }
+    pub(crate) fn __reduce188<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PTier<PFactorOp, PFactor> = PFactor => ActionFn(116);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action116::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 91)
+    }
+    pub(crate) fn __reduce189<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PTier<PMathOp, PTerm> = PTier<PMathOp, PTerm>, PMathOp, PTerm => ActionFn(117);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant31(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action117::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 92)
+    }
+    pub(crate) fn __reduce190<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PTier<PMathOp, PTerm> = PTerm => ActionFn(118);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action118::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 92)
+    }
+    pub(crate) fn __reduce191<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PType = T_INT => ActionFn(1);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action1::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 93)
+    }
+    pub(crate) fn __reduce192<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PType = T_FLOAT => ActionFn(2);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 93)
+    }
+    pub(crate) fn __reduce193<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PType = T_BOOl => ActionFn(3);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action3::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 93)
+    }
+    pub(crate) fn __reduce194<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PType = T_STR => ActionFn(4);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action4::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 93)
+    }
+    pub(crate) fn __reduce195<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PType = T_DATAFRAME => ActionFn(5);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action5::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 93)
+    }
+    pub(crate) fn __reduce196<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PType = T_SERIES => ActionFn(6);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 93)
+    }
+    pub(crate) fn __reduce197<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PType = ID => ActionFn(7);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action7::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 93)
+    }
+    pub(crate) fn __reduce198<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PType = FN, LPAREN, PComma<PType>, RPAREN, COLON, PFunctionType => ActionFn(8);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant9(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant27(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action8::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (6, 93)
+    }
+    pub(crate) fn __reduce199<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PType? = PType => ActionFn(142);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action142::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
+        (1, 94)
+    }
+    pub(crate) fn __reduce200<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PType? =  => ActionFn(143);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action143::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
+        (0, 94)
+    }
+    pub(crate) fn __reduce201<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PUnaryOP = SUB => ActionFn(52);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action52::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 95)
+    }
+    pub(crate) fn __reduce202<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PUnaryOP = NOT => ActionFn(53);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action53::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 95)
+    }
+    pub(crate) fn __reduce203<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PUnaryOP? = PUnaryOP => ActionFn(112);
+        let __sym0 = __pop_Variant31(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action112::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant43(__nt), __end));
+        (1, 96)
+    }
+    pub(crate) fn __reduce204<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PUnaryOP? =  => ActionFn(113);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action113::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant43(__nt), __end));
+        (0, 96)
+    }
+    pub(crate) fn __reduce205<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PVarAssignment = PVarReference, ASSIGN, PExpr => ActionFn(31);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant45(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action31::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (3, 97)
+    }
+    pub(crate) fn __reduce210<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PVarDeclaration = LET, ID, ASSIGN, PExpr, SEMICOLON => ActionFn(276);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action276::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (5, 98)
+    }
+    pub(crate) fn __reduce211<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PVarRefIndex = PBrackets<PIndexing> => ActionFn(29);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action29::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 99)
+    }
+    pub(crate) fn __reduce212<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PVarRefIndex? = PVarRefIndex => ActionFn(124);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action124::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (1, 100)
+    }
+    pub(crate) fn __reduce213<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PVarRefIndex? =  => ActionFn(125);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action125::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (0, 100)
+    }
+    pub(crate) fn __reduce214<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PVarReference = ID, PVarRefIndex => ActionFn(306);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant24(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action306::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (2, 101)
+    }
+    pub(crate) fn __reduce215<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PVarReference = ID => ActionFn(307);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action307::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (1, 101)
+    }
+    pub(crate) fn __reduce216<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PVarReference = ID, PVarRefIndex, (DOT <ID>)+ => ActionFn(308);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant24(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action308::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (3, 101)
+    }
+    pub(crate) fn __reduce217<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PVarReference = ID, (DOT <ID>)+ => ActionFn(309);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action309::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (2, 101)
+    }
+    pub(crate) fn __reduce218<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PVector = LBRACKET, PComma<PExpr>, RBRACKET => ActionFn(279);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant23(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action279::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant46(__nt), __end));
+        (3, 102)
+    }
+    pub(crate) fn __reduce219<
+        'input,
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PWhileLoop = WHILE, LPAREN, PExpr, RPAREN, PBlock => ActionFn(95);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant14(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action95::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (5, 103)
+    }
+}
+pub use self::__parse__PProgram::PProgramParser;
+
+fn __action0<
+    'input,
+>(
+    (_, __0, _): (usize, Program, usize),
+) -> Program
+{
+    __0
+}
+
+fn __action1<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> DataType
+{
+    DataType::Int
+}
+
+fn __action2<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> DataType
+{
+    DataType::Float
+}
+
+fn __action3<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> DataType
+{
+    DataType::Bool
+}
+
+fn __action4<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> DataType
+{
+    DataType::String
+}
+
+fn __action5<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> DataType
+{
+    DataType::DataFrame
+}
+
+fn __action6<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> DataType
+{
+    DataType::Series
+}
+
+fn __action7<
+    'input,
+>(
+    (_, __0, _): (usize, &'input str, usize),
+) -> DataType
+{
+    DataType::Struct(String::from(__0))
+}
+
+fn __action8<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, params, _): (usize, Vec<DataType>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, data_type, _): (usize, DataType, usize),
+) -> DataType
+{
+    DataType::Function(params, Box::new(data_type))
+}
+
+fn __action9<
+    'input,
+>(
+    (_, __0, _): (usize, DataType, usize),
+) -> DataType
+{
+    __0
+}
+
+fn __action10<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> DataType
+{
+    DataType::Void
+}
+
+fn __action11<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, i, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Const
+{
+    Const {
+    span: Span::new(l, r),
+    ..Const::new(i, DataType::Int)
+}
+}
+
+fn __action12<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, f, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Const
+{
+    Const {
+    span: Span::new(l, r),
+    ..Const::new(f, DataType::Float)
+}
+}
+
+fn __action13<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Const
+{
+    Const { span: Span::new(l, r), ..Const::new("true", DataType::Bool) }
+}
+
+fn __action14<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Const
+{
+    Const { span: Span::new(l, r), ..Const::new("false", DataType::Bool) }
+}
+
+fn __action15<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, s, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Const
+{
+    Const {
+    span: Span::new(l, r),
+    ..Const::new(&s, DataType::String)
+}
+}
+
+fn __action16<
+    'input,
+>(
+    (_, __0, _): (usize, Const, usize),
+) -> Const
+{
+    __0
+}
+
+fn __action17<
+    'input,
+>(
+    (_, __0, _): (usize, Const, usize),
+) -> Const
+{
+    __0
+}
+
+fn __action18<
+    'input,
+>(
+    (_, __0, _): (usize, Const, usize),
+) -> Const
+{
+    __0
+}
+
+fn __action19<
+    'input,
+>(
+    (_, __0, _): (usize, Const, usize),
+) -> Const
+{
+    __0
+}
+
+fn __action20<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Expression
+{
+    Expression::Access(Access {
+        span: Span::new(l, r),
+        ..Access::new(Id::new(id, None), vec![])
+    })
+}
+
+fn __action21<
+    'input,
+>(
+    (_, idx, _): (usize, Box<Expression>, usize),
+) -> Index
+{
+    Index::Simple(idx)
+}
+
+fn __action22<
+    'input,
+>(
+    (_, start, _): (usize, core::option::Option<Box<Expression>>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, end, _): (usize, core::option::Option<Box<Expression>>, usize),
+    (_, step, _): (usize, core::option::Option<core::option::Option<Box<Expression>>>, usize),
+) -> Index
+{
+    Index::Range {
+        start,
+        end,
+        step: step.flatten(),
+    }
+}
+
+fn __action23<
+    'input,
+>(
+    (_, __0, _): (usize, Vec<Box<Expression>>, usize),
+) -> Result<Dimension,__lalrpop_util::ParseError<usize,Token<'input>,SyntaxError>>
+{
+    Dimension::from_lengths(__0)
+    .map_err(|error| ParseError::User { error })
+}
+
+fn __action24<
+    'input,
+>(
+    (_, __0, _): (usize, Dimension, usize),
+) -> Dimension
+{
+    __0
+}
+
+fn __action25<
+    'input,
+>(
+    (_, __0, _): (usize, Vec<()>, usize),
+) -> Dimension
+{
+    Dimension::new_runtime(__0.len() as i8)
+}
+
+fn __action26<
+    'input,
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ()
+{
+    ()
+}
+
+fn __action27<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, data_type, _): (usize, DataType, usize),
+    (_, dim, _): (usize, core::option::Option<Dimension>, usize),
+    (_, value, _): (usize, core::option::Option<Box<Expression>>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Result<Variable,__lalrpop_util::ParseError<usize,Token<'input>,SyntaxError>>
+{
+    {
+        let dim = dim.unwrap_or(Dimension::new_scalar());
+        if let Some(value) = &value {
+            dim.check_value(value).map_err(|message| ParseError::User {
+                error: SyntaxError { message, span: value.span(), unexpected_eof: false },
+            })?;
+        }
+
+        Ok(Variable {
+            span: Span::new(l, r),
+            ..Variable::new(String::from(id), data_type, dim, value)
+        })
+    }
+}
+
+fn __action28<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, value, _): (usize, Box<Expression>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Variable
+{
+    Variable {
+        span: Span::new(l, r),
+        ..Variable::new_inferred(String::from(id), value)
+    }
+}
+
+fn __action29<
+    'input,
+>(
+    (_, __0, _): (usize, Vec<Index>, usize),
+) -> Vec<Index>
+{
+    __0
+}
+
+fn __action30<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, &'input str, usize),
+    (_, indexing, _): (usize, core::option::Option<Vec<Index>>, usize),
+    (_, fields, _): (usize, alloc::vec::Vec<&'input str>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Access
+{
+    Access {
+        span: Span::new(l, r),
+        fields: fields.into_iter().map(String::from).collect(),
+        ..Access::new(
+            Id::new(id, None),
+            indexing.unwrap_or(vec![])
+        )
+    }
+}
+
+fn __action31<
+    'input,
+>(
+    (_, var, _): (usize, Access, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, exp, _): (usize, Box<Expression>, usize),
+) -> Statement
+{
+    Statement::VarAssign(var, exp)
+}
+
+fn __action32<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::And
+}
+
+fn __action33<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::Or
+}
+
+fn __action34<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::LessThan
+}
+
+fn __action35<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::GreaterThan
+}
+
+fn __action36<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::LessOrEq
+}
+
+fn __action37<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::GreaterOrEq
+}
+
+fn __action38<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::NotEq
+}
+
+fn __action39<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::Eq
+}
+
+fn __action40<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::Add
+}
+
+fn __action41<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::Sub
+}
+
+fn __action42<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::Mul
+}
+
+fn __action43<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::Div
+}
+
+fn __action44<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::MatMul
+}
+
+fn __action45<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::Pipe
+}
+
+fn __action46<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    __0
+}
+
+fn __action47<
+    'input,
+>(
+    (_, __0, _): (usize, Lambda, usize),
+) -> Box<Expression>
+{
+    Box::new(Expression::Lambda(__0))
+}
+
+fn __action48<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+    (_, __1, _): (usize, Operator, usize),
+    (_, __2, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    Box::new(
+            Expression::Op(
+                Operation::new(__0, __1, __2)
+            )
+        )
+}
+
+fn __action49<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    __0
+}
+
+fn __action50<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    __0
+}
+
+fn __action51<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    __0
+}
+
+fn __action52<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::Neg
+}
+
+fn __action53<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Operator
+{
+    Operator::Not
+}
+
+fn __action54<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+    (_, __1, _): (usize, Operator, usize),
+    (_, __2, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    Box::new(
+            Expression::Op(
+                Operation::new(__0, __1, __2)
+            )
+        )
+}
+
+fn __action55<
+    'input,
+>(
+    (_, value, _): (usize, Box<Expression>, usize),
+    (_, op, _): (usize, Operator, usize),
+    (_, call, _): (usize, Call, usize),
+) -> Box<Expression>
+{
+    Box::new(
+            Expression::Op(
+                Operation::new(value, op, Box::new(Expression::Call(call)))
+            )
+        )
+}
+
+fn __action56<
+    'input,
+>(
+    (_, op, _): (usize, core::option::Option<Operator>, usize),
+    (_, item, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    match op {
+        None => item,
+        Some(Operator::Not) => Box::new(Expression::Not(item)),
+        Some(_) => Box::new(Expression::Negative(item)),
+    }
+}
+
+fn __action57<
+    'input,
+>(
+    (_, __0, _): (usize, Access, usize),
+) -> Box<Expression>
+{
+    Box::new(Expression::Access(__0))
+}
+
+fn __action58<
+    'input,
+>(
+    (_, __0, _): (usize, Const, usize),
+) -> Box<Expression>
+{
+    Box::new(Expression::Const(__0))
+}
+
+fn __action59<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, Box<Expression>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Box<Expression>
+{
+    __0
+}
+
+fn __action60<
+    'input,
+>(
+    (_, __0, _): (usize, Call, usize),
+) -> Box<Expression>
+{
+    Box::new(Expression::Call(__0))
+}
+
+fn __action61<
+    'input,
+>(
+    (_, __0, _): (usize, Vector, usize),
+) -> Box<Expression>
+{
+    Box::new(Expression::Vector(__0))
+}
+
+fn __action62<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, items, _): (usize, Vec<Box<Expression>>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Vector
+{
+    Vector {
+    span: Span::new(l, r),
+    ..Vector::new(items)
+}
+}
+
+fn __action63<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, dtype, _): (usize, DataType, usize),
+    (_, dim, _): (usize, core::option::Option<Dimension>, usize),
+) -> FunctionParam
+{
+    FunctionParam(Variable {
+        span: Span::new(l, r),
+        ..Variable::new(
+            String::from(id),
+            dtype,
+            dim.unwrap_or(Dimension::new_scalar()),
+            None
+        )
+    })
+}
+
+fn __action64<
+    'input,
+>(
+    (_, __0, _): (usize, Vec<Box<Expression>>, usize),
+) -> Vec<Box<Expression>>
+{
+    __0
+}
+
+fn __action65<
+    'input,
+>(
+    (_, __0, _): (usize, Vec<FunctionParam>, usize),
+) -> Vec<FunctionParam>
+{
+    __0
+}
+
+fn __action66<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, params, _): (usize, Vec<FunctionParam>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, dtype, _): (usize, DataType, usize),
+    (_, dim, _): (usize, core::option::Option<Dimension>, usize),
+) -> FunctionSignature
+{
+    FunctionSignature {
+    id:  String::from(id),
+    data_type: dtype,
+    dimension: dim.unwrap_or(Dimension::new_scalar()),
+    params: params,
+    is_native: false,
+    span: Span::new(l, r)
+}
+}
+
+fn __action67<
+    'input,
+>(
+    (_, signature, _): (usize, FunctionSignature, usize),
+    (_, block, _): (usize, Block, usize),
+) -> Function
+{
+    Function::new(signature, block)
+}
+
+fn __action68<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, params, _): (usize, Vec<Box<Expression>>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Call
+{
+    Call {
+    span: Span::new(l, r),
+    ..Call::new(id, params)
+}
+}
+
+fn __action69<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, params, _): (usize, Vec<FunctionParam>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, body, _): (usize, Box<Expression>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Lambda
+{
+    Lambda {
+        span: Span::new(l, r),
+        ..Lambda::new(params, body)
+    }
+}
+
+fn __action70<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, body, _): (usize, Box<Expression>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Lambda
+{
+    Lambda {
+        span: Span::new(l, r),
+        ..Lambda::new(vec![], body)
+    }
+}
+
+fn __action71<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, Box<Expression>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Statement
+{
+    Statement::Return(__0)
+}
+
+fn __action72<
+    'input,
+>(
+    (_, __0, _): (usize, Statement, usize),
+) -> Statement
+{
+    __0
+}
+
+fn __action73<
+    'input,
+>(
+    (_, __0, _): (usize, Statement, usize),
+) -> Statement
+{
+    __0
+}
+
+fn __action74<
+    'input,
+>(
+    (_, __0, _): (usize, Statement, usize),
+) -> Statement
+{
+    __0
+}
+
+fn __action75<
+    'input,
+>(
+    (_, __0, _): (usize, Variable, usize),
+) -> Statement
+{
+    Statement::VarDeclaration(__0)
+}
+
+fn __action76<
+    'input,
+>(
+    (_, __0, _): (usize, Function, usize),
+) -> Statement
+{
+    Statement::FunctionDeclaration(__0)
+}
+
+fn __action77<
+    'input,
+>(
+    (_, __0, _): (usize, Statement, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Statement
+{
+    __0
+}
+
+fn __action78<
+    'input,
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, data_type, _): (usize, DataType, usize),
+) -> Variable
+{
+    Variable {
+    span: Span::new(l, r),
+    ..Variable::new_scalar(id, data_type)
+}
+}
+
+fn __action79<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, id, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, fields, _): (usize, Vec<Variable>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Struct
+{
+    Struct {
+    span: Span::new(l, r),
+    ..Struct::new(id, fields)
+}
+}
+
+fn __action80<
+    'input,
+>(
+    (_, __0, _): (usize, Struct, usize),
+) -> Statement
+{
+    Statement::StructDeclaration(__0)
+}
+
+fn __action81<
+    'input,
+>(
+    (_, __0, _): (usize, Statement, usize),
+) -> Statement
+{
+    __0
+}
+
+fn __action82<
+    'input,
+>(
+    (_, __0, _): (usize, Statement, usize),
+) -> Block
+{
+    Block(vec![__0])
+}
+
+fn __action83<
+    'input,
+>(
+    (_, __0, _): (usize, Block, usize),
+) -> Block
+{
+    __0
+}
+
+fn __action84<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Statement
+{
+    Statement::Break
+}
+
+fn __action85<
+    'input,
+>(
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> Statement
+{
+    Statement::Continue
+}
+
+fn __action86<
+    'input,
+>(
+    (_, __0, _): (usize, Statement, usize),
+) -> Statement
+{
+    __0
+}
+
+fn __action87<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> Statement
+{
+    Statement::Expression(__0)
+}
+
+fn __action88<
+    'input,
+>(
+    (_, __0, _): (usize, Statement, usize),
+) -> Block
+{
+    Block(vec![__0])
+}
+
+fn __action89<
+    'input,
+>(
+    (_, __0, _): (usize, Block, usize),
+) -> Block
+{
+    __0
+}
+
+fn __action90<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, condition, _): (usize, Box<Expression>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, if_block, _): (usize, Block, usize),
+    (_, else_block, _): (usize, core::option::Option<Block>, usize),
+) -> Statement
+{
+    Statement::If {
+            condition: condition,
+            if_block: if_block,
+            else_block: else_block
+        }
+}
+
+fn __action91<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, statements, _): (usize, alloc::vec::Vec<Statement>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Block
+{
+    Block(statements)
+}
+
+fn __action92<
+    'input,
+>(
+    (_, __0, _): (usize, Statement, usize),
+) -> Statement
+{
+    __0
+}
+
+fn __action93<
+    'input,
+>(
+    (_, __0, _): (usize, Statement, usize),
+) -> Statement
+{
+    __0
+}
+
+fn __action94<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, id, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, start, _): (usize, Box<Expression>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, end, _): (usize, Box<Expression>, usize),
+    (_, step, _): (usize, core::option::Option<Box<Expression>>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, block, _): (usize, Block, usize),
+) -> Statement
+{
+    Statement::For {
+        iterator_id: String::from(id),
+        range: (start, end, step),
+        block: block
+    }
+}
+
+fn __action95<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, condition, _): (usize, Box<Expression>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, block, _): (usize, Block, usize),
+) -> Statement
+{
+    Statement::While {
+        condition: condition,
+        block: block
+    }
+}
+
+fn __action96<
+    'input,
+>(
+    (_, __0, _): (usize, alloc::vec::Vec<Statement>, usize),
+) -> Program
+{
+    Program(__0)
+}
+
+fn __action97<
+    'input,
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Statement>
+{
+    alloc::vec![]
+}
+
+fn __action98<
+    'input,
+>(
+    (_, v, _): (usize, alloc::vec::Vec<Statement>, usize),
+) -> alloc::vec::Vec<Statement>
+{
+    v
+}
+
+fn __action99<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> core::option::Option<Box<Expression>>
+{
+    Some(__0)
+}
+
+fn __action100<
+    'input,
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Box<Expression>>
+{
+    None
+}
+
+fn __action101<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    __0
+}
+
+fn __action102<
+    'input,
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Statement>
+{
+    alloc::vec![]
+}
+
+fn __action103<
+    'input,
+>(
+    (_, v, _): (usize, alloc::vec::Vec<Statement>, usize),
+) -> alloc::vec::Vec<Statement>
+{
+    v
+}
+
+fn __action104<
+    'input,
+>(
+    (_, __0, _): (usize, Block, usize),
+) -> core::option::Option<Block>
+{
+    Some(__0)
+}
+
+fn __action105<
+    'input,
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Block>
+{
+    None
+}
+
+fn __action106<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, Block, usize),
+) -> Block
+{
+    __0
+}
+
+fn __action107<
+    'input,
+>(
+    (_, mut vec, _): (usize, alloc::vec::Vec<Variable>, usize),
+    (_, c, _): (usize, core::option::Option<Variable>, usize),
+) -> Vec<Variable>
+{
+    match c {
+        None => vec,
+        Some(c) => {
+            vec.push(c);
+            vec
+        }
+    }
+}
+
+fn __action108<
+    'input,
+>(
+    (_, mut vec, _): (usize, alloc::vec::Vec<FunctionParam>, usize),
+    (_, c, _): (usize, core::option::Option<FunctionParam>, usize),
+) -> Vec<FunctionParam>
+{
+    match c {
+        None => vec,
+        Some(c) => {
+            vec.push(c);
+            vec
+        }
+    }
+}
+
+fn __action109<
+    'input,
+>(
+    (_, __0, _): (usize, Dimension, usize),
+) -> core::option::Option<Dimension>
+{
+    Some(__0)
+}
+
+fn __action110<
+    'input,
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Dimension>
+{
+    None
+}
+
+fn __action111<
+    'input,
+>(
+    (_, mut vec, _): (usize, alloc::vec::Vec<Box<Expression>>, usize),
+    (_, c, _): (usize, core::option::Option<Box<Expression>>, usize),
+) -> Vec<Box<Expression>>
+{
+    match c {
+        None => vec,
+        Some(c) => {
+            vec.push(c);
+            vec
+        }
     }
 }
-pub(crate) use self::__lalrpop_util::lexer::Token;
 
-#[allow(unused_variables)]
-fn __action0<
+fn __action112<
+    'input,
+>(
+    (_, __0, _): (usize, Operator, usize),
+) -> core::option::Option<Operator>
+{
+    Some(__0)
+}
+
+fn __action113<
+    'input,
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Operator>
+{
+    None
+}
+
+fn __action114<
+    'input,
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Box<Expression>
+{
+    Box::new(__0)
+}
+
+fn __action115<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+    (_, __1, _): (usize, Operator, usize),
+    (_, __2, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    Box::new(
+            Expression::Op(
+                Operation::new(__0, __1, __2)
+            )
+        )
+}
+
+fn __action116<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    __0
+}
+
+fn __action117<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+    (_, __1, _): (usize, Operator, usize),
+    (_, __2, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    Box::new(
+            Expression::Op(
+                Operation::new(__0, __1, __2)
+            )
+        )
+}
+
+fn __action118<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    __0
+}
+
+fn __action119<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+    (_, __1, _): (usize, Operator, usize),
+    (_, __2, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    Box::new(
+            Expression::Op(
+                Operation::new(__0, __1, __2)
+            )
+        )
+}
+
+fn __action120<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
+{
+    __0
+}
+
+fn __action121<
+    'input,
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<&'input str>
+{
+    alloc::vec![]
+}
+
+fn __action122<
+    'input,
+>(
+    (_, v, _): (usize, alloc::vec::Vec<&'input str>, usize),
+) -> alloc::vec::Vec<&'input str>
+{
+    v
+}
+
+fn __action123<
+    'input,
+>(
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    __0
+}
+
+fn __action124<
+    'input,
+>(
+    (_, __0, _): (usize, Vec<Index>, usize),
+) -> core::option::Option<Vec<Index>>
+{
+    Some(__0)
+}
+
+fn __action125<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Program, usize),
-) -> Program
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Vec<Index>>
 {
-    __0
+    None
 }
 
-#[allow(unused_variables)]
-fn __action1<
+fn __action126<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> DataType
+    (_, __0, _): (usize, alloc::vec::Vec<Index>, usize),
+) -> Vec<Index>
 {
-    DataType::Int
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action2<
+fn __action127<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> DataType
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> core::option::Option<Box<Expression>>
 {
-    DataType::Float
+    Some(__0)
 }
 
-#[allow(unused_variables)]
-fn __action3<
+fn __action128<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> DataType
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Box<Expression>>
 {
-    DataType::Bool
+    None
 }
 
-#[allow(unused_variables)]
-fn __action4<
+fn __action129<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> DataType
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> Box<Expression>
 {
-    DataType::String
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action5<
+fn __action130<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> DataType
+    (_, __0, _): (usize, Dimension, usize),
+) -> core::option::Option<Dimension>
 {
-    DataType::DataFrame
+    Some(__0)
 }
 
-#[allow(unused_variables)]
-fn __action6<
+fn __action131<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> DataType
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Dimension>
 {
-    DataType::Series
+    None
 }
 
-#[allow(unused_variables)]
-fn __action7<
+fn __action132<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, DataType, usize),
-) -> DataType
+    (_, __0, _): (usize, alloc::vec::Vec<()>, usize),
+) -> Vec<()>
 {
     __0
 }
 
-#[allow(unused_variables)]
-fn __action8<
+fn __action133<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> DataType
+    (_, __0, _): (usize, alloc::vec::Vec<Box<Expression>>, usize),
+) -> Vec<Box<Expression>>
 {
-    DataType::Void
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action9<
+fn __action134<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Const
+    (_, __0, _): (usize, core::option::Option<Box<Expression>>, usize),
+) -> core::option::Option<core::option::Option<Box<Expression>>>
 {
-    Const::new(__0, DataType::Int)
+    Some(__0)
 }
 
-#[allow(unused_variables)]
-fn __action10<
+fn __action135<
     'input,
 >(
-    input: &'input str,
-    (_, f, _): (usize, &'input str, usize),
-) -> Const
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<core::option::Option<Box<Expression>>>
 {
-    Const::new(f, DataType::Float)
+    None
 }
 
-#[allow(unused_variables)]
-fn __action11<
+fn __action136<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Const
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, core::option::Option<Box<Expression>>, usize),
+) -> core::option::Option<Box<Expression>>
 {
-    Const::new(__0, DataType::Bool)
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action12<
+fn __action137<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Const
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> core::option::Option<Box<Expression>>
 {
-    Const::new(__0, DataType::Bool)
+    Some(__0)
 }
 
-#[allow(unused_variables)]
-fn __action13<
+fn __action138<
     'input,
 >(
-    input: &'input str,
-    (_, s, _): (usize, &'input str, usize),
-) -> Const
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Box<Expression>>
 {
-    Const::new(&s[1..s.len() - 1], DataType::String)
+    None
 }
 
-#[allow(unused_variables)]
-fn __action14<
+fn __action139<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Const, usize),
-) -> Const
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
 {
-    __0
+    __lookbehind.clone()
 }
 
-#[allow(unused_variables)]
-fn __action15<
+fn __action140<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Const, usize),
-) -> Const
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
 {
-    __0
+    __lookahead.clone()
 }
 
-#[allow(unused_variables)]
-fn __action16<
+fn __action141<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Const, usize),
-) -> Const
+    (_, mut vec, _): (usize, alloc::vec::Vec<DataType>, usize),
+    (_, c, _): (usize, core::option::Option<DataType>, usize),
+) -> Vec<DataType>
 {
-    __0
+    match c {
+        None => vec,
+        Some(c) => {
+            vec.push(c);
+            vec
+        }
+    }
 }
 
-#[allow(unused_variables)]
-fn __action17<
+fn __action142<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Const, usize),
-) -> Const
+    (_, __0, _): (usize, DataType, usize),
+) -> core::option::Option<DataType>
 {
-    __0
+    Some(__0)
 }
 
-#[allow(unused_variables)]
-fn __action18<
+fn __action143<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Expression
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<DataType>
 {
-    Expression::Access(Access::new(
-        Id::new(__0, None),
-        vec![],
-    ))
+    None
 }
 
-#[allow(unused_variables)]
-fn __action19<
+fn __action144<
     'input,
 >(
-    input: &'input str,
-    (_, idx, _): (usize, Box<Expression>, usize),
-) -> Index
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<DataType>
 {
-    Index::Simple(idx)
+    alloc::vec![]
 }
 
-#[allow(unused_variables)]
-fn __action20<
+fn __action145<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, a, _): (usize, Const, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, b, _): (usize, Const, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Dimension
+    (_, v, _): (usize, alloc::vec::Vec<DataType>, usize),
+) -> alloc::vec::Vec<DataType>
 {
-    Dimension::new(2, vec![a, b])
+    v
 }
 
-#[allow(unused_variables)]
-fn __action21<
+fn __action146<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Const, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Dimension
+    (_, __0, _): (usize, DataType, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> DataType
 {
-    Dimension::new(1, vec![__0])
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action22<
+fn __action147<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, id, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, data_type, _): (usize, DataType, usize),
-    (_, dim, _): (usize, core::option::Option<Dimension>, usize),
-    (_, value, _): (usize, core::option::Option<Box<Expression>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Variable
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> alloc::vec::Vec<Box<Expression>>
 {
-    Variable::new(
-        String::from(id),
-        data_type,
-        dim.unwrap_or(Dimension::new_scalar()),
-        value
-    )
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action23<
+fn __action148<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Index, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Vec<Index>
+    (_, v, _): (usize, alloc::vec::Vec<Box<Expression>>, usize),
+    (_, e, _): (usize, Box<Expression>, usize),
+) -> alloc::vec::Vec<Box<Expression>>
 {
-    vec![__0]
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action24<
+fn __action149<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, a, _): (usize, Index, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, b, _): (usize, Index, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Vec<Index>
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, Box<Expression>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Box<Expression>
 {
-    vec![a, b]
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action25<
+fn __action150<
     'input,
 >(
-    input: &'input str,
-    (_, id, _): (usize, &'input str, usize),
-    (_, indexing, _): (usize, core::option::Option<Vec<Index>>, usize),
-) -> Access
+    (_, __0, _): (usize, (), usize),
+) -> alloc::vec::Vec<()>
 {
-    Access::new(
-            Id::new(id, None),
-            indexing.unwrap_or(vec![])
-        )
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action26<
+fn __action151<
     'input,
 >(
-    input: &'input str,
-    (_, var, _): (usize, Access, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, exp, _): (usize, Box<Expression>, usize),
-) -> Statement
+    (_, v, _): (usize, alloc::vec::Vec<()>, usize),
+    (_, e, _): (usize, (), usize),
+) -> alloc::vec::Vec<()>
 {
-    Statement::VarAssign(var, exp)
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action27<
+fn __action152<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, (), usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> ()
 {
-    Operator::And
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action28<
+fn __action153<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    (_, __0, _): (usize, Index, usize),
+) -> alloc::vec::Vec<Index>
 {
-    Operator::Or
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action29<
+fn __action154<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    (_, v, _): (usize, alloc::vec::Vec<Index>, usize),
+    (_, e, _): (usize, Index, usize),
+) -> alloc::vec::Vec<Index>
 {
-    Operator::LessThan
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action30<
+fn __action155<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, Index, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Index
 {
-    Operator::GreaterThan
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action31<
+fn __action156<
     'input,
 >(
-    input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+) -> alloc::vec::Vec<&'input str>
 {
-    Operator::LessOrEq
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action32<
+fn __action157<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    (_, v, _): (usize, alloc::vec::Vec<&'input str>, usize),
+    (_, e, _): (usize, &'input str, usize),
+) -> alloc::vec::Vec<&'input str>
 {
-    Operator::GreaterOrEq
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action33<
+fn __action158<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Box<Expression>>
 {
-    Operator::NotEq
+    alloc::vec![]
 }
 
-#[allow(unused_variables)]
-fn __action34<
+fn __action159<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    (_, v, _): (usize, alloc::vec::Vec<Box<Expression>>, usize),
+) -> alloc::vec::Vec<Box<Expression>>
 {
-    Operator::Eq
+    v
 }
 
-#[allow(unused_variables)]
-fn __action35<
+fn __action160<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    (_, __0, _): (usize, Box<Expression>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Box<Expression>
 {
-    Operator::Add
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action36<
+fn __action161<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    (_, __0, _): (usize, FunctionParam, usize),
+) -> core::option::Option<FunctionParam>
 {
-    Operator::Sub
+    Some(__0)
 }
 
-#[allow(unused_variables)]
-fn __action37<
+fn __action162<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<FunctionParam>
 {
-    Operator::Mul
+    None
 }
 
-#[allow(unused_variables)]
-fn __action38<
+fn __action163<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<FunctionParam>
 {
-    Operator::Div
+    alloc::vec![]
 }
 
-#[allow(unused_variables)]
-fn __action39<
+fn __action164<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    (_, v, _): (usize, alloc::vec::Vec<FunctionParam>, usize),
+) -> alloc::vec::Vec<FunctionParam>
 {
-    Operator::Pipe
+    v
 }
 
-#[allow(unused_variables)]
-fn __action40<
+fn __action165<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    (_, __0, _): (usize, FunctionParam, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> FunctionParam
 {
     __0
 }
 
-#[allow(unused_variables)]
-fn __action41<
+fn __action166<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-    (_, __1, _): (usize, Operator, usize),
-    (_, __2, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    (_, __0, _): (usize, Variable, usize),
+) -> core::option::Option<Variable>
 {
-    Box::new(
-            Expression::Op(
-                Operation::new(__0, __1, __2)
-            )
-        )
+    Some(__0)
 }
 
-#[allow(unused_variables)]
-fn __action42<
+fn __action167<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Variable>
 {
-    __0
+    None
 }
 
-#[allow(unused_variables)]
-fn __action43<
+fn __action168<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Variable>
 {
-    __0
+    alloc::vec![]
 }
 
-#[allow(unused_variables)]
-fn __action44<
+fn __action169<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    (_, v, _): (usize, alloc::vec::Vec<Variable>, usize),
+) -> alloc::vec::Vec<Variable>
 {
-    __0
+    v
 }
 
-#[allow(unused_variables)]
-fn __action45<
+fn __action170<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> &'input str
+    (_, __0, _): (usize, Variable, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Variable
 {
     __0
 }
 
-#[allow(unused_variables)]
-fn __action46<
+fn __action171<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> &'input str
+    (_, __0, _): (usize, Statement, usize),
+) -> alloc::vec::Vec<Statement>
 {
-    __0
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action47<
+fn __action172<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-    (_, __1, _): (usize, Operator, usize),
-    (_, __2, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    (_, v, _): (usize, alloc::vec::Vec<Statement>, usize),
+    (_, e, _): (usize, Statement, usize),
+) -> alloc::vec::Vec<Statement>
 {
-    Box::new(
-            Expression::Op(
-                Operation::new(__0, __1, __2)
-            )
-        )
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action48<
+fn __action173<
     'input,
 >(
-    input: &'input str,
-    (_, op, _): (usize, core::option::Option<&'input str>, usize),
-    (_, item, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    (_, __0, _): (usize, Statement, usize),
+) -> alloc::vec::Vec<Statement>
 {
-    match op {
-        None => item,
-        Some(op) => match &op[..] {
-            "!" => Box::new(Expression::Not(item)),
-            "-" => Box::new(Expression::Negative(item)),
-            _ => panic!()
-        }
-    }
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action49<
+fn __action174<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Access, usize),
-) -> Box<Expression>
+    (_, v, _): (usize, alloc::vec::Vec<Statement>, usize),
+    (_, e, _): (usize, Statement, usize),
+) -> alloc::vec::Vec<Statement>
 {
-    Box::new(Expression::Access(__0))
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action50<
+fn __action175<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Const, usize),
-) -> Box<Expression>
+    (_, __0, _): (usize, Variable, usize),
+) -> alloc::vec::Vec<Variable>
 {
-    Box::new(Expression::Const(__0))
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action51<
+fn __action176<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Box<Expression>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Box<Expression>
+    (_, v, _): (usize, alloc::vec::Vec<Variable>, usize),
+    (_, e, _): (usize, Variable, usize),
+) -> alloc::vec::Vec<Variable>
 {
-    __0
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action52<
+fn __action177<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Call, usize),
-) -> Box<Expression>
+    (_, __0, _): (usize, FunctionParam, usize),
+) -> alloc::vec::Vec<FunctionParam>
 {
-    Box::new(Expression::Call(__0))
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action53<
+fn __action178<
     'input,
 >(
-    input: &'input str,
-    (_, id, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, dtype, _): (usize, DataType, usize),
-    (_, dim, _): (usize, core::option::Option<Dimension>, usize),
-) -> FunctionParam
+    (_, v, _): (usize, alloc::vec::Vec<FunctionParam>, usize),
+    (_, e, _): (usize, FunctionParam, usize),
+) -> alloc::vec::Vec<FunctionParam>
 {
-    FunctionParam(Variable::new(
-        String::from(id),
-        dtype,
-        dim.unwrap_or(Dimension::new_scalar()),
-        None
-    ))
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action54<
+fn __action179<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Vec<Box<Expression>>, usize),
-) -> Vec<Box<Expression>>
+    (_, __0, _): (usize, Box<Expression>, usize),
+) -> alloc::vec::Vec<Box<Expression>>
 {
-    __0
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action55<
+fn __action180<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Vec<FunctionParam>, usize),
-) -> Vec<FunctionParam>
+    (_, v, _): (usize, alloc::vec::Vec<Box<Expression>>, usize),
+    (_, e, _): (usize, Box<Expression>, usize),
+) -> alloc::vec::Vec<Box<Expression>>
 {
-    __0
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action56<
+fn __action181<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, id, _): (usize, &'input str, usize),
-    (_, params, _): (usize, Vec<FunctionParam>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, dtype, _): (usize, DataType, usize),
-) -> FunctionSignature
+    (_, __0, _): (usize, DataType, usize),
+) -> alloc::vec::Vec<DataType>
 {
-    FunctionSignature {
-    id:  String::from(&id[..id.len() - 1]),
-    data_type: dtype,
-    params: params,
-    is_native: false
-}
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action57<
+fn __action182<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> Statement
+    (_, v, _): (usize, alloc::vec::Vec<DataType>, usize),
+    (_, e, _): (usize, DataType, usize),
+) -> alloc::vec::Vec<DataType>
 {
-    __0
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action58<
+fn __action183<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Variable, usize),
-) -> Statement
+    __0: (usize, Box<Expression>, usize),
+    __1: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<Box<Expression>>
 {
-    Statement::VarDeclaration(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action160(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action179(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action59<
+fn __action184<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, statements, _): (usize, alloc::vec::Vec<Statement>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Block
+    __0: (usize, alloc::vec::Vec<Box<Expression>>, usize),
+    __1: (usize, Box<Expression>, usize),
+    __2: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<Box<Expression>>
 {
-    Block(statements)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action160(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action180(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action60<
+fn __action185<
     'input,
 >(
-    input: &'input str,
-    (_, signature, _): (usize, FunctionSignature, usize),
-    (_, block, _): (usize, Block, usize),
-) -> Function
+    __0: (usize, core::option::Option<Box<Expression>>, usize),
+) -> Vec<Box<Expression>>
 {
-    Function::new(signature, block)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action158(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action111(
+        __temp0,
+        __0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action61<
+fn __action186<
     'input,
 >(
-    input: &'input str,
-    (_, id, _): (usize, &'input str, usize),
-    (_, params, _): (usize, Vec<Box<Expression>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Call
+    __0: (usize, alloc::vec::Vec<Box<Expression>>, usize),
+    __1: (usize, core::option::Option<Box<Expression>>, usize),
+) -> Vec<Box<Expression>>
 {
-    Call::new(&id[..id.len() - 1], params)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action159(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action111(
+        __temp0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action62<
+fn __action187<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Box<Expression>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Statement
+    __0: (usize, FunctionParam, usize),
+    __1: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<FunctionParam>
 {
-    Statement::Return(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action165(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action177(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action63<
+fn __action188<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> Statement
+    __0: (usize, alloc::vec::Vec<FunctionParam>, usize),
+    __1: (usize, FunctionParam, usize),
+    __2: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<FunctionParam>
 {
-    __0
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action165(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action178(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action64<
+fn __action189<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> Statement
+    __0: (usize, core::option::Option<FunctionParam>, usize),
+) -> Vec<FunctionParam>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action163(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action108(
+        __temp0,
+        __0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action65<
+fn __action190<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> Statement
+    __0: (usize, alloc::vec::Vec<FunctionParam>, usize),
+    __1: (usize, core::option::Option<FunctionParam>, usize),
+) -> Vec<FunctionParam>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action164(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action108(
+        __temp0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action66<
+fn __action191<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Statement
+    __0: (usize, Variable, usize),
+    __1: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<Variable>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action170(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action175(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action67<
+fn __action192<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Function, usize),
-) -> Statement
+    __0: (usize, alloc::vec::Vec<Variable>, usize),
+    __1: (usize, Variable, usize),
+    __2: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<Variable>
 {
-    Statement::FunctionDeclaration(__0)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action170(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action176(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action68<
+fn __action193<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> Statement
+    __0: (usize, core::option::Option<Variable>, usize),
+) -> Vec<Variable>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action168(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action107(
+        __temp0,
+        __0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action69<
+fn __action194<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Variable, usize),
-) -> Statement
+    __0: (usize, alloc::vec::Vec<Variable>, usize),
+    __1: (usize, core::option::Option<Variable>, usize),
+) -> Vec<Variable>
 {
-    Statement::VarDeclaration(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action169(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action107(
+        __temp0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action70<
+fn __action195<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> Block
+    __0: (usize, DataType, usize),
+    __1: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<DataType>
 {
-    Block(vec![__0])
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action146(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action181(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action71<
+fn __action196<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Block, usize),
-) -> Block
+    __0: (usize, alloc::vec::Vec<DataType>, usize),
+    __1: (usize, DataType, usize),
+    __2: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<DataType>
 {
-    __0
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action146(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action182(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action72<
+fn __action197<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Statement
+    __0: (usize, core::option::Option<DataType>, usize),
+) -> Vec<DataType>
 {
-    Statement::Break
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action144(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action141(
+        __temp0,
+        __0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action73<
+fn __action198<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Statement
+    __0: (usize, alloc::vec::Vec<DataType>, usize),
+    __1: (usize, core::option::Option<DataType>, usize),
+) -> Vec<DataType>
 {
-    Statement::Continue
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action145(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action141(
+        __temp0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action74<
+fn __action199<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> Statement
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Box<Expression>, usize),
+) -> core::option::Option<Box<Expression>>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action129(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action127(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action75<
+fn __action200<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> Statement
-{
-    Statement::Expression(__0)
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, usize, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, DataType, usize),
+    __6: (usize, core::option::Option<Dimension>, usize),
+    __7: (usize, Token<'input>, usize),
+    __8: (usize, Box<Expression>, usize),
+    __9: (usize, Token<'input>, usize),
+) -> Result<Variable,__lalrpop_util::ParseError<usize,Token<'input>,SyntaxError>>
+{
+    let __start0 = __7.0.clone();
+    let __end0 = __8.2.clone();
+    let __temp0 = __action199(
+        __7,
+        __8,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action27(
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+        __9,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action76<
+fn __action201<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> Block
-{
-    Block(vec![__0])
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, usize, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, DataType, usize),
+    __6: (usize, core::option::Option<Dimension>, usize),
+    __7: (usize, Token<'input>, usize),
+) -> Result<Variable,__lalrpop_util::ParseError<usize,Token<'input>,SyntaxError>>
+{
+    let __start0 = __6.2.clone();
+    let __end0 = __7.0.clone();
+    let __temp0 = __action128(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action27(
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+        __7,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action77<
+fn __action202<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Block, usize),
-) -> Block
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Box<Expression>, usize),
+) -> core::option::Option<Box<Expression>>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action101(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action99(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action78<
+fn __action203<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, condition, _): (usize, Box<Expression>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, if_block, _): (usize, Block, usize),
-    (_, else_block, _): (usize, core::option::Option<Block>, usize),
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Box<Expression>, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, Box<Expression>, usize),
+    __7: (usize, Token<'input>, usize),
+    __8: (usize, Box<Expression>, usize),
+    __9: (usize, Token<'input>, usize),
+    __10: (usize, Block, usize),
 ) -> Statement
 {
-    Statement::If {
-            condition: condition,
-            if_block: if_block,
-            else_block: else_block
-        }
+    let __start0 = __7.0.clone();
+    let __end0 = __8.2.clone();
+    let __temp0 = __action202(
+        __7,
+        __8,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action94(
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+        __9,
+        __10,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action79<
+fn __action204<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, statements, _): (usize, alloc::vec::Vec<Statement>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Block
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Box<Expression>, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, Box<Expression>, usize),
+    __7: (usize, Token<'input>, usize),
+    __8: (usize, Block, usize),
+) -> Statement
 {
-    Block(statements)
+    let __start0 = __6.2.clone();
+    let __end0 = __7.0.clone();
+    let __temp0 = __action100(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action94(
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+        __7,
+        __8,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action80<
+fn __action205<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> Statement
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Box<Expression>, usize),
+) -> core::option::Option<Box<Expression>>
 {
-    __0
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action137(
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action136(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action81<
+fn __action206<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> Statement
+    __0: (usize, Token<'input>, usize),
+) -> core::option::Option<Box<Expression>>
 {
-    __0
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action138(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action136(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action82<
+fn __action207<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, id, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, start, _): (usize, Box<Expression>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, end, _): (usize, Box<Expression>, usize),
-    (_, step, _): (usize, core::option::Option<Box<Expression>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, block, _): (usize, Block, usize),
-) -> Statement
+    __0: (usize, Box<Expression>, usize),
+) -> Vec<Box<Expression>>
 {
-    Statement::For {
-        iterator_id: String::from(id),
-        range: (start, end, step),
-        block: block
-    }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action137(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action185(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action83<
+fn __action208<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, condition, _): (usize, Box<Expression>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, block, _): (usize, Block, usize),
-) -> Statement
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<Box<Expression>>
 {
-    Statement::While {
-        condition: condition,
-        block: block
-    }
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action138(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action185(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action84<
+fn __action209<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, alloc::vec::Vec<Statement>, usize),
-) -> Program
+    __0: (usize, alloc::vec::Vec<Box<Expression>>, usize),
+    __1: (usize, Box<Expression>, usize),
+) -> Vec<Box<Expression>>
 {
-    Program(__0)
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action137(
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action186(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action85<
+fn __action210<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Statement>
+    __0: (usize, alloc::vec::Vec<Box<Expression>>, usize),
+) -> Vec<Box<Expression>>
 {
-    alloc::vec![]
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action138(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action186(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action86<
+fn __action211<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Statement>, usize),
-) -> alloc::vec::Vec<Statement>
+    __0: (usize, Box<Expression>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Box<Expression>, usize),
+    __3: (usize, core::option::Option<core::option::Option<Box<Expression>>>, usize),
+) -> Index
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __start1 = __2.0.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action137(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action137(
+        __2,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action22(
+        __temp0,
+        __1,
+        __temp1,
+        __3,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action87<
+fn __action212<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> core::option::Option<Box<Expression>>
+    __0: (usize, Box<Expression>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, core::option::Option<core::option::Option<Box<Expression>>>, usize),
+) -> Index
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __start1 = __1.2.clone();
+    let __end1 = __2.0.clone();
+    let __temp0 = __action137(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action138(
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action22(
+        __temp0,
+        __1,
+        __temp1,
+        __2,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action88<
+fn __action213<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Box<Expression>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Box<Expression>, usize),
+    __2: (usize, core::option::Option<core::option::Option<Box<Expression>>>, usize),
+) -> Index
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __1.0.clone();
+    let __end1 = __1.2.clone();
+    let __temp0 = __action138(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action137(
+        __1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action22(
+        __temp0,
+        __0,
+        __temp1,
+        __2,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action89<
+fn __action214<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, core::option::Option<core::option::Option<Box<Expression>>>, usize),
+) -> Index
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __1.0.clone();
+    let __temp0 = __action138(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action138(
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action22(
+        __temp0,
+        __0,
+        __temp1,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action90<
+fn __action215<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Statement>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Box<Expression>, usize),
+) -> core::option::Option<core::option::Option<Box<Expression>>>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action205(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action134(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action91<
+fn __action216<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Statement>, usize),
-) -> alloc::vec::Vec<Statement>
+    __0: (usize, Token<'input>, usize),
+) -> core::option::Option<core::option::Option<Box<Expression>>>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action206(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action134(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action92<
+fn __action217<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Block, usize),
-) -> core::option::Option<Block>
+    __0: (usize, Box<Expression>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Box<Expression>, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Box<Expression>, usize),
+) -> Index
 {
-    Some(__0)
+    let __start0 = __3.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action215(
+        __3,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action211(
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action93<
+fn __action218<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Block>
+    __0: (usize, Box<Expression>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Box<Expression>, usize),
+    __3: (usize, Token<'input>, usize),
+) -> Index
 {
-    None
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action216(
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action211(
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action94<
+fn __action219<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Block, usize),
-) -> Block
+    __0: (usize, Box<Expression>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Box<Expression>, usize),
+) -> Index
 {
-    __0
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action135(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action211(
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action95<
+fn __action220<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Statement>
+    __0: (usize, Box<Expression>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Box<Expression>, usize),
+) -> Index
 {
-    alloc::vec![]
+    let __start0 = __2.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action215(
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action212(
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action96<
+fn __action221<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Statement>, usize),
-) -> alloc::vec::Vec<Statement>
+    __0: (usize, Box<Expression>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+) -> Index
 {
-    v
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action216(
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action212(
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action97<
+fn __action222<
     'input,
 >(
-    input: &'input str,
-    (_, mut vec, _): (usize, alloc::vec::Vec<FunctionParam>, usize),
-    (_, c, _): (usize, core::option::Option<FunctionParam>, usize),
-) -> Vec<FunctionParam>
+    __0: (usize, Box<Expression>, usize),
+    __1: (usize, Token<'input>, usize),
+) -> Index
 {
-    match c {
-        None => vec,
-        Some(c) => {
-            vec.push(c);
-            vec
-        }
-    }
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action135(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action212(
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action98<
+fn __action223<
     'input,
 >(
-    input: &'input str,
-    (_, mut vec, _): (usize, alloc::vec::Vec<Box<Expression>>, usize),
-    (_, c, _): (usize, core::option::Option<Box<Expression>>, usize),
-) -> Vec<Box<Expression>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Box<Expression>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Box<Expression>, usize),
+) -> Index
 {
-    match c {
-        None => vec,
-        Some(c) => {
-            vec.push(c);
-            vec
-        }
-    }
+    let __start0 = __2.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action215(
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action213(
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action99<
+fn __action224<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> core::option::Option<&'input str>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Box<Expression>, usize),
+    __2: (usize, Token<'input>, usize),
+) -> Index
 {
-    Some(__0)
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action216(
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action213(
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action100<
+fn __action225<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<&'input str>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Box<Expression>, usize),
+) -> Index
 {
-    None
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action135(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action213(
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action101<
+fn __action226<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Expression, usize),
-) -> Box<Expression>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Box<Expression>, usize),
+) -> Index
 {
-    Box::new(__0)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action215(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action214(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action102<
+fn __action227<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-    (_, __1, _): (usize, Operator, usize),
-    (_, __2, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+) -> Index
 {
-    Box::new(
-            Expression::Op(
-                Operation::new(__0, __1, __2)
-            )
-        )
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action216(
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action214(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action103<
+fn __action228<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    __0: (usize, Token<'input>, usize),
+) -> Index
 {
-    __0
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action135(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action214(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action104<
+fn __action229<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-    (_, __1, _): (usize, Operator, usize),
-    (_, __2, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+) -> alloc::vec::Vec<&'input str>
 {
-    Box::new(
-            Expression::Op(
-                Operation::new(__0, __1, __2)
-            )
-        )
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action123(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action156(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action105<
+fn __action230<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    __0: (usize, alloc::vec::Vec<&'input str>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+) -> alloc::vec::Vec<&'input str>
 {
-    __0
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action123(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action157(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action106<
+fn __action231<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-    (_, __1, _): (usize, Operator, usize),
-    (_, __2, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, core::option::Option<Vec<Index>>, usize),
+    __3: (usize, usize, usize),
+) -> Access
 {
-    Box::new(
-            Expression::Op(
-                Operation::new(__0, __1, __2)
-            )
-        )
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action121(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action30(
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action107<
+fn __action232<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, core::option::Option<Vec<Index>>, usize),
+    __3: (usize, alloc::vec::Vec<&'input str>, usize),
+    __4: (usize, usize, usize),
+) -> Access
 {
-    __0
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action122(
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action30(
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action108<
+fn __action233<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Vec<Index>, usize),
-) -> core::option::Option<Vec<Index>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Block, usize),
+) -> core::option::Option<Block>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action106(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action104(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action109<
+fn __action234<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Vec<Index>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Box<Expression>, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Block, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, Block, usize),
+) -> Statement
 {
-    None
+    let __start0 = __5.0.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action233(
+        __5,
+        __6,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action90(
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action110<
+fn __action235<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> core::option::Option<Box<Expression>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Box<Expression>, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Block, usize),
+) -> Statement
 {
-    Some(__0)
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action105(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action90(
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action111<
+fn __action236<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Box<Expression>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, (), usize),
+    __2: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<()>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action152(
+        __0,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action150(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action112<
+fn __action237<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> Box<Expression>
+    __0: (usize, alloc::vec::Vec<()>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, (), usize),
+    __3: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<()>
 {
-    __0
+    let __start0 = __1.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action152(
+        __1,
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action151(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action113<
+fn __action238<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Dimension, usize),
-) -> core::option::Option<Dimension>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Box<Expression>, usize),
+    __2: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<Box<Expression>>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action149(
+        __0,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action147(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action114<
+fn __action239<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Dimension>
+    __0: (usize, alloc::vec::Vec<Box<Expression>>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Box<Expression>, usize),
+    __3: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<Box<Expression>>
 {
-    None
+    let __start0 = __1.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action149(
+        __1,
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action148(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action115<
+fn __action240<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> core::option::Option<Box<Expression>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Index, usize),
+    __2: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<Index>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action155(
+        __0,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action153(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action116<
+fn __action241<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Box<Expression>>
+    __0: (usize, alloc::vec::Vec<Index>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Index, usize),
+    __3: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<Index>
 {
-    None
+    let __start0 = __1.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action155(
+        __1,
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action154(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action117<
+fn __action242<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Box<Expression>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, usize, usize),
+) -> Const
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action13(
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action118<
+fn __action243<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Box<Expression>>, usize),
-) -> alloc::vec::Vec<Box<Expression>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, usize, usize),
+) -> Const
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action119<
+fn __action244<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Box<Expression>
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Const
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action120<
+fn __action245<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, FunctionParam, usize),
-) -> core::option::Option<FunctionParam>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Vec<FunctionParam>, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, Token<'input>, usize),
+    __7: (usize, DataType, usize),
+    __8: (usize, core::option::Option<Dimension>, usize),
+) -> FunctionSignature
 {
-    Some(__0)
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action66(
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action121<
+fn __action246<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<FunctionParam>
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, DataType, usize),
+    __4: (usize, core::option::Option<Dimension>, usize),
+) -> FunctionParam
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action63(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action122<
+fn __action247<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<FunctionParam>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Vec<Box<Expression>>, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, usize, usize),
+) -> Call
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action68(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action123<
+fn __action248<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<FunctionParam>, usize),
-) -> alloc::vec::Vec<FunctionParam>
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Expression
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action20(
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action124<
+fn __action249<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, FunctionParam, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> FunctionParam
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Const
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action125<
+fn __action250<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> alloc::vec::Vec<Statement>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Vec<FunctionParam>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Box<Expression>, usize),
+    __4: (usize, usize, usize),
+) -> Lambda
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action69(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action126<
+fn __action251<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Statement>, usize),
-    (_, e, _): (usize, Statement, usize),
-) -> alloc::vec::Vec<Statement>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Box<Expression>, usize),
+    __2: (usize, usize, usize),
+) -> Lambda
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action70(
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action127<
+fn __action252<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> alloc::vec::Vec<Statement>
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> Const
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action15(
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action128<
+fn __action253<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Statement>, usize),
-    (_, e, _): (usize, Statement, usize),
-) -> alloc::vec::Vec<Statement>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Vec<Variable>, usize),
+    __5: (usize, Token<'input>, usize),
+) -> Struct
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action79(
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action129<
+fn __action254<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Statement, usize),
-) -> alloc::vec::Vec<Statement>
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, DataType, usize),
+) -> Variable
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action78(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action130<
+fn __action255<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Statement>, usize),
-    (_, e, _): (usize, Statement, usize),
-) -> alloc::vec::Vec<Statement>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, DataType, usize),
+    __5: (usize, core::option::Option<Dimension>, usize),
+    __6: (usize, Token<'input>, usize),
+    __7: (usize, Box<Expression>, usize),
+    __8: (usize, Token<'input>, usize),
+) -> Result<Variable,__lalrpop_util::ParseError<usize,Token<'input>,SyntaxError>>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action200(
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action131<
+fn __action256<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, FunctionParam, usize),
-) -> alloc::vec::Vec<FunctionParam>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, DataType, usize),
+    __5: (usize, core::option::Option<Dimension>, usize),
+    __6: (usize, Token<'input>, usize),
+) -> Result<Variable,__lalrpop_util::ParseError<usize,Token<'input>,SyntaxError>>
 {
-    alloc::vec![__0]
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action201(
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action132<
+fn __action257<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<FunctionParam>, usize),
-    (_, e, _): (usize, FunctionParam, usize),
-) -> alloc::vec::Vec<FunctionParam>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Box<Expression>, usize),
+    __5: (usize, Token<'input>, usize),
+) -> Variable
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action28(
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action133<
+fn __action258<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expression>, usize),
-) -> alloc::vec::Vec<Box<Expression>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, core::option::Option<Vec<Index>>, usize),
+    __2: (usize, usize, usize),
+) -> Access
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action231(
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action134<
+fn __action259<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Box<Expression>>, usize),
-    (_, e, _): (usize, Box<Expression>, usize),
-) -> alloc::vec::Vec<Box<Expression>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, core::option::Option<Vec<Index>>, usize),
+    __2: (usize, alloc::vec::Vec<&'input str>, usize),
+    __3: (usize, usize, usize),
+) -> Access
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action232(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action135<
+fn __action260<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, Box<Expression>, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<Box<Expression>>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Vec<Box<Expression>>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, usize, usize),
+) -> Vector
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action119(
-        input,
-        __0,
-        __1,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action140(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action133(
-        input,
+    __action62(
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
-#[allow(unused_variables)]
-fn __action136<
+fn __action261<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Box<Expression>>, usize),
-    __1: (usize, Box<Expression>, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<Box<Expression>>
+    __0: (usize, Token<'input>, usize),
+) -> Const
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action119(
-        input,
-        __1,
-        __2,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action134(
-        input,
+    __action242(
         __0,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action137<
+fn __action262<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, core::option::Option<Box<Expression>>, usize),
-) -> Vec<Box<Expression>>
+    __0: (usize, Token<'input>, usize),
+) -> Const
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action117(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action139(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action98(
-        input,
-        __temp0,
+    __action243(
         __0,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action138<
+fn __action263<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Box<Expression>>, usize),
-    __1: (usize, core::option::Option<Box<Expression>>, usize),
-) -> Vec<Box<Expression>>
+    __0: (usize, &'input str, usize),
+) -> Const
 {
-    let __start0 = __0.0.clone();
+    let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action118(
-        input,
-        __0,
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action98(
-        input,
+    __action244(
+        __0,
         __temp0,
-        __1,
     )
 }
 
-#[allow(unused_variables)]
-fn __action139<
+fn __action264<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, FunctionParam, usize),
+    __0: (usize, Token<'input>, usize),
     __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<FunctionParam>
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Vec<FunctionParam>, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, DataType, usize),
+    __7: (usize, core::option::Option<Dimension>, usize),
+) -> FunctionSignature
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action124(
-        input,
-        __0,
-        __1,
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action131(
-        input,
+    __action245(
+        __0,
+        __1,
         __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
     )
 }
 
-#[allow(unused_variables)]
-fn __action140<
+fn __action265<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<FunctionParam>, usize),
-    __1: (usize, FunctionParam, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<FunctionParam>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, DataType, usize),
+    __3: (usize, core::option::Option<Dimension>, usize),
+) -> FunctionParam
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action124(
-        input,
-        __1,
-        __2,
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action132(
-        input,
+    __action246(
         __0,
         __temp0,
+        __1,
+        __2,
+        __3,
     )
 }
 
-#[allow(unused_variables)]
-fn __action141<
+fn __action266<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, core::option::Option<FunctionParam>, usize),
-) -> Vec<FunctionParam>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Vec<Box<Expression>>, usize),
+    __3: (usize, Token<'input>, usize),
+) -> Call
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action122(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action139(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action97(
-        input,
-        __temp0,
+    __action247(
         __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action142<
+fn __action267<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<FunctionParam>, usize),
-    __1: (usize, core::option::Option<FunctionParam>, usize),
-) -> Vec<FunctionParam>
+    __0: (usize, &'input str, usize),
+) -> Expression
 {
-    let __start0 = __0.0.clone();
+    let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action123(
-        input,
-        __0,
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action97(
-        input,
+    __action248(
+        __0,
         __temp0,
-        __1,
     )
 }
 
-#[allow(unused_variables)]
-fn __action143<
+fn __action268<
     'input,
 >(
-    input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, Box<Expression>, usize),
-) -> core::option::Option<Box<Expression>>
+) -> Const
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action112(
-        input,
-        __0,
-        __1,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action110(
-        input,
+    __action249(
+        __0,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action144<
+fn __action269<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, DataType, usize),
-    __4: (usize, core::option::Option<Dimension>, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Box<Expression>, usize),
-    __7: (usize, &'input str, usize),
-) -> Variable
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Vec<FunctionParam>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Box<Expression>, usize),
+) -> Lambda
 {
-    let __start0 = __5.0.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action143(
-        input,
-        __5,
-        __6,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action22(
-        input,
+    __action250(
         __0,
         __1,
         __2,
         __3,
-        __4,
         __temp0,
-        __7,
     )
 }
 
-#[allow(unused_variables)]
-fn __action145<
+fn __action270<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, DataType, usize),
-    __4: (usize, core::option::Option<Dimension>, usize),
-    __5: (usize, &'input str, usize),
-) -> Variable
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Box<Expression>, usize),
+) -> Lambda
 {
-    let __start0 = __4.2.clone();
-    let __end0 = __5.0.clone();
-    let __temp0 = __action111(
-        input,
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action139(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action22(
-        input,
+    __action251(
         __0,
         __1,
-        __2,
-        __3,
-        __4,
         __temp0,
-        __5,
     )
 }
 
-#[allow(unused_variables)]
-fn __action146<
+fn __action271<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Box<Expression>, usize),
-) -> core::option::Option<Box<Expression>>
+    __0: (usize, String, usize),
+) -> Const
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action89(
-        input,
-        __0,
-        __1,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action87(
-        input,
+    __action252(
+        __0,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action147<
+fn __action272<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Token<'input>, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Expression>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Box<Expression>, usize),
-    __6: (usize, &'input str, usize),
-    __7: (usize, Box<Expression>, usize),
-    __8: (usize, &'input str, usize),
-    __9: (usize, Block, usize),
-) -> Statement
-{
-    let __start0 = __6.0.clone();
-    let __end0 = __7.2.clone();
-    let __temp0 = __action146(
-        input,
-        __6,
-        __7,
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Vec<Variable>, usize),
+    __4: (usize, Token<'input>, usize),
+) -> Struct
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action82(
-        input,
+    __action253(
         __0,
         __1,
+        __temp0,
         __2,
         __3,
         __4,
-        __5,
-        __temp0,
-        __8,
-        __9,
     )
 }
 
-#[allow(unused_variables)]
-fn __action148<
+fn __action273<
     'input,
 >(
-    input: &'input str,
     __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, DataType, usize),
+) -> Variable
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action254(
+        __0,
+        __temp0,
+        __1,
+        __2,
+    )
+}
+
+fn __action274<
+    'input,
+>(
+    __0: (usize, Token<'input>, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Expression>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Box<Expression>, usize),
-    __6: (usize, &'input str, usize),
-    __7: (usize, Block, usize),
-) -> Statement
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, DataType, usize),
+    __4: (usize, core::option::Option<Dimension>, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, Box<Expression>, usize),
+    __7: (usize, Token<'input>, usize),
+) -> Result<Variable,__lalrpop_util::ParseError<usize,Token<'input>,SyntaxError>>
 {
-    let __start0 = __5.2.clone();
-    let __end0 = __6.0.clone();
-    let __temp0 = __action88(
-        input,
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action139(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action82(
-        input,
+    __action255(
         __0,
         __1,
+        __temp0,
         __2,
         __3,
         __4,
         __5,
-        __temp0,
         __6,
         __7,
     )
 }
 
-#[allow(unused_variables)]
-fn __action149<
+fn __action275<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Block, usize),
-) -> core::option::Option<Block>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, DataType, usize),
+    __4: (usize, core::option::Option<Dimension>, usize),
+    __5: (usize, Token<'input>, usize),
+) -> Result<Variable,__lalrpop_util::ParseError<usize,Token<'input>,SyntaxError>>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action94(
-        input,
-        __0,
-        __1,
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action92(
-        input,
+    __action256(
+        __0,
+        __1,
         __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
     )
 }
 
-#[allow(unused_variables)]
-fn __action150<
+fn __action276<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Box<Expression>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Block, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Block, usize),
-) -> Statement
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Box<Expression>, usize),
+    __4: (usize, Token<'input>, usize),
+) -> Variable
 {
-    let __start0 = __4.0.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action149(
-        input,
-        __4,
-        __5,
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action78(
-        input,
+    __action257(
         __0,
         __1,
+        __temp0,
         __2,
         __3,
-        __temp0,
+        __4,
     )
 }
 
-#[allow(unused_variables)]
-fn __action151<
+fn __action277<
     'input,
 >(
-    input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, Box<Expression>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Block, usize),
-) -> Statement
+    __1: (usize, core::option::Option<Vec<Index>>, usize),
+) -> Access
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action93(
-        input,
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action139(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action78(
-        input,
+    __action258(
         __0,
         __1,
-        __2,
-        __3,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action152<
+fn __action278<
     'input,
 >(
-    input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, DataType, usize),
-    __3: (usize, Dimension, usize),
-) -> FunctionParam
+    __1: (usize, core::option::Option<Vec<Index>>, usize),
+    __2: (usize, alloc::vec::Vec<&'input str>, usize),
+) -> Access
 {
-    let __start0 = __3.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action113(
-        input,
-        __3,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action139(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action53(
-        input,
+    __action259(
         __0,
         __1,
         __2,
@@ -6950,26 +10697,22 @@ fn __action152<
     )
 }
 
-#[allow(unused_variables)]
-fn __action153<
+fn __action279<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, DataType, usize),
-) -> FunctionParam
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Vec<Box<Expression>>, usize),
+    __2: (usize, Token<'input>, usize),
+) -> Vector
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action114(
-        input,
+    let __temp0 = __action139(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action53(
-        input,
+    __action260(
         __0,
         __1,
         __2,
@@ -6977,30 +10720,26 @@ fn __action153<
     )
 }
 
-#[allow(unused_variables)]
-fn __action154<
+fn __action280<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Token<'input>, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
     __3: (usize, DataType, usize),
     __4: (usize, Dimension, usize),
-    __5: (usize, &'input str, usize),
+    __5: (usize, Token<'input>, usize),
     __6: (usize, Box<Expression>, usize),
-    __7: (usize, &'input str, usize),
-) -> Variable
+    __7: (usize, Token<'input>, usize),
+) -> Result<Variable,__lalrpop_util::ParseError<usize,Token<'input>,SyntaxError>>
 {
     let __start0 = __4.0.clone();
     let __end0 = __4.2.clone();
-    let __temp0 = __action113(
-        input,
+    let __temp0 = __action130(
         __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action144(
-        input,
+    __action274(
         __0,
         __1,
         __2,
@@ -7012,30 +10751,26 @@ fn __action154<
     )
 }
 
-#[allow(unused_variables)]
-fn __action155<
+fn __action281<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Token<'input>, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
     __3: (usize, DataType, usize),
-    __4: (usize, &'input str, usize),
+    __4: (usize, Token<'input>, usize),
     __5: (usize, Box<Expression>, usize),
-    __6: (usize, &'input str, usize),
-) -> Variable
+    __6: (usize, Token<'input>, usize),
+) -> Result<Variable,__lalrpop_util::ParseError<usize,Token<'input>,SyntaxError>>
 {
     let __start0 = __3.2.clone();
     let __end0 = __4.0.clone();
-    let __temp0 = __action114(
-        input,
+    let __temp0 = __action131(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action144(
-        input,
+    __action274(
         __0,
         __1,
         __2,
@@ -7047,28 +10782,24 @@ fn __action155<
     )
 }
 
-#[allow(unused_variables)]
-fn __action156<
+fn __action282<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Token<'input>, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
     __3: (usize, DataType, usize),
     __4: (usize, Dimension, usize),
-    __5: (usize, &'input str, usize),
-) -> Variable
+    __5: (usize, Token<'input>, usize),
+) -> Result<Variable,__lalrpop_util::ParseError<usize,Token<'input>,SyntaxError>>
 {
     let __start0 = __4.0.clone();
     let __end0 = __4.2.clone();
-    let __temp0 = __action113(
-        input,
+    let __temp0 = __action130(
         __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action145(
-        input,
+    __action275(
         __0,
         __1,
         __2,
@@ -7078,28 +10809,24 @@ fn __action156<
     )
 }
 
-#[allow(unused_variables)]
-fn __action157<
+fn __action283<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Token<'input>, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
     __3: (usize, DataType, usize),
-    __4: (usize, &'input str, usize),
-) -> Variable
+    __4: (usize, Token<'input>, usize),
+) -> Result<Variable,__lalrpop_util::ParseError<usize,Token<'input>,SyntaxError>>
 {
     let __start0 = __3.2.clone();
     let __end0 = __4.0.clone();
-    let __temp0 = __action114(
-        input,
+    let __temp0 = __action131(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action145(
-        input,
+    __action275(
         __0,
         __1,
         __2,
@@ -7109,436 +10836,546 @@ fn __action157<
     )
 }
 
-#[allow(unused_variables)]
-fn __action158<
+fn __action284<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, Box<Expression>, usize),
-) -> Vec<Box<Expression>>
+    __0: (usize, FunctionParam, usize),
+) -> Vec<FunctionParam>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action115(
-        input,
+    let __temp0 = __action161(
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action137(
-        input,
+    __action189(
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action159<
+fn __action285<
     'input,
 >(
-    input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> Vec<Box<Expression>>
+) -> Vec<FunctionParam>
 {
     let __start0 = __lookbehind.clone();
     let __end0 = __lookahead.clone();
-    let __temp0 = __action116(
-        input,
+    let __temp0 = __action162(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action137(
-        input,
+    __action189(
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action160<
+fn __action286<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Box<Expression>>, usize),
-    __1: (usize, Box<Expression>, usize),
-) -> Vec<Box<Expression>>
+    __0: (usize, alloc::vec::Vec<FunctionParam>, usize),
+    __1: (usize, FunctionParam, usize),
+) -> Vec<FunctionParam>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action115(
-        input,
+    let __temp0 = __action161(
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action138(
-        input,
+    __action190(
         __0,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action161<
+fn __action287<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Box<Expression>>, usize),
-) -> Vec<Box<Expression>>
+    __0: (usize, alloc::vec::Vec<FunctionParam>, usize),
+) -> Vec<FunctionParam>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action116(
-        input,
+    let __temp0 = __action162(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action138(
-        input,
+    __action190(
         __0,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action162<
+fn __action288<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, FunctionParam, usize),
-) -> Vec<FunctionParam>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Program
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action97(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action96(
+        __temp0,
+    )
+}
+
+fn __action289<
+    'input,
+>(
+    __0: (usize, alloc::vec::Vec<Statement>, usize),
+) -> Program
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action120(
-        input,
+    let __temp0 = __action98(
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action141(
-        input,
+    __action96(
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action163<
+fn __action290<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<FunctionParam>
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Vec<FunctionParam>, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, DataType, usize),
+    __7: (usize, Dimension, usize),
+) -> FunctionSignature
 {
-    let __start0 = __lookbehind.clone();
-    let __end0 = __lookahead.clone();
-    let __temp0 = __action121(
-        input,
+    let __start0 = __7.0.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action109(
+        __7,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action264(
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
+}
+
+fn __action291<
+    'input,
+>(
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Vec<FunctionParam>, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, DataType, usize),
+) -> FunctionSignature
+{
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action110(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action141(
-        input,
+    __action264(
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action164<
+fn __action292<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<FunctionParam>, usize),
-    __1: (usize, FunctionParam, usize),
-) -> Vec<FunctionParam>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, DataType, usize),
+    __3: (usize, Dimension, usize),
+) -> FunctionParam
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action120(
-        input,
-        __1,
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action109(
+        __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action142(
-        input,
+    __action265(
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action165<
+fn __action293<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<FunctionParam>, usize),
-) -> Vec<FunctionParam>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, DataType, usize),
+) -> FunctionParam
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action121(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action110(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action142(
-        input,
+    __action265(
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action166<
+fn __action294<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, Token<'input>, usize),
 ) -> Block
 {
     let __start0 = __0.2.clone();
     let __end0 = __1.0.clone();
-    let __temp0 = __action95(
-        input,
+    let __temp0 = __action102(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action59(
-        input,
+    __action91(
         __0,
         __temp0,
         __1,
     )
 }
 
-#[allow(unused_variables)]
-fn __action167<
+fn __action295<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Token<'input>, usize),
     __1: (usize, alloc::vec::Vec<Statement>, usize),
-    __2: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
 ) -> Block
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action96(
-        input,
+    let __temp0 = __action103(
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action59(
-        input,
+    __action91(
         __0,
         __temp0,
         __2,
     )
 }
 
-#[allow(unused_variables)]
-fn __action168<
+fn __action296<
+    'input,
+>(
+    __0: (usize, Variable, usize),
+) -> Vec<Variable>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action166(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action193(
+        __temp0,
+    )
+}
+
+fn __action297<
     'input,
 >(
-    input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> Program
+) -> Vec<Variable>
 {
     let __start0 = __lookbehind.clone();
     let __end0 = __lookahead.clone();
-    let __temp0 = __action85(
-        input,
+    let __temp0 = __action167(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action84(
-        input,
+    __action193(
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action169<
+fn __action298<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Statement>, usize),
-) -> Program
+    __0: (usize, alloc::vec::Vec<Variable>, usize),
+    __1: (usize, Variable, usize),
+) -> Vec<Variable>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action166(
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action194(
+        __0,
+        __temp0,
+    )
+}
+
+fn __action299<
+    'input,
+>(
+    __0: (usize, alloc::vec::Vec<Variable>, usize),
+) -> Vec<Variable>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action167(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action194(
+        __0,
+        __temp0,
+    )
+}
+
+fn __action300<
+    'input,
+>(
+    __0: (usize, DataType, usize),
+) -> Vec<DataType>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __temp0 = __action142(
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action84(
-        input,
+    __action197(
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action170<
+fn __action301<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-) -> Block
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<DataType>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action90(
-        input,
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action143(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action79(
-        input,
-        __0,
+    __action197(
         __temp0,
-        __1,
     )
 }
 
-#[allow(unused_variables)]
-fn __action171<
+fn __action302<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, alloc::vec::Vec<Statement>, usize),
-    __2: (usize, &'input str, usize),
-) -> Block
+    __0: (usize, alloc::vec::Vec<DataType>, usize),
+    __1: (usize, DataType, usize),
+) -> Vec<DataType>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action91(
-        input,
+    let __temp0 = __action142(
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action79(
-        input,
+    __action198(
         __0,
         __temp0,
-        __2,
     )
 }
 
-#[allow(unused_variables)]
-fn __action172<
+fn __action303<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, alloc::vec::Vec<DataType>, usize),
+) -> Vec<DataType>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action143(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action198(
+        __0,
+        __temp0,
+    )
+}
+
+fn __action304<
+    'input,
+>(
+    __0: (usize, Operator, usize),
     __1: (usize, Box<Expression>, usize),
 ) -> Box<Expression>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action99(
-        input,
+    let __temp0 = __action112(
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action48(
-        input,
+    __action56(
         __temp0,
         __1,
     )
 }
 
-#[allow(unused_variables)]
-fn __action173<
+fn __action305<
     'input,
 >(
-    input: &'input str,
     __0: (usize, Box<Expression>, usize),
 ) -> Box<Expression>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action100(
-        input,
+    let __temp0 = __action113(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action48(
-        input,
+    __action56(
         __temp0,
         __0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action174<
+fn __action306<
     'input,
 >(
-    input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, Vec<Index>, usize),
 ) -> Access
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action108(
-        input,
+    let __temp0 = __action124(
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action25(
-        input,
+    __action277(
         __0,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action175<
+fn __action307<
     'input,
 >(
-    input: &'input str,
     __0: (usize, &'input str, usize),
 ) -> Access
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action109(
-        input,
+    let __temp0 = __action125(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action277(
+        __0,
+        __temp0,
+    )
+}
+
+fn __action308<
+    'input,
+>(
+    __0: (usize, &'input str, usize),
+    __1: (usize, Vec<Index>, usize),
+    __2: (usize, alloc::vec::Vec<&'input str>, usize),
+) -> Access
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action124(
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action278(
+        __0,
+        __temp0,
+        __2,
+    )
+}
+
+fn __action309<
+    'input,
+>(
+    __0: (usize, &'input str, usize),
+    __1: (usize, alloc::vec::Vec<&'input str>, usize),
+) -> Access
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action125(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action25(
-        input,
+    __action278(
         __0,
         __temp0,
+        __1,
     )
 }
 
 pub trait __ToTriple<'input, >
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>;
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, SyntaxError>>;
 }
 
 impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize)
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, SyntaxError>> {
         Ok(value)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize), &'static str>
+impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize), SyntaxError>
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, SyntaxError>> {
         match value {
             Ok(v) => Ok(v),
             Err(error) => Err(__lalrpop_util::ParseError::User { error }),
@@ -0,0 +1,334 @@
+//! Tokenizer of Moris programs, used by the parser generated with LALRPOP.
+
+use memory::types::IntType;
+
+use crate::{span::Span, SyntaxError};
+
+/// Token along with its start and end positions in the source, in bytes.
+pub type Spanned<'input> = Result<(usize, Token<'input>, usize), SyntaxError>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token<'input> {
+    Id(&'input str),
+    Integer(&'input str),
+    Float(&'input str),
+    /// String literal, with its escape sequences replaced.
+    Str(String),
+
+    // Types
+    TInt,
+    TFloat,
+    TBool,
+    TStr,
+    TDataFrame,
+    TSeries,
+    TVoid,
+
+    // Keywords
+    Let,
+    True,
+    False,
+    Fn,
+    Struct,
+    Return,
+    Continue,
+    Break,
+    For,
+    While,
+    In,
+    If,
+    Else,
+
+    // Operators
+    And,
+    Or,
+    NotEqual,
+    Not,
+    Equal,
+    Pipe,
+    Bar,
+    LessOrEq,
+    GreaterOrEq,
+    LessThan,
+    GreaterThan,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    MatMul,
+    Assign,
+
+    // Delimiters
+    LParen,
+    RParen,
+    LCBracket,
+    RCBracket,
+    LBracket,
+    RBracket,
+    Comma,
+    Colon,
+    Semicolon,
+    Dot,
+}
+
+/// Splits the source of a program into tokens, skipping whitespace and
+/// comments. Line comments start with `//`, block comments are enclosed
+/// by `/*` and `*/`.
+pub struct Lexer<'input> {
+    source: &'input str,
+    position: usize,
+}
+
+impl<'input> Lexer<'input> {
+    pub fn new(source: &'input str) -> Self {
+        Lexer {
+            source,
+            position: 0,
+        }
+    }
+
+    /// Returns the character a number of characters after the current
+    /// position, without consuming it.
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.source[self.position..].chars().nth(n)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.peek_nth(0)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let next = self.peek()?;
+        self.position += next.len_utf8();
+        Some(next)
+    }
+
+    /// Consumes the characters matching a predicate.
+    fn bump_while(&mut self, predicate: impl Fn(char) -> bool) {
+        while self.peek().is_some_and(&predicate) {
+            self.bump();
+        }
+    }
+
+    fn error(message: String, start: usize, end: usize, unexpected_eof: bool) -> SyntaxError {
+        SyntaxError {
+            message,
+            span: Span::new(start, end),
+            unexpected_eof,
+        }
+    }
+
+    /// Skips whitespace and comments until the start of the next token.
+    fn skip_trivia(&mut self) -> Result<(), SyntaxError> {
+        loop {
+            self.bump_while(char::is_whitespace);
+
+            match (self.peek(), self.peek_nth(1)) {
+                (Some('/'), Some('/')) => self.bump_while(|next| next != '\n'),
+                (Some('/'), Some('*')) => {
+                    let start = self.position;
+                    self.position += 2;
+                    match self.source[self.position..].find("*/") {
+                        Some(end) => self.position += end + 2,
+                        None => {
+                            return Err(Lexer::error(
+                                String::from("Unterminated block comment"),
+                                start,
+                                start + 2,
+                                true,
+                            ))
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn keyword(word: &str) -> Option<Token<'static>> {
+        let token = match word {
+            "int" => Token::TInt,
+            "float" => Token::TFloat,
+            "bool" => Token::TBool,
+            "str" => Token::TStr,
+            "DataFrame" => Token::TDataFrame,
+            "Series" => Token::TSeries,
+            "void" => Token::TVoid,
+            "let" => Token::Let,
+            "true" => Token::True,
+            "false" => Token::False,
+            "fn" => Token::Fn,
+            "struct" => Token::Struct,
+            "return" => Token::Return,
+            "continue" => Token::Continue,
+            "break" => Token::Break,
+            "for" => Token::For,
+            "while" => Token::While,
+            "in" => Token::In,
+            "if" => Token::If,
+            "else" => Token::Else,
+            _ => return None,
+        };
+
+        Some(token)
+    }
+
+    fn identifier(&mut self, start: usize) -> Token<'input> {
+        self.bump_while(|next| next.is_ascii_alphanumeric() || next == '_');
+        let word = &self.source[start..self.position];
+
+        Lexer::keyword(word).unwrap_or(Token::Id(word))
+    }
+
+    /// Reads an integer or a float, floats have a fractional part or an
+    /// exponent, e.g. `2.5`, `1e-3` or `6.02E23`. Integers must fit an int.
+    fn number(&mut self, start: usize) -> Result<Token<'input>, SyntaxError> {
+        let is_digit = |next: Option<char>| next.is_some_and(|next| next.is_ascii_digit());
+        let mut is_float = false;
+
+        self.bump_while(|next| next.is_ascii_digit());
+
+        if self.peek() == Some('.') && is_digit(self.peek_nth(1)) {
+            is_float = true;
+            self.bump();
+            self.bump_while(|next| next.is_ascii_digit());
+        }
+
+        if let Some('e' | 'E') = self.peek() {
+            let sign = matches!(self.peek_nth(1), Some('+' | '-')) as usize;
+            if is_digit(self.peek_nth(1 + sign)) {
+                is_float = true;
+                self.position += 1 + sign;
+                self.bump_while(|next| next.is_ascii_digit());
+            }
+        }
+
+        let number = &self.source[start..self.position];
+        if is_float {
+            return Ok(Token::Float(number));
+        } else if number.parse::<IntType>().is_err() {
+            return Err(Lexer::error(
+                format!("Integer {} is too large for an int", number),
+                start,
+                self.position,
+                false,
+            ));
+        }
+
+        Ok(Token::Integer(number))
+    }
+
+    fn string(&mut self, start: usize) -> Result<Token<'input>, SyntaxError> {
+        let mut value = String::new();
+
+        loop {
+            let escape_start = self.position;
+            match self.bump() {
+                Some('"') => return Ok(Token::Str(value)),
+                Some('\\') => {
+                    let escaped = match self.bump() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('0') => '\0',
+                        Some('\\') => '\\',
+                        Some('"') => '"',
+                        Some(other) => {
+                            return Err(Lexer::error(
+                                format!("Unknown escape sequence `\\{}`", other),
+                                escape_start,
+                                self.position,
+                                false,
+                            ))
+                        }
+                        None => break,
+                    };
+                    value.push(escaped);
+                }
+                Some(next) => value.push(next),
+                None => break,
+            }
+        }
+
+        Err(Lexer::error(
+            String::from("Unterminated string literal"),
+            start,
+            start + 1,
+            true,
+        ))
+    }
+
+    /// Reads an operator or delimiter, two character operators take
+    /// precedence over the single character ones.
+    fn symbol(&mut self, start: usize, first: char) -> Result<Token<'input>, SyntaxError> {
+        let double = match (first, self.peek()) {
+            ('&', Some('&')) => Some(Token::And),
+            ('|', Some('|')) => Some(Token::Or),
+            ('|', Some('>')) => Some(Token::Pipe),
+            ('!', Some('=')) => Some(Token::NotEqual),
+            ('=', Some('=')) => Some(Token::Equal),
+            ('<', Some('=')) => Some(Token::LessOrEq),
+            ('>', Some('=')) => Some(Token::GreaterOrEq),
+            _ => None,
+        };
+        if let Some(token) = double {
+            self.bump();
+            return Ok(token);
+        }
+
+        let token = match first {
+            '|' => Token::Bar,
+            '!' => Token::Not,
+            '=' => Token::Assign,
+            '<' => Token::LessThan,
+            '>' => Token::GreaterThan,
+            '+' => Token::Add,
+            '-' => Token::Sub,
+            '*' => Token::Mul,
+            '/' => Token::Div,
+            '@' => Token::MatMul,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '{' => Token::LCBracket,
+            '}' => Token::RCBracket,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            ',' => Token::Comma,
+            ':' => Token::Colon,
+            ';' => Token::Semicolon,
+            '.' => Token::Dot,
+            _ => {
+                return Err(Lexer::error(
+                    String::from("Invalid token"),
+                    start,
+                    self.position,
+                    false,
+                ))
+            }
+        };
+
+        Ok(token)
+    }
+}
+
+impl<'input> Iterator for Lexer<'input> {
+    type Item = Spanned<'input>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(error) = self.skip_trivia() {
+            // The rest of the source can't be tokenized
+            self.position = self.source.len();
+            return Some(Err(error));
+        }
+
+        let start = self.position;
+        let token = match self.bump()? {
+            first if first.is_ascii_alphabetic() || first == '_' => Ok(self.identifier(start)),
+            first if first.is_ascii_digit() => self.number(start),
+            '"' => self.string(start),
+            first => self.symbol(start, first),
+        };
+
+        Some(token.map(|token| (start, token, self.position)))
+    }
+}
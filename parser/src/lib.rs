@@ -1,31 +1,91 @@
 pub mod expressions;
 pub mod functions;
 pub mod grammar;
+pub mod lexer;
 pub mod semantics;
+pub mod span;
 pub mod statements;
+pub mod structs;
 pub mod types;
 
 use std::{fmt::Debug, vec, fs};
 
 use grammar::PProgramParser;
-use memory::types::{FloatType, IntType};
+use lalrpop_util::ParseError;
+use lexer::Lexer;
+use memory::types::{DataType, FloatType, IntType};
+use span::Span;
 use statements::Program;
 
 use self::expressions::{constant::Const, Expression};
 
 pub fn try_file(path: &str) -> Program {
     match fs::read_to_string(path) {
-        Ok(file_content) => PProgramParser::new().parse(file_content.as_str()).unwrap(),
+        Ok(file_content) => parse_program(file_content.as_str()).unwrap(),
         Err(error) => panic!("path: {} -> {}", path, error),
     }
 }
 
+/// Describes the first syntax error found while parsing a program.
+#[derive(Debug, Clone)]
+pub struct SyntaxError {
+    pub message: String,
+    pub span: Span,
+    /// Whether the source ended before the program was complete.
+    pub unexpected_eof: bool,
+}
+
+/// Parses the source code of a full program.
+pub fn parse_program(source: &str) -> Result<Program, SyntaxError> {
+    PProgramParser::new()
+        .parse(Lexer::new(source))
+        .map_err(|error| match error {
+            ParseError::InvalidToken { location } => SyntaxError {
+                message: String::from("Invalid token"),
+                span: Span::new(location, location + 1),
+                unexpected_eof: false,
+            },
+            ParseError::UnrecognizedEOF { location, expected } => SyntaxError {
+                message: format!(
+                    "Unexpected end of file, expected one of: {}",
+                    expected.join(", ")
+                ),
+                span: Span::new(location, location + 1),
+                unexpected_eof: true,
+            },
+            ParseError::UnrecognizedToken {
+                token: (start, _, end),
+                expected,
+            } => SyntaxError {
+                message: format!(
+                    "Unexpected token `{}`, expected one of: {}",
+                    &source[start..end],
+                    expected.join(", ")
+                ),
+                span: Span::new(start, end),
+                unexpected_eof: false,
+            },
+            ParseError::ExtraToken {
+                token: (start, _, end),
+            } => SyntaxError {
+                message: format!("Unexpected token `{}`", &source[start..end]),
+                span: Span::new(start, end),
+                unexpected_eof: false,
+            },
+            ParseError::User { error } => error,
+        })
+}
+
 #[derive(Debug, Clone)]
 pub struct Dimension {
     pub dimensions: i8,
+    /// Size of each axis, 0 for sizes only known at runtime.
     pub shape: Vec<usize>,
     pub size: usize,
     pub acc_size: Vec<usize>,
+    /// Size expressions of arrays allocated at runtime, empty for arrays
+    /// with a fixed size.
+    pub lengths: Vec<Box<Expression>>,
 }
 
 impl Dimension {
@@ -35,24 +95,27 @@ impl Dimension {
             shape: vec![],
             size: 1,
             acc_size: vec![],
+            lengths: vec![],
         }
     }
 
-    pub fn new_from_values(dimensions: i8, shape: Vec<Const>) -> Dimension {
-        let usize_shape: Vec<usize> = shape
+    /// Creates the dimension of an array of a fixed size, failing at the
+    /// first size that isn't a positive integer.
+    pub fn new(dimensions: i8, shape: Vec<Const>) -> Result<Dimension, SyntaxError> {
+        let usize_shape = shape
             .iter()
-            .map(
-                |constant| match str::parse::<usize>(constant.value.as_str()) {
-                    Ok(size) => {
-                        if size <= 0 {
-                            panic!("Invalid dimension size {size} in array declaration.")
-                        }
-                        size
-                    }
-                    Err(error) => panic!("Can't parse variable dimension: {:#?}", error),
-                },
-            )
-            .collect();
+            .map(|constant| match constant.value.parse::<usize>() {
+                Ok(size) if size > 0 => Ok(size),
+                _ => Err(SyntaxError {
+                    message: format!(
+                        "Invalid dimension size {} in array declaration, sizes must be positive",
+                        constant.value
+                    ),
+                    span: constant.span,
+                    unexpected_eof: false,
+                }),
+            })
+            .collect::<Result<Vec<usize>, SyntaxError>>()?;
 
         let size = usize_shape.iter().fold(1, |acc, item| acc * item);
 
@@ -61,41 +124,81 @@ impl Dimension {
             shape: usize_shape,
             size,
             acc_size: vec![],
+            lengths: vec![],
         };
 
         new_dim.calc_acc_size();
-        new_dim
+        Ok(new_dim)
     }
 
-    pub fn new(dimensions: i8, shape: Vec<Const>) -> Dimension {
-        let usize_shape: Vec<usize> = shape
+    /// Creates the dimension of an array declaration from its size
+    /// expressions, the array is allocated at runtime unless every size is
+    /// an integer literal.
+    pub fn from_lengths(lengths: Vec<Box<Expression>>) -> Result<Dimension, SyntaxError> {
+        let constants: Vec<Const> = lengths
             .iter()
-            .map(
-                |constant| match str::parse::<usize>(constant.value.as_str()) {
-                    Ok(size) => {
-                        if size <= 0 {
-                            panic!("Invalid dimension size {size} in array declaration.")
-                        }
-                        size
-                    }
-                    Err(error) => panic!("Can't parse variable dimension: {:#?}", error),
-                },
-            )
+            .filter_map(|length| match length.as_ref() {
+                Expression::Const(constant) if constant.dtype == DataType::Int => {
+                    Some(constant.clone())
+                }
+                _ => None,
+            })
             .collect();
 
-        let size = usize_shape.iter().fold(1, |acc, item| acc * item);
+        if constants.len() == lengths.len() {
+            return Dimension::new(lengths.len() as i8, constants);
+        }
 
+        let mut new_dim = Dimension::new_runtime(lengths.len() as i8);
+        new_dim.lengths = lengths;
+        Ok(new_dim)
+    }
+
+    /// Creates the dimension of an array whose sizes are only known at
+    /// runtime, like array parameters declared as `int[]`.
+    pub fn new_runtime(dimensions: i8) -> Dimension {
         let mut new_dim = Dimension {
             dimensions,
-            shape: usize_shape,
-            size,
+            shape: vec![0; dimensions as usize],
+            size: 0,
             acc_size: vec![],
+            lengths: vec![],
         };
 
         new_dim.calc_acc_size();
         new_dim
     }
 
+    /// Checks the value of a declaration fits its dimension, array literals
+    /// must have the declared shape.
+    pub fn check_value(&self, value: &Expression) -> Result<(), String> {
+        let vector = match value {
+            Expression::Vector(vector) => vector,
+            _ => return Ok(()),
+        };
+        let shape = vector.shape()?;
+
+        if self.dimensions == 0 {
+            Err(String::from("Array literals can only initialize arrays"))
+        } else if self.is_runtime_sized() {
+            Err(String::from(
+                "Array literals can't initialize arrays sized at runtime",
+            ))
+        } else if shape != self.shape {
+            Err(format!(
+                "Array literal of shape {:?} can't initialize an array of shape {:?}",
+                shape, self.shape
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Whether the size of the array is only known at runtime.
+    pub fn is_runtime_sized(&self) -> bool {
+        self.dimensions > 0 && self.shape.contains(&0)
+    }
+
     fn calc_acc_size(&mut self) {
         if self.acc_size.len() != self.dimensions as usize {
             let mut new_acc_size = self
@@ -103,8 +206,9 @@ impl Dimension {
                 .iter()
                 .rev()
                 .scan(1, |acc, &curr| {
-                    *acc = *acc * curr;
-                    Some(*acc / curr)
+                    let stride = *acc;
+                    *acc *= curr;
+                    Some(stride)
                 })
                 .collect::<Vec<usize>>();
             new_acc_size.reverse();
@@ -127,7 +231,7 @@ pub enum TypeConst {
 mod tests {
     use memory::types::DataType;
 
-    use super::{expressions::constant::Const, Dimension};
+    use super::{expressions::constant::Const, span::Span, Dimension};
 
     #[test]
     fn test_array_offset() {
@@ -137,17 +241,21 @@ mod tests {
                 Const {
                     dtype: DataType::Int,
                     value: String::from("7"),
+                    span: Span::default(),
                 },
                 Const {
                     dtype: DataType::Int,
                     value: String::from("6"),
+                    span: Span::default(),
                 },
                 Const {
                     dtype: DataType::Int,
                     value: String::from("4"),
+                    span: Span::default(),
                 },
             ],
-        );
+        )
+        .unwrap();
         assert_eq!(dim.acc_size, vec![24, 4, 1]);
         // assert_eq!(dim.get_array_offset(vec![0, 0, 3]), 3);
         // assert_eq!(dim.get_array_offset(vec![0, 3, 0]), 3 * 4);
@@ -15,18 +15,39 @@ pub enum ExitStatement {
     Return,
 }
 
+/// Result of a type check, holding the resulting data type or a
+/// description of the incompatibility.
+pub type SemanticResult = Result<DataType, std::string::String>;
+
 pub struct SemanticRules {}
 
 impl SemanticRules {
-    pub fn match_type(operator: Operator, left: DataType, right: DataType) -> DataType {
+    /// Checks two shapes have the same number of dimensions and sizes, the
+    /// sizes only known at runtime (0) match any size.
+    pub fn match_shape(left: &[usize], right: &[usize]) -> bool {
+        left.len() == right.len()
+            && left
+                .iter()
+                .zip(right)
+                .all(|(left, right)| left == right || *left == 0 || *right == 0)
+    }
+
+    pub fn match_type(operator: Operator, left: DataType, right: DataType) -> SemanticResult {
         match operator {
+            Div | Add | Sub | Mul | And | Or | LessThan | GreaterThan | NotEq | Eq | LessOrEq
+            | GreaterOrEq
+                if left == Series || right == Series =>
+            {
+                Self::series_match(left, right)
+            }
             Div => match left {
                 Int | Bool | Float => match right {
-                    Int | Float | Bool => Float,
+                    Int | Float | Bool => Ok(Float),
                     _ => Self::fail(left, right),
                 },
                 _ => Self::fail(left, right),
             },
+            MatMul => Self::matmul_match(left, right),
             Add => Self::arith_match(left, right, true),
             Sub | Mul => Self::arith_match(left, right, false),
             Pipe | ForwardPipe => Self::pipe_match(left, right),
@@ -35,109 +56,130 @@ impl SemanticRules {
                 Self::comparison_match(left, right)
             }
             Assign => Self::assign_match(left, right),
-            Not | Neg => panic!(),
+            Not | Neg => Err(format!(
+                "Operator {} is not a binary operator",
+                operator.to_string()
+            )),
+        }
+    }
+
+    fn fail(left: DataType, right: DataType) -> SemanticResult {
+        Err(format!(
+            "Types {:?} and {:?} are not compatible!",
+            left, right
+        ))
+    }
+
+    /// Operations on Series apply to each of their values, resulting in
+    /// another Series.
+    fn series_match(left: DataType, right: DataType) -> SemanticResult {
+        match (&left, &right) {
+            (Series, Int | Float | Bool | Series) | (Int | Float | Bool, Series) => Ok(Series),
+            _ => Self::fail(left, right),
         }
     }
 
-    fn fail(left: DataType, right: DataType) -> ! {
-        panic!(
-            "{}",
-            format!("Types {:?} and {:?} are not compatible!", left, right)
-        );
+    /// Matrix products keep integers only when both matrices hold them.
+    fn matmul_match(left: DataType, right: DataType) -> SemanticResult {
+        match (&left, &right) {
+            (Int, Int) => Ok(Int),
+            (Int | Float, Int | Float) => Ok(Float),
+            _ => Self::fail(left, right),
+        }
     }
 
-    fn pipe_match_r(left: DataType, right: DataType) -> DataType {
+    fn pipe_match_r(left: DataType, right: DataType) -> SemanticResult {
         match right {
-            Function(func) => *func.clone(),
+            Function(_, func) => Ok(*func.clone()),
             _ => Self::fail(left, right),
         }
     }
 
-    fn pipe_match(left: DataType, right: DataType) -> DataType {
+    fn pipe_match(left: DataType, right: DataType) -> SemanticResult {
         match left {
             Int | Float | Bool | String | DataFrame => Self::pipe_match_r(left, right),
             _ => Self::fail(left, right),
         }
     }
 
-    fn assign_match(left: DataType, right: DataType) -> DataType {
+    fn assign_match(left: DataType, right: DataType) -> SemanticResult {
         match left {
             Int => match right {
-                Int | Float | Bool => Int,
+                Int | Float | Bool => Ok(Int),
                 _ => Self::fail(left, right),
             },
             Float => match right {
-                Int | Float | Bool => Float,
+                Int | Float | Bool => Ok(Float),
                 _ => Self::fail(left, right),
             },
             Bool => match right {
-                Int | Float | Bool => Bool,
+                Int | Float | Bool => Ok(Bool),
                 _ => Self::fail(left, right),
             },
             String => match right {
-                String => String,
+                String => Ok(String),
                 _ => Self::fail(left, right),
             },
             DataFrame => match right {
-                DataFrame => DataFrame,
+                DataFrame => Ok(DataFrame),
                 _ => Self::fail(left, right),
             },
             _ => Self::fail(left, right),
         }
     }
 
-    fn comparison_match_r(left: DataType, right: DataType) -> DataType {
+    fn comparison_match_r(left: DataType, right: DataType) -> SemanticResult {
         match right {
-            Int | Float | Bool => Bool,
+            Int | Float | Bool => Ok(Bool),
             _ => Self::fail(left, right),
         }
     }
 
-    fn comparison_match(left: DataType, right: DataType) -> DataType {
+    fn comparison_match(left: DataType, right: DataType) -> SemanticResult {
         match left {
             Int | Float | Bool => Self::comparison_match_r(left, right),
             String => match right {
-                String => Bool,
+                String => Ok(Bool),
                 _ => Self::fail(left, right),
             },
             _ => Self::fail(left, right),
         }
     }
 
-    fn bool_match_r(left: DataType, right: DataType) -> DataType {
+    fn bool_match_r(left: DataType, right: DataType) -> SemanticResult {
         match right {
-            Int | Float | Bool | String | DataFrame => Bool,
+            Int | Float | Bool | String | DataFrame => Ok(Bool),
             _ => Self::fail(left, right),
         }
     }
 
-    fn bool_match(left: DataType, right: DataType) -> DataType {
+    fn bool_match(left: DataType, right: DataType) -> SemanticResult {
         match left {
             Int | Float | Bool | String | DataFrame => Self::bool_match_r(left, right),
             _ => Self::fail(left, right),
         }
     }
 
-    fn arith_match(left: DataType, right: DataType, is_add: bool) -> DataType {
+    fn arith_match(left: DataType, right: DataType, is_add: bool) -> SemanticResult {
         match left {
             Int => match right {
-                Int | Bool => Int,
-                Float => Float,
+                Int | Bool => Ok(Int),
+                Float => Ok(Float),
                 _ => Self::fail(left, right),
             },
             Float => match right {
-                Int | Float | Bool => Float,
+                Int | Float | Bool => Ok(Float),
                 _ => Self::fail(left, right),
             },
             Bool => match right {
-                Int | Bool => Int,
-                Float => Float,
+                Int | Bool => Ok(Int),
+                Float => Ok(Float),
                 _ => Self::fail(left, right),
             },
             String => match right {
                 String => {
                     if is_add {
-                        String
+                        Ok(String)
                     } else {
                         Self::fail(left, right)
                     }
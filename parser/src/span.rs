@@ -0,0 +1,51 @@
+/// Byte range of a node within the source code it was parsed from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Span {
+        Span { start, end }
+    }
+
+    /// Returns the smallest span covering both spans.
+    pub fn join(&self, other: &Span) -> Span {
+        if *self == Span::default() {
+            return *other;
+        } else if *other == Span::default() {
+            return *self;
+        }
+
+        Span {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    /// Returns the 1-based (line, column) position of the span start
+    /// within the source.
+    pub fn line_col(&self, source: &str) -> (usize, usize) {
+        let start = self.start.min(source.len());
+        let preceding = &source[..start];
+        let line = preceding.matches('\n').count() + 1;
+        let line_start = preceding.rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+
+        (line, preceding[line_start..].chars().count() + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Span;
+
+    #[test]
+    fn test_line_col() {
+        let source = "let x: int;\nlet y: float = x;\n";
+
+        assert_eq!(Span::new(0, 3).line_col(source), (1, 1));
+        assert_eq!(Span::new(16, 17).line_col(source), (2, 5));
+        assert_eq!(Span::new(4, 5).join(&Span::new(16, 17)), Span::new(4, 17));
+    }
+}
@@ -3,6 +3,8 @@ use std::fmt::Debug;
 use crate::{
     expressions::{id::Access, Expression},
     functions::Function,
+    span::Span,
+    structs::Struct,
     types::Variable,
 };
 
@@ -25,12 +27,34 @@ pub enum Statement {
         block: Block,
     },
     FunctionDeclaration(Function),
+    StructDeclaration(Struct),
     Return(Box<Expression>),
     VoidReturn,
     Break,
     Continue,
 }
 
+impl Statement {
+    /// Returns the source span of the statement header, used to map
+    /// the generated code back to a source line.
+    ///
+    /// Statements without any spanned node return an empty span.
+    pub fn span(&self) -> Span {
+        match self {
+            Statement::VarDeclaration(var) => var.span,
+            Statement::VarAssign(access, expr) => access.span.join(&expr.span()),
+            Statement::Expression(expr) => expr.span(),
+            Statement::If { condition, .. } => condition.span(),
+            Statement::For { range, .. } => range.0.span(),
+            Statement::While { condition, .. } => condition.span(),
+            Statement::FunctionDeclaration(func) => func.signature.span,
+            Statement::StructDeclaration(declaration) => declaration.span,
+            Statement::Return(expr) => expr.span(),
+            Statement::VoidReturn | Statement::Break | Statement::Continue => Span::default(),
+        }
+    }
+}
+
 impl Debug for Statement {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -65,6 +89,7 @@ impl Debug for Statement {
                 write!(fmt, "WHILE ({:#?}) {{\n {:#?}\n}}", condition, block)
             }
             Statement::FunctionDeclaration(func) => write!(fmt, "{:#?}", func),
+            Statement::StructDeclaration(declaration) => write!(fmt, "{:#?}", declaration),
             Statement::Return(stmt) => write!(fmt, "RETURN {:#?}", stmt),
             Statement::Break => write!(fmt, "Break"),
             Statement::Continue => write!(fmt, "Continue"),
@@ -0,0 +1,20 @@
+use crate::{span::Span, types::Variable};
+
+/// Declaration of a record type, its fields are scalar values of any other
+/// data type, including previously declared structs.
+#[derive(Debug, Clone)]
+pub struct Struct {
+    pub id: String,
+    pub fields: Vec<Variable>,
+    pub span: Span,
+}
+
+impl Struct {
+    pub fn new(id: &str, fields: Vec<Variable>) -> Struct {
+        Struct {
+            id: String::from(id),
+            fields,
+            span: Span::default(),
+        }
+    }
+}
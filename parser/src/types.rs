@@ -1,6 +1,6 @@
 use memory::types::DataType;
 
-use crate::Dimension;
+use crate::{span::Span, Dimension};
 
 use super::expressions::Expression;
 
@@ -18,6 +18,7 @@ pub enum OperatorType {
 pub enum Operator {
     Mul,
     Div,
+    MatMul,
     Add,
     Sub,
     Pipe,
@@ -40,6 +41,7 @@ impl Operator {
         match self {
             Operator::Mul => "*",
             Operator::Div => "/",
+            Operator::MatMul => "@",
             Operator::Add => "+",
             Operator::Sub => "-",
             Operator::Pipe => "|>",
@@ -93,7 +95,7 @@ impl Operator {
             | Operator::NotEq
             | Operator::Eq => OperatorType::Comparison,
             Operator::And | Operator::Or | Operator::Not => OperatorType::Boolean,
-            Operator::Mul | Operator::Div | Operator::Add | Operator::Sub => {
+            Operator::Mul | Operator::Div | Operator::MatMul | Operator::Add | Operator::Sub => {
                 OperatorType::Arithmetic
             }
             Operator::Pipe | Operator::ForwardPipe => OperatorType::Pipe,
@@ -109,6 +111,10 @@ pub struct Variable {
     pub data_type: DataType,
     pub dimension: Dimension,
     pub value: Option<Box<Expression>>,
+    pub span: Span,
+    /// Whether the type and dimension are inferred from the value, for
+    /// declarations without a type.
+    pub inferred: bool,
 }
 
 impl Variable {
@@ -118,6 +124,8 @@ impl Variable {
             data_type,
             dimension: Dimension::new_scalar(),
             value: None,
+            span: Span::default(),
+            inferred: false,
         }
     }
 
@@ -132,6 +140,17 @@ impl Variable {
             data_type,
             dimension,
             value,
+            span: Span::default(),
+            inferred: false,
+        }
+    }
+
+    /// Creates a declaration whose type and dimension are inferred from
+    /// its value once it is generated.
+    pub fn new_inferred(id: String, value: Box<Expression>) -> Variable {
+        Variable {
+            inferred: true,
+            ..Variable::new(id, DataType::Void, Dimension::new_scalar(), Some(value))
         }
     }
 
@@ -1,17 +1,11 @@
 use parser;
 
-fn get_parser() -> parser::grammar::PProgramParser {
-    parser::grammar::PProgramParser::new()
-}
-
 fn expect_fail(in_str: &str) {
-    let parser = get_parser();
-    assert!(std::panic::catch_unwind(|| parser.parse(in_str).unwrap()).is_err());
+    assert!(std::panic::catch_unwind(|| parser::parse_program(in_str).unwrap()).is_err());
 }
 
 fn expect_success(in_str: &str) {
-    let parser = get_parser();
-    assert!(std::panic::catch_unwind(|| parser.parse(in_str).unwrap()).is_ok());
+    assert!(std::panic::catch_unwind(|| parser::parse_program(in_str).unwrap()).is_ok());
 }
 
 #[test]
@@ -39,12 +33,14 @@ fn test_expressions() {
     expect_success("5 + 2- 3 *(5 + 10)/ 3;");
     expect_success("5 |> fn2;");
     expect_success("(fnid(x, y, z) + 5 / 2) |> pipeFn-2;");
+    expect_success("a @ b * 2 + transpose(c) @ d;");
 
     expect_fail("a |> 3;");
     expect_fail("(5 + 3;");
     expect_fail("fn + 5;");
     expect_fail("if + 5;");
     expect_fail("5 * 4 /3 |>for + int;");
+    expect_fail("a @ ;");
 }
 
 #[test]
@@ -53,17 +49,29 @@ fn test_variables() {
     expect_success("let x:int= 5;");
     expect_success("let x : int = 5;");
     expect_success("let df: DataFrame;");
-    // TODO: reimplement vector constants
-    // expect_success("let vec: int[1][1] = [[a]];");
+    expect_success("let vec: int[1][1] = [[a]];");
+    expect_success("let vec: int[3] = [1, 2, 3];");
+    expect_success("let mat: float[2][2] = [[1.0, 0.0], [0.0, 1.0]];");
+    expect_success("vec = [x, y + 1, f(z)];");
+    expect_success("let total = sum(arr) / 2;");
+    expect_success("let vec = [1, 2, 3];");
+    expect_success("let t: int[2][2][2] = [[[1, 2], [3, 4]], [[5, 6], [7, 8]]];");
+    expect_success("let t: float[n][3][4][5]; t[0][1][2][3] = t[i][j][k][l];");
 
     expect_fail("5 = 7;");
     expect_fail("let 3:int = 4;");
     expect_fail("var:float=5;");
     expect_fail("var = ;");
-    // expect_fail("let vec: int[1][1][3] = [[a]];");
+    expect_fail("let vec: int[1][1][3] = [[a]];");
+    expect_fail("let vec: int[3] = [1, 2];");
+    expect_fail("let vec: int[2][2] = [[1, 2], [3]];");
+    expect_fail("let vec: int[2] = [[1], [2]];");
+    expect_fail("let x: int = [1];");
     expect_fail("let vec: int[2][];");
     expect_fail("let vec: bool[][3];");
     expect_fail("let vec: bool[];");
+    expect_fail("let x;");
+    expect_fail("let x = ;");
 }
 
 #[test]
@@ -145,7 +153,7 @@ fn test_if() {
         else x = 7;
     ",
     );
-    expect_fail(
+    expect_success(
         " 
         if (x == 2) {
             let y:int = 4;
@@ -169,7 +177,7 @@ fn test_for() {
     );
     expect_success("for(it in a + 2 : b + 2 : -1) {}");
 
-    expect_fail(
+    expect_success(
         "for (it in 1:-2) {
         let x:int = 2;
     }",
@@ -200,7 +208,7 @@ fn test_while() {
     );
     expect_success("while(a || b) {}");
 
-    expect_fail(
+    expect_success(
         "while (true) {
         let x:int = 2;
     }",
@@ -208,3 +216,115 @@ fn test_while() {
     expect_fail("while + 2;");
     expect_fail("while(let x:int = 1){}");
 }
+
+#[test]
+fn test_functions() {
+    expect_success("fn f(x: int): int { return x; }");
+    expect_success("fn identity(): float[4][3] { let m: float[4][3]; return m; }");
+    expect_success("fn first(m: float[][]): float[][] { return m; }");
+    expect_success("fn volume(t: int[][][]): int[][][] { return t; }");
+    expect_success("fn log(msg: str): void { print(msg); }");
+
+    expect_success(
+        "fn outer(x: int): int {
+        fn inner(y: int): int { return x + y; }
+        return inner(1);
+    }",
+    );
+    expect_success("if (true) { fn f(): void { print(1); } }");
+
+    expect_fail("fn f(x: int) { return x; }");
+    expect_fail("fn f(): [3] { return x; }");
+}
+
+#[test]
+fn test_lambdas() {
+    expect_success("let double: fn(int): int = |x: int| x * 2;");
+    expect_success("let add: fn(int, float): float = |a: int, b: float| a + b;");
+    expect_success("let seven: fn(): int = || 7;");
+    expect_success("fn apply(f: fn(int): bool, x: int): bool { return f(x); }");
+    expect_success("fn adder(n: int): fn(int): int { return |x: int| x + n; }");
+    expect_success("xs |> map(|x: float| x * 2.0) |> filter(positive);");
+    expect_success("xs |> scale(2.0);");
+    expect_success("print(reduce(xs, |acc: int, x: int| acc + x, 0));");
+
+    expect_fail("let f: fn(int) = |x: int| x;");
+    expect_fail("|x: int| { return x; };");
+    expect_fail("xs |> |x: int| x;");
+    expect_fail("|x| x + 1;");
+}
+
+#[test]
+fn test_structs() {
+    expect_success("struct Point { x: float, y: float }");
+    expect_success("struct Segment { start: Point, end: Point }");
+    expect_success("let p: Point; p.x = 2.5; print(p.x + p.y);");
+    expect_success("let path: Point[10]; path[2].x = path[1].y;");
+    expect_success("let s: Segment; s.start.x = 1;");
+    expect_success("fn norm(p: Point): float { return p.x * p.x + p.y * p.y; }");
+
+    expect_fail("struct Point { x: float[2] }");
+    expect_fail("struct { x: float }");
+    expect_fail("let p: Point; p. = 2;");
+}
+
+#[test]
+fn test_slices() {
+    expect_success("let b = arr[2:8];");
+    expect_success("print(mean(m[i][:]), sum(m[:][j]));");
+    expect_success("let evens = arr[::2]; let tail = arr[n - 2:];");
+    expect_success("let v = t[1:3:2][:len(t[0]) - 1][k];");
+    expect_success("m[0:2][1:] = m[1:][:2];");
+    expect_success("scatter(x[:n], y[:n]);");
+
+    expect_fail("let b = arr[2:8:1:1];");
+    expect_fail("let b = arr[2 8];");
+    expect_fail("let vec: int[2:3];");
+    expect_fail("fn f(v: int[:]) {}");
+}
+
+#[test]
+fn test_tokens() {
+    expect_success("f (x);");
+    expect_success("let v: int = a[1] [2];");
+    expect_success("if\n(x > 0) { print(x); }");
+    expect_success("for (i in 0:10) print(i);");
+    expect_success("while (x) { x = false; }");
+    expect_success("let x: int = 2; // Line comment");
+    expect_success("let x: /* type */ int = /* value */ 2;");
+    expect_success("print(\"tab\\t, quote \\\" and line\\n\");");
+    expect_success("let x: float = 1.5e-3 + 2E10 + 6e+2;");
+    expect_success("let iffy: int = format + fortune;");
+
+    expect_fail("let x: int = 2; /* unterminated");
+    expect_fail("print(\"unterminated);");
+    expect_fail("print(\"bad \\q escape\");");
+    expect_fail("let x: int = 2 & 3;");
+    expect_fail("let x: float = 1e;");
+    expect_fail("let m: int[2][2][];");
+}
+
+#[test]
+fn test_lexer() {
+    use parser::lexer::{Lexer, Token};
+
+    let tokens: Vec<_> = Lexer::new("x[0] |> f(\"a\\n\") // done\n1.5e3")
+        .map(|token| token.unwrap())
+        .collect();
+
+    assert_eq!(
+        tokens,
+        vec![
+            (0, Token::Id("x"), 1),
+            (1, Token::LBracket, 2),
+            (2, Token::Integer("0"), 3),
+            (3, Token::RBracket, 4),
+            (5, Token::Pipe, 7),
+            (8, Token::Id("f"), 9),
+            (9, Token::LParen, 10),
+            (10, Token::Str(String::from("a\n")), 15),
+            (15, Token::RParen, 16),
+            (25, Token::Float("1.5e3"), 30),
+        ]
+    );
+}
@@ -1,11 +1,174 @@
-use moris_lang::vm::runner::Runner;
-use std::env;
+use moris_lang::vm::{
+    debugger::Debugger,
+    disassembler::{DisasmFormat, Disassembler},
+    repl::Repl,
+    runner::Runner,
+    virtual_machine::VirtualMachine,
+};
+use std::{env, path::Path, process};
+
+const USAGE: &str = "\
+Usage:
+    moris <file.mo>                          compile and run a program
+    moris compile <file.mo> [-o <out.mobc>]  compile a program into a bytecode file
+    moris run <file.mobc>                    run a compiled bytecode file
+    moris disasm <file.mo|file.mobc> [--format text|markdown|json]
+                                             list the instructions of a program
+    moris repl                               start an interactive session
+    moris debug <file.mo>                    run a program step by step
+
+Options:
+    -O[level]    optimize the generated code when compiling, -O0 disables it";
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(1);
+}
+
+/// Opens the source file of a program, exiting if it can't be read.
+fn runner(path: &str) -> Runner {
+    match Runner::new(path) {
+        Ok(runner) => runner,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
+
+/// Separates the optimization level flag from the rest of the arguments.
+fn optimization_level(args: Vec<String>) -> (Vec<String>, usize) {
+    let mut level = 0;
+    let args = args
+        .into_iter()
+        .filter(|arg| match arg.strip_prefix("-O") {
+            Some("") => {
+                level = 1;
+                false
+            }
+            Some(value) => {
+                level = value.parse().unwrap_or_else(|_| usage());
+                false
+            }
+            None => true,
+        })
+        .collect();
+
+    (args, level)
+}
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let (args, level) = optimization_level(env::args().collect());
+
+    let path = match args.get(1) {
+        Some(path) => path,
+        None => usage(),
+    };
+
+    if path == "repl" {
+        Repl::new().run();
+        return;
+    }
+
+    if path == "compile" {
+        let (path, out_path) = match &args[2..] {
+            [path] => (path, Path::new(path).with_extension("mobc")),
+            [path, flag, out_path] if flag == "-o" => (path, Path::new(out_path).to_path_buf()),
+            _ => usage(),
+        };
+
+        let mut runner = runner(path);
+        runner
+            .with_output_path(out_path.to_str().unwrap())
+            .with_optimization(level);
+        if let Err(errors) = runner.compile() {
+            runner.print_errors(&errors);
+            process::exit(1);
+        }
+        return;
+    }
+
+    if path == "run" {
+        let path = match args.get(2) {
+            Some(path) => path,
+            None => usage(),
+        };
+
+        let mut vm = match VirtualMachine::load(path) {
+            Ok(vm) => vm,
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        };
+        if let Err(error) = vm.execute() {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+        return;
+    }
+
+    if path == "disasm" {
+        let (path, format) = match &args[2..] {
+            [path] => (path, DisasmFormat::Text),
+            [path, flag, format] if flag == "--format" => match format.parse() {
+                Ok(format) => (path, format),
+                Err(error) => {
+                    eprintln!("{}", error);
+                    process::exit(1);
+                }
+            },
+            _ => usage(),
+        };
+
+        let data = if path.ends_with(".mo") {
+            let mut runner = runner(path);
+            runner.with_optimization(level);
+            if let Err(errors) = runner.generate() {
+                runner.print_errors(&errors);
+                process::exit(1);
+            }
+            runner.manager.program_meta()
+        } else {
+            match VirtualMachine::read_program(path) {
+                Ok(data) => data,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    process::exit(1);
+                }
+            }
+        };
+
+        print!("{}", Disassembler::new(&data).render(format));
+        return;
+    }
+
+    if path == "debug" {
+        let path = match args.get(2) {
+            Some(path) => path,
+            None => usage(),
+        };
+
+        let mut runner = runner(path);
+        if let Err(errors) = runner.compile() {
+            runner.print_errors(&errors);
+            process::exit(1);
+        }
 
-    let path = &args[1];
+        match Debugger::new(runner.manager) {
+            Ok(mut debugger) => debugger.run(),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        }
+        return;
+    }
 
-    let mut runner = Runner::new(path).unwrap();
-    runner.compile_and_run();
+    let mut runner = runner(path);
+    runner.with_optimization(level);
+    if let Err(error) = runner.compile_and_run() {
+        runner.print_run_error(&error);
+        process::exit(1);
+    }
 }
@@ -1,6 +1,14 @@
 pub mod virtual_machine;
 pub mod memory_manager;
+pub mod array;
+pub mod linalg;
+pub mod math;
+pub mod strings;
 pub mod inspector;
 pub mod runner;
 pub mod utils;
-pub mod natives;
\ No newline at end of file
+pub mod natives;
+pub mod errors;
+pub mod repl;
+pub mod debugger;
+pub mod disassembler;
\ No newline at end of file
@@ -0,0 +1,243 @@
+use std::{collections::BTreeMap, ops::Range};
+
+use memory::{
+    resolver::MemAddress,
+    types::{DataType, FloatType, IntType},
+};
+
+use super::{
+    errors::{RuntimeErrorKind, RuntimeResult},
+    memory_manager::Item,
+};
+
+/// Arrays of a scope, by the address of their first item.
+pub type Arrays = BTreeMap<MemAddress, Array>;
+
+/// Views of arrays, by the address of their first item.
+pub type Views = BTreeMap<MemAddress, View>;
+
+/// Items of an array, stored contiguously by data type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Buffer {
+    Int(Vec<IntType>),
+    Float(Vec<FloatType>),
+    Bool(Vec<bool>),
+    String(Vec<String>),
+    Pointer(Vec<MemAddress>),
+    /// Items without a fixed size representation, e.g. DataFrames, which
+    /// are undefined until assigned.
+    Items(Vec<Option<Item>>),
+}
+
+/// Array stored in memory, its items are laid out in row major order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Array {
+    pub shape: Vec<usize>,
+    pub buffer: Buffer,
+}
+
+impl Array {
+    /// Creates an array of the given shape, with its items set to the zero
+    /// value of their type.
+    pub fn new(data_type: &DataType, shape: &[usize]) -> Array {
+        let size = shape.iter().product();
+
+        let buffer = match data_type {
+            DataType::Int => Buffer::Int(vec![0; size]),
+            DataType::Float => Buffer::Float(vec![0.0; size]),
+            DataType::Bool => Buffer::Bool(vec![false; size]),
+            DataType::String => Buffer::String(vec![String::new(); size]),
+            DataType::Pointer => Buffer::Pointer(vec![0; size]),
+            _ => Buffer::Items(vec![None; size]),
+        };
+
+        Array {
+            shape: shape.to_vec(),
+            buffer,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.buffer {
+            Buffer::Int(items) => items.len(),
+            Buffer::Float(items) => items.len(),
+            Buffer::Bool(items) => items.len(),
+            Buffer::String(items) => items.len(),
+            Buffer::Pointer(items) => items.len(),
+            Buffer::Items(items) => items.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn type_name(&self) -> &str {
+        match &self.buffer {
+            Buffer::Int(_) => "Int",
+            Buffer::Float(_) => "Float",
+            Buffer::Bool(_) => "Bool",
+            Buffer::String(_) => "String",
+            Buffer::Pointer(_) => "Pointer",
+            Buffer::Items(_) => "Item",
+        }
+    }
+
+    /// Returns the item at an index, None if it is out of bounds or was
+    /// never assigned.
+    pub fn get(&self, index: usize) -> Option<Item> {
+        match &self.buffer {
+            Buffer::Int(items) => items.get(index).map(|item| Item::Int(*item)),
+            Buffer::Float(items) => items.get(index).map(|item| Item::Float(*item)),
+            Buffer::Bool(items) => items.get(index).map(|item| Item::Bool(*item)),
+            Buffer::String(items) => items.get(index).map(|item| Item::String(item.clone())),
+            Buffer::Pointer(items) => items.get(index).map(|item| Item::Pointer(*item)),
+            Buffer::Items(items) => items.get(index).cloned().flatten(),
+        }
+    }
+
+    /// Replaces the item at an index.
+    ///
+    /// Fails if the index is out of bounds or the item doesn't match the
+    /// type of the array.
+    pub fn set(&mut self, index: usize, item: Item) -> RuntimeResult<()> {
+        let size = self.len();
+        let type_name = self.type_name().to_string();
+
+        let slot = match (&mut self.buffer, item) {
+            (Buffer::Int(items), Item::Int(item)) => items.get_mut(index).map(|slot| *slot = item),
+            (Buffer::Float(items), Item::Float(item)) => {
+                items.get_mut(index).map(|slot| *slot = item)
+            }
+            (Buffer::Bool(items), Item::Bool(item)) => {
+                items.get_mut(index).map(|slot| *slot = item)
+            }
+            (Buffer::String(items), Item::String(item)) => {
+                items.get_mut(index).map(|slot| *slot = item)
+            }
+            (Buffer::Pointer(items), Item::Pointer(item)) => {
+                items.get_mut(index).map(|slot| *slot = item)
+            }
+            (Buffer::Items(items), item) => items.get_mut(index).map(|slot| *slot = Some(item)),
+            (_, item) => {
+                return Err(RuntimeErrorKind::TypeError(format!(
+                    "Can't store {} value in an array of {} items",
+                    item.type_name(),
+                    type_name
+                )))
+            }
+        };
+
+        slot.ok_or(RuntimeErrorKind::IndexError {
+            index: index as IntType,
+            size: size as IntType,
+        })
+    }
+
+    /// Range of the items of the row starting at an index, a row spanning
+    /// the last `rank` axes of the array.
+    pub fn row(&self, start: usize, rank: usize) -> RuntimeResult<Range<usize>> {
+        if self.shape.len() < rank {
+            return Err(RuntimeErrorKind::ValueError(format!(
+                "Expected an array of {} dimensions, found {}",
+                rank,
+                self.shape.len()
+            )));
+        }
+
+        let size = self.shape[self.shape.len() - rank..]
+            .iter()
+            .product::<usize>();
+        if start + size > self.len() {
+            return Err(RuntimeErrorKind::IndexError {
+                index: (start + size) as IntType,
+                size: self.len() as IntType,
+            });
+        }
+
+        Ok(start..start + size)
+    }
+
+    /// Returns the items within a range as floats, None if the array is not
+    /// numeric.
+    pub fn to_floats(&self, range: Range<usize>) -> Option<Vec<FloatType>> {
+        match &self.buffer {
+            Buffer::Int(items) => {
+                Some(items[range].iter().map(|item| *item as FloatType).collect())
+            }
+            Buffer::Float(items) => Some(items[range].to_vec()),
+            _ => None,
+        }
+    }
+}
+
+/// Items of an array taken every number of items along each axis, seen as
+/// an array of their own without copying them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct View {
+    /// Address of the first item of the view within the viewed array.
+    pub source: MemAddress,
+    pub shape: Vec<usize>,
+    /// Number of items of the viewed array between consecutive indices of
+    /// each axis.
+    pub strides: Vec<usize>,
+}
+
+impl View {
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the address of the viewed item at an index of the view, its
+    /// items are laid out in row major order.
+    pub fn address(&self, index: usize) -> MemAddress {
+        let mut rest = index;
+        let mut offset = 0;
+        for (size, stride) in self.shape.iter().zip(&self.strides).rev() {
+            offset += (rest % size) * stride;
+            rest /= size;
+        }
+
+        self.source + offset as MemAddress
+    }
+}
+
+/// Finds the array holding an address, returns the address of its first
+/// item along with the array.
+pub fn find(arrays: &Arrays, address: MemAddress) -> Option<(MemAddress, &Array)> {
+    let (start, array) = arrays.range(..=address).next_back()?;
+
+    // Empty arrays are found by the address of their first item
+    if *start == address || ((address - start) as usize) < array.len() {
+        Some((*start, array))
+    } else {
+        None
+    }
+}
+
+/// Mutable version of [find].
+pub fn find_mut(arrays: &mut Arrays, address: MemAddress) -> Option<(MemAddress, &mut Array)> {
+    let (start, array) = arrays.range_mut(..=address).next_back()?;
+
+    if *start == address || ((address - start) as usize) < array.len() {
+        Some((*start, array))
+    } else {
+        None
+    }
+}
+
+/// Finds the view holding an address, returns the address of its first item
+/// along with the view.
+pub fn find_view(views: &Views, address: MemAddress) -> Option<(MemAddress, &View)> {
+    let (start, view) = views.range(..=address).next_back()?;
+
+    if ((address - start) as usize) < view.len() {
+        Some((*start, view))
+    } else {
+        None
+    }
+}
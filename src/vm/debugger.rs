@@ -0,0 +1,372 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    io::{self, BufRead, Write},
+};
+
+use codegen::{manager::Manager, symbols::SymbolEntry};
+use memory::{
+    resolver::{MemAddress, MemoryResolver, MemoryScope},
+    types::DataType,
+};
+
+use super::{
+    errors::{RuntimeError, RuntimeResult},
+    memory_manager::{CallContext, Item, MemoryManager},
+    virtual_machine::VirtualMachine,
+};
+
+const HELP: &str = "\
+    break <line>    (b)   set a breakpoint at a source line
+    delete <line>         remove a breakpoint
+    breakpoints           list the breakpoints
+    continue        (c)   run until the next breakpoint
+    step            (s)   run until the next line, entering function calls
+    next            (n)   run until the next line, stepping over function calls
+    finish          (f)   run until the current function returns
+    print <name>    (p)   print a variable visible from the selected frame
+    locals                print the local variables of the selected frame
+    backtrace       (bt)  print the call stack
+    frame <index>         select a frame from the call stack
+    help                  show this message
+    quit            (q)   exit the debugger
+
+An empty line repeats the last command.";
+
+/// Condition to pause the execution of a program being debugged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Resume {
+    /// Pause at the next breakpoint.
+    Continue,
+    /// Pause at the next source line, including lines of called functions.
+    Step,
+    /// Pause at the next source line of the current function or its callers.
+    Next,
+    /// Pause once the current function returns.
+    Finish,
+}
+
+/// Interactive session to execute a program step by step.
+///
+/// Generalizes the `Inspector` to a live program: variables are looked up
+/// by name using the symbol tables of the code generation manager.
+pub struct Debugger {
+    pub manager: Manager,
+    pub vm: VirtualMachine,
+    pub breakpoints: BTreeSet<usize>,
+    source: String,
+    started: bool,
+    /// Index of the frame selected for inspection, 0 is the innermost.
+    frame: usize,
+}
+
+impl Debugger {
+    /// Creates a debugging session for the program generated by a manager.
+    pub fn new(manager: Manager) -> RuntimeResult<Debugger> {
+        let vm = VirtualMachine::new(manager.program_meta())?;
+        let source = fs::read_to_string(manager.source_path.as_str()).unwrap_or_default();
+
+        Ok(Debugger {
+            manager,
+            vm,
+            breakpoints: BTreeSet::new(),
+            source,
+            started: false,
+            frame: 0,
+        })
+    }
+
+    /// Reads and executes debugger commands from stdin until it is closed
+    /// or the user quits.
+    pub fn run(&mut self) {
+        let stdin = io::stdin();
+        let mut last_command = String::new();
+
+        println!(
+            "Debugging {}, type help to list the commands.",
+            self.manager.source_path
+        );
+
+        loop {
+            print!("(moris) ");
+            io::stdout().flush().unwrap();
+
+            let mut line = String::new();
+            match stdin.lock().read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => (),
+            }
+
+            if !line.trim().is_empty() {
+                last_command = String::from(line.trim());
+            }
+
+            if !self.command(last_command.clone().as_str()) {
+                break;
+            }
+        }
+    }
+
+    /// Executes a debugger command, returns false if the session should end.
+    pub fn command(&mut self, command: &str) -> bool {
+        let mut args = command.split_whitespace();
+        let name = args.next().unwrap_or("");
+        let arg = args.next();
+
+        match (name, arg) {
+            ("break" | "b", Some(line)) => match line.parse::<usize>() {
+                Ok(line) => self.add_breakpoint(line),
+                Err(_) => eprintln!("Invalid line {}", line),
+            },
+            ("delete", Some(line)) => match line.parse::<usize>() {
+                Ok(line) if self.breakpoints.remove(&line) => (),
+                _ => eprintln!("No breakpoint at line {}", line),
+            },
+            ("breakpoints", None) => self
+                .breakpoints
+                .iter()
+                .for_each(|line| println!("{}", self.source_line(*line))),
+            ("continue" | "c" | "run", None) => self.resume_and_report(Resume::Continue),
+            ("step" | "s", None) => self.resume_and_report(Resume::Step),
+            ("next" | "n", None) => self.resume_and_report(Resume::Next),
+            ("finish" | "f", None) => self.resume_and_report(Resume::Finish),
+            ("print" | "p", Some(id)) => match self.variable(id) {
+                Some(value) => println!("{} = {}", id, value),
+                None => eprintln!("No variable {} in the current frame", id),
+            },
+            ("locals", None) => self.print_locals(),
+            ("backtrace" | "bt", None) => self.print_backtrace(),
+            ("frame", Some(index)) => match index.parse::<usize>() {
+                Ok(index) if index < self.vm.stack_trace().len() => {
+                    self.frame = index;
+                    self.print_backtrace();
+                }
+                _ => eprintln!("Invalid frame {}", index),
+            },
+            ("help", None) => println!("{}", HELP),
+            ("quit" | "q", None) => return false,
+            _ => eprintln!(
+                "Unknown command {}, type help to list the commands",
+                command
+            ),
+        }
+
+        true
+    }
+
+    /// Sets a breakpoint, warning if no code was generated for the line.
+    pub fn add_breakpoint(&mut self, line: usize) {
+        if !self.vm.data.line_table.contains(&line) {
+            eprintln!("Warning: no code was generated for line {}", line);
+        }
+
+        self.breakpoints.insert(line);
+    }
+
+    /// Returns the source line and call depth of the next instruction.
+    fn location(&self) -> (Option<usize>, usize) {
+        (
+            self.vm.data.line(self.vm.instruction_pointer),
+            self.vm.memory.call_context.len(),
+        )
+    }
+
+    /// Source line of the next instruction to execute.
+    pub fn current_line(&self) -> Option<usize> {
+        self.location().0
+    }
+
+    /// Executes the program until the resume condition is met or the
+    /// program finishes. Pauses are only made at the first instruction of
+    /// a source line.
+    pub fn resume(&mut self, mode: Resume) -> Result<(), RuntimeError> {
+        let start = self.location();
+        let mut previous = if self.started { Some(start) } else { None };
+        self.started = true;
+        self.frame = 0;
+
+        while !self.vm.finished() {
+            let current = self.location();
+
+            if let (Some(line), true) = (current.0, previous != Some(current)) {
+                let (_, depth) = current;
+                let stop = match mode {
+                    Resume::Continue => self.breakpoints.contains(&line),
+                    Resume::Step => true,
+                    Resume::Next => depth <= start.1 && current != start,
+                    Resume::Finish => depth < start.1,
+                };
+
+                if stop {
+                    return Ok(());
+                }
+            }
+
+            previous = Some(current);
+            self.vm.step()?;
+        }
+
+        Ok(())
+    }
+
+    fn resume_and_report(&mut self, mode: Resume) {
+        if self.vm.finished() {
+            println!("The program is not running");
+            return;
+        }
+
+        match self.resume(mode) {
+            Ok(()) if self.vm.finished() => println!("Program finished"),
+            Ok(()) => match self.current_line() {
+                Some(line) => println!("{}", self.source_line(line)),
+                None => println!("Paused at quadruple {}", self.vm.instruction_pointer),
+            },
+            Err(error) => {
+                eprintln!("{}", error);
+                eprintln!("The program stopped, its state can still be inspected");
+            }
+        }
+    }
+
+    fn source_line(&self, line: usize) -> String {
+        let content = self.source.lines().nth(line - 1).unwrap_or("");
+        format!("{:>4} | {}", line, content)
+    }
+
+    /// Returns the call of the selected frame, None for the global scope.
+    fn selected_context(&self) -> Option<&CallContext> {
+        self.vm.memory.call_context.iter().rev().nth(self.frame)
+    }
+
+    fn read(&self, address: MemAddress) -> Option<Item> {
+        let memory = &self.vm.memory;
+        match MemoryResolver::get_scope_from_address(address) {
+            Some(MemoryScope::Local) => self.selected_context().and_then(|context| {
+                MemoryManager::read(&context.locals, &context.arrays, address).ok()
+            }),
+            Some(_) => MemoryManager::read(&memory.globals, &memory.arrays, address).ok(),
+            None => None,
+        }
+    }
+
+    fn format_symbol(&self, symbol: &SymbolEntry) -> String {
+        let format_item = |item: Option<Item>| match item {
+            Some(item) => format!("{}", item),
+            None => String::from("undefined"),
+        };
+
+        if let DataType::Struct(id) = &symbol.data_type {
+            if symbol.dimension.shape.is_empty() {
+                return format_item(Item::from_struct(symbol, &|address| self.read(address)));
+            }
+
+            // Arrays of structs are shown by the array of each field
+            let fields = symbol
+                .fields
+                .iter()
+                .map(|field| format!("{}: {}", field.id, self.format_symbol(field)))
+                .collect::<Vec<String>>();
+            return format!("{} {{ {} }}", id, fields.join(", "));
+        }
+
+        if symbol.dimension.size <= 1 && !symbol.dimension.is_runtime_sized() {
+            return format_item(self.read(symbol.address));
+        }
+
+        match self.read(symbol.address) {
+            Some(Item::Pointer(start)) => {
+                // The size of arrays allocated at runtime is only known by
+                // the machine
+                let size = match symbol.dimension.is_runtime_sized() {
+                    true => self
+                        .vm
+                        .memory
+                        .get_array(start)
+                        .map(|(array, offset)| array.len() - offset)
+                        .unwrap_or(0),
+                    false => symbol.dimension.size,
+                };
+                let items = (0..size)
+                    .map(|offset| format_item(self.read(start + offset as MemAddress)))
+                    .collect::<Vec<String>>();
+                format!("[{}]", items.join(", "))
+            }
+            item => format_item(item),
+        }
+    }
+
+    /// Formats the value of a variable visible from the selected frame.
+    pub fn variable(&self, id: &str) -> Option<String> {
+        let local = self
+            .frame_symbols()
+            .into_iter()
+            .find(|symbol| symbol.id == id);
+        let symbol = local.or_else(|| {
+            self.manager
+                .env
+                .entries
+                .get("global")
+                .and_then(|env| env.symbols.get(id))
+        })?;
+
+        Some(self.format_symbol(symbol))
+    }
+
+    /// Returns the variables of the selected frame, those of the blocks
+    /// spanning its paused quadruple first, innermost blocks first.
+    fn frame_symbols(&self) -> Vec<&SymbolEntry> {
+        let procedure_id = match self.selected_context() {
+            Some(context) => context.procedure_id.as_str(),
+            None => "global",
+        };
+        let position = match self.vm.stack_trace().get(self.frame) {
+            Some(frame) => frame.position,
+            None => return vec![],
+        };
+
+        match self.manager.env.entries.get(procedure_id) {
+            Some(env) => {
+                let mut symbols = env.block_symbols(position);
+                symbols.extend(env.symbols.values());
+                symbols
+            }
+            None => vec![],
+        }
+    }
+
+    fn print_locals(&self) {
+        let mut symbols: Vec<&SymbolEntry> = vec![];
+        for symbol in self.frame_symbols() {
+            // Variables of inner blocks shadow the outer ones
+            if symbols.iter().all(|seen| seen.id != symbol.id) {
+                symbols.push(symbol);
+            }
+        }
+        symbols.sort_by_key(|symbol| symbol.address);
+
+        symbols.iter().for_each(|symbol| {
+            println!(
+                "{}: {:?} = {}",
+                symbol.id,
+                symbol.data_type,
+                self.format_symbol(symbol)
+            )
+        });
+    }
+
+    fn print_backtrace(&self) {
+        for (index, frame) in self.vm.stack_trace().iter().enumerate() {
+            let marker = if index == self.frame { "*" } else { " " };
+            let procedure_id = match &frame.procedure_id {
+                Some(procedure_id) => format!("{}()", procedure_id),
+                None => String::from("<global>"),
+            };
+            let line = match frame.line {
+                Some(line) => format!("line {}", line),
+                None => format!("quadruple {}", frame.position),
+            };
+
+            println!("{} #{} {} at {}", marker, index, procedure_id, line);
+        }
+    }
+}
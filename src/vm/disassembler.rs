@@ -0,0 +1,405 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
+
+use codegen::{
+    meta::ProgramMeta,
+    natives::NativeFunction,
+    quadruples::{Instruction, Literal, Operand, Quadruple},
+};
+use memory::{
+    resolver::{MemAddress, MemoryResolver, MemoryScope},
+    types::DataType,
+};
+
+/// Output formats of a disassembled program.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DisasmFormat {
+    Text,
+    Markdown,
+    Json,
+}
+
+impl FromStr for DisasmFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "text" => Ok(DisasmFormat::Text),
+            "markdown" | "md" => Ok(DisasmFormat::Markdown),
+            "json" => Ok(DisasmFormat::Json),
+            _ => Err(format!(
+                "Unknown format {}, expected text, markdown or json",
+                format
+            )),
+        }
+    }
+}
+
+/// Decoded instruction, ready to be printed.
+#[derive(Debug)]
+pub struct Row {
+    pub index: usize,
+    /// Labels pointing to the instruction, procedure entry points first.
+    pub labels: Vec<String>,
+    pub operator: String,
+    pub operands: [String; 3],
+    /// Position the instruction may jump to.
+    pub target: Option<usize>,
+    /// Positions of the instructions jumping to this one.
+    pub sources: Vec<usize>,
+    pub line: Option<usize>,
+}
+
+/// Listing of the instructions of a compiled program.
+///
+/// Addresses are decoded as `scope:type:offset`, constants are replaced by
+/// their values and jumps point to labels, so the code generated for a
+/// program can be read and diffed.
+pub struct Disassembler {
+    pub rows: Vec<Row>,
+}
+
+impl Disassembler {
+    pub fn new(data: &ProgramMeta) -> Disassembler {
+        let mut procedures = data
+            .procedure_table
+            .values()
+            .filter(|func| func.id.parse::<NativeFunction>().is_err())
+            .collect::<Vec<_>>();
+        procedures.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut labels: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        for procedure in procedures {
+            labels
+                .entry(procedure.procedure_address)
+                .or_default()
+                .push(procedure.id.clone());
+        }
+
+        let targets = data
+            .quadruples
+            .iter()
+            .map(Disassembler::target)
+            .collect::<Vec<Option<usize>>>();
+
+        let mut sources: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (index, target) in targets.iter().enumerate() {
+            if let Some(target) = target {
+                sources.entry(*target).or_default().push(index);
+            }
+        }
+
+        let jump_targets = sources.keys().copied().collect::<BTreeSet<usize>>();
+        for target in jump_targets.iter() {
+            labels
+                .entry(*target)
+                .or_default()
+                .push(Disassembler::jump_label(*target));
+        }
+
+        let rows = data
+            .quadruples
+            .iter()
+            .enumerate()
+            .map(|(index, instruction)| {
+                let (operator, operands) = Disassembler::decode(data, instruction);
+
+                Row {
+                    index,
+                    labels: labels.get(&index).cloned().unwrap_or_default(),
+                    operator,
+                    operands,
+                    target: targets[index],
+                    sources: sources.get(&index).cloned().unwrap_or_default(),
+                    line: data.line(index),
+                }
+            })
+            .collect();
+
+        Disassembler { rows }
+    }
+
+    fn target(instruction: &Instruction) -> Option<usize> {
+        match instruction {
+            Instruction::Goto(Operand::Label(target))
+            | Instruction::GotoFalse(_, Operand::Label(target)) => Some(*target),
+            _ => None,
+        }
+    }
+
+    fn jump_label(target: usize) -> String {
+        format!(".L{}", target)
+    }
+
+    fn type_name(data_type: &DataType) -> &'static str {
+        match data_type {
+            DataType::Int => "int",
+            DataType::Float => "float",
+            DataType::Bool => "bool",
+            DataType::String => "str",
+            DataType::DataFrame => "df",
+            DataType::Series => "series",
+            DataType::Pointer => "ptr",
+            _ => "?",
+        }
+    }
+
+    /// Formats an address as `scope:type:offset`, or the value it holds if
+    /// it is a constant.
+    pub fn address(data: &ProgramMeta, address: MemAddress) -> String {
+        if MemoryResolver::get_scope_from_address(address).is_none()
+            || MemoryResolver::get_type_from_address(address).is_none()
+        {
+            return address.to_string();
+        }
+
+        let (scope, data_type, offset) = MemoryResolver::get_offset(address);
+        let scope = match scope {
+            MemoryScope::Global => "G",
+            MemoryScope::Local => "L",
+            MemoryScope::Heap => "H",
+            MemoryScope::Constant => match data.constant_table.get(&address) {
+                Some(value) if data_type == DataType::String => return format!("{:?}", value),
+                Some(value) => return value.clone(),
+                None => "C",
+            },
+        };
+
+        format!(
+            "{}:{}:{}",
+            scope,
+            Disassembler::type_name(&data_type),
+            offset
+        )
+    }
+
+    fn operand(data: &ProgramMeta, operand: &Operand) -> String {
+        match operand {
+            Operand::Direct(address) => Disassembler::address(data, *address),
+            Operand::Indirect(address) => format!("*{}", Disassembler::address(data, *address)),
+            Operand::AddressOf(address) => format!("&{}", Disassembler::address(data, *address)),
+            Operand::Const(Literal::Text(text)) => format!("{:?}", text),
+            Operand::Const(_) => operand.to_string(),
+            Operand::Label(target) => Disassembler::jump_label(*target),
+        }
+    }
+
+    fn decode(data: &ProgramMeta, instruction: &Instruction) -> (String, [String; 3]) {
+        let operand = |operand: &Operand| Disassembler::operand(data, operand);
+        let Quadruple(operator, _, _, _) = Quadruple::from(instruction);
+        let empty = String::new;
+
+        let operands = match instruction {
+            Instruction::Empty => return (String::from("nop"), Default::default()),
+            Instruction::Operation(_, left, right, dest)
+            | Instruction::Elementwise(_, left, right, dest) => {
+                [operand(left), operand(right), operand(dest)]
+            }
+            Instruction::Unary(_, value, dest) | Instruction::Cast(_, value, dest) => {
+                [operand(value), empty(), operand(dest)]
+            }
+            Instruction::Verify(value, bound) => [operand(value), empty(), operand(bound)],
+            Instruction::Goto(target) => [empty(), empty(), operand(target)],
+            Instruction::GotoFalse(check, target) => [operand(check), empty(), operand(target)],
+            Instruction::Era(id) | Instruction::GoSub(id) => [empty(), empty(), id.clone()],
+            Instruction::Param(value, index) => [operand(value), empty(), index.to_string()],
+            Instruction::Mark(depth) | Instruction::Release(depth) => {
+                [empty(), empty(), depth.to_string()]
+            }
+            Instruction::Return(value) | Instruction::Print(value) | Instruction::Free(value) => {
+                [empty(), empty(), operand(value)]
+            }
+            Instruction::VoidReturn | Instruction::EndFunc | Instruction::EndProgram => {
+                Default::default()
+            }
+            Instruction::Alloc(address, shape) => {
+                let shape = shape
+                    .iter()
+                    .map(|size| size.to_string())
+                    .collect::<Vec<String>>();
+                [empty(), shape.join("x"), operand(address)]
+            }
+            Instruction::Len(value, axis, rank, dest) => {
+                [operand(value), format!("{}/{}", axis, rank), operand(dest)]
+            }
+            Instruction::New(data_type, sizes, dest) => {
+                let sizes = sizes.iter().map(operand).collect::<Vec<String>>();
+                [
+                    Disassembler::type_name(data_type).to_string(),
+                    sizes.join("x"),
+                    operand(dest),
+                ]
+            }
+            Instruction::View(source, sizes, strides, dest) => {
+                let sizes = sizes.iter().map(operand).collect::<Vec<String>>();
+                let strides = strides.iter().map(operand).collect::<Vec<String>>();
+                [
+                    operand(source),
+                    format!("{}/{}", sizes.join("x"), strides.join("x")),
+                    operand(dest),
+                ]
+            }
+            Instruction::Copy(source, count, dest) => {
+                [operand(source), operand(count), operand(dest)]
+            }
+            Instruction::Call(closure, result) => [
+                operand(closure),
+                empty(),
+                result.as_ref().map_or_else(empty, operand),
+            ],
+            Instruction::Closure(id, captured, dest) => {
+                let captured = captured.iter().map(operand).collect::<Vec<String>>();
+                [id.clone(), captured.join(","), operand(dest)]
+            }
+        };
+
+        (operator, operands)
+    }
+
+    pub fn render(&self, format: DisasmFormat) -> String {
+        match format {
+            DisasmFormat::Text => self.to_text(),
+            DisasmFormat::Markdown => self.to_markdown(),
+            DisasmFormat::Json => self.to_json(),
+        }
+    }
+
+    /// Annotation of the jumps from and to an instruction.
+    fn note(row: &Row) -> String {
+        let mut notes = vec![];
+        if let Some(target) = row.target {
+            notes.push(format!("-> {}", target));
+        }
+        if !row.sources.is_empty() {
+            let sources = row
+                .sources
+                .iter()
+                .map(|source| source.to_string())
+                .collect::<Vec<String>>();
+            notes.push(format!("<- {}", sources.join(", ")));
+        }
+        if let Some(line) = row.line {
+            notes.push(format!("line {}", line));
+        }
+
+        notes.join("; ")
+    }
+
+    pub fn to_text(&self) -> String {
+        let width =
+            |column: &dyn Fn(&Row) -> usize| self.rows.iter().map(column).max().unwrap_or(0);
+        let index_width = width(&|row| row.index.to_string().len());
+        let operator_width = width(&|row| row.operator.len());
+        let operand_widths = [0, 1, 2].map(|i| width(&|row| row.operands[i].len()));
+
+        let mut output = String::new();
+        for row in self.rows.iter() {
+            for label in row.labels.iter() {
+                output.push_str(format!("{}:\n", label).as_str());
+            }
+
+            let line = format!(
+                "  {:>index_width$}  {:<operator_width$}  {:<w0$}  {:<w1$}  {:<w2$}  ; {}",
+                row.index,
+                row.operator,
+                row.operands[0],
+                row.operands[1],
+                row.operands[2],
+                Disassembler::note(row),
+                w0 = operand_widths[0],
+                w1 = operand_widths[1],
+                w2 = operand_widths[2],
+            );
+            output.push_str(line.trim_end_matches([' ', ';']));
+            output.push('\n');
+        }
+
+        output
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let escape = |cell: &str| cell.replace('|', "\\|");
+
+        let mut output = String::from(
+            "| # | label | operator | left | right | result | note |\n\
+             | - | ----- | -------- | ---- | ----- | ------ | ---- |\n",
+        );
+        for row in self.rows.iter() {
+            output.push_str(
+                format!(
+                    "| {} | {} | {} | {} | {} | {} | {} |\n",
+                    row.index,
+                    escape(row.labels.join(" ").as_str()),
+                    escape(row.operator.as_str()),
+                    escape(row.operands[0].as_str()),
+                    escape(row.operands[1].as_str()),
+                    escape(row.operands[2].as_str()),
+                    escape(Disassembler::note(row).as_str()),
+                )
+                .as_str(),
+            );
+        }
+
+        output
+    }
+
+    pub fn to_json(&self) -> String {
+        let optional = |value: Option<usize>| match value {
+            Some(value) => value.to_string(),
+            None => String::from("null"),
+        };
+        let strings = |items: &[String]| {
+            items
+                .iter()
+                .map(|item| json_string(item))
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                let sources = row
+                    .sources
+                    .iter()
+                    .map(|source| source.to_string())
+                    .collect::<Vec<String>>();
+
+                format!(
+                    "  {{\"index\": {}, \"labels\": [{}], \"operator\": {}, \"operands\": [{}], \"target\": {}, \"sources\": [{}], \"line\": {}}}",
+                    row.index,
+                    strings(&row.labels),
+                    json_string(row.operator.as_str()),
+                    strings(&row.operands),
+                    optional(row.target),
+                    sources.join(", "),
+                    optional(row.line),
+                )
+            })
+            .collect::<Vec<String>>();
+
+        format!("[\n{}\n]\n", rows.join(",\n"))
+    }
+}
+
+/// Encodes a string as a JSON string literal.
+fn json_string(value: &str) -> String {
+    let mut encoded = String::from("\"");
+    for chr in value.chars() {
+        match chr {
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            '\n' => encoded.push_str("\\n"),
+            '\r' => encoded.push_str("\\r"),
+            '\t' => encoded.push_str("\\t"),
+            chr if chr.is_control() => encoded.push_str(format!("\\u{:04x}", chr as u32).as_str()),
+            chr => encoded.push(chr),
+        }
+    }
+    encoded.push('"');
+
+    encoded
+}
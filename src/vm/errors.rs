@@ -0,0 +1,126 @@
+use std::fmt::{Display, Formatter};
+
+use memory::{resolver::MemAddress, types::IntType};
+
+/// Cause of a failure found while executing a program.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuntimeErrorKind {
+    IndexError {
+        index: IntType,
+        size: IntType,
+    },
+    ZeroDivisionError,
+    /// The result of an operation on integers doesn't fit an int.
+    OverflowError,
+    TypeError(String),
+    /// A value was read from an address that was never assigned.
+    UndefinedValue(MemAddress),
+    ValueError(String),
+    IOError(String),
+    /// The object file holds an instruction the machine can't execute.
+    InvalidInstruction(String),
+}
+
+pub type RuntimeResult<T> = Result<T, RuntimeErrorKind>;
+
+impl RuntimeErrorKind {
+    pub fn name(&self) -> &str {
+        match self {
+            RuntimeErrorKind::IndexError { .. } => "IndexError",
+            RuntimeErrorKind::ZeroDivisionError => "ZeroDivisionError",
+            RuntimeErrorKind::OverflowError => "OverflowError",
+            RuntimeErrorKind::TypeError(_) => "TypeError",
+            RuntimeErrorKind::UndefinedValue(_) => "UndefinedValueError",
+            RuntimeErrorKind::ValueError(_) => "ValueError",
+            RuntimeErrorKind::IOError(_) => "IOError",
+            RuntimeErrorKind::InvalidInstruction(_) => "InvalidInstruction",
+        }
+    }
+}
+
+impl Display for RuntimeErrorKind {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuntimeErrorKind::IndexError { index, size } => {
+                write!(fmt, "index {} is out of bounds for size {}", index, size)
+            }
+            RuntimeErrorKind::ZeroDivisionError => write!(fmt, "division by zero"),
+            RuntimeErrorKind::OverflowError => write!(fmt, "integer overflow"),
+            RuntimeErrorKind::UndefinedValue(address) => {
+                write!(
+                    fmt,
+                    "value at address {} was used before being assigned",
+                    address
+                )
+            }
+            RuntimeErrorKind::TypeError(message)
+            | RuntimeErrorKind::ValueError(message)
+            | RuntimeErrorKind::IOError(message)
+            | RuntimeErrorKind::InvalidInstruction(message) => write!(fmt, "{}", message),
+        }
+    }
+}
+
+/// Active procedure call at the moment an error was raised.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StackFrame {
+    /// None for the global scope.
+    pub procedure_id: Option<String>,
+    /// Quadruple being executed by the frame.
+    pub position: usize,
+    pub line: Option<usize>,
+}
+
+/// Error raised while executing a program, located by the quadruple
+/// that failed and the Moris call stack leading to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeError {
+    pub kind: RuntimeErrorKind,
+    pub position: usize,
+    pub line: Option<usize>,
+    /// Call stack, innermost frame first.
+    pub stack: Vec<StackFrame>,
+    pub source_path: String,
+}
+
+impl RuntimeError {
+    fn location(&self, frame: &StackFrame) -> String {
+        let location = match frame.line {
+            Some(line) => format!("{}:{}", self.source_path, line),
+            None if self.source_path.is_empty() => format!("quadruple {}", frame.position),
+            None => format!("{} (quadruple {})", self.source_path, frame.position),
+        };
+
+        match &frame.procedure_id {
+            Some(procedure_id) => format!("{} in {}()", location, procedure_id),
+            None => location,
+        }
+    }
+}
+
+impl Display for RuntimeError {
+    /// Formats the error with its stack trace, e.g.
+    ///
+    /// ```text
+    /// IndexError at find.mo:5 in find(): index 10 is out of bounds for size 10
+    ///     called from find.mo:30
+    /// ```
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let location = match self.stack.first() {
+            Some(frame) => self.location(frame),
+            None => self.location(&StackFrame {
+                procedure_id: None,
+                position: self.position,
+                line: self.line,
+            }),
+        };
+
+        write!(fmt, "{} at {}: {}", self.kind.name(), location, self.kind)?;
+
+        for frame in self.stack.iter().skip(1) {
+            write!(fmt, "\n    called from {}", self.location(frame))?;
+        }
+
+        Ok(())
+    }
+}
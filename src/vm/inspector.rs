@@ -4,13 +4,17 @@ use memory::resolver::MemAddress;
 
 use crate::vm::runner::Runner;
 
-use super::memory_manager::Item;
+use super::{
+    array::{self, Arrays},
+    memory_manager::Item,
+};
 
 type TargetMeta = HashMap<String, MemAddress>;
 
 pub struct Inspector {
     pub target_meta: HashMap<String, MemAddress>,
     pub memory: HashMap<MemAddress, Item>,
+    pub arrays: Arrays,
 }
 
 /// Runner wrapper to inspect the variable values of a program
@@ -21,27 +25,40 @@ impl Inspector {
 
         let mut runner = Runner::new(path_buf.to_str().unwrap()).unwrap();
 
-        runner.compile();
+        if let Err(errors) = runner.compile() {
+            runner.print_errors(&errors);
+            panic!("Can't compile {}", path);
+        }
 
         let manager = &runner.manager;
 
         let target_meta: TargetMeta;
         if let Some(global_env) = manager.env.entries.get("global") {
+            // Struct fields are inspected by their path, as in `point.x`
             target_meta = global_env
                 .symbols
                 .iter()
-                .map(|(id, entry)| (id.clone(), entry.address))
+                .flat_map(|(id, entry)| {
+                    entry.leaves().into_iter().map(move |(path, leaf)| {
+                        let path = std::iter::once(id.clone()).chain(path);
+                        (path.collect::<Vec<String>>().join("."), leaf.address)
+                    })
+                })
                 .collect::<TargetMeta>();
         } else {
             panic!("Can't parse global environment!")
         }
         
         runner.clean();
-        let vm = runner.run();
+        let vm = match runner.run() {
+            Ok(vm) => vm,
+            Err(error) => panic!("{}", error),
+        };
         
         Inspector {
             target_meta,
             memory: vm.memory.globals,
+            arrays: vm.memory.arrays,
         }
     }
 
@@ -53,7 +70,7 @@ impl Inspector {
         *item == value
     }
 
-    pub fn get_address(&self, id: &str) -> u16 {
+    pub fn get_address(&self, id: &str) -> MemAddress {
         *self.target_meta.get(id).unwrap()
     }
 
@@ -62,6 +79,14 @@ impl Inspector {
         self.memory.get(item_address).unwrap().clone()
     }
 
+    /// Returns the items of a global array
+    pub fn get_array(&self, id: &str) -> Vec<Item> {
+        let start = self.get(id).unwrap_pointer();
+        let (_, array) = array::find(&self.arrays, start).unwrap();
+
+        (0..array.len()).filter_map(|index| array.get(index)).collect()
+    }
+
     /// Prints all global variables with their end value
     pub fn debug(&self) {
         self.target_meta.iter().for_each(|(key, value)| {
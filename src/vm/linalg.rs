@@ -0,0 +1,262 @@
+use memory::{
+    resolver::MemAddress,
+    types::{DataType, FloatType, IntType},
+};
+
+use super::{
+    array::Buffer,
+    errors::{RuntimeErrorKind, RuntimeResult},
+    memory_manager::MemoryManager,
+};
+
+/// Items of a vector or matrix read from memory, laid out in row major
+/// order. Vectors are handled as matrices of a single column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    pub shape: Vec<usize>,
+    pub items: Vec<FloatType>,
+}
+
+impl Matrix {
+    /// Reads the array a pointer refers to, taking its last axes as the
+    /// axes of the matrix. Pointers to a row of an array read only the items
+    /// of that row.
+    pub fn read(memory: &MemoryManager, address: MemAddress, rank: usize) -> RuntimeResult<Matrix> {
+        let (array, start) = memory.get_array(address)?;
+
+        let range = array.row(start, rank)?;
+        let shape = array.shape[array.shape.len() - rank..].to_vec();
+        let items = array.to_floats(range).ok_or_else(|| {
+            RuntimeErrorKind::TypeError(format!(
+                "Can't operate a matrix of {} items",
+                array.type_name()
+            ))
+        })?;
+
+        Ok(Matrix { shape, items })
+    }
+
+    pub fn identity(size: usize) -> Matrix {
+        let mut items = vec![0.0; size * size];
+        for index in 0..size {
+            items[index * size + index] = 1.0;
+        }
+
+        Matrix {
+            shape: vec![size, size],
+            items,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.shape[0]
+    }
+
+    pub fn cols(&self) -> usize {
+        self.shape.get(1).copied().unwrap_or(1)
+    }
+
+    fn get(&self, row: usize, col: usize) -> FloatType {
+        self.items[row * self.cols() + col]
+    }
+
+    fn check_square(&self) -> RuntimeResult<()> {
+        if self.rows() != self.cols() {
+            return Err(RuntimeErrorKind::ValueError(format!(
+                "Expected a square matrix, found a {}x{} matrix",
+                self.rows(),
+                self.cols()
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let (rows, cols) = (self.rows(), self.cols());
+        let items = (0..cols * rows)
+            .map(|index| self.get(index % rows, index / rows))
+            .collect();
+
+        Matrix {
+            shape: vec![cols, rows],
+            items,
+        }
+    }
+
+    pub fn mul(&self, other: &Matrix) -> RuntimeResult<Matrix> {
+        if self.cols() != other.rows() {
+            return Err(RuntimeErrorKind::ValueError(format!(
+                "Can't multiply a {}x{} matrix by a {}x{} matrix",
+                self.rows(),
+                self.cols(),
+                other.rows(),
+                other.cols()
+            )));
+        }
+
+        let (rows, cols) = (self.rows(), other.cols());
+        let items = (0..rows * cols)
+            .map(|index| {
+                let (row, col) = (index / cols, index % cols);
+                (0..self.cols())
+                    .map(|k| self.get(row, k) * other.get(k, col))
+                    .sum()
+            })
+            .collect();
+
+        Ok(Matrix {
+            shape: vec![rows, cols],
+            items,
+        })
+    }
+
+    pub fn dot(&self, other: &Matrix) -> RuntimeResult<FloatType> {
+        if self.items.len() != other.items.len() {
+            return Err(RuntimeErrorKind::ValueError(format!(
+                "Can't calculate the dot product of vectors of {} and {} items",
+                self.items.len(),
+                other.items.len()
+            )));
+        }
+
+        Ok(self
+            .items
+            .iter()
+            .zip(other.items.iter())
+            .map(|(left, right)| left * right)
+            .sum())
+    }
+
+    /// Reduces the matrix to row echelon form through gaussian elimination
+    /// with partial pivoting, applying the same row operations to the
+    /// augmented matrix. Returns the determinant of the matrix.
+    fn eliminate(&self, augmented: &mut Matrix) -> RuntimeResult<FloatType> {
+        self.check_square()?;
+
+        let size = self.rows();
+        let width = augmented.cols();
+        let mut items = self.items.clone();
+        let mut det = 1.0;
+
+        for col in 0..size {
+            let pivot = (col..size)
+                .max_by(|a, b| {
+                    items[a * size + col]
+                        .abs()
+                        .total_cmp(&items[b * size + col].abs())
+                })
+                .unwrap();
+
+            if items[pivot * size + col].abs() <= FloatType::EPSILON {
+                return Ok(0.0);
+            }
+            if pivot != col {
+                for k in 0..size {
+                    items.swap(col * size + k, pivot * size + k);
+                }
+                for k in 0..width {
+                    augmented.items.swap(col * width + k, pivot * width + k);
+                }
+                det = -det;
+            }
+
+            let value = items[col * size + col];
+            det *= value;
+
+            for row in 0..size {
+                if row == col {
+                    continue;
+                }
+
+                let factor = items[row * size + col] / value;
+                for k in 0..size {
+                    items[row * size + k] -= factor * items[col * size + k];
+                }
+                for k in 0..width {
+                    augmented.items[row * width + k] -= factor * augmented.items[col * width + k];
+                }
+            }
+        }
+
+        // Leaves the diagonal in ones, so the augmented matrix holds the result
+        for row in 0..size {
+            let value = items[row * size + row];
+            for k in 0..width {
+                augmented.items[row * width + k] /= value;
+            }
+        }
+
+        Ok(det)
+    }
+
+    pub fn det(&self) -> RuntimeResult<FloatType> {
+        let mut augmented = Matrix {
+            shape: vec![self.rows(), 0],
+            items: vec![],
+        };
+
+        self.eliminate(&mut augmented)
+    }
+
+    pub fn inverse(&self) -> RuntimeResult<Matrix> {
+        let mut inverse = Matrix::identity(self.rows());
+
+        if self.eliminate(&mut inverse)? == 0.0 {
+            return Err(RuntimeErrorKind::ValueError(String::from(
+                "Can't invert a singular matrix",
+            )));
+        }
+
+        Ok(inverse)
+    }
+
+    /// Finds the vector x for which the product of the matrix by x equals
+    /// the given values.
+    pub fn solve(&self, values: &Matrix) -> RuntimeResult<Matrix> {
+        if values.rows() != self.rows() {
+            return Err(RuntimeErrorKind::ValueError(format!(
+                "Can't solve a system of {} equations with {} values",
+                self.rows(),
+                values.rows()
+            )));
+        }
+
+        let mut solution = values.clone();
+        if self.eliminate(&mut solution)? == 0.0 {
+            return Err(RuntimeErrorKind::ValueError(String::from(
+                "Can't solve a system with a singular matrix",
+            )));
+        }
+
+        Ok(solution)
+    }
+
+    /// Stores the matrix in a new array of the heap, returns the address of
+    /// its first item.
+    pub fn store(
+        self,
+        memory: &mut MemoryManager,
+        data_type: &DataType,
+    ) -> RuntimeResult<MemAddress> {
+        let shape = self
+            .shape
+            .iter()
+            .map(|size| *size as IntType)
+            .collect::<Vec<IntType>>();
+        let address = memory.alloc_heap(data_type, &shape)?;
+
+        let (array, _) = memory.get_array_mut(address)?;
+        array.buffer = match data_type {
+            DataType::Int => Buffer::Int(
+                self.items
+                    .iter()
+                    .map(|item| item.round() as IntType)
+                    .collect(),
+            ),
+            _ => Buffer::Float(self.items),
+        };
+
+        Ok(address)
+    }
+}
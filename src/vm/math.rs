@@ -0,0 +1,206 @@
+use codegen::natives::NativeFunction;
+use memory::types::{DataType, FloatType, IntType};
+
+use super::{
+    errors::{RuntimeErrorKind, RuntimeResult},
+    memory_manager::{Item, MemoryManager},
+    virtual_machine::VirtualMachine,
+};
+
+/// Parameter of a math function, arrays and Series are operated item by
+/// item.
+enum Operand {
+    Scalar(Item),
+    Items(Vec<Item>),
+}
+
+/// Applies a math function to its parameters, the last one being the rank
+/// of the array parameters.
+///
+/// Returns a new array or Series if any parameter is one, otherwise the
+/// scalar result.
+pub fn run(
+    function: &NativeFunction,
+    mut params: Vec<Item>,
+    memory: &mut MemoryManager,
+) -> RuntimeResult<Item> {
+    let rank = params.pop().map(Item::cast_int).transpose()?.unwrap_or(0) as usize;
+
+    let (mut shape, mut is_series) = (None, false);
+    let mut operands = vec![];
+    for param in params {
+        operands.push(match param {
+            Item::Pointer(address) => {
+                let (array, start) = memory.get_array(address)?;
+
+                // Rows of arrays are read alone, taking only the last axes
+                let range = array.row(start, rank)?;
+                let array_shape = array.shape[array.shape.len() - rank..].to_vec();
+                let items = range
+                    .map(|index| {
+                        array
+                            .get(index)
+                            .ok_or(RuntimeErrorKind::UndefinedValue(address))
+                    })
+                    .collect::<RuntimeResult<Vec<Item>>>()?;
+
+                match &shape {
+                    Some(shape) if *shape != array_shape => {
+                        return Err(RuntimeErrorKind::ValueError(format!(
+                            "Function {} can't operate arrays with dimensions {:?} and {:?}",
+                            function, shape, array_shape
+                        )))
+                    }
+                    _ => shape = Some(array_shape),
+                }
+                Operand::Items(items)
+            }
+            Item::Series(series) => {
+                is_series = true;
+                Operand::Items(
+                    series
+                        .iter()
+                        .map(VirtualMachine::from_any_value)
+                        .collect::<RuntimeResult<Vec<Item>>>()?,
+                )
+            }
+            item => Operand::Scalar(item),
+        });
+    }
+
+    let lengths = operands
+        .iter()
+        .filter_map(|operand| match operand {
+            Operand::Items(items) => Some(items.len()),
+            Operand::Scalar(_) => None,
+        })
+        .collect::<Vec<usize>>();
+    if lengths.windows(2).any(|pair| pair[0] != pair[1]) {
+        return Err(RuntimeErrorKind::ValueError(format!(
+            "Function {} can't operate Series of {} and {} items",
+            function, lengths[0], lengths[1]
+        )));
+    }
+
+    let args = |index: usize| {
+        operands
+            .iter()
+            .map(|operand| match operand {
+                Operand::Scalar(item) => item.clone(),
+                Operand::Items(items) => items[index].clone(),
+            })
+            .collect::<Vec<Item>>()
+    };
+    let length = match lengths.first() {
+        Some(length) => *length,
+        None => return apply(function, &args(0)),
+    };
+    let results = (0..length)
+        .map(|index| apply(function, &args(index)))
+        .collect::<RuntimeResult<Vec<Item>>>()?;
+
+    if is_series {
+        return Ok(Item::Series(VirtualMachine::to_item_series(
+            function.to_string().as_str(),
+            results,
+        )?));
+    }
+
+    let data_type = match results.first() {
+        Some(Item::Int(_)) => DataType::Int,
+        _ => DataType::Float,
+    };
+    let shape = shape
+        .unwrap_or_default()
+        .iter()
+        .map(|size| *size as IntType)
+        .collect::<Vec<IntType>>();
+    let address = memory.alloc_heap(&data_type, &shape)?;
+    let (array, _) = memory.get_array_mut(address)?;
+    for (index, item) in results.into_iter().enumerate() {
+        array.set(index, item)?;
+    }
+
+    Ok(Item::Pointer(address))
+}
+
+/// Applies a math function to scalar values. Integers are kept by the
+/// functions that can't produce fractions out of them.
+fn apply(function: &NativeFunction, args: &[Item]) -> RuntimeResult<Item> {
+    if args.iter().all(|arg| matches!(arg, Item::Int(_))) {
+        let ints = args
+            .iter()
+            .cloned()
+            .map(Item::cast_int)
+            .collect::<RuntimeResult<Vec<IntType>>>()?;
+
+        match function {
+            NativeFunction::Abs => {
+                return ints[0]
+                    .checked_abs()
+                    .map(Item::Int)
+                    .ok_or(RuntimeErrorKind::OverflowError)
+            }
+            NativeFunction::Floor | NativeFunction::Ceil | NativeFunction::Round => {
+                return Ok(Item::Int(ints[0]))
+            }
+            NativeFunction::Min => return Ok(Item::Int(ints[0].min(ints[1]))),
+            NativeFunction::Max => return Ok(Item::Int(ints[0].max(ints[1]))),
+            NativeFunction::Clamp if ints[1] <= ints[2] => {
+                return Ok(Item::Int(ints[0].clamp(ints[1], ints[2])))
+            }
+            _ => (),
+        }
+    }
+
+    let floats = args
+        .iter()
+        .cloned()
+        .map(Item::cast_float)
+        .collect::<RuntimeResult<Vec<FloatType>>>()?;
+    let x = floats[0];
+
+    let value = match function {
+        NativeFunction::Abs => x.abs(),
+        NativeFunction::Sqrt if x < 0.0 => {
+            return Err(RuntimeErrorKind::ValueError(format!(
+                "Can't take the square root of negative number {}",
+                x
+            )))
+        }
+        NativeFunction::Sqrt => x.sqrt(),
+        NativeFunction::Pow => x.powf(floats[1]),
+        NativeFunction::Exp => x.exp(),
+        NativeFunction::Log | NativeFunction::Ln if x <= 0.0 => {
+            return Err(RuntimeErrorKind::ValueError(format!(
+                "Can't take the logarithm of {}, it must be positive",
+                x
+            )))
+        }
+        NativeFunction::Log => x.log10(),
+        NativeFunction::Ln => x.ln(),
+        NativeFunction::Sin => x.sin(),
+        NativeFunction::Cos => x.cos(),
+        NativeFunction::Tan => x.tan(),
+        NativeFunction::Floor => return Ok(Item::Int(x.floor() as IntType)),
+        NativeFunction::Ceil => return Ok(Item::Int(x.ceil() as IntType)),
+        NativeFunction::Round => return Ok(Item::Int(x.round() as IntType)),
+        NativeFunction::Min => x.min(floats[1]),
+        NativeFunction::Max => x.max(floats[1]),
+        NativeFunction::Clamp if floats[1] > floats[2] => {
+            return Err(RuntimeErrorKind::ValueError(format!(
+                "Invalid bounds {} and {}, the lower bound can't be greater",
+                floats[1], floats[2]
+            )))
+        }
+        NativeFunction::Clamp => x.clamp(floats[1], floats[2]),
+        _ => {
+            return Err(RuntimeErrorKind::InvalidInstruction(format!(
+                "{} is not a math function",
+                function
+            )))
+        }
+    };
+
+    Ok(Item::Float(value))
+}
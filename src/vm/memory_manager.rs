@@ -1,6 +1,6 @@
 use core::panic;
 use std::{
-    borrow::BorrowMut,
+    borrow::{BorrowMut, Cow},
     collections::{HashMap, LinkedList},
     fmt::Display,
 };
@@ -8,21 +8,29 @@ use std::{
 use polars::{prelude::DataFrame, series::Series};
 use variantly::Variantly;
 
-use codegen::{meta::ProgramMeta, symbols::FunctionEntry};
+use codegen::{
+    meta::ProgramMeta,
+    quadruples::{Literal, Operand},
+    symbols::{FunctionEntry, SymbolEntry},
+};
 
 use memory::{
     resolver::{MemAddress, MemoryResolver, MemoryScope},
     types::{DataType, FloatType, IntType},
+    virtual_allocator::{MemoryCounter, VirtualAllocator},
+};
+
+use super::{
+    array::{self, Array, Arrays, View, Views},
+    errors::{RuntimeErrorKind, RuntimeResult},
 };
 
 macro_rules! match_types {
     ($typ:tt, $left:expr, $right:expr) => {{
-        if let Item::$typ(op1) = $left {
-            if let Item::$typ(op2) = $right {
-                return (op1, op2);
-            }
+        match ($left, $right) {
+            (Item::$typ(op1), Item::$typ(op2)) => Ok((op1, op2)),
+            (op1, op2) => Err(Item::mismatch(stringify!($typ), &op1, &op2)),
         }
-        panic!();
     }};
 }
 
@@ -35,51 +43,111 @@ pub enum Item {
     DataFrame(DataFrame),
     Series(Series),
     Pointer(MemAddress),
-    ArrayEnd,
+    /// Values of the fields of a struct, along with the struct id.
+    #[variantly(rename = "record")]
+    Struct(String, Vec<(String, Item)>),
+    /// Procedure of a nested function, along with the values it captured.
+    Closure(String, Vec<Item>),
 }
 
 impl Item {
-    pub fn cast_int(item: Item) -> IntType {
+    /// Gathers the values of the fields of a struct variable, reading each
+    /// of them from its address. Returns None for arrays of structs or if
+    /// any field can't be read.
+    pub fn from_struct(
+        symbol: &SymbolEntry,
+        read: &impl Fn(MemAddress) -> Option<Item>,
+    ) -> Option<Item> {
+        let id = match &symbol.data_type {
+            DataType::Struct(id) => id,
+            _ => return read(symbol.address),
+        };
+        if !symbol.dimension.shape.is_empty() {
+            return None;
+        }
+
+        let fields = symbol
+            .fields
+            .iter()
+            .map(|field| Some((field.id.clone(), Item::from_struct(field, read)?)))
+            .collect::<Option<Vec<(String, Item)>>>()?;
+
+        Some(Item::Struct(id.clone(), fields))
+    }
+
+    pub fn type_name(&self) -> &str {
+        match self {
+            Item::Int(_) => "Int",
+            Item::Float(_) => "Float",
+            Item::Bool(_) => "Bool",
+            Item::String(_) => "String",
+            Item::DataFrame(_) => "DataFrame",
+            Item::Series(_) => "Series",
+            Item::Pointer(_) => "Pointer",
+            Item::Struct(..) => "Struct",
+            Item::Closure(..) => "Function",
+        }
+    }
+
+    fn mismatch(expected: &str, left: &Item, right: &Item) -> RuntimeErrorKind {
+        RuntimeErrorKind::TypeError(format!(
+            "Expected two {} operands, found {} and {}",
+            expected,
+            left.type_name(),
+            right.type_name()
+        ))
+    }
+
+    pub fn cast_int(item: Item) -> RuntimeResult<IntType> {
         match item {
-            Item::Int(item) => item,
-            Item::Float(item) => item as IntType,
+            Item::Int(item) => Ok(item),
+            Item::Float(item) => Ok(item as IntType),
             Item::Bool(item) => {
                 if item {
-                    1
+                    Ok(1)
                 } else {
-                    0
+                    Ok(0)
                 }
             }
-            Item::Pointer(item) => item as IntType,
-            _ => panic!("Cant cast {:#?} to int", item),
+            Item::Pointer(item) => Ok(item as IntType),
+            _ => Err(RuntimeErrorKind::TypeError(format!(
+                "Can't cast {} to Int",
+                item.type_name()
+            ))),
+        }
+    }
+
+    pub fn cast_float(item: Item) -> RuntimeResult<FloatType> {
+        match item {
+            Item::Int(item) => Ok(item as FloatType),
+            Item::Float(item) => Ok(item),
+            Item::Bool(item) => Ok(if item { 1.0 } else { 0.0 }),
+            _ => Err(RuntimeErrorKind::TypeError(format!(
+                "Can't cast {} to Float",
+                item.type_name()
+            ))),
         }
     }
 
-    pub fn match_ints(left: Item, right: Item) -> (IntType, IntType) {
-        (Item::cast_int(left), Item::cast_int(right))
+    pub fn match_ints(left: Item, right: Item) -> RuntimeResult<(IntType, IntType)> {
+        Ok((Item::cast_int(left)?, Item::cast_int(right)?))
     }
 
-    pub fn match_floats(op1: Item, op2: Item) -> (FloatType, FloatType) {
-        match_types!(Float, op1, op2);
+    pub fn match_floats(op1: Item, op2: Item) -> RuntimeResult<(FloatType, FloatType)> {
+        match_types!(Float, op1, op2)
     }
 
-    pub fn match_strings(op1: Item, op2: Item) -> (String, String) {
-        match_types!(String, op1, op2);
+    pub fn match_strings(op1: Item, op2: Item) -> RuntimeResult<(String, String)> {
+        match_types!(String, op1, op2)
     }
 
-    pub fn match_pointers(op1: Item, op2: Item) -> (MemAddress, MemAddress) {
-        match op1 {
-            Item::Int(op1) => match op2 {
-                Item::Int(op2) => (op1 as MemAddress, op2 as MemAddress),
-                Item::Pointer(op2) => (op1 as MemAddress, op2),
-                _ => panic!(),
-            },
-            Item::Pointer(op1) => match op2 {
-                Item::Int(op2) => (op1, op2 as MemAddress),
-                Item::Pointer(op2) => (op1, op2),
-                _ => panic!(),
-            },
-            _ => panic!(),
+    pub fn match_pointers(op1: Item, op2: Item) -> RuntimeResult<(MemAddress, MemAddress)> {
+        match (op1, op2) {
+            (Item::Int(op1), Item::Int(op2)) => Ok((op1 as MemAddress, op2 as MemAddress)),
+            (Item::Int(op1), Item::Pointer(op2)) => Ok((op1 as MemAddress, op2)),
+            (Item::Pointer(op1), Item::Int(op2)) => Ok((op1, op2 as MemAddress)),
+            (Item::Pointer(op1), Item::Pointer(op2)) => Ok((op1, op2)),
+            (op1, op2) => Err(Item::mismatch("Pointer", &op1, &op2)),
         }
     }
 }
@@ -92,9 +160,16 @@ impl Display for Item {
             Item::Bool(item) => write!(fmt, "{}", item),
             Item::String(item) => write!(fmt, "{}", item),
             Item::Pointer(item) => write!(fmt, "prt({})", item),
-            Item::ArrayEnd => write!(fmt, "END"),
             Item::DataFrame(df) => write!(fmt, "{:#?}", df),
             Item::Series(item) => write!(fmt, "{:#?}", item),
+            Item::Struct(id, fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(field, item)| format!("{}: {}", field, item))
+                    .collect::<Vec<String>>();
+                write!(fmt, "{} {{ {} }}", id, fields.join(", "))
+            }
+            Item::Closure(id, _) => write!(fmt, "fn {}", id),
         }
     }
 }
@@ -121,6 +196,14 @@ impl CallHold {
 pub struct CallContext {
     pub procedure_id: String,
     pub locals: HashMap<MemAddress, Item>,
+    pub arrays: Arrays,
+    /// Heap usage when the call started, the arrays allocated during the
+    /// call are released when it returns.
+    pub heap_mark: MemoryCounter,
+    /// Heap usage when each open block scope of the call started.
+    pub block_marks: Vec<MemoryCounter>,
+    /// Address receiving the returned value, for calls to function values.
+    pub result: Option<MemAddress>,
 }
 
 impl CallContext {
@@ -128,6 +211,10 @@ impl CallContext {
         CallContext {
             procedure_id,
             locals,
+            arrays: Arrays::new(),
+            heap_mark: MemoryCounter::new(),
+            block_marks: vec![],
+            result: None,
         }
     }
 }
@@ -135,35 +222,65 @@ impl CallContext {
 #[derive(Debug)]
 pub struct MemoryManager {
     pub globals: HashMap<MemAddress, Item>,
+    /// Arrays of the global scope and the heap.
+    pub arrays: Arrays,
+    /// Space of the arrays allocated at runtime.
+    pub heap: VirtualAllocator,
+    /// Views of arrays, they take space from the heap without holding any
+    /// items and are released along with the arrays of the block creating
+    /// them.
+    pub views: Views,
+    /// Heap usage when each open block scope of the global scope started.
+    pub block_marks: Vec<MemoryCounter>,
     pub call_context: LinkedList<CallContext>,
     pub call_hold: LinkedList<CallHold>,
 }
 
 impl MemoryManager {
-    pub fn from_data(data: &ProgramMeta) -> MemoryManager {
-        let constants = data
-            .constant_table
-            .iter()
-            .map(|(address, value)| {
-                let (_, data_type, _) = MemoryResolver::get_offset(*address);
-
-                let val = match data_type {
-                    DataType::Int => Item::Int(value.parse::<IntType>().unwrap()),
-                    DataType::Float => Item::Float(value.parse::<FloatType>().unwrap()),
-                    DataType::Bool => Item::Bool(value.parse::<bool>().unwrap()),
-                    DataType::String => Item::String(value.clone()),
-                    DataType::Pointer => Item::Pointer(value.parse::<MemAddress>().unwrap()),
-                    _ => todo!(),
-                };
-                (*address, val)
-            })
-            .collect::<HashMap<MemAddress, Item>>();
-
-        MemoryManager {
-            globals: HashMap::from(constants),
+    pub fn from_data(data: &ProgramMeta) -> RuntimeResult<MemoryManager> {
+        let mut memory = MemoryManager {
+            globals: HashMap::new(),
+            arrays: Arrays::new(),
+            heap: VirtualAllocator::new(),
+            views: Views::new(),
+            block_marks: vec![],
             call_context: LinkedList::new(),
             call_hold: LinkedList::new(),
+        };
+
+        memory.load_constants(data)?;
+
+        Ok(memory)
+    }
+
+    /// Stores the values of the program constant table.
+    ///
+    /// Fails if a constant can't be parsed as a value of the type of its
+    /// address.
+    pub fn load_constants(&mut self, data: &ProgramMeta) -> RuntimeResult<()> {
+        for (address, value) in data.constant_table.iter() {
+            let (_, data_type, _) = MemoryResolver::get_offset(*address);
+            let invalid = || {
+                RuntimeErrorKind::ValueError(format!(
+                    "Invalid {:?} constant {:?} at address {}",
+                    data_type, value, address
+                ))
+            };
+
+            let item = match data_type {
+                DataType::Int => Item::Int(value.parse::<IntType>().map_err(|_| invalid())?),
+                DataType::Float => Item::Float(value.parse::<FloatType>().map_err(|_| invalid())?),
+                DataType::Bool => Item::Bool(value.parse::<bool>().map_err(|_| invalid())?),
+                DataType::String => Item::String(value.clone()),
+                DataType::Pointer => {
+                    Item::Pointer(value.parse::<MemAddress>().map_err(|_| invalid())?)
+                }
+                _ => return Err(invalid()),
+            };
+            self.globals.insert(*address, item);
         }
+
+        Ok(())
     }
 
     pub fn curr_context(&self) -> &CallContext {
@@ -200,53 +317,96 @@ impl MemoryManager {
         params
     }
 
-    pub fn pop_params(&mut self) -> Vec<Item> {
+    pub fn pop_params(&mut self) -> RuntimeResult<Vec<Item>> {
         let call_params = self.curr_hold().call_params.clone();
 
-        let params: Vec<Item> = call_params
+        let params = call_params
             .iter()
-            .map(|value_addr| {
-                let value = self.resolved_get(*value_addr);
-                value
-            })
-            .collect();
+            .map(|value_addr| self.resolved_get(*value_addr))
+            .collect::<RuntimeResult<Vec<Item>>>();
 
         self.pop_hold();
 
         params
     }
 
-    pub fn push_context(&mut self, context: &FunctionEntry) {
+    pub fn push_context(&mut self, context: &FunctionEntry) -> RuntimeResult<()> {
         let call_params = self.curr_hold().call_params.clone();
 
         let procedure_id = self.curr_hold().procedure_id.clone();
 
-        let call_params = call_params
+        let values = call_params
             .iter()
-            .enumerate()
-            .map(|(param_index, value_addr)| {
-                let (param_addr, _, _) = context.params.get(param_index).unwrap();
+            .map(|value_addr| self.resolved_get(*value_addr))
+            .collect::<RuntimeResult<Vec<Item>>>();
 
-                (*value_addr, *param_addr)
-            })
-            .collect::<Vec<(MemAddress, MemAddress)>>();
+        self.pop_hold();
 
-        let locals: HashMap<MemAddress, Item> = call_params
-            .iter()
-            .map(|(value_addr, param_addr)| {
-                let value = self.resolved_get(*value_addr);
-                (param_addr.clone(), value)
-            })
-            .collect();
+        self.enter(procedure_id, context, values?);
 
-        self.call_context
-            .push_back(CallContext::new(procedure_id, locals));
+        Ok(())
+    }
 
-        self.pop_hold();
+    /// Starts a call to a function with the values of its parameters.
+    pub fn enter(&mut self, procedure_id: String, context: &FunctionEntry, values: Vec<Item>) {
+        let locals = context
+            .params
+            .iter()
+            .zip(values)
+            .map(|((param_addr, _, _), value)| (*param_addr, value))
+            .collect::<HashMap<MemAddress, Item>>();
+
+        let mut context = CallContext::new(procedure_id, locals);
+        context.heap_mark = self.heap.get_counter(&MemoryScope::Heap).clone();
+        self.call_context.push_back(context);
     }
 
+    /// Ends the current call, releasing the heap arrays allocated during
+    /// it.
     pub fn pop_context(&mut self) {
-        self.call_context.pop_back();
+        if let Some(context) = self.call_context.pop_back() {
+            self.release_heap(context.heap_mark);
+        }
+    }
+
+    /// Marks of the open block scopes of the current call, or of the global
+    /// scope outside of calls.
+    fn block_marks_mut(&mut self) -> &mut Vec<MemoryCounter> {
+        match self.call_context.back_mut() {
+            Some(context) => &mut context.block_marks,
+            None => &mut self.block_marks,
+        }
+    }
+
+    /// Records the heap usage when a block scope at a depth opens.
+    pub fn mark_block(&mut self, depth: usize) {
+        let mark = self.heap.get_counter(&MemoryScope::Heap).clone();
+        let marks = self.block_marks_mut();
+        marks.truncate(depth);
+        marks.push(mark);
+    }
+
+    /// Releases the heap arrays allocated since the block scope at a depth
+    /// opened, its mark is kept for the next iteration of loops.
+    pub fn release_block(&mut self, depth: usize) {
+        let marks = self.block_marks_mut();
+        if let Some(mark) = marks.get(depth).cloned() {
+            marks.truncate(depth + 1);
+            self.release_heap(mark);
+        }
+    }
+
+    /// Releases the heap arrays and views allocated after a mark.
+    fn release_heap(&mut self, mark: MemoryCounter) {
+        let kept = |address: &MemAddress| match MemoryResolver::get_offset(*address) {
+            (MemoryScope::Heap, data_type, offset) => {
+                (offset as usize) < mark.get(&data_type).copied().unwrap_or(0)
+            }
+            _ => true,
+        };
+        self.arrays.retain(|address, _| kept(address));
+        self.views.retain(|address, _| kept(address));
+        *self.heap.get_counter(&MemoryScope::Heap) = mark;
     }
 
     pub fn curr_hold(&self) -> &CallHold {
@@ -261,11 +421,19 @@ impl MemoryManager {
         self.call_hold.push_back(CallHold::new(procedure_id));
     }
 
+    /// Deletes the value at an address, along with the array starting at
+    /// it if there is one.
     pub fn delete(&mut self, address: MemAddress) {
         let (scope, _, _) = MemoryResolver::get_offset(address);
         match scope {
-            MemoryScope::Global | MemoryScope::Constant => self.globals.remove(&address),
-            MemoryScope::Local => self.curr_locals_mut().remove(&address),
+            MemoryScope::Global | MemoryScope::Constant | MemoryScope::Heap => {
+                self.globals.remove(&address);
+                self.arrays.remove(&address);
+            }
+            MemoryScope::Local => {
+                self.curr_locals_mut().remove(&address);
+                self.curr_context_mut().arrays.remove(&address);
+            }
         };
     }
 
@@ -277,143 +445,355 @@ impl MemoryManager {
         self.call_hold.pop_back();
     }
 
-    pub fn get_address(&self, address: &String) -> MemAddress {
-        if address.starts_with("*") {
-            let address = address[1..].parse::<MemAddress>().unwrap();
-
-            let accesed = self._get(address).unwrap();
-            match accesed {
-                Item::Pointer(addr) => *addr as MemAddress,
-                _ => panic!("Element is not a pointer"),
+    /// Resolves the memory location an operand refers to, following the
+    /// pointer of indirect operands.
+    pub fn get_address(&self, operand: &Operand) -> RuntimeResult<MemAddress> {
+        match operand {
+            Operand::Direct(address) => Ok(*address),
+            Operand::Indirect(address) => {
+                let accesed = self._get(*address)?;
+                match accesed {
+                    Item::Pointer(addr) => Ok(addr),
+                    _ => Err(RuntimeErrorKind::TypeError(format!(
+                        "{} at address {} is not a pointer",
+                        accesed.type_name(),
+                        address
+                    ))),
+                }
             }
-        } else {
-            let address = address.parse::<MemAddress>().unwrap();
-            address
+            operand => Err(RuntimeErrorKind::InvalidInstruction(format!(
+                "Operand {} is not an address",
+                operand
+            ))),
         }
     }
 
-    pub fn update(&mut self, address: MemAddress, item: Item) {
+    /// Stores a value, either as a variable or as the item of the array
+    /// holding the address.
+    pub fn update(&mut self, address: MemAddress, item: Item) -> RuntimeResult<()> {
+        let address = self.resolve(address);
         let (scope, _, _) = MemoryResolver::get_offset(address);
-        match scope {
-            MemoryScope::Global | MemoryScope::Constant => self.globals.insert(address, item),
-            MemoryScope::Local => self.curr_locals_mut().insert(address, item),
+        let (values, arrays) = match scope {
+            MemoryScope::Global | MemoryScope::Constant | MemoryScope::Heap => {
+                (&mut self.globals, &mut self.arrays)
+            }
+            MemoryScope::Local => {
+                let context = self.curr_context_mut();
+                (&mut context.locals, &mut context.arrays)
+            }
         };
-    }
 
-    fn _get(&self, address: MemAddress) -> Result<&Item, String> {
-        if let Some(scope) = MemoryResolver::get_scope_from_address(address) {
-            match scope {
-                MemoryScope::Global | MemoryScope::Constant => self._get_global(address),
-                MemoryScope::Local => self._get_local(address),
+        match array::find_mut(arrays, address) {
+            Some((start, array)) => array.set((address - start) as usize, item),
+            None => {
+                values.insert(address, item);
+                Ok(())
             }
-        } else {
-            Err(format!("{address} is not a valid address"))
         }
     }
 
-    fn _get_global(&self, address: MemAddress) -> Result<&Item, String> {
-        if let Some(item) = self.globals.get(&address) {
-            Ok(item)
-        } else {
-            Err(format!("Cant find global address {address}"))
+    /// Reserves an array of the given shape, its items are set to the zero
+    /// value of the type of the address.
+    pub fn alloc(&mut self, address: MemAddress, shape: &[usize]) -> RuntimeResult<()> {
+        let data_type = MemoryResolver::get_type_from_address(address).ok_or_else(|| {
+            RuntimeErrorKind::InvalidInstruction(format!("{address} is not a valid address"))
+        })?;
+        let array = Array::new(data_type, shape);
+        let items = address..address + array.len() as MemAddress;
+
+        // Addresses of a closed block can be reused by the array, the values
+        // left in them would hide its items
+        let (scope, _, _) = MemoryResolver::get_offset(address);
+        let (values, arrays) = match scope {
+            MemoryScope::Global | MemoryScope::Constant | MemoryScope::Heap => {
+                (&mut self.globals, &mut self.arrays)
+            }
+            MemoryScope::Local => {
+                let context = self.curr_context_mut();
+                (&mut context.locals, &mut context.arrays)
+            }
+        };
+        values.retain(|value_address, _| !items.contains(value_address));
+        arrays.insert(address, array);
+
+        Ok(())
+    }
+
+    /// Allocates an array whose shape is known at runtime, returns the
+    /// address of its first item.
+    ///
+    /// Fails if a size is not positive or the array doesn't fit in the heap.
+    pub fn alloc_heap(
+        &mut self,
+        data_type: &DataType,
+        shape: &[IntType],
+    ) -> RuntimeResult<MemAddress> {
+        if let Some(size) = shape.iter().find(|size| **size < 0) {
+            return Err(RuntimeErrorKind::ValueError(format!(
+                "Invalid array size {}, sizes can't be negative",
+                size
+            )));
         }
-    }
 
-    fn _get_local(&self, address: MemAddress) -> Result<&Item, String> {
-        if let Some(item) = self.curr_locals().get(&address) {
-            Ok(item)
-        } else {
-            Err(format!("Cant find local address {address}"))
+        let shape = shape
+            .iter()
+            .map(|size| *size as usize)
+            .collect::<Vec<usize>>();
+        // Empty arrays still take an address of their own
+        let size = shape.iter().product::<usize>().max(1);
+        let address = self
+            .heap
+            .try_assign_location(&MemoryScope::Heap, data_type, size)
+            .map_err(RuntimeErrorKind::ValueError)?;
+
+        self.alloc(address, &shape)?;
+        Ok(address)
+    }
+
+    /// Creates a view over the items of an array from an address, returns
+    /// the address of its first item.
+    ///
+    /// Fails if a size or a stride is not positive or the view doesn't fit
+    /// in the heap.
+    pub fn new_view(
+        &mut self,
+        source: MemAddress,
+        shape: &[IntType],
+        strides: &[IntType],
+    ) -> RuntimeResult<MemAddress> {
+        if let Some(size) = shape.iter().find(|size| **size <= 0) {
+            return Err(RuntimeErrorKind::ValueError(format!(
+                "Invalid slice of {} items, slices must take at least one item",
+                size
+            )));
+        } else if let Some(stride) = strides.iter().find(|stride| **stride <= 0) {
+            return Err(RuntimeErrorKind::ValueError(format!(
+                "Invalid slice step {}, steps must be positive",
+                stride
+            )));
         }
+
+        let data_type = MemoryResolver::get_type_from_address(source).ok_or_else(|| {
+            RuntimeErrorKind::InvalidInstruction(format!("{source} is not a valid address"))
+        })?;
+        let view = View {
+            source,
+            shape: shape.iter().map(|size| *size as usize).collect(),
+            strides: strides.iter().map(|stride| *stride as usize).collect(),
+        };
+        let address = self
+            .heap
+            .try_assign_location(&MemoryScope::Heap, data_type, view.len())
+            .map_err(RuntimeErrorKind::ValueError)?;
+
+        let items = address..address + view.len() as MemAddress;
+        self.globals
+            .retain(|value_address, _| !items.contains(value_address));
+        self.views.insert(address, view);
+
+        Ok(address)
     }
 
-    pub fn resolved_get(&mut self, address: MemAddress) -> Item {
-        self._get(address).unwrap().clone()
+    /// Follows the views holding an address to the address of the item it
+    /// refers to.
+    fn resolve(&self, address: MemAddress) -> MemAddress {
+        let mut address = address;
+        while let Some((start, view)) = array::find_view(&self.views, address) {
+            address = view.address((address - start) as usize);
+        }
+
+        address
+    }
+
+    /// Copies a number of items between arrays, from the addresses two
+    /// pointers refer to.
+    ///
+    /// Fails if the destination holds less items from its address onwards,
+    /// or if the source doesn't hold exactly that many items, the size of
+    /// the array or one of its rows starting at the address.
+    pub fn copy(
+        &mut self,
+        source: MemAddress,
+        dest: MemAddress,
+        count: usize,
+    ) -> RuntimeResult<()> {
+        let out_of_bounds = |array: &Array, start: usize| {
+            RuntimeErrorKind::ValueError(format!(
+                "Can't copy {} items, the array only holds {} items",
+                count,
+                array.len() - start
+            ))
+        };
+
+        let (array, start) = self.get_array(source)?;
+        // Sizes of the rows spanning the last axes that start at the address
+        let rows = (0..array.shape.len())
+            .map(|axis| array.shape[axis..].iter().product::<usize>())
+            .filter(|size| *size == 0 || start % size == 0)
+            .collect::<Vec<usize>>();
+        if !rows.contains(&count) || start + count > array.len() {
+            return Err(RuntimeErrorKind::ValueError(format!(
+                "Can't copy {} items, the array holds {} items",
+                count,
+                rows.first().copied().unwrap_or(array.len() - start)
+            )));
+        }
+        let items = (start..start + count)
+            .map(|index| array.get(index))
+            .collect::<Option<Vec<Item>>>()
+            .ok_or(RuntimeErrorKind::UndefinedValue(source))?;
+
+        self.modify_array(dest, |array, start| {
+            if start + count > array.len() {
+                return Err(out_of_bounds(array, start));
+            }
+            for (index, item) in items.into_iter().enumerate() {
+                array.set(start + index, item)?;
+            }
+
+            Ok(())
+        })
     }
 
-    pub fn safe_resolved_get(&mut self, address: MemAddress) -> Result<Item, String> {
-        let res = self._get(address);
-        if let Ok(item) = res {
-            Ok(item.clone())
-        } else {
-            Err(res.unwrap_err())
+    /// Reads a value from the variables and arrays of a scope.
+    pub fn read(
+        values: &HashMap<MemAddress, Item>,
+        arrays: &Arrays,
+        address: MemAddress,
+    ) -> RuntimeResult<Item> {
+        if let Some(item) = values.get(&address) {
+            return Ok(item.clone());
         }
+
+        array::find(arrays, address)
+            .and_then(|(start, array)| array.get((address - start) as usize))
+            .ok_or(RuntimeErrorKind::UndefinedValue(address))
     }
 
-    pub fn safe_get(&mut self, address: &String) -> Result<Item, String> {
-        if address.starts_with("&") {
-            let address = address[1..].parse::<MemAddress>().unwrap();
-            Ok(Item::Pointer(address))
-        } else if address.starts_with("*") {
-            let next = &address[1..].to_string();
-            let attempt = self.safe_get(next);
-            if let Ok(next_address) = attempt {
-                if let Item::Pointer(address) = next_address {
-                    self.safe_get(&format!("{}", address))
-                } else {
-                    Err(String::from("Item is not a pointer"))
+    fn _get(&self, address: MemAddress) -> RuntimeResult<Item> {
+        let address = self.resolve(address);
+        if let Some(scope) = MemoryResolver::get_scope_from_address(address) {
+            match scope {
+                MemoryScope::Global | MemoryScope::Constant | MemoryScope::Heap => {
+                    MemoryManager::read(&self.globals, &self.arrays, address)
+                }
+                MemoryScope::Local => {
+                    let context = self.curr_context();
+                    MemoryManager::read(&context.locals, &context.arrays, address)
                 }
-            } else {
-                attempt
             }
         } else {
-            let parse = address.parse::<MemAddress>();
-            if let Ok(address) = parse {
-                Ok(self.resolved_get(address))
-            } else {
-                let err = format!("Item {} is not an address", address).to_owned();
-                Err(err.to_owned())
-            }
+            Err(RuntimeErrorKind::InvalidInstruction(format!(
+                "{address} is not a valid address"
+            )))
         }
     }
 
-    pub fn get(&mut self, address: &String) -> Item {
-        if address == "END" {
-            return Item::ArrayEnd;
-        } else {
-            self.safe_get(address).unwrap()
-        }
+    pub fn resolved_get(&mut self, address: MemAddress) -> RuntimeResult<Item> {
+        self._get(address)
     }
 
-    pub fn get_array(&mut self, start_address: &MemAddress) -> Vec<Option<Item>> {
-        let mut curr_address = *start_address;
-        let mut items: Vec<Option<Item>> = Vec::new();
-        loop {
-            let current = self.safe_resolved_get(curr_address);
+    /// Reads the value of an instruction operand.
+    pub fn get(&mut self, operand: &Operand) -> RuntimeResult<Item> {
+        match operand {
+            Operand::Direct(_) | Operand::Indirect(_) => {
+                let address = self.get_address(operand)?;
+                self.resolved_get(address)
+            }
+            Operand::AddressOf(address) => Ok(Item::Pointer(*address)),
+            Operand::Const(Literal::End) => Err(RuntimeErrorKind::InvalidInstruction(
+                String::from("Array end markers are no longer supported"),
+            )),
+            Operand::Const(Literal::Int(value)) => Ok(Item::Int(*value)),
+            Operand::Const(Literal::Text(text)) => Ok(Item::String(text.clone())),
+            Operand::Const(Literal::Void) => Err(RuntimeErrorKind::TypeError(String::from(
+                "Void value can't be used",
+            ))),
+            Operand::Label(_) => Err(RuntimeErrorKind::InvalidInstruction(format!(
+                "Label {} is not a value",
+                operand
+            ))),
+        }
+    }
 
-            if let Ok(item) = current {
-                if item == Item::ArrayEnd {
-                    break;
+    /// Returns the array holding the address a pointer refers to, along
+    /// with the index of the address within it.
+    ///
+    /// The items of views are gathered in an array of their own.
+    pub fn get_array(&self, address: MemAddress) -> RuntimeResult<(Cow<'_, Array>, usize)> {
+        if let Some((start, view)) = array::find_view(&self.views, address) {
+            let data_type = MemoryResolver::get_type_from_address(start).ok_or_else(|| {
+                RuntimeErrorKind::InvalidInstruction(format!("{start} is not a valid address"))
+            })?;
+            let mut array = Array::new(data_type, &view.shape);
+            for index in 0..view.len() {
+                // Items never assigned are left undefined
+                if let Ok(item) = self._get(view.address(index)) {
+                    array.set(index, item)?;
                 }
-                items.push(Some(item));
-            } else {
-                items.push(None);
             }
 
-            curr_address += 1;
+            return Ok((Cow::Owned(array), (address - start) as usize));
         }
 
-        items
+        let arrays = match MemoryResolver::get_scope_from_address(address) {
+            Some(MemoryScope::Local) => &self.curr_context().arrays,
+            _ => &self.arrays,
+        };
+
+        array::find(arrays, address)
+            .map(|(start, array)| (Cow::Borrowed(array), (address - start) as usize))
+            .ok_or(RuntimeErrorKind::UndefinedValue(address))
     }
 
-    pub fn alter_array<F>(&mut self, start_address: &MemAddress, cb: F)
-    where
-        F: Fn(&mut Self, (MemAddress, Option<Item>)) -> (),
-    {
-        let mut curr_address = *start_address;
-        loop {
-            let current = self.safe_resolved_get(curr_address);
+    /// Shape of the array or view holding the address a pointer refers to.
+    pub fn get_shape(&self, address: MemAddress) -> RuntimeResult<Vec<usize>> {
+        match array::find_view(&self.views, address) {
+            Some((_, view)) => Ok(view.shape.clone()),
+            None => Ok(self.get_array(address)?.0.shape.clone()),
+        }
+    }
 
-            if let Ok(item) = current {
-                if item == Item::ArrayEnd {
-                    break;
-                }
-                cb(self, (curr_address, Some(item)));
-            } else {
-                cb(self, (curr_address, None));
+    /// Mutable version of [MemoryManager::get_array].
+    pub fn get_array_mut(&mut self, address: MemAddress) -> RuntimeResult<(&mut Array, usize)> {
+        let arrays = match MemoryResolver::get_scope_from_address(address) {
+            Some(MemoryScope::Local) => &mut self.curr_context_mut().arrays,
+            _ => &mut self.arrays,
+        };
+
+        array::find_mut(arrays, address)
+            .map(|(start, array)| (array, (address - start) as usize))
+            .ok_or(RuntimeErrorKind::UndefinedValue(address))
+    }
+
+    /// Applies changes to the array holding the address a pointer refers
+    /// to, along with the index of the address within it.
+    ///
+    /// The changes to the items gathered from a view are written back to
+    /// the viewed array.
+    pub fn modify_array<T>(
+        &mut self,
+        address: MemAddress,
+        change: impl FnOnce(&mut Array, usize) -> RuntimeResult<T>,
+    ) -> RuntimeResult<T> {
+        let start = match array::find_view(&self.views, address) {
+            Some((start, _)) => start,
+            None => {
+                let (array, index) = self.get_array_mut(address)?;
+                return change(array, index);
             }
+        };
 
-            curr_address += 1;
+        let (array, index) = self.get_array(address)?;
+        let mut array = array.into_owned();
+        let result = change(&mut array, index)?;
+
+        for index in 0..array.len() {
+            if let Some(item) = array.get(index) {
+                self.update(start + index as MemAddress, item)?;
+            }
         }
+
+        Ok(result)
     }
 }
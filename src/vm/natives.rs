@@ -3,14 +3,19 @@ use memory::{
     resolver::MemoryResolver,
     types::{DataType, FloatType, IntType},
 };
-use polars::prelude::{AnyValue, CsvReader, SerReader};
+use polars::prelude::{AnyValue, CsvReader, NamedFrom, SerReader, Series};
 use rand::Rng;
 use std::{io, iter::zip};
 
 use crate::plots::{backend::TextDrawingBackend, context::PlotContext};
 
 use super::{
+    array::Buffer,
+    errors::{RuntimeErrorKind, RuntimeResult},
+    linalg::Matrix,
+    math,
     memory_manager::{Item, MemoryManager},
+    strings,
     utils::*,
 };
 
@@ -18,79 +23,98 @@ pub fn run_native(
     plot_ctx: &mut PlotContext,
     native_func: NativeFunction,
     memory: &mut MemoryManager,
-) -> Option<(NativeFunction, Item)> {
+) -> RuntimeResult<Option<(NativeFunction, Item)>> {
     let mut return_value: Option<(NativeFunction, Item)> = None;
     let mut rng = rand::thread_rng();
 
     match native_func {
         NativeFunction::Zeros => {
-            let params = memory.pop_params();
+            let params = memory.pop_params()?;
             let array_pointer = params.get(0).unwrap();
+            let rank = unwrap_int_param(&params, 1)? as usize;
 
             if let Item::Pointer(array_address) = array_pointer {
-                memory.alter_array(array_address, |memory, (next_address, _)| {
-                    memory.update(next_address, Item::Int(0));
-                });
+                memory.modify_array(*array_address, |array, start| {
+                    let row = array.row(start, rank)?;
+                    match &mut array.buffer {
+                        Buffer::Int(items) => items[row].fill(0),
+                        Buffer::Float(items) => items[row].fill(0.0),
+                        Buffer::Bool(items) => items[row].fill(false),
+                        _ => {
+                            return Err(RuntimeErrorKind::TypeError(format!(
+                                "Can't fill array of {} items with zeros",
+                                array.type_name()
+                            )))
+                        }
+                    }
+                    Ok(())
+                })?;
             }
         }
         NativeFunction::RandomFill => {
-            let params = memory.pop_params();
+            let params = memory.pop_params()?;
             let array_pointer = params.get(0).unwrap();
-            let min = params.get(1).unwrap().to_owned();
-            let min = min.unwrap_int();
-            let max = params.get(2).unwrap().to_owned();
-            let max = max.unwrap_int();
+            let min = unwrap_int_param(&params, 1)?;
+            let max = unwrap_int_param(&params, 2)?;
+            let rank = unwrap_int_param(&params, 3)? as usize;
+
+            if min >= max {
+                return Err(RuntimeErrorKind::ValueError(format!(
+                    "Can't fill array with random numbers in the empty range {}..{}",
+                    min, max
+                )));
+            }
 
             if let Item::Pointer(array_address) = array_pointer {
-                let array_type = MemoryResolver::get_type_from_address(*array_address).unwrap();
-
-                memory.alter_array(array_address, |memory, (next_address, _)| {
-                    match array_type {
-                        DataType::Int => memory.update(
-                            next_address,
-                            Item::Int(rand::thread_rng().gen_range(min..max)),
-                        ),
-                        DataType::Float => memory.update(
-                            next_address,
-                            Item::Float(
-                                rand::thread_rng().gen_range(min as FloatType..max as FloatType),
-                            ),
-                        ),
-                        _ => panic!(
-                            "Can't fill array of type {:#?} with random numbers!",
-                            array_type
-                        ),
-                    };
-                });
+                memory.modify_array(*array_address, |array, start| {
+                    let row = array.row(start, rank)?;
+                    match &mut array.buffer {
+                        Buffer::Int(items) => items[row]
+                            .iter_mut()
+                            .for_each(|item| *item = rng.gen_range(min..max)),
+                        Buffer::Float(items) => items[row].iter_mut().for_each(|item| {
+                            *item = rng.gen_range(min as FloatType..max as FloatType)
+                        }),
+                        _ => {
+                            return Err(RuntimeErrorKind::TypeError(format!(
+                                "Can't fill array of {} items with random numbers",
+                                array.type_name()
+                            )))
+                        }
+                    }
+                    Ok(())
+                })?;
             }
         }
         NativeFunction::ScalarMul => {
-            let params = memory.pop_params();
+            let params = memory.pop_params()?;
             let array_pointer = params.get(0).unwrap();
-            let factor = params.get(1).unwrap().to_owned();
-            let factor = factor.unwrap_float();
+            let factor = unwrap_float_param(&params, 1)?;
+            let rank = unwrap_int_param(&params, 2)? as usize;
 
             if let Item::Pointer(array_address) = array_pointer {
-                let array_type = MemoryResolver::get_type_from_address(*array_address).unwrap();
-
-                memory.alter_array(array_address, |memory, (next_address, value)| {
-                    if let Some(value) = value {
-                        match array_type {
-                            DataType::Int => memory.update(
-                                next_address,
-                                Item::Int(value.unwrap_int() * factor as IntType),
-                            ),
-                            DataType::Float => memory
-                                .update(next_address, Item::Float(value.unwrap_float() * factor)),
-                            _ => panic!(
-                                "Can't fill array of type {:#?} with random numbers!",
-                                array_type
-                            ),
-                        };
-                    } else {
-                        panic!("Undefined element in array multiplication!")
+                memory.modify_array(*array_address, |array, start| {
+                    let row = array.row(start, rank)?;
+                    match &mut array.buffer {
+                        Buffer::Int(items) => {
+                            for item in items[row].iter_mut() {
+                                *item = item
+                                    .checked_mul(factor as IntType)
+                                    .ok_or(RuntimeErrorKind::OverflowError)?;
+                            }
+                        }
+                        Buffer::Float(items) => {
+                            items[row].iter_mut().for_each(|item| *item *= factor)
+                        }
+                        _ => {
+                            return Err(RuntimeErrorKind::TypeError(format!(
+                                "Can't multiply array of {} items by a scalar",
+                                array.type_name()
+                            )))
+                        }
                     }
-                });
+                    Ok(())
+                })?;
             }
         }
         NativeFunction::Read => {
@@ -100,7 +124,7 @@ pub fn run_native(
 
             io::stdin()
                 .read_line(&mut in_line)
-                .expect("failed to readline");
+                .map_err(|error| RuntimeErrorKind::IOError(error.to_string()))?;
 
             while in_line.ends_with('\n') || in_line.ends_with('\n') {
                 in_line.pop();
@@ -108,26 +132,30 @@ pub fn run_native(
 
             let inputs: Vec<&str> = in_line.split(' ').collect();
 
-            zip(inputs, params).for_each(|(input, param_addr)| {
+            for (input, param_addr) in zip(inputs, params) {
                 let input_type = MemoryResolver::get_type_from_address(param_addr).unwrap();
+                let parse_error = || {
+                    RuntimeErrorKind::ValueError(format!(
+                        "Can't parse {:?} as {:?}",
+                        input, input_type
+                    ))
+                };
                 let item = match input_type {
-                    DataType::Int => match input.parse::<IntType>() {
-                        Ok(parsed) => Item::Int(parsed),
-                        Err(err) => panic!("{:#?}", err),
-                    },
-                    DataType::Float => match input.parse::<FloatType>() {
-                        Ok(parsed) => Item::Float(parsed),
-                        Err(err) => panic!("{:#?}", err),
-                    },
+                    DataType::Int => {
+                        Item::Int(input.parse::<IntType>().map_err(|_| parse_error())?)
+                    }
+                    DataType::Float => {
+                        Item::Float(input.parse::<FloatType>().map_err(|_| parse_error())?)
+                    }
                     DataType::String => Item::String(input.to_string()),
-                    _ => panic!("Type {:#?} can't be parsed from a string", input_type),
+                    _ => return Err(parse_error()),
                 };
 
-                memory.update(param_addr, item)
-            });
+                memory.update(param_addr, item)?;
+            }
         }
         NativeFunction::ReadCsv => {
-            let params = memory.pop_params();
+            let params = memory.pop_params()?;
             let file_path = params.get(0).unwrap();
 
             if let Item::String(file_path) = file_path {
@@ -137,94 +165,97 @@ pub fn run_native(
                     if let Ok(df) = df_result {
                         return_value = Some((NativeFunction::ReadCsv, Item::DataFrame(df)));
                     } else {
-                        panic!(
+                        return Err(RuntimeErrorKind::IOError(format!(
                             "Could not read file {file_path} -> {}",
                             df_result.unwrap_err()
-                        );
+                        )));
                     }
                 } else {
-                    panic!("Could not read file {file_path}");
+                    return Err(RuntimeErrorKind::IOError(format!(
+                        "Could not read file {file_path}"
+                    )));
                 }
             }
         }
         NativeFunction::Select => {
-            let params = memory.pop_params();
-            let df = params.get(0).unwrap().to_owned();
-            let df = df.unwrap_data_frame();
-            let select_col = params.get(1).unwrap().to_owned();
-            let select_col = select_col.unwrap_string();
+            let params = memory.pop_params()?;
+            let df = unwrap_df_param(&params, 0)?;
+            let select_col = unwrap_str_param(&params, 1)?;
 
             let selected = df.column(select_col.as_str());
             if let Ok(selected) = selected.cloned() {
                 return_value = Some((NativeFunction::Select, Item::Series(selected)));
             } else {
-                panic!("Can't select column {} from DataFrame!", select_col);
+                return Err(RuntimeErrorKind::ValueError(format!(
+                    "Can't select column {} from DataFrame",
+                    select_col
+                )));
             }
         }
         NativeFunction::PrintNames => {
-            let params = memory.pop_params();
-            let df = unwrap_df_param(&params, 0);
+            let params = memory.pop_params()?;
+            let df = unwrap_df_param(&params, 0)?;
 
             df.get_columns().iter().for_each(|col| {
                 println!("{:#?} - {:#?}", col.name(), col.dtype());
             })
         }
         NativeFunction::SetPlotOut => {
-            let params = memory.pop_params();
-            let path = unwrap_str_param(&params, 0);
+            let params = memory.pop_params()?;
+            let path = unwrap_str_param(&params, 0)?;
 
             plot_ctx.set_output_path(path);
         }
         NativeFunction::SetCaption => {
-            let params = memory.pop_params();
-            let caption = unwrap_str_param(&params, 0);
+            let params = memory.pop_params()?;
+            let caption = unwrap_str_param(&params, 0)?;
 
             plot_ctx.set_caption(caption);
         }
         NativeFunction::SetXTitle => {
-            let params = memory.pop_params();
-            let caption = unwrap_str_param(&params, 0);
+            let params = memory.pop_params()?;
+            let caption = unwrap_str_param(&params, 0)?;
 
             plot_ctx.set_x_label(caption);
         }
         NativeFunction::SetYTitle => {
-            let params = memory.pop_params();
-            let caption = unwrap_str_param(&params, 0);
+            let params = memory.pop_params()?;
+            let caption = unwrap_str_param(&params, 0)?;
 
             plot_ctx.set_y_label(caption);
         }
         NativeFunction::SetXBounds => {
-            let params = memory.pop_params();
-            let min = unwrap_float_param(&params, 0);
-            let max = unwrap_float_param(&params, 1);
+            let params = memory.pop_params()?;
+            let min = unwrap_float_param(&params, 0)?;
+            let max = unwrap_float_param(&params, 1)?;
 
             plot_ctx.set_x_bounds((min, max));
         }
         NativeFunction::SetYBounds => {
-            let params = memory.pop_params();
-            let min = unwrap_float_param(&params, 0);
-            let max = unwrap_float_param(&params, 1);
+            let params = memory.pop_params()?;
+            let min = unwrap_float_param(&params, 0)?;
+            let max = unwrap_float_param(&params, 1)?;
 
             plot_ctx.set_y_bounds((min, max));
         }
         NativeFunction::Scatter => {
-            let params = memory.pop_params();
-            let x_series = unwrap_series_param(&params, 0);
-            let y_series = unwrap_series_param(&params, 1);
+            let params = memory.pop_params()?;
+            let x_series = plot_points(memory, &params, 0)?;
+            let y_series = plot_points(memory, &params, 1)?;
 
             plot_ctx
                 .draw_scatter::<TextDrawingBackend>(&x_series, &y_series)
-                .unwrap();
+                .map_err(|error| RuntimeErrorKind::IOError(error.to_string()))?;
 
             plot_ctx.reset_context();
         }
         NativeFunction::Random => {
-            memory.pop_params();
+            memory.pop_params()?;
             return_value = Some((NativeFunction::Random, Item::Float(rng.gen_range(0.0..1.0))))
         }
         NativeFunction::Describe => {
-            let params = memory.pop_params();
-            let df = unwrap_df_param(&params, 0);
+            let params = memory.pop_params()?;
+            let df = unwrap_df_param(&params, 0)?;
             println!("{:#?}", df.describe(None));
         }
         NativeFunction::Sum
@@ -232,29 +263,25 @@ pub fn run_native(
         | NativeFunction::Median
         | NativeFunction::Std
         | NativeFunction::Var => {
-            let params = memory.pop_params();
+            let params = memory.pop_params()?;
             let target = params.first().unwrap();
 
             let value: FloatType;
 
             if target.is_pointer() {
-                let start_address = target.clone().unwrap_pointer();
-                let start_item = memory.resolved_get(start_address.clone());
-                let array = memory.get_array(&start_address);
-                let filtered = array.iter().filter(|item| item.is_some());
-
-                let mut items = match start_item {
-                    Item::Int(_) => filtered
-                        .map(|item| item.clone().unwrap().unwrap_int() as FloatType)
-                        .collect::<Vec<FloatType>>(),
-                    Item::Float(_) => filtered
-                        .map(|item| item.clone().unwrap().unwrap_float())
-                        .collect::<Vec<FloatType>>(),
-                    _ => panic!("Can't calculate {} of the item type.", native_func),
-                };
+                let rank = unwrap_int_param(&params, 1)? as usize;
+                let (array, start) = memory.get_array(target.clone().unwrap_pointer())?;
+
+                let mut items = array.to_floats(array.row(start, rank)?).ok_or_else(|| {
+                    RuntimeErrorKind::TypeError(format!(
+                        "Can't calculate {} of {} items",
+                        native_func,
+                        array.type_name()
+                    ))
+                })?;
 
                 value = match native_func {
-                    NativeFunction::Sum => items.iter().sum(),
+                    NativeFunction::Sum => items.iter().fold(0.0, |acc, item| acc + item),
                     NativeFunction::Mean => {
                         items.iter().sum::<FloatType>() / items.len() as FloatType
                     }
@@ -292,43 +319,200 @@ pub fn run_native(
                     _ => panic!(),
                 }
             } else {
-                let target = target.to_owned().unwrap_series();
-                value = match native_func {
-                    NativeFunction::Sum => target.sum().unwrap(),
-                    NativeFunction::Mean => target.mean().unwrap(),
-                    NativeFunction::Median => target.median().unwrap(),
-                    NativeFunction::Std => {
-                        if let AnyValue::Float64(item) = target
-                            .std_as_series(0)
-                            .cast(&polars::prelude::DataType::Float64)
-                            .unwrap()
-                            .get(0)
-                        {
-                            item
-                        } else {
-                            panic!()
-                        }
-                    }
-                    NativeFunction::Var => {
-                        if let AnyValue::Float64(item) = target
-                            .var_as_series(0)
-                            .cast(&polars::prelude::DataType::Float64)
-                            .unwrap()
-                            .get(0)
-                        {
-                            item
-                        } else {
-                            panic!()
-                        }
+                let target = unwrap_series_param(&params, 0)?;
+                let as_float = |series: polars::prelude::Series| {
+                    let series = series.cast(&polars::prelude::DataType::Float64).ok()?;
+                    match series.get(0) {
+                        AnyValue::Float64(item) => Some(item),
+                        _ => None,
                     }
+                };
+
+                value = match native_func {
+                    NativeFunction::Sum => target.sum(),
+                    NativeFunction::Mean => target.mean(),
+                    NativeFunction::Median => target.median(),
+                    NativeFunction::Std => as_float(target.std_as_series(0)),
+                    NativeFunction::Var => as_float(target.var_as_series(0)),
                     _ => panic!(),
                 }
+                .ok_or_else(|| {
+                    RuntimeErrorKind::ValueError(format!(
+                        "Can't calculate {} of series {}",
+                        native_func,
+                        target.name()
+                    ))
+                })?;
+            }
+            return_value = Some((native_func, Item::Float(value)));
+        }
+        NativeFunction::Transpose | NativeFunction::Inverse => {
+            let params = memory.pop_params()?;
+            let matrix = Matrix::read(memory, unwrap_pointer_param(&params, 0)?, 2)?;
+
+            let result = match native_func {
+                NativeFunction::Transpose => matrix.transpose(),
+                _ => matrix.inverse()?,
+            };
+            let address = result.store(memory, &DataType::Float)?;
+            return_value = Some((native_func, Item::Pointer(address)));
+        }
+        NativeFunction::Identity => {
+            let params = memory.pop_params()?;
+            let size = unwrap_int_param(&params, 0)?;
+
+            if size <= 0 {
+                return Err(RuntimeErrorKind::ValueError(format!(
+                    "Invalid matrix size {}, sizes must be positive",
+                    size
+                )));
             }
+
+            let address = Matrix::identity(size as usize).store(memory, &DataType::Float)?;
+            return_value = Some((native_func, Item::Pointer(address)));
+        }
+        NativeFunction::Dot => {
+            let params = memory.pop_params()?;
+            let left = Matrix::read(memory, unwrap_pointer_param(&params, 0)?, 1)?;
+            let right = Matrix::read(memory, unwrap_pointer_param(&params, 1)?, 1)?;
+
+            return_value = Some((native_func, Item::Float(left.dot(&right)?)));
+        }
+        NativeFunction::Det => {
+            let params = memory.pop_params()?;
+            let matrix = Matrix::read(memory, unwrap_pointer_param(&params, 0)?, 2)?;
+
+            return_value = Some((native_func, Item::Float(matrix.det()?)));
+        }
+        NativeFunction::Solve => {
+            let params = memory.pop_params()?;
+            let matrix = Matrix::read(memory, unwrap_pointer_param(&params, 0)?, 2)?;
+            let values = Matrix::read(memory, unwrap_pointer_param(&params, 1)?, 1)?;
+
+            let address = matrix.solve(&values)?.store(memory, &DataType::Float)?;
+            return_value = Some((native_func, Item::Pointer(address)));
+        }
+        NativeFunction::Substr => {
+            let params = memory.pop_params()?;
+            let text = unwrap_str_param(&params, 0)?;
+            let start = unwrap_int_param(&params, 1)?;
+            let length = unwrap_int_param(&params, 2)?;
+
+            let value = strings::substr(&text, start, length)?;
+            return_value = Some((native_func, Item::String(value)));
+        }
+        NativeFunction::Find => {
+            let params = memory.pop_params()?;
+            let text = unwrap_str_param(&params, 0)?;
+            let pattern = unwrap_str_param(&params, 1)?;
+
+            let position = strings::find(&text, &pattern);
+            return_value = Some((native_func, Item::Int(position)));
+        }
+        NativeFunction::Replace => {
+            let params = memory.pop_params()?;
+            let text = unwrap_str_param(&params, 0)?;
+            let from = unwrap_str_param(&params, 1)?;
+            let to = unwrap_str_param(&params, 2)?;
+
+            return_value = Some((native_func, Item::String(text.replace(&from, &to))));
+        }
+        NativeFunction::Split => {
+            let params = memory.pop_params()?;
+            let text = unwrap_str_param(&params, 0)?;
+            let separator = unwrap_str_param(&params, 1)?;
+
+            let parts = strings::split(&text, &separator)?;
+            let address = memory.alloc_heap(&DataType::String, &[parts.len() as IntType])?;
+            let (array, _) = memory.get_array_mut(address)?;
+            array.buffer = Buffer::String(parts);
+
+            return_value = Some((native_func, Item::Pointer(address)));
+        }
+        NativeFunction::Join => {
+            let params = memory.pop_params()?;
+            let separator = unwrap_str_param(&params, 1)?;
+
+            let (array, start) = memory.get_array(unwrap_pointer_param(&params, 0)?)?;
+            let items = match &array.buffer {
+                Buffer::String(items) => {
+                    let size = array.shape.last().copied().unwrap_or(0);
+                    &items[start..start + size]
+                }
+                _ => {
+                    return Err(RuntimeErrorKind::TypeError(format!(
+                        "Can't join array of {} items",
+                        array.type_name()
+                    )))
+                }
+            };
+
+            let value = items.join(&separator);
+            return_value = Some((native_func, Item::String(value)));
+        }
+        NativeFunction::Upper | NativeFunction::Lower | NativeFunction::Trim => {
+            let params = memory.pop_params()?;
+            let text = unwrap_str_param(&params, 0)?;
+
+            let value = match native_func {
+                NativeFunction::Upper => text.to_uppercase(),
+                NativeFunction::Lower => text.to_lowercase(),
+                _ => text.trim().to_string(),
+            };
+            return_value = Some((native_func, Item::String(value)));
+        }
+        NativeFunction::StartsWith | NativeFunction::EndsWith => {
+            let params = memory.pop_params()?;
+            let text = unwrap_str_param(&params, 0)?;
+            let affix = unwrap_str_param(&params, 1)?;
+
+            let value = match native_func {
+                NativeFunction::StartsWith => text.starts_with(&affix),
+                _ => text.ends_with(&affix),
+            };
+            return_value = Some((native_func, Item::Bool(value)));
+        }
+        NativeFunction::ToInt => {
+            let params = memory.pop_params()?;
+            let value = strings::to_int(&unwrap_str_param(&params, 0)?)?;
+            return_value = Some((native_func, Item::Int(value)));
+        }
+        NativeFunction::ToFloat => {
+            let params = memory.pop_params()?;
+            let value = strings::to_float(&unwrap_str_param(&params, 0)?)?;
             return_value = Some((native_func, Item::Float(value)));
         }
+        NativeFunction::Format => {
+            let params = memory.pop_params()?;
+            let template = unwrap_str_param(&params, 0)?;
+
+            let value = strings::format(&template, &params[1..])?;
+            return_value = Some((native_func, Item::String(value)));
+        }
+        _ if native_func.is_math() => {
+            let params = memory.pop_params()?;
+            let value = math::run(&native_func, params, memory)?;
+            return_value = Some((native_func, value));
+        }
         NativeFunction::ToCsv => todo!(),
         _ => todo!(),
     }
 
-    return_value
+    Ok(return_value)
+}
+
+/// Reads the coordinates of plotted points from a Series or from the items
+/// of the last axis of an array.
+fn plot_points(memory: &MemoryManager, params: &Vec<Item>, index: usize) -> RuntimeResult<Series> {
+    let address = match params.get(index) {
+        Some(Item::Pointer(address)) => *address,
+        _ => return unwrap_series_param(params, index),
+    };
+
+    let (array, start) = memory.get_array(address)?;
+    let items = array.to_floats(array.row(start, 1)?).ok_or_else(|| {
+        RuntimeErrorKind::TypeError(format!("Can't plot array of {} items", array.type_name()))
+    })?;
+
+    Ok(Series::new(if index == 0 { "x" } else { "y" }, items))
 }
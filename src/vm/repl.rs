@@ -0,0 +1,265 @@
+use std::io::{self, BufRead, Write};
+
+use codegen::{
+    declare_natives, generate_program, manager::Manager, natives::NativeFunction,
+    node::ExpressionNode,
+};
+use memory::types::DataType;
+use parser::{
+    expressions::{call::Call, Expression},
+    parse_program,
+    statements::{Program, Statement},
+    SyntaxError,
+};
+
+use super::{
+    disassembler::{DisasmFormat, Disassembler},
+    memory_manager::Item,
+    virtual_machine::VirtualMachine,
+};
+
+/// Name used to locate errors found in the REPL input.
+const REPL_SOURCE: &str = "<repl>";
+
+const HELP: &str = "\
+Statements are executed as soon as they are complete, blocks can span
+multiple lines. Enter an empty line to submit an incomplete input.
+
+    :vars     list the global variables and their values
+    :funcs    list the declared functions
+    :quads    print the generated quadruples
+    :help     show this message
+    :quit     exit the REPL";
+
+/// Interactive session to compile and execute programs incrementally.
+///
+/// Keeps the code generation state and the virtual machine memory
+/// between inputs, so variables and functions declared in previous
+/// inputs remain available.
+pub struct Repl {
+    pub manager: Manager,
+    pub vm: VirtualMachine,
+}
+
+impl Repl {
+    pub fn new() -> Repl {
+        let mut manager = Manager::new();
+        declare_natives(&mut manager).expect("Can't declare native functions");
+
+        let vm = VirtualMachine::new(manager.program_meta()).expect("Can't start the machine");
+
+        Repl { manager, vm }
+    }
+
+    /// Reads and evaluates inputs from stdin until it is closed or the
+    /// user quits.
+    pub fn run(&mut self) {
+        let stdin = io::stdin();
+        let mut input = String::new();
+
+        println!("Moris REPL, type :help for more information.");
+
+        loop {
+            print!("{}", if input.is_empty() { ">> " } else { ".. " });
+            io::stdout().flush().unwrap();
+
+            let mut line = String::new();
+            match stdin.lock().read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => (),
+            }
+
+            if input.is_empty() && line.trim().starts_with(':') {
+                if !self.command(line.trim()) {
+                    break;
+                }
+                continue;
+            }
+
+            let submit = line.trim().is_empty();
+            input.push_str(line.as_str());
+
+            if input.trim().is_empty() {
+                input.clear();
+                continue;
+            }
+
+            match Repl::parse(input.as_str(), submit) {
+                Some(Ok(program)) => self.eval(program, input.as_str()),
+                Some(Err(error)) => self.print_error(error.into(), input.as_str()),
+                // Keep reading lines until the input is complete
+                None => continue,
+            }
+
+            input.clear();
+        }
+    }
+
+    /// Parses an input, returning None if it is still incomplete.
+    ///
+    /// A missing semicolon at the end of the input is inserted.
+    fn parse(input: &str, submit: bool) -> Option<Result<Program, SyntaxError>> {
+        match parse_program(input) {
+            Err(error) if error.unexpected_eof => {
+                match parse_program(format!("{};", input.trim_end()).as_str()) {
+                    Ok(program) => Some(Ok(program)),
+                    Err(_) if !submit => None,
+                    Err(_) => Some(Err(error)),
+                }
+            }
+            result => Some(result),
+        }
+    }
+
+    /// Generates and executes the code of an input program.
+    ///
+    /// Nothing from the input is kept if it fails to compile. Runtime errors
+    /// leave the values assigned before the failure.
+    pub fn eval(&mut self, mut program: Program, source: &str) {
+        let snapshot = self.manager.clone();
+        let start = self.manager.get_next_pos();
+
+        self.echo_result(&mut program);
+        self.manager.set_source(REPL_SOURCE, source);
+
+        if let Err(errors) = generate_program(program, &mut self.manager) {
+            self.manager = snapshot;
+            errors
+                .into_iter()
+                .for_each(|error| self.print_error(error, source));
+            return;
+        }
+
+        if let Err(error) = self.vm.reload(self.manager.program_meta()) {
+            self.manager = snapshot;
+            eprintln!("{}", error);
+            return;
+        }
+        self.vm.instruction_pointer = start;
+
+        if let Err(error) = self.vm.execute() {
+            eprintln!("{}", error);
+            self.vm.unwind();
+        }
+
+        io::stdout().flush().unwrap();
+    }
+
+    /// Wraps a trailing expression statement in a println call, so the
+    /// value of the expression gets printed.
+    fn echo_result(&mut self, program: &mut Program) {
+        let Program(statements) = program;
+
+        match statements.pop() {
+            Some(Statement::Expression(expr)) => {
+                let echo = match expr.data_type(&mut self.manager) {
+                    Ok(DataType::Void) | Err(_) => false,
+                    Ok(_) => true,
+                };
+
+                if echo {
+                    let call = Call {
+                        span: expr.span(),
+                        ..Call::new(NativeFunction::Println.to_string().as_str(), vec![expr])
+                    };
+                    statements.push(Statement::Expression(Box::new(Expression::Call(call))));
+                } else {
+                    statements.push(Statement::Expression(expr));
+                }
+            }
+            Some(stmt) => statements.push(stmt),
+            None => (),
+        }
+    }
+
+    fn print_error(&self, error: codegen::errors::CompileError, source: &str) {
+        eprintln!("{}", error.render(REPL_SOURCE, source));
+    }
+
+    /// Runs a meta-command, returns false if the session should end.
+    fn command(&self, command: &str) -> bool {
+        match command {
+            ":vars" => self.print_vars(),
+            ":funcs" => self.print_funcs(),
+            ":quads" => print!(
+                "{}",
+                Disassembler::new(&self.manager.program_meta()).render(DisasmFormat::Text)
+            ),
+            ":help" => println!("{}", HELP),
+            ":quit" | ":q" => return false,
+            _ => eprintln!(
+                "Unknown command {}, type :help to list the commands",
+                command
+            ),
+        }
+
+        true
+    }
+
+    fn print_vars(&self) {
+        let mut symbols = match self.manager.env.entries.get("global") {
+            Some(global_env) => global_env.symbols.values().collect::<Vec<_>>(),
+            None => vec![],
+        };
+        symbols.sort_by_key(|symbol| symbol.address);
+
+        for symbol in symbols {
+            let shape = match symbol.dimension.shape.as_slice() {
+                [] => String::new(),
+                shape => format!("{:?}", shape),
+            };
+
+            let globals = &self.vm.memory.globals;
+            match Item::from_struct(symbol, &|address| globals.get(&address).cloned()) {
+                Some(value) if shape.is_empty() => {
+                    println!("{}: {:?} = {}", symbol.id, symbol.data_type, value)
+                }
+                Some(_) | None => println!("{}: {:?}{}", symbol.id, symbol.data_type, shape),
+            }
+        }
+    }
+
+    fn print_funcs(&self) {
+        let mut functions = self
+            .vm
+            .data
+            .procedure_table
+            .values()
+            .filter(|func| func.id.parse::<NativeFunction>().is_err())
+            .collect::<Vec<_>>();
+        functions.sort_by_key(|func| func.procedure_address);
+
+        for func in functions {
+            let symbols = self.manager.env.entries.get(&func.id);
+            let params = func
+                .params
+                .iter()
+                .map(|(address, data_type, _)| {
+                    let id = symbols
+                        .and_then(|env| {
+                            env.symbols
+                                .values()
+                                .find(|symbol| symbol.address == *address)
+                        })
+                        .map_or("_", |symbol| symbol.id.as_str());
+
+                    format!("{}: {:?}", id, data_type)
+                })
+                .collect::<Vec<String>>();
+
+            println!(
+                "fn {}({}): {:?} at quadruple {}",
+                func.id,
+                params.join(", "),
+                func.return_type,
+                func.procedure_address
+            );
+        }
+    }
+}
+
+impl Default for Repl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,14 +1,27 @@
+use std::fs;
 use std::path::PathBuf;
 
+use codegen::errors::{AtSpan, CompileError};
 use codegen::generate;
 use codegen::manager::Manager;
+use codegen::optimizer::optimize;
+use parser::span::Span;
 
-use super::virtual_machine::VirtualMachine;
+use super::{errors::RuntimeError, virtual_machine::VirtualMachine};
+
+/// Failure while compiling or executing a program.
+#[derive(Debug)]
+pub enum RunError {
+    Compile(Vec<CompileError>),
+    Runtime(RuntimeError),
+}
 
 pub struct Runner {
     pub path: String,
     pub out_path: String,
     pub manager: Manager,
+    /// Optimization level of the generated code, 0 disables it.
+    pub optimization: usize,
 }
 
 impl Runner {
@@ -22,6 +35,7 @@ impl Runner {
                 path: path.to_string(),
                 out_path: String::from("out.o"),
                 manager,
+                optimization: 0,
             })
         } else {
             Err(format!("Path {path} is not a file!"))
@@ -38,6 +52,7 @@ impl Runner {
                 path: path.to_string(),
                 out_path: String::from("out.o"),
                 manager: Manager::new(),
+                optimization: 0,
             })
         } else {
             Err(format!("Path {path} is not a file!"))
@@ -49,26 +64,65 @@ impl Runner {
         self
     }
 
-    pub fn compile(&mut self) {
-        generate(self.path.as_str(), &mut self.manager);
-        self.manager.dump(&PathBuf::from(self.out_path.as_str()));
+    pub fn with_optimization(&mut self, level: usize) -> &mut Self {
+        self.optimization = level;
+        self
+    }
+
+    /// Generates the code of the program without writing the object file.
+    pub fn generate(&mut self) -> Result<(), Vec<CompileError>> {
+        generate(self.path.as_str(), &mut self.manager)?;
+        optimize(&mut self.manager, self.optimization);
+        Ok(())
+    }
+
+    pub fn compile(&mut self) -> Result<(), Vec<CompileError>> {
+        self.generate()?;
+        self.manager
+            .dump(&PathBuf::from(self.out_path.as_str()))
+            .at(Span::default())
+            .map_err(|error| vec![error])
     }
 
-    pub fn compile_and_run(&mut self) {
-        self.compile();
+    pub fn compile_and_run(&mut self) -> Result<(), RunError> {
+        self.compile().map_err(RunError::Compile)?;
         self.clean();
-        self.run();
+        self.run().map_err(RunError::Runtime)?;
+        Ok(())
+    }
+
+    /// Prints compilation errors as diagnostics pointing to the source file.
+    pub fn print_errors(&self, errors: &[CompileError]) {
+        let source = fs::read_to_string(self.path.as_str()).unwrap_or_default();
+
+        errors
+            .iter()
+            .for_each(|error| eprintln!("{}", error.render(self.path.as_str(), source.as_str())));
+
+        eprintln!(
+            "error: could not compile {} due to {} previous error(s)",
+            self.path,
+            errors.len()
+        );
+    }
+
+    /// Prints the failure of a program run to stderr.
+    pub fn print_run_error(&self, error: &RunError) {
+        match error {
+            RunError::Compile(errors) => self.print_errors(errors),
+            RunError::Runtime(error) => eprintln!("{}", error),
+        }
     }
 
     pub fn clean(&mut self) {
         self.manager.reset();
     }
 
-    pub fn run(&mut self) -> VirtualMachine {
-        let mut vm = VirtualMachine::load(&self.out_path.as_str());
+    pub fn run(&mut self) -> Result<VirtualMachine, RuntimeError> {
+        let mut vm = VirtualMachine::load(self.out_path.as_str()).unwrap();
 
-        vm.execute();
+        vm.execute()?;
 
-        vm
+        Ok(vm)
     }
 }
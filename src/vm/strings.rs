@@ -0,0 +1,78 @@
+use memory::types::{FloatType, IntType};
+
+use super::{
+    errors::{RuntimeErrorKind, RuntimeResult},
+    memory_manager::Item,
+};
+
+/// Takes `length` characters of a string starting at the character in
+/// position `start`.
+pub fn substr(text: &str, start: IntType, length: IntType) -> RuntimeResult<String> {
+    let size = text.chars().count() as IntType;
+    if start < 0 || start > size {
+        return Err(RuntimeErrorKind::IndexError { index: start, size });
+    }
+    if length < 0 || start + length > size {
+        return Err(RuntimeErrorKind::ValueError(format!(
+            "Can't take {} characters from position {} of a string of {} characters",
+            length, start, size
+        )));
+    }
+
+    Ok(text
+        .chars()
+        .skip(start as usize)
+        .take(length as usize)
+        .collect())
+}
+
+/// Returns the position of the first character of the first occurrence of
+/// `pattern`, or -1 if the string doesn't contain it.
+pub fn find(text: &str, pattern: &str) -> IntType {
+    match text.find(pattern) {
+        Some(byte) => text[..byte].chars().count() as IntType,
+        None => -1,
+    }
+}
+
+pub fn split(text: &str, separator: &str) -> RuntimeResult<Vec<String>> {
+    if separator.is_empty() {
+        return Err(RuntimeErrorKind::ValueError(String::from(
+            "Can't split a string with an empty separator",
+        )));
+    }
+
+    Ok(text.split(separator).map(String::from).collect())
+}
+
+pub fn to_int(text: &str) -> RuntimeResult<IntType> {
+    text.trim()
+        .parse::<IntType>()
+        .map_err(|_| RuntimeErrorKind::ValueError(format!("Can't convert \"{}\" to an int", text)))
+}
+
+pub fn to_float(text: &str) -> RuntimeResult<FloatType> {
+    text.trim()
+        .parse::<FloatType>()
+        .map_err(|_| RuntimeErrorKind::ValueError(format!("Can't convert \"{}\" to a float", text)))
+}
+
+/// Replaces each `{}` placeholder of the template with the next value.
+pub fn format(template: &str, values: &[Item]) -> RuntimeResult<String> {
+    let parts = template.split("{}").collect::<Vec<&str>>();
+    if parts.len() - 1 != values.len() {
+        return Err(RuntimeErrorKind::ValueError(format!(
+            "Template has {} placeholders, {} values were provided",
+            parts.len() - 1,
+            values.len()
+        )));
+    }
+
+    let mut result = String::from(parts[0]);
+    for (value, part) in values.iter().zip(&parts[1..]) {
+        result.push_str(&value.to_string());
+        result.push_str(part);
+    }
+
+    Ok(result)
+}
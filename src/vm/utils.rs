@@ -1,29 +1,54 @@
-use memory::types::{IntType, FloatType};
+use memory::{
+    resolver::MemAddress,
+    types::{FloatType, IntType},
+};
 use polars::{prelude::DataFrame, series::Series};
 
-use super::memory_manager::Item;
+use super::{
+    errors::{RuntimeErrorKind, RuntimeResult},
+    memory_manager::Item,
+};
 
-pub fn unwrap_int_param(params: &Vec<Item>, index: usize) -> IntType {
-    let item = params.get(index).unwrap().to_owned();
-    item.unwrap_int()
+fn get_param(params: &[Item], index: usize) -> RuntimeResult<Item> {
+    params.get(index).cloned().ok_or_else(|| {
+        RuntimeErrorKind::InvalidInstruction(format!("Missing call parameter {}", index))
+    })
 }
 
-pub fn unwrap_float_param(params: &Vec<Item>, index: usize) -> FloatType {
-    let item = params.get(index).unwrap().to_owned();
-    item.unwrap_float()
+fn param_type_error(expected: &str, item: &Item) -> RuntimeErrorKind {
+    RuntimeErrorKind::TypeError(format!(
+        "Expected {} parameter, found {}",
+        expected,
+        item.type_name()
+    ))
 }
 
-pub fn unwrap_str_param(params: &Vec<Item>, index: usize) -> String {
-    let item = params.get(index).unwrap().to_owned();
-    item.unwrap_string()
+pub fn unwrap_int_param(params: &Vec<Item>, index: usize) -> RuntimeResult<IntType> {
+    let item = get_param(params, index)?;
+    item.clone().int_or_else(|| param_type_error("Int", &item))
 }
 
-pub fn unwrap_df_param(params: &Vec<Item>, index: usize) -> DataFrame {
-    let item = params.get(index).unwrap().to_owned();
-    item.unwrap_data_frame()
+pub fn unwrap_float_param(params: &Vec<Item>, index: usize) -> RuntimeResult<FloatType> {
+    let item = get_param(params, index)?;
+    item.clone().float_or_else(|| param_type_error("Float", &item))
 }
 
-pub fn unwrap_series_param(params: &Vec<Item>, index: usize) -> Series {
-    let item = params.get(index).unwrap().to_owned();
-    item.unwrap_series()
+pub fn unwrap_str_param(params: &Vec<Item>, index: usize) -> RuntimeResult<String> {
+    let item = get_param(params, index)?;
+    item.clone().string_or_else(|| param_type_error("String", &item))
+}
+
+pub fn unwrap_df_param(params: &Vec<Item>, index: usize) -> RuntimeResult<DataFrame> {
+    let item = get_param(params, index)?;
+    item.clone().data_frame_or_else(|| param_type_error("DataFrame", &item))
+}
+
+pub fn unwrap_series_param(params: &Vec<Item>, index: usize) -> RuntimeResult<Series> {
+    let item = get_param(params, index)?;
+    item.clone().series_or_else(|| param_type_error("Series", &item))
+}
+
+pub fn unwrap_pointer_param(params: &[Item], index: usize) -> RuntimeResult<MemAddress> {
+    let item = get_param(params, index)?;
+    item.clone().pointer_or_else(|| param_type_error("array", &item))
 }
@@ -1,51 +1,66 @@
-use core::panic;
-use std::{cmp::Ordering, collections::LinkedList, fs::File, str::FromStr};
+use std::{cmp::Ordering, collections::LinkedList, fs, iter::zip, str::FromStr};
 
-use codegen::{meta::ProgramMeta, natives::NativeFunction, quadruples::Quadruple};
+use codegen::{
+    bytecode,
+    meta::{LegacyProgramMeta, ProgramMeta},
+    natives::NativeFunction,
+    quadruples::{Instruction, Literal, Operand},
+};
 
 use memory::{
     resolver::{MemAddress, MemoryResolver},
     types::{DataType, FloatType, IntType},
 };
+use parser::types::Operator;
+use polars::prelude::{
+    AnyValue, ChunkCompare, DataType as PolarsType, IntoSeries, NamedFrom, PolarsError, Series,
+};
 
 use crate::plots::context::PlotContext;
 
 use super::{
+    array::Buffer,
+    errors::{RuntimeError, RuntimeErrorKind, RuntimeResult, StackFrame},
+    linalg::Matrix,
     memory_manager::{Item, MemoryManager},
     natives::run_native,
 };
 
 macro_rules! cast {
-    ($op:expr, [ $($x:tt),*], $y:ty  ) =>
+    ($op:expr, [ $($x:tt),*], $y:ty, $target:expr ) =>
        {
         match $op {
             $(
                 Item::$x(op) => op as $y,
             )*
-            _ => panic!()
+            op => return Err(RuntimeErrorKind::TypeError(format!(
+                "Can't cast {} to {}", op.type_name(), $target
+            )))
         }
        }
     ;
 }
 
 macro_rules! arith_operation {
-    ($data_type:expr, $self: expr, $op: tt, $left: expr, $right: expr, $dest: expr) => {
+    ($data_type:expr, $self: expr, $op: tt, $checked: ident, $left: expr, $right: expr, $dest: expr) => {
         match $data_type {
             DataType::Int => {
-                let (op1, op2) = Item::match_ints($left, $right);
-                $self.memory.update($dest, Item::Int(op1 $op op2));
+                let (op1, op2) = Item::match_ints($left, $right)?;
+                let result = op1.$checked(op2).ok_or(RuntimeErrorKind::OverflowError)?;
+                $self.memory.update($dest, Item::Int(result))?;
             }
             DataType::Float => {
-                let (op1, op2) = Item::match_floats($left, $right);
-                $self.memory.update($dest, Item::Float(op1 $op op2));
+                let (op1, op2) = Item::match_floats($left, $right)?;
+                $self.memory.update($dest, Item::Float(op1 $op op2))?;
             }
             DataType::Pointer => {
-                let (op1, op2) = Item::match_pointers($left, $right);
-                $self.memory.update($dest, Item::Pointer(op1 $op op2));
+                let (op1, op2) = Item::match_pointers($left, $right)?;
+                let result = op1.$checked(op2).ok_or(RuntimeErrorKind::OverflowError)?;
+                $self.memory.update($dest, Item::Pointer(result))?;
             }
             DataType::String => {
-                let (left, right) = Item::match_strings($left, $right);
-                $self.memory.update($dest, Item::String(format!("{}{}", left, right)));
+                let (left, right) = Item::match_strings($left, $right)?;
+                $self.memory.update($dest, Item::String(format!("{}{}", left, right)))?;
             },
             _ => todo!(),
         }
@@ -59,18 +74,18 @@ macro_rules! operate {
 }
 
 macro_rules! logic_cmp {
-    ($data_type:expr, $self: expr, $op: tt, $curr_instruction: expr) => {
+    ($data_type:expr, $self: expr, $op: tt, $operands: expr) => {
         match $data_type {
             DataType::Int => {
-                let (_, left, right, dest) = $self.unpack_binary($curr_instruction);
-                let (left, right) = Item::match_ints(left, right);
+                let (left, right, dest) = $self.unpack_binary($operands)?;
+                let (left, right) = Item::match_ints(left, right)?;
 
-                $self.memory.update(dest, Item::Bool(left $op right));
+                $self.memory.update(dest, Item::Bool(left $op right))?;
             }
             DataType::Float => {
-                let (_, left, right, dest) = $self.unpack_binary($curr_instruction);
-                let (left, right) = Item::match_floats(left, right);
-                $self.memory.update(dest, Item::Bool(left $op right));
+                let (left, right, dest) = $self.unpack_binary($operands)?;
+                let (left, right) = Item::match_floats(left, right)?;
+                $self.memory.update(dest, Item::Bool(left $op right))?;
             }
             DataType::Bool => todo!(),
             DataType::String => panic!(),
@@ -81,288 +96,1141 @@ macro_rules! logic_cmp {
     };
 }
 
+/// Next instruction to execute after the current one.
+enum Jump {
+    Next,
+    To(usize),
+    Halt,
+}
+
 pub struct VirtualMachine {
     pub data: ProgramMeta,
     pub memory: MemoryManager,
+    /// Position of the next quadruple to execute.
+    pub instruction_pointer: usize,
+    /// Return positions of the active function calls.
+    pub call_pointer: LinkedList<usize>,
+    plot_ctx: PlotContext,
 }
 
 impl VirtualMachine {
-    pub fn load(path: &str) -> VirtualMachine {
-        let reader = File::open(path).unwrap();
-        let data: ProgramMeta = serde_pickle::from_reader(reader, Default::default()).unwrap();
-        let memory = MemoryManager::from_data(&data);
+    pub fn new(data: ProgramMeta) -> RuntimeResult<VirtualMachine> {
+        let memory = MemoryManager::from_data(&data)?;
 
-        VirtualMachine { data, memory }
+        Ok(VirtualMachine {
+            data,
+            memory,
+            instruction_pointer: 0,
+            call_pointer: LinkedList::new(),
+            plot_ctx: PlotContext::new(),
+        })
     }
 
-    fn unpack_unary(&mut self, instruction: &Quadruple) -> (Item, MemAddress) {
-        let Quadruple(_, op, _, dest) = instruction;
-        let op = self.memory.get(&op);
-        let dest = self.memory.get_address(&dest);
+    /// Loads a program from an object file, either in the bytecode format
+    /// or a legacy pickle file.
+    pub fn load(path: &str) -> Result<VirtualMachine, String> {
+        VirtualMachine::new(VirtualMachine::read_program(path)?)
+            .map_err(|error| format!("Invalid object file {}: {}", path, error))
+    }
+
+    /// Reads the program stored in an object file.
+    pub fn read_program(path: &str) -> Result<ProgramMeta, String> {
+        let bytes = fs::read(path).map_err(|error| format!("Can't read {}: {}", path, error))?;
+
+        if bytecode::is_bytecode(&bytes) {
+            bytecode::read(&bytes)
+        } else {
+            serde_pickle::from_slice::<LegacyProgramMeta>(&bytes, Default::default())
+                .map_err(|error| format!("Invalid object file {}: {}", path, error))?
+                .try_into()
+        }
+    }
+
+    /// Replaces the program being executed, keeping the memory state so
+    /// code generated incrementally can resume from its new instructions.
+    pub fn reload(&mut self, data: ProgramMeta) -> RuntimeResult<()> {
+        self.memory.load_constants(&data)?;
+        self.data = data;
+        Ok(())
+    }
+
+    /// Drops every active call, leaving the machine back in the global scope.
+    pub fn unwind(&mut self) {
+        self.call_pointer.clear();
+        self.memory.call_context.clear();
+        self.memory.call_hold.clear();
+    }
+
+    fn unpack_unary(
+        &mut self,
+        value: &Operand,
+        dest: &Operand,
+    ) -> RuntimeResult<(Item, MemAddress)> {
+        let value = self.memory.get(value)?;
+        let dest = self.memory.get_address(dest)?;
 
-        (op, dest)
+        Ok((value, dest))
     }
 
-    fn unpack_binary<'a>(
+    fn unpack_binary(
         &mut self,
-        instruction: &'a Quadruple,
-    ) -> (&'a str, Item, Item, MemAddress) {
-        let Quadruple(operator, left, right, dest) = instruction;
-        let left = self.memory.get(&left);
-        let right = self.memory.get(&right);
-        let dest = self.memory.get_address(&dest);
-
-        (operator.as_str(), left, right, dest)
+        (left, right, dest): (&Operand, &Operand, &Operand),
+    ) -> RuntimeResult<(Item, Item, MemAddress)> {
+        let left = self.memory.get(left)?;
+        let right = self.memory.get(right)?;
+        let dest = self.memory.get_address(dest)?;
+
+        Ok((left, right, dest))
     }
 
-    fn arithmetic_op(&mut self, quadruple: &Quadruple) {
-        let (instruction, left, right, dest) = self.unpack_binary(quadruple);
+    fn arithmetic_op(
+        &mut self,
+        operator: Operator,
+        operands: (&Operand, &Operand, &Operand),
+    ) -> RuntimeResult<()> {
+        let (left, right, dest) = self.unpack_binary(operands)?;
 
         let data_type = MemoryResolver::get_type_from_address(dest).unwrap();
 
-        match instruction {
-            "+" => arith_operation!(data_type, self, +, left, right, dest),
-            "-" => arith_operation!(data_type, self, -, left, right, dest),
-            "*" => arith_operation!(data_type, self, *, left, right, dest),
-            "/" => arith_operation!(data_type, self, /, left, right, dest),
+        match operator {
+            Operator::Add => arith_operation!(data_type, self, +, checked_add, left, right, dest),
+            Operator::Sub => arith_operation!(data_type, self, -, checked_sub, left, right, dest),
+            Operator::Mul => arith_operation!(data_type, self, *, checked_mul, left, right, dest),
+            Operator::Div => {
+                if let Item::Int(0) | Item::Pointer(0) = right {
+                    return Err(RuntimeErrorKind::ZeroDivisionError);
+                } else if let Item::Float(right) = right {
+                    if right == 0.0 {
+                        return Err(RuntimeErrorKind::ZeroDivisionError);
+                    }
+                }
+
+                arith_operation!(data_type, self, /, checked_div, left, right, dest)
+            }
             _ => todo!(),
         }
+
+        Ok(())
     }
 
-    fn boolean_op(&mut self, quadruple: &Quadruple) {
-        let (op, left, right, dest) = self.unpack_binary(quadruple);
+    fn boolean_op(
+        &mut self,
+        operator: Operator,
+        operands: (&Operand, &Operand, &Operand),
+    ) -> RuntimeResult<()> {
+        let (left, right, dest) = self.unpack_binary(operands)?;
 
-        let left = left.unwrap_bool();
-        let right = right.unwrap_bool();
+        let left = VirtualMachine::unwrap_bool(left)?;
+        let right = VirtualMachine::unwrap_bool(right)?;
 
-        let result = match op {
-            "&&" => operate!(left, &&, right),
-            "||" => operate!(left, ||, right),
+        let result = match operator {
+            Operator::And => operate!(left, &&, right),
+            Operator::Or => operate!(left, ||, right),
             _ => todo!(),
         };
 
-        self.memory.update(dest, Item::Bool(result));
+        self.memory.update(dest, Item::Bool(result))?;
+
+        Ok(())
     }
 
-    fn logic_cmp(&mut self, quadruple: &Quadruple) {
-        let operator = quadruple.0.as_str();
-        let left_addr = self.memory.get_address(&quadruple.1);
+    fn logic_cmp(
+        &mut self,
+        operator: Operator,
+        operands: (&Operand, &Operand, &Operand),
+    ) -> RuntimeResult<()> {
+        let left_addr = self.memory.get_address(operands.0)?;
         // Instructions for comparisons expect both operators to be of the same type
         // Proper casting instruction for compatible types is emitted during compile time
         let data_type = MemoryResolver::get_type_from_address(left_addr).unwrap();
 
         match data_type {
             DataType::String => {
-                let (_, left, right, dest) = self.unpack_binary(quadruple);
-                let (left, right) = Item::match_strings(left, right);
+                let (left, right, dest) = self.unpack_binary(operands)?;
+                let (left, right) = Item::match_strings(left, right)?;
                 let cmp = left.cmp(&right);
                 let result: bool = match operator {
-                    ">" => cmp == Ordering::Greater,
-                    ">=" => cmp == Ordering::Greater || cmp == Ordering::Equal,
-                    "<" => cmp == Ordering::Less,
-                    "<=" => cmp == Ordering::Less || cmp == Ordering::Equal,
-                    "==" => cmp == Ordering::Equal,
-                    "!=" => cmp != Ordering::Equal,
+                    Operator::GreaterThan => cmp == Ordering::Greater,
+                    Operator::GreaterOrEq => cmp == Ordering::Greater || cmp == Ordering::Equal,
+                    Operator::LessThan => cmp == Ordering::Less,
+                    Operator::LessOrEq => cmp == Ordering::Less || cmp == Ordering::Equal,
+                    Operator::Eq => cmp == Ordering::Equal,
+                    Operator::NotEq => cmp != Ordering::Equal,
                     _ => panic!(),
                 };
 
-                self.memory.update(dest, Item::Bool(result));
+                self.memory.update(dest, Item::Bool(result))?;
             }
             _ => match operator {
-                ">" => logic_cmp!(data_type, self, >, &quadruple),
-                ">=" => logic_cmp!(data_type, self, >=, &quadruple),
-                "<" => logic_cmp!(data_type, self, <, &quadruple),
-                "<=" => logic_cmp!(data_type, self, <=, &quadruple),
-                "==" => logic_cmp!(data_type, self, ==, &quadruple),
-                "!=" => logic_cmp!(data_type, self, !=, &quadruple),
+                Operator::GreaterThan => logic_cmp!(data_type, self, >, operands),
+                Operator::GreaterOrEq => logic_cmp!(data_type, self, >=, operands),
+                Operator::LessThan => logic_cmp!(data_type, self, <, operands),
+                Operator::LessOrEq => logic_cmp!(data_type, self, <=, operands),
+                Operator::Eq => logic_cmp!(data_type, self, ==, operands),
+                Operator::NotEq => logic_cmp!(data_type, self, !=, operands),
                 _ => todo!(),
             },
         }
+
+        Ok(())
     }
 
-    pub fn return_value(&mut self, function_id: &String, value: Item) {
-        let func_meta = self.data.get_func(&function_id);
-        let return_addr = func_meta.return_address.unwrap();
-        self.memory.update(return_addr, value);
+    /// Applies an operator to the values of Series, scalar operands are
+    /// applied to every value.
+    /// Multiplies the matrices two pointers refer to, the product is stored
+    /// in a new array of integers only if both matrices hold integers.
+    fn matmul(&mut self, operands: (&Operand, &Operand, &Operand)) -> RuntimeResult<()> {
+        let (left, right, dest) = self.unpack_binary(operands)?;
+
+        let (left, right) = Item::match_pointers(left, right)?;
+        let data_type = match (
+            &self.memory.get_array(left)?.0.buffer,
+            &self.memory.get_array(right)?.0.buffer,
+        ) {
+            (Buffer::Int(_), Buffer::Int(_)) => DataType::Int,
+            _ => DataType::Float,
+        };
+
+        let left = Matrix::read(&self.memory, left, 2)?;
+        let right = Matrix::read(&self.memory, right, 2)?;
+        let address = left.mul(&right)?.store(&mut self.memory, &data_type)?;
+
+        self.memory.update(dest, Item::Pointer(address))
     }
 
-    pub fn return_value_native(&mut self, function_id: NativeFunction, value: Item) {
-        self.return_value(&function_id.to_string(), value)
+    fn series_op(
+        &mut self,
+        operator: Operator,
+        operands: (&Operand, &Operand, &Operand),
+    ) -> RuntimeResult<()> {
+        let (left, right, dest) = self.unpack_binary(operands)?;
+
+        let (name, length) = match (&left, &right) {
+            (Item::Series(left), Item::Series(right)) if left.len() != right.len() => {
+                return Err(RuntimeErrorKind::ValueError(format!(
+                    "Can't operate Series of lengths {} and {}",
+                    left.len(),
+                    right.len()
+                )))
+            }
+            (Item::Series(series), _) | (_, Item::Series(series)) => {
+                (series.name().to_string(), series.len())
+            }
+            _ => {
+                return Err(RuntimeErrorKind::TypeError(format!(
+                    "Expected a Series operand, found {} and {}",
+                    left.type_name(),
+                    right.type_name()
+                )))
+            }
+        };
+
+        let polars_error = |error: PolarsError| RuntimeErrorKind::ValueError(error.to_string());
+        let (left, right) = match operator {
+            // Divisions are never truncated, like the ones between scalars
+            Operator::Div => (
+                VirtualMachine::to_series(left, &name, length)?
+                    .cast(&PolarsType::Float64)
+                    .map_err(polars_error)?,
+                VirtualMachine::to_series(right, &name, length)?
+                    .cast(&PolarsType::Float64)
+                    .map_err(polars_error)?,
+            ),
+            _ => (
+                VirtualMachine::to_series(left, &name, length)?,
+                VirtualMachine::to_series(right, &name, length)?,
+            ),
+        };
+
+        let bools = |series: &Series| series.bool().cloned();
+        let result = match operator {
+            Operator::And => (&bools(&left).map_err(polars_error)?
+                & &bools(&right).map_err(polars_error)?)
+                .into_series(),
+            Operator::Or => (&bools(&left).map_err(polars_error)?
+                | &bools(&right).map_err(polars_error)?)
+                .into_series(),
+            _ if !left.dtype().is_numeric() || !right.dtype().is_numeric() => {
+                return Err(RuntimeErrorKind::TypeError(format!(
+                    "Can't apply {} to Series of {} and {} values",
+                    operator.to_string(),
+                    left.dtype(),
+                    right.dtype()
+                )))
+            }
+            Operator::Add => &left + &right,
+            Operator::Sub => &left - &right,
+            Operator::Mul => &left * &right,
+            Operator::Div => &left / &right,
+            Operator::GreaterThan => left.gt(&right).map_err(polars_error)?.into_series(),
+            Operator::GreaterOrEq => left.gt_eq(&right).map_err(polars_error)?.into_series(),
+            Operator::LessThan => left.lt(&right).map_err(polars_error)?.into_series(),
+            Operator::LessOrEq => left.lt_eq(&right).map_err(polars_error)?.into_series(),
+            Operator::Eq => left.equal(&right).map_err(polars_error)?.into_series(),
+            Operator::NotEq => left.not_equal(&right).map_err(polars_error)?.into_series(),
+            operator => {
+                return Err(RuntimeErrorKind::InvalidInstruction(format!(
+                    "Unknown binary operator {}",
+                    operator.to_string()
+                )))
+            }
+        };
+
+        self.memory.update(dest, Item::Series(result))
+    }
+
+    /// Turns the operand of a Series operation into a Series, scalars are
+    /// repeated to the length of the other operand.
+    fn to_series(item: Item, name: &str, length: usize) -> RuntimeResult<Series> {
+        match item {
+            Item::Series(series) => Ok(series),
+            Item::Int(item) => Ok(Series::new(name, vec![item; length])),
+            Item::Float(item) => Ok(Series::new(name, vec![item; length])),
+            Item::Bool(item) => Ok(Series::new(name, vec![item; length])),
+            item => Err(RuntimeErrorKind::TypeError(format!(
+                "Can't operate a {} value with a Series",
+                item.type_name()
+            ))),
+        }
     }
 
-    pub fn execute(&mut self) {
-        let mut instruction_pointer = 0;
-        let mut call_pointer: LinkedList<usize> = LinkedList::new();
-        let mut pre_call_stack: LinkedList<String> = LinkedList::new();
-        let mut plot_ctx = PlotContext::new();
-        let quadruples: Vec<Quadruple> = self.data.quadruples.drain(..).collect();
-
-        while instruction_pointer < quadruples.len() {
-            let curr_instruction = quadruples.get(instruction_pointer).unwrap();
-            match curr_instruction.0.as_str() {
-                "*" | "+" | "-" | "/" => self.arithmetic_op(curr_instruction),
-                ">" | ">=" | "<" | "<=" | "==" | "!=" => self.logic_cmp(curr_instruction),
-                "&&" | "||" => self.boolean_op(curr_instruction),
-                "not" => {
-                    let Quadruple(_, to_negate, _, dest) = curr_instruction;
-                    let to_negate = self.memory.get(to_negate);
-                    let to_negate = to_negate.unwrap_bool();
-                    let dest = self.memory.get_address(dest);
-
-                    self.memory.update(dest, Item::Bool(!to_negate));
+    /// Items an operand of an element-wise operation applies to, scalars
+    /// are repeated for every item.
+    fn items(&self, operand: Item, size: usize) -> RuntimeResult<Vec<Item>> {
+        match operand {
+            Item::Pointer(address) => {
+                let (array, start) = self.memory.get_array(address)?;
+                if array.len() - start < size {
+                    return Err(RuntimeErrorKind::ValueError(format!(
+                        "Can't operate arrays of {} and {} items",
+                        array.len() - start,
+                        size
+                    )));
                 }
-                "neg" => {
-                    let Quadruple(_, to_negate, _, dest) = curr_instruction;
-                    let to_negate = self.memory.get(to_negate);
-                    let dest = self.memory.get_address(dest);
-
-                    match to_negate {
-                        Item::Int(item) => self.memory.update(dest, Item::Int(-item)),
-                        Item::Float(item) => self.memory.update(dest, Item::Float(-item)),
-                        _ => panic!("{:#?} can't be negated", to_negate),
-                    }
+
+                (start..start + size)
+                    .map(|index| {
+                        array
+                            .get(index)
+                            .ok_or(RuntimeErrorKind::UndefinedValue(address))
+                    })
+                    .collect()
+            }
+            item => Ok(vec![item; size]),
+        }
+    }
+
+    /// Applies an operator to a pair of items, the result is converted to
+    /// the given data type.
+    fn apply(
+        operator: Operator,
+        data_type: &DataType,
+        left: Item,
+        right: Item,
+    ) -> RuntimeResult<Item> {
+        match operator {
+            Operator::Add | Operator::Sub | Operator::Mul | Operator::Div => {
+                if operator == Operator::Div && Item::cast_float(right.clone())? == 0.0 {
+                    return Err(RuntimeErrorKind::ZeroDivisionError);
                 }
-                "=" => {
-                    let (op, dest) = self.unpack_unary(curr_instruction);
-                    self.memory.update(dest, op);
+
+                match data_type {
+                    DataType::Int => {
+                        let (left, right) = Item::match_ints(left, right)?;
+                        match operator {
+                            Operator::Add => left.checked_add(right),
+                            Operator::Sub => left.checked_sub(right),
+                            Operator::Mul => left.checked_mul(right),
+                            _ => left.checked_div(right),
+                        }
+                        .map(Item::Int)
+                        .ok_or(RuntimeErrorKind::OverflowError)
+                    }
+                    DataType::Float => {
+                        let (left, right) = (Item::cast_float(left)?, Item::cast_float(right)?);
+                        Ok(Item::Float(match operator {
+                            Operator::Add => left + right,
+                            Operator::Sub => left - right,
+                            Operator::Mul => left * right,
+                            _ => left / right,
+                        }))
+                    }
+                    DataType::String if operator == Operator::Add => {
+                        let (left, right) = Item::match_strings(left, right)?;
+                        Ok(Item::String(format!("{}{}", left, right)))
+                    }
+                    data_type => Err(RuntimeErrorKind::TypeError(format!(
+                        "Can't apply {} to produce {:?} items",
+                        operator.to_string(),
+                        data_type
+                    ))),
                 }
-                "Float" => {
-                    let (op, dest) = self.unpack_unary(curr_instruction);
+            }
+            Operator::And | Operator::Or => {
+                let (left, right) = (
+                    VirtualMachine::unwrap_bool(left)?,
+                    VirtualMachine::unwrap_bool(right)?,
+                );
+                Ok(Item::Bool(match operator {
+                    Operator::And => left && right,
+                    _ => left || right,
+                }))
+            }
+            _ => {
+                let ordering = match (&left, &right) {
+                    (Item::String(left), Item::String(right)) => Some(left.cmp(right)),
+                    (Item::Float(_), _) | (_, Item::Float(_)) => {
+                        Item::cast_float(left)?.partial_cmp(&Item::cast_float(right)?)
+                    }
+                    _ => Some(Item::cast_int(left)?.cmp(&Item::cast_int(right)?)),
+                };
 
-                    let op = match op {
-                        Item::Bool(op) => Item::Float((op as u8) as FloatType),
-                        _ => op,
-                    };
-                    let op = cast!(op, [Int, Float, Pointer], FloatType);
+                // Comparisons with NaN values are only unequal
+                let result = match (operator, ordering) {
+                    (Operator::NotEq, None) => true,
+                    (_, None) => false,
+                    (Operator::GreaterThan, Some(ordering)) => ordering == Ordering::Greater,
+                    (Operator::GreaterOrEq, Some(ordering)) => ordering != Ordering::Less,
+                    (Operator::LessThan, Some(ordering)) => ordering == Ordering::Less,
+                    (Operator::LessOrEq, Some(ordering)) => ordering != Ordering::Greater,
+                    (Operator::Eq, Some(ordering)) => ordering == Ordering::Equal,
+                    (Operator::NotEq, Some(ordering)) => ordering != Ordering::Equal,
+                    (operator, _) => {
+                        return Err(RuntimeErrorKind::InvalidInstruction(format!(
+                            "Unknown binary operator {}",
+                            operator.to_string()
+                        )))
+                    }
+                };
 
-                    self.memory.update(dest, Item::Float(op));
-                }
-                "Int" => {
-                    let (op, dest) = self.unpack_unary(curr_instruction);
+                Ok(Item::Bool(result))
+            }
+        }
+    }
 
-                    let op = cast!(op, [Int, Float, Pointer, Bool], IntType);
+    fn unwrap_bool(item: Item) -> RuntimeResult<bool> {
+        match item {
+            Item::Bool(item) => Ok(item),
+            _ => Err(RuntimeErrorKind::TypeError(format!(
+                "Expected Bool value, found {}",
+                item.type_name()
+            ))),
+        }
+    }
 
-                    self.memory.update(dest, Item::Int(op));
-                }
-                "Bool" => {
-                    let (op, dest) = self.unpack_unary(curr_instruction);
+    pub fn return_value(&mut self, function_id: &String, value: Item) -> RuntimeResult<()> {
+        let func_meta = self.data.get_func(function_id);
+        let return_addr = func_meta.return_address.unwrap();
+        self.memory.update(return_addr, value)
+    }
+
+    pub fn return_value_native(
+        &mut self,
+        function_id: NativeFunction,
+        value: Item,
+    ) -> RuntimeResult<()> {
+        self.return_value(&function_id.to_string(), value)
+    }
+
+    /// Checks whether there are no instructions left to execute.
+    pub fn finished(&self) -> bool {
+        self.instruction_pointer >= self.data.quadruples.len()
+    }
+
+    /// Runs the program until it finishes or fails.
+    pub fn execute(&mut self) -> Result<(), RuntimeError> {
+        while self.step()? {}
+
+        Ok(())
+    }
+
+    /// Executes the instruction at the instruction pointer.
+    ///
+    /// Returns false once the program has finished. On failure the
+    /// instruction pointer is left at the failing instruction.
+    pub fn step(&mut self) -> Result<bool, RuntimeError> {
+        if self.finished() {
+            return Ok(false);
+        }
+
+        // The instructions are moved out while executing, so the instruction
+        // can be borrowed along with the rest of the machine.
+        let quadruples = std::mem::take(&mut self.data.quadruples);
+        let result = self.run_instruction(&quadruples);
+        self.data.quadruples = quadruples;
+
+        match result {
+            Ok(Jump::Next) => self.instruction_pointer += 1,
+            Ok(Jump::To(position)) => self.instruction_pointer = position,
+            Ok(Jump::Halt) => self.instruction_pointer = self.data.quadruples.len(),
+            Err(kind) => return Err(self.trace(kind)),
+        }
 
-                    let op = cast!(op, [Int, Float, Pointer, Bool], IntType);
-                    let op = if op > 0 { true } else { false };
+        Ok(!self.finished())
+    }
+
+    /// Returns the Moris call stack at the current instruction, innermost
+    /// frame first.
+    pub fn stack_trace(&self) -> Vec<StackFrame> {
+        let mut stack = vec![];
+        let mut position = self.instruction_pointer;
+        let mut return_positions = self.call_pointer.iter().rev();
+
+        for context in self.memory.call_context.iter().rev() {
+            stack.push(StackFrame {
+                procedure_id: Some(context.procedure_id.clone()),
+                position,
+                line: self.data.line(position),
+            });
+
+            // The caller is paused at the gosub right before its return position
+            position = return_positions.next().map_or(0, |next| next - 1);
+        }
+
+        stack.push(StackFrame {
+            procedure_id: None,
+            position,
+            line: self.data.line(position),
+        });
+
+        stack
+    }
+
+    fn trace(&self, kind: RuntimeErrorKind) -> RuntimeError {
+        RuntimeError {
+            kind,
+            position: self.instruction_pointer,
+            line: self.data.line(self.instruction_pointer),
+            stack: self.stack_trace(),
+            source_path: self.data.source_path.clone(),
+        }
+    }
+
+    /// Returns the position an operand of a jump instruction points to.
+    fn label(target: &Operand) -> RuntimeResult<usize> {
+        match target {
+            Operand::Label(position) => Ok(*position),
+            operand => Err(RuntimeErrorKind::InvalidInstruction(format!(
+                "Jump target {} is not a label",
+                operand
+            ))),
+        }
+    }
+
+    /// Starts a call to the function of a closure, after its parameters are
+    /// set, and returns the position the function starts at.
+    fn enter_closure(&mut self, closure: Item) -> RuntimeResult<usize> {
+        let (function_id, captured) = match closure {
+            Item::Closure(function_id, captured) => (function_id, captured),
+            item => {
+                return Err(RuntimeErrorKind::TypeError(format!(
+                    "Can't call a {} value",
+                    item.type_name()
+                )))
+            }
+        };
+
+        let func_meta = self.data.get_func(&function_id);
+        for (address, value) in func_meta.captures.iter().zip(captured.iter()) {
+            self.memory.update(*address, value.clone())?;
+        }
+        if let Some(closure_address) = func_meta.closure_address {
+            self.memory
+                .update(closure_address, Item::Closure(function_id, captured))?;
+        }
+
+        if let Some(return_addres) = func_meta.return_address {
+            self.memory.delete(return_addres);
+        }
+
+        Ok(func_meta.procedure_address)
+    }
+
+    /// Calls a function value from a native function, running its
+    /// instructions until it returns. Arguments are converted to the types
+    /// of the parameters, as casts are emitted for regular calls.
+    fn invoke(
+        &mut self,
+        quadruples: &[Instruction],
+        closure: &Item,
+        args: Vec<Item>,
+    ) -> RuntimeResult<Option<Item>> {
+        let func_meta = match closure {
+            Item::Closure(function_id, _) => self.data.get_func(function_id).clone(),
+            item => {
+                return Err(RuntimeErrorKind::TypeError(format!(
+                    "Can't call a {} value",
+                    item.type_name()
+                )))
+            }
+        };
 
-                    self.memory.update(dest, Item::Bool(op));
+        let args = zip(args, func_meta.params.iter())
+            .map(|(arg, (_, data_type, _))| match data_type {
+                DataType::Int => Ok(Item::Int(Item::cast_int(arg)?)),
+                DataType::Float => Ok(Item::Float(Item::cast_float(arg)?)),
+                _ => Ok(arg),
+            })
+            .collect::<RuntimeResult<Vec<Item>>>()?;
+
+        self.memory.enter(func_meta.id.clone(), &func_meta, args);
+        let caller = self.instruction_pointer;
+        self.instruction_pointer = self.enter_closure(closure.clone())?;
+
+        let depth = self.call_pointer.len();
+        self.call_pointer.push_back(caller + 1);
+
+        while self.call_pointer.len() > depth {
+            match self.run_instruction(quadruples)? {
+                Jump::Next => self.instruction_pointer += 1,
+                Jump::To(position) => self.instruction_pointer = position,
+                Jump::Halt => {
+                    return Err(RuntimeErrorKind::InvalidInstruction(String::from(
+                        "Program ended within a function call",
+                    )))
                 }
-                "ver" => {
-                    let Quadruple(_, value, _, bound) = curr_instruction;
+            }
+        }
+        self.instruction_pointer = caller;
 
-                    let value = self.memory.get(&value).unwrap_int();
-                    let bound: IntType = bound.parse().unwrap();
-                    if value >= bound {
-                        panic!("Index out of bounds!");
-                    }
+        match func_meta.return_address {
+            Some(return_address) => Ok(Some(self.memory.get(&Operand::Direct(return_address))?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Runs the native functions applying a function value to each item of
+    /// an array or Series, returning the pointer to a new array, a new
+    /// Series or the reduced value.
+    fn apply_function(
+        &mut self,
+        quadruples: &[Instruction],
+        function: NativeFunction,
+    ) -> RuntimeResult<Item> {
+        // The rank of the array goes last so rows of arrays are read alone
+        let mut params = self.memory.pop_params()?;
+        let rank = params.pop().map(Item::cast_int).transpose()?.unwrap_or(0) as usize;
+        let mut params = params.into_iter();
+        let (items, closure) = (params.next(), params.next());
+        let closure = closure.ok_or_else(|| {
+            RuntimeErrorKind::InvalidInstruction(format!("Missing parameters for {}", function))
+        })?;
+        let return_type = match &closure {
+            Item::Closure(function_id, _) => self.data.get_func(function_id).return_type.clone(),
+            _ => DataType::Void,
+        };
+
+        let (values, shape, array_type) = match items {
+            Some(Item::Pointer(address)) => {
+                let (array, start) = self.memory.get_array(address)?;
+                let values = array
+                    .row(start, rank)?
+                    .map(|index| {
+                        array
+                            .get(index)
+                            .ok_or(RuntimeErrorKind::UndefinedValue(address))
+                    })
+                    .collect::<RuntimeResult<Vec<Item>>>()?;
+                let shape = array.shape[array.shape.len() - rank..].to_vec();
+                let data_type = MemoryResolver::get_type_from_address(address).cloned();
+                (values, shape, data_type)
+            }
+            Some(Item::Series(series)) => {
+                let values = series
+                    .iter()
+                    .map(VirtualMachine::from_any_value)
+                    .collect::<RuntimeResult<Vec<Item>>>()?;
+                (values, vec![], None)
+            }
+            item => {
+                return Err(RuntimeErrorKind::TypeError(format!(
+                    "Function {} can't apply a function to a {} value",
+                    function,
+                    item.as_ref().map_or("Void", |item| item.type_name())
+                )))
+            }
+        };
+
+        let returned = |value: Option<Item>| {
+            value.ok_or_else(|| {
+                RuntimeErrorKind::InvalidInstruction(String::from(
+                    "Applied function returned no value",
+                ))
+            })
+        };
+
+        let results = match function {
+            NativeFunction::Reduce => {
+                let mut accumulated = params.next().ok_or_else(|| {
+                    RuntimeErrorKind::InvalidInstruction(String::from(
+                        "Missing initial value for reduce",
+                    ))
+                })?;
+                for value in values {
+                    let args = vec![accumulated, value];
+                    accumulated = returned(self.invoke(quadruples, &closure, args)?)?;
                 }
-                "goto" => {
-                    let Quadruple(_, _, _, next) = curr_instruction;
-                    instruction_pointer = next.parse::<usize>().unwrap();
-                    continue;
+                return Ok(accumulated);
+            }
+            NativeFunction::Filter => {
+                let mut kept = vec![];
+                for value in values {
+                    let keep = returned(self.invoke(quadruples, &closure, vec![value.clone()])?)?;
+                    if VirtualMachine::unwrap_bool(keep)? {
+                        kept.push(value);
+                    }
                 }
-                "gotoFalse" => {
-                    let Quadruple(_, check, _, next) = curr_instruction;
-
-                    let check = self.memory.get(check);
-                    match check {
-                        Item::Bool(check) => {
-                            if !check {
-                                instruction_pointer = next.parse::<usize>().unwrap();
-                                continue;
-                            }
-                        }
-                        _ => panic!("Can't check non-boolean condition."),
-                    };
+                kept
+            }
+            _ => values
+                .into_iter()
+                .map(|value| returned(self.invoke(quadruples, &closure, vec![value])?))
+                .collect::<RuntimeResult<Vec<Item>>>()?,
+        };
+
+        if array_type.is_none() {
+            return Ok(Item::Series(VirtualMachine::to_item_series(
+                function.to_string().as_str(),
+                results,
+            )?));
+        }
+
+        let (data_type, shape) = match function {
+            NativeFunction::Filter => (array_type.unwrap(), vec![results.len() as IntType]),
+            _ => (
+                return_type,
+                shape.iter().map(|size| *size as IntType).collect(),
+            ),
+        };
+
+        let address = self.memory.alloc_heap(&data_type, &shape)?;
+        let (array, _) = self.memory.get_array_mut(address)?;
+        for (index, item) in results.into_iter().enumerate() {
+            array.set(index, item)?;
+        }
+
+        Ok(Item::Pointer(address))
+    }
+
+    /// Reads the value of a Series item.
+    pub(crate) fn from_any_value(value: AnyValue) -> RuntimeResult<Item> {
+        match value {
+            AnyValue::Boolean(value) => Ok(Item::Bool(value)),
+            AnyValue::Utf8(value) => Ok(Item::String(value.to_string())),
+            AnyValue::Int32(value) => Ok(Item::Int(value as IntType)),
+            AnyValue::Int64(value) => Ok(Item::Int(value as IntType)),
+            AnyValue::UInt32(value) => Ok(Item::Int(value as IntType)),
+            AnyValue::UInt64(value) => Ok(Item::Int(value as IntType)),
+            AnyValue::Float32(value) => Ok(Item::Float(value as FloatType)),
+            AnyValue::Float64(value) => Ok(Item::Float(value as FloatType)),
+            value => Err(RuntimeErrorKind::TypeError(format!(
+                "Can't read Series item {}",
+                value
+            ))),
+        }
+    }
+
+    /// Builds a Series out of scalar values of the same type.
+    pub(crate) fn to_item_series(name: &str, items: Vec<Item>) -> RuntimeResult<Series> {
+        let series = match items.first() {
+            None => Series::new_empty(name, &PolarsType::Float64),
+            Some(Item::Int(_)) => Series::new(
+                name,
+                items
+                    .into_iter()
+                    .map(Item::cast_int)
+                    .collect::<RuntimeResult<Vec<IntType>>>()?,
+            ),
+            Some(Item::Float(_)) => Series::new(
+                name,
+                items
+                    .into_iter()
+                    .map(Item::cast_float)
+                    .collect::<RuntimeResult<Vec<FloatType>>>()?,
+            ),
+            Some(Item::Bool(_)) => Series::new(
+                name,
+                items
+                    .into_iter()
+                    .map(VirtualMachine::unwrap_bool)
+                    .collect::<RuntimeResult<Vec<bool>>>()?,
+            ),
+            Some(Item::String(_)) => Series::new(
+                name,
+                items
+                    .into_iter()
+                    .map(|item| item.to_string())
+                    .collect::<Vec<String>>(),
+            ),
+            Some(item) => {
+                return Err(RuntimeErrorKind::TypeError(format!(
+                    "Series can't hold {} values",
+                    item.type_name()
+                )))
+            }
+        };
+
+        Ok(series)
+    }
+
+    fn run_instruction(&mut self, quadruples: &[Instruction]) -> RuntimeResult<Jump> {
+        match &quadruples[self.instruction_pointer] {
+            Instruction::Operation(operator, left, right, dest) => {
+                let operands = (left, right, dest);
+                let dest_address = self.memory.get_address(dest)?;
+
+                if let Some(DataType::Series) = MemoryResolver::get_type_from_address(dest_address)
+                {
+                    self.series_op(*operator, operands)?;
+                    return Ok(Jump::Next);
                 }
-                "era" => {
-                    let Quadruple(_, _, _, function_id) = curr_instruction;
-                    self.memory.push_hold(function_id.clone());
-                    pre_call_stack.push_back(function_id.clone());
+
+                match operator {
+                    Operator::Mul | Operator::Add | Operator::Sub | Operator::Div => {
+                        self.arithmetic_op(*operator, operands)?
+                    }
+                    Operator::GreaterThan
+                    | Operator::GreaterOrEq
+                    | Operator::LessThan
+                    | Operator::LessOrEq
+                    | Operator::Eq
+                    | Operator::NotEq => self.logic_cmp(*operator, operands)?,
+                    Operator::And | Operator::Or => self.boolean_op(*operator, operands)?,
+                    Operator::MatMul => self.matmul(operands)?,
+                    operator => {
+                        return Err(RuntimeErrorKind::InvalidInstruction(format!(
+                            "Unknown binary operator {}",
+                            operator.to_string()
+                        )))
+                    }
                 }
-                "param" => {
-                    let Quadruple(_, arg_addr, _, _) = curr_instruction;
+            }
+            Instruction::Unary(Operator::Not, to_negate, dest) => {
+                let (to_negate, dest) = self.unpack_unary(to_negate, dest)?;
+                let to_negate = VirtualMachine::unwrap_bool(to_negate)?;
 
-                    let value_addr = self.memory.get_address(arg_addr);
+                self.memory.update(dest, Item::Bool(!to_negate))?;
+            }
+            Instruction::Unary(Operator::Neg, to_negate, dest) => {
+                let (to_negate, dest) = self.unpack_unary(to_negate, dest)?;
 
-                    self.memory.push_param(value_addr);
+                match to_negate {
+                    Item::Int(item) => {
+                        let negated = item.checked_neg().ok_or(RuntimeErrorKind::OverflowError)?;
+                        self.memory.update(dest, Item::Int(negated))?
+                    }
+                    Item::Float(item) => self.memory.update(dest, Item::Float(-item))?,
+                    _ => {
+                        return Err(RuntimeErrorKind::TypeError(format!(
+                            "{} can't be negated",
+                            to_negate.type_name()
+                        )))
+                    }
                 }
-                "gosub" => {
-                    let Quadruple(_, _, _, function_id) = curr_instruction;
+            }
+            Instruction::Unary(Operator::Assign, value, dest) => {
+                let (op, dest) = self.unpack_unary(value, dest)?;
+                self.memory.update(dest, op)?;
+            }
+            Instruction::Unary(operator, _, _) => {
+                return Err(RuntimeErrorKind::InvalidInstruction(format!(
+                    "Unknown unary operator {}",
+                    operator.to_string()
+                )))
+            }
+            Instruction::Cast(DataType::Float, value, dest) => {
+                let (op, dest) = self.unpack_unary(value, dest)?;
 
-                    if let Ok(native_func) = NativeFunction::from_str(function_id) {
-                        let return_value = run_native(&mut plot_ctx, native_func, &mut self.memory);
-                        if let Some((func, value)) = return_value {
-                            self.return_value_native(func, value);
-                        }
-                        pre_call_stack.pop_back();
-                    } else {
-                        call_pointer.push_back(instruction_pointer + 1);
+                let op = match op {
+                    Item::Bool(op) => Item::Float((op as u8) as FloatType),
+                    _ => op,
+                };
+                let op = cast!(op, [Int, Float, Pointer], FloatType, "Float");
 
-                        let func_meta = self.data.get_func(&function_id);
-                        self.memory.push_context(func_meta);
-                        pre_call_stack.pop_back();
+                self.memory.update(dest, Item::Float(op))?;
+            }
+            Instruction::Cast(DataType::Int, value, dest) => {
+                let (op, dest) = self.unpack_unary(value, dest)?;
 
-                        // Cleanup function return address to catch no-return errors
-                        if let Some(return_addres) = func_meta.return_address {
-                            self.memory.delete(return_addres);
-                        }
+                let op = cast!(op, [Int, Float, Pointer, Bool], IntType, "Int");
 
-                        instruction_pointer = func_meta.procedure_address;
-                        continue;
-                    }
+                self.memory.update(dest, Item::Int(op))?;
+            }
+            Instruction::Cast(DataType::Bool, value, dest) => {
+                let (op, dest) = self.unpack_unary(value, dest)?;
+
+                let op = cast!(op, [Int, Float, Pointer, Bool], IntType, "Bool");
+                let op = op > 0;
+
+                self.memory.update(dest, Item::Bool(op))?;
+            }
+            Instruction::Cast(DataType::String, value, dest) => {
+                let (op, dest) = self.unpack_unary(value, dest)?;
+
+                self.memory.update(dest, Item::String(op.to_string()))?;
+            }
+            Instruction::Cast(data_type, _, _) => {
+                return Err(RuntimeErrorKind::InvalidInstruction(format!(
+                    "Can't cast values to {:?}",
+                    data_type
+                )))
+            }
+            Instruction::Verify(value, bound) => {
+                let value = Item::cast_int(self.memory.get(value)?)?;
+                let bound = Item::cast_int(self.memory.get(bound)?)?;
+                if value < 0 || value >= bound {
+                    return Err(RuntimeErrorKind::IndexError {
+                        index: value,
+                        size: bound,
+                    });
                 }
-                "return" | "voidReturn" => {
-                    let Quadruple(_, _, _, return_value_addr) = curr_instruction;
-                    let function_id = &self.memory.curr_context().procedure_id;
+            }
+            Instruction::Goto(next) => return Ok(Jump::To(VirtualMachine::label(next)?)),
+            Instruction::GotoFalse(check, next) => {
+                let check = self.memory.get(check)?;
+                match check {
+                    Item::Bool(check) => {
+                        if !check {
+                            return Ok(Jump::To(VirtualMachine::label(next)?));
+                        }
+                    }
+                    _ => {
+                        return Err(RuntimeErrorKind::TypeError(format!(
+                            "Can't check non-boolean condition of type {}",
+                            check.type_name()
+                        )))
+                    }
+                };
+            }
+            Instruction::Era(function_id) => {
+                self.memory.push_hold(function_id.clone());
+            }
+            Instruction::Param(arg_addr, _) => {
+                let value_addr = self.memory.get_address(arg_addr)?;
 
-                    let func_meta = self.data.get_func(function_id);
-                    if let None = func_meta.return_address {
-                        if !return_value_addr.is_empty() {
-                            panic!("Can't return value for void function");
+                self.memory.push_param(value_addr);
+            }
+            Instruction::GoSub(function_id) => {
+                let native = NativeFunction::resolve(function_id, &self.data.procedure_table);
+                if let Some(native_func) = native {
+                    let return_value = match native_func {
+                        NativeFunction::Map | NativeFunction::Filter | NativeFunction::Reduce => {
+                            let value = self.apply_function(quadruples, native_func)?;
+                            Some((NativeFunction::from_str(function_id).unwrap(), value))
                         }
-                    } else {
-                        let return_addr = func_meta.return_address.unwrap();
-                        let value = self.memory.get(return_value_addr);
-                        self.memory.update(return_addr, value);
+                        _ => run_native(&mut self.plot_ctx, native_func, &mut self.memory)?,
+                    };
+                    if let Some((func, value)) = return_value {
+                        self.return_value_native(func, value)?;
+                    }
+                } else {
+                    let func_meta = self.data.get_func(function_id);
+                    self.memory.push_context(func_meta)?;
+                    self.call_pointer.push_back(self.instruction_pointer + 1);
+
+                    // Cleanup function return address to catch no-return errors
+                    if let Some(return_addres) = func_meta.return_address {
+                        self.memory.delete(return_addres);
                     }
 
-                    self.memory.pop_context();
-                    instruction_pointer = call_pointer.pop_back().unwrap();
-                    continue;
+                    return Ok(Jump::To(func_meta.procedure_address));
                 }
-                "endFunc" => {
-                    self.memory.pop_context();
-                    instruction_pointer = call_pointer.pop_back().unwrap();
-                    continue;
+            }
+            Instruction::Call(closure, result) => {
+                let closure = self.memory.get(closure)?;
+                let (function_id, func_meta) = match &closure {
+                    Item::Closure(function_id, _) => {
+                        (function_id.clone(), self.data.get_func(function_id))
+                    }
+                    item => {
+                        return Err(RuntimeErrorKind::TypeError(format!(
+                            "Can't call a {} value",
+                            item.type_name()
+                        )))
+                    }
+                };
+
+                // The result is stored in the caller, once the call returns
+                let result = match result {
+                    Some(result) => Some(self.memory.get_address(result)?),
+                    None => None,
+                };
+                // Function values are called by the id of the variable holding them
+                self.memory.push_context(func_meta)?;
+                let context = self.memory.curr_context_mut();
+                context.procedure_id = function_id;
+                context.result = result;
+                self.call_pointer.push_back(self.instruction_pointer + 1);
+
+                return Ok(Jump::To(self.enter_closure(closure)?));
+            }
+            Instruction::Closure(function_id, captured, dest) => {
+                let captured = captured
+                    .iter()
+                    .map(|value| self.memory.get(value))
+                    .collect::<RuntimeResult<Vec<Item>>>()?;
+
+                let dest = self.memory.get_address(dest)?;
+                self.memory
+                    .update(dest, Item::Closure(function_id.clone(), captured))?;
+            }
+            Instruction::Return(return_value_addr) => {
+                let function_id = &self.memory.curr_context().procedure_id;
+
+                let func_meta = self.data.get_func(function_id);
+                let value = self.memory.get(return_value_addr)?;
+                if let Some(return_addr) = func_meta.return_address {
+                    self.memory.update(return_addr, value.clone())?;
+                } else {
+                    return Err(RuntimeErrorKind::InvalidInstruction(String::from(
+                        "Can't return value for void function",
+                    )));
                 }
-                "endProgram" => {
-                    break;
+
+                let result = self.memory.curr_context().result;
+                self.memory.pop_context();
+                if let Some(result) = result {
+                    self.memory.update(result, value)?;
+                }
+                return Ok(Jump::To(self.call_pointer.pop_back().unwrap()));
+            }
+            Instruction::VoidReturn | Instruction::EndFunc => {
+                let context = self.memory.curr_context();
+                if context.result.is_some() {
+                    return Err(RuntimeErrorKind::InvalidInstruction(format!(
+                        "Function {} ended without returning a value",
+                        context.procedure_id
+                    )));
                 }
-                "print" => {
-                    let Quadruple(_, _, _, print_target) = curr_instruction;
 
-                    if let Ok(print_target) = self.memory.safe_get(print_target) {
-                        print!("{} ", print_target);
-                    } else {
-                        print!("{}", print_target);
+                self.memory.pop_context();
+                return Ok(Jump::To(self.call_pointer.pop_back().unwrap()));
+            }
+            Instruction::EndProgram => return Ok(Jump::Halt),
+            Instruction::Print(Operand::Const(Literal::Text(text))) => print!("{}", text),
+            Instruction::Print(print_target) => {
+                print!("{} ", self.memory.get(print_target)?);
+            }
+            Instruction::Free(address) => {
+                let del_address = self.memory.get_address(address)?;
+                self.memory.delete(del_address);
+            }
+            Instruction::Mark(depth) => self.memory.mark_block(*depth),
+            Instruction::Release(depth) => self.memory.release_block(*depth),
+            Instruction::Alloc(address, shape) => {
+                let address = self.memory.get_address(address)?;
+                self.memory.alloc(address, shape)?;
+            }
+            Instruction::Len(value, axis, rank, dest) => {
+                let length = match self.memory.get(value)? {
+                    Item::Pointer(address) => {
+                        let shape = self.memory.get_shape(address)?;
+                        let first_axis = match rank {
+                            0 => 0,
+                            rank => shape.len().saturating_sub(*rank),
+                        };
+                        shape.get(first_axis + axis).copied().ok_or_else(|| {
+                            RuntimeErrorKind::InvalidInstruction(format!(
+                                "Array has no axis {}",
+                                axis
+                            ))
+                        })?
                     }
-                }
-                "free" => {
-                    let Quadruple(_, _, _, address) = curr_instruction;
+                    Item::DataFrame(df) => df.height(),
+                    Item::Series(series) => series.len(),
+                    Item::String(text) => text.chars().count(),
+                    item => {
+                        return Err(RuntimeErrorKind::TypeError(format!(
+                            "Can't get the length of a {} value",
+                            item.type_name()
+                        )))
+                    }
+                };
 
-                    let del_address = self.memory.get_address(address);
-                    self.memory.delete(del_address);
-                }
-                _ => panic!(),
+                let dest = self.memory.get_address(dest)?;
+                self.memory.update(dest, Item::Int(length as IntType))?;
             }
+            Instruction::New(data_type, sizes, dest) => {
+                let shape = sizes
+                    .iter()
+                    .map(|size| Item::cast_int(self.memory.get(size)?))
+                    .collect::<RuntimeResult<Vec<IntType>>>()?;
+                let address = self.memory.alloc_heap(data_type, &shape)?;
 
-            instruction_pointer += 1;
+                let dest = self.memory.get_address(dest)?;
+                self.memory.update(dest, Item::Pointer(address))?;
+            }
+            Instruction::View(source, sizes, strides, dest) => {
+                let source = match self.memory.get(source)? {
+                    Item::Pointer(address) => address,
+                    item => {
+                        return Err(RuntimeErrorKind::TypeError(format!(
+                            "Can't slice a {} value",
+                            item.type_name()
+                        )))
+                    }
+                };
+                let mut integers = |operands: &Vec<Operand>| {
+                    operands
+                        .iter()
+                        .map(|operand| Item::cast_int(self.memory.get(operand)?))
+                        .collect::<RuntimeResult<Vec<IntType>>>()
+                };
+                let (shape, strides) = (integers(sizes)?, integers(strides)?);
+                let address = self.memory.new_view(source, &shape, &strides)?;
+
+                let dest = self.memory.get_address(dest)?;
+                self.memory.update(dest, Item::Pointer(address))?;
+            }
+            Instruction::Copy(source, count, dest) => {
+                let count = Item::cast_int(self.memory.get(count)?)?;
+                let (source, dest) = match (self.memory.get(source)?, self.memory.get(dest)?) {
+                    (Item::Pointer(source), Item::Pointer(dest)) => (source, dest),
+                    (source, dest) => {
+                        return Err(RuntimeErrorKind::TypeError(format!(
+                            "Can't copy items from a {} value to a {} value",
+                            source.type_name(),
+                            dest.type_name()
+                        )))
+                    }
+                };
+
+                self.memory.copy(source, dest, count as usize)?;
+            }
+            Instruction::Elementwise(operator, left, right, dest) => {
+                let dest = match self.memory.get(dest)? {
+                    Item::Pointer(address) => address,
+                    item => {
+                        return Err(RuntimeErrorKind::TypeError(format!(
+                            "Can't store the items of an operation in a {} value",
+                            item.type_name()
+                        )))
+                    }
+                };
+                let data_type = MemoryResolver::get_type_from_address(dest).ok_or_else(|| {
+                    RuntimeErrorKind::InvalidInstruction(format!("{dest} is not a valid address"))
+                })?;
+                let (array, start) = self.memory.get_array(dest)?;
+                let size = array.len() - start;
+
+                let left = self.memory.get(left)?;
+                let right = self.memory.get(right)?;
+                let items = zip(self.items(left, size)?, self.items(right, size)?)
+                    .map(|(left, right)| VirtualMachine::apply(*operator, data_type, left, right))
+                    .collect::<RuntimeResult<Vec<Item>>>()?;
+
+                let (array, start) = self.memory.get_array_mut(dest)?;
+                for (index, item) in items.into_iter().enumerate() {
+                    array.set(start + index, item)?;
+                }
+            }
+            Instruction::Empty => {
+                return Err(RuntimeErrorKind::InvalidInstruction(String::from(
+                    "Unresolved instruction",
+                )))
+            }
         }
+
+        Ok(Jump::Next)
     }
 }
@@ -1,4 +1,10 @@
-use moris_lang::vm::{memory_manager::Item, inspector::Inspector};
+use codegen::errors::CompileError;
+use moris_lang::vm::{
+    errors::{RuntimeError, RuntimeErrorKind},
+    inspector::Inspector,
+    memory_manager::Item,
+    runner::Runner,
+};
 use serial_test::file_serial;
 
 fn test_file(file_name: &str) -> Inspector {
@@ -13,6 +19,29 @@ fn build_file_path(file_name: &str) -> String {
     format!("examples/{file_name}").to_owned()
 }
 
+/// Returns the errors reported when compiling a program that must fail.
+fn compile_errors(source: &str) -> Vec<CompileError> {
+    let path = std::env::temp_dir().join("moris_compile_errors.mo");
+    std::fs::write(&path, source).unwrap();
+
+    let mut manager = codegen::manager::Manager::new();
+    codegen::generate(path.to_str().unwrap(), &mut manager).unwrap_err()
+}
+
+/// Returns the error raised when running a program that must fail.
+fn runtime_error(file_path: &str) -> RuntimeError {
+    let mut runner = Runner::new(file_path).unwrap();
+    runner.compile().unwrap();
+    runner.run().err().unwrap()
+}
+
+/// Returns the error raised when running a source that must fail.
+fn source_runtime_error(source: &str) -> RuntimeErrorKind {
+    let path = std::env::temp_dir().join("moris_runtime_errors.mo");
+    std::fs::write(&path, source).unwrap();
+    runtime_error(path.to_str().unwrap()).kind
+}
+
 #[test]
 #[file_serial]
 fn test_pipes() {
@@ -61,3 +90,797 @@ fn test_arrays() {
     assert_eq!(data.get("j"), Item::Int(10));
     // TODO: assert values
 }
+
+#[test]
+#[file_serial]
+fn test_compile_errors() {
+    let source = "let x: int = 5;\nlet y: int = x + \"a\";\nlet x: float;\nprintln(z);\n";
+    let errors = compile_errors(source);
+
+    let lines = errors
+        .iter()
+        .map(|error| error.span.line_col(source).0)
+        .collect::<Vec<usize>>();
+    assert_eq!(lines, vec![2, 3, 4]);
+    assert_eq!(errors[1].message, "x was already defined!");
+
+    let errors = compile_errors("let x: int = 99999999999999999999;\n");
+    assert_eq!(
+        errors[0].message,
+        "Integer 99999999999999999999 is too large for an int"
+    );
+
+    let source = "let a: int[2];\nlet b: int[0];\n";
+    let errors = compile_errors(source);
+    assert_eq!(errors[0].span.line_col(source), (2, 12));
+    assert_eq!(
+        errors[0].message,
+        "Invalid dimension size 0 in array declaration, sizes must be positive"
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_large_arrays() {
+    let data = test_file("large_arrays.mo");
+    assert_eq!(data.get("x"), Item::Float(2.5));
+
+    let source = "let m: float[500][500];\nlet big: int[20000000];\n";
+    let errors = compile_errors(source);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].span.line_col(source).0, 2);
+    assert!(errors[0].message.starts_with("Not enough Global memory"));
+}
+
+#[test]
+#[file_serial]
+fn test_array_storage() {
+    let data = test_file("array_storage.mo");
+    assert_eq!(
+        data.get_array("a"),
+        vec![
+            Item::Float(0.0),
+            Item::Float(5.0),
+            Item::Float(0.0),
+            Item::Float(3.0)
+        ]
+    );
+    assert_eq!(data.get("m"), Item::Float(2.0));
+    assert_eq!(
+        data.get_array("b"),
+        vec![Item::Int(0), Item::Int(0), Item::Int(7)]
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_array_rows() {
+    let data = test_file("array_rows.mo");
+    assert_eq!(data.get("first_mean"), Item::Float(1.5));
+    assert_eq!(data.get("first_sum"), Item::Float(3.0));
+    assert_eq!(data.get("middle_median"), Item::Float(3.5));
+    assert_eq!(data.get("middle_total"), Item::Float(7.0));
+    assert_eq!(
+        data.get_array("doubled"),
+        [2.0, 4.0].map(Item::Float).to_vec()
+    );
+    assert_eq!(data.get_array("large"), vec![Item::Float(4.0)]);
+    assert_eq!(data.get("middle_product"), Item::Float(12.0));
+
+    // Natives given a row leave the other rows untouched
+    assert_eq!(
+        data.get_array("m"),
+        [0.0, 0.0, 30.0, 40.0, 5.0, 6.0].map(Item::Float).to_vec()
+    );
+    assert_eq!(
+        data.get_array("t"),
+        [5, 5, 5, 0, 0, 0].map(Item::Int).to_vec()
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_runtime_sized_arrays() {
+    let data = test_file("runtime_sized.mo");
+    assert_eq!(data.get("n"), Item::Int(3));
+    assert_eq!(data.get_array("xs").len(), 6);
+    assert_eq!(data.get("rows"), Item::Int(3));
+    assert_eq!(data.get("cols"), Item::Int(4));
+    assert_eq!(data.get("t"), Item::Float(22.5));
+    assert_eq!(data.get_array("m")[11], Item::Int(7));
+
+    assert_eq!(
+        source_runtime_error("let n: int = 3;\nlet xs: float[n * 2];\nxs[n * 2] = 1.0;\n"),
+        RuntimeErrorKind::IndexError { index: 6, size: 6 }
+    );
+
+    let errors = compile_errors("let size: float = 2.0;\nlet xs: int[size];\n");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "Array sizes must be integers, found Float"
+    );
+
+    let errors =
+        compile_errors("fn g(a: int[]): int { return len(a); }\nlet f: float[2];\nlet n = g(f);\n");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "Array of Float items cannot be assigned to a parameter of Int items."
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_heap_release() {
+    // Each loop allocates twice the heap, unless every iteration releases
+    // the arrays of the previous one
+    let data = test_file("heap_release.mo");
+    assert_eq!(data.get("declared"), Item::Int(20_000_000));
+    assert_eq!(data.get("counted"), Item::Int(20_000_000));
+    assert_eq!(data.get("sliced"), Item::Int(19_999_800));
+    assert_eq!(data.get("summed"), Item::Float(400.0));
+    assert_eq!(data.get("scaled"), Item::Int(20_000_000));
+}
+
+#[test]
+#[file_serial]
+fn test_array_literals() {
+    let data = test_file("array_literals.mo");
+    assert_eq!(
+        data.get_array("v"),
+        vec![Item::Int(3), Item::Int(1), Item::Int(20)]
+    );
+    assert_eq!(
+        data.get_array("m"),
+        vec![
+            Item::Float(1.0),
+            Item::Float(0.0),
+            Item::Float(5.0),
+            Item::Float(6.5)
+        ]
+    );
+    assert_eq!(data.get("avg"), Item::Float(3.0));
+    assert_eq!(
+        data.get_array("w"),
+        vec![Item::Float(1.0), Item::Float(2.0)]
+    );
+
+    let errors = compile_errors("let v: int[3] = [1, 2];\n");
+    assert_eq!(
+        errors[0].message,
+        "Array literal of shape [2] can't initialize an array of shape [3]"
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_array_returns() {
+    let data = test_file("array_returns.mo");
+    assert_eq!(data.get_array("f"), vec![Item::Int(0), Item::Int(89)]);
+    assert_eq!(data.get_array("copy"), vec![Item::Int(55), Item::Int(89)]);
+    assert_eq!(
+        data.get_array("m"),
+        vec![Item::Int(0), Item::Int(0), Item::Int(55), Item::Int(89)]
+    );
+
+    let errors = compile_errors("fn f(): int[] {\n    let a: int[2];\n    return a;\n}\n");
+    assert_eq!(
+        errors[0].message,
+        "Arrays returned by reference must be array parameters or global arrays"
+    );
+
+    // Sized array parameters only take arrays of the same shape and type
+    let errors =
+        compile_errors("fn g(a: int[2]): int { return a[0]; }\nlet f: float[2];\nlet n = g(f);\n");
+    assert_eq!(
+        errors[0].message,
+        "Array of Float items cannot be assigned to a parameter of Int items."
+    );
+    let errors = compile_errors(
+        "fn f(a: int[2][2]): int { return a[1][1]; }\nlet m: int[3][3];\nlet n = f(m);\n",
+    );
+    assert_eq!(
+        errors[0].message,
+        "Can't pass item with dimensions [3, 3] to a parameter with dimensions [2, 2]"
+    );
+    let errors = compile_errors("fn g(a: int[3]): int { return a[0]; }\nlet n = g(4);\n");
+    assert_eq!(
+        errors[0].message,
+        "Can't pass item with dimensions [] to a parameter with dimensions [3]"
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_elementwise_operations() {
+    let data = test_file("elementwise.mo");
+    assert_eq!(
+        data.get_array("c"),
+        vec![Item::Float(3.0), Item::Float(6.0), Item::Float(9.0)]
+    );
+    assert_eq!(
+        data.get_array("mask"),
+        vec![Item::Bool(false), Item::Bool(true), Item::Bool(true)]
+    );
+    assert_eq!(
+        data.get_array("j"),
+        vec![Item::Int(9), Item::Int(8), Item::Int(7), Item::Int(6)]
+    );
+    assert_eq!(data.get("scaled"), Item::Float(26.0 / 3.0));
+
+    let errors = compile_errors("let a: int[3];\nlet b: int[2];\nlet c: int[3] = a + b;\n");
+    assert_eq!(
+        errors[0].message,
+        "Can't operate items with dimensions [3] and [2]"
+    );
+
+    let errors = compile_errors("let a: int[3];\nlet c = -a;\n");
+    assert_eq!(
+        errors[0].message,
+        "Operator - can't be applied to an array with dimensions [3]"
+    );
+    let errors = compile_errors("let a: bool[2][2];\nlet c = !a;\n");
+    assert_eq!(
+        errors[0].message,
+        "Operator ! can't be applied to an array with dimensions [2, 2]"
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_matrix_operations() {
+    let data = test_file("matrix_operations.mo");
+    let floats = |items: &[f64]| {
+        items
+            .iter()
+            .map(|item| Item::Float(*item))
+            .collect::<Vec<Item>>()
+    };
+    let rounded = |id: &str| {
+        data.get_array(id)
+            .iter()
+            .map(|item| Item::Float((item.clone().unwrap_float() * 1e9).round() / 1e9))
+            .collect::<Vec<Item>>()
+    };
+    assert_eq!(data.get_array("c"), floats(&[22.0, 28.0, 49.0, 64.0]));
+    assert_eq!(
+        data.get_array("j"),
+        vec![Item::Int(7), Item::Int(10), Item::Int(15), Item::Int(22)]
+    );
+    assert_eq!(data.get_array("t"), floats(&[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]));
+    assert_eq!(rounded("inv"), floats(&[0.6, -0.7, -0.2, 0.4]));
+    assert_eq!(rounded("x"), floats(&[-0.8, 0.6]));
+    assert_eq!(data.get_array("e"), floats(&[1.0, 0.0, 0.0, 1.0]));
+    assert_eq!(data.get("d"), Item::Float(10.0));
+    assert_eq!(data.get("p"), Item::Float(32.0));
+
+    let errors = compile_errors("let a: float[2][3];\nlet b: float[2][3] = a @ a;\n");
+    assert_eq!(
+        errors[0].message,
+        "Can't multiply matrices with dimensions [2, 3] and [2, 3]"
+    );
+
+    let errors = compile_errors("let a: float[2][3];\nlet d: float = det(a);\n");
+    assert_eq!(
+        errors[0].message,
+        "Function det takes a square matrix, found dimensions [2, 3]"
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_structs() {
+    let data = test_file("structs.mo");
+    assert_eq!(data.get("p.x"), Item::Float(3.0));
+    assert_eq!(data.get("s.start.y"), Item::Float(4.0));
+    assert_eq!(data.get("s.end.x"), Item::Float(1.0));
+    assert_eq!(data.get("n"), Item::Float(25.0));
+    assert_eq!(data.get("count"), Item::Int(3));
+    assert_eq!(
+        data.get_array("path.x"),
+        vec![Item::Float(1.0), Item::Float(4.0), Item::Float(1.0)]
+    );
+
+    let point = Item::Struct(
+        String::from("Point"),
+        vec![
+            (String::from("x"), Item::Float(3.0)),
+            (String::from("y"), Item::Float(4.5)),
+        ],
+    );
+    assert_eq!(format!("{}", point), "Point { x: 3, y: 4.5 }");
+
+    let errors = compile_errors("struct Point { x: float }\nlet p: Point;\np.z = 1;\n");
+    assert_eq!(errors[0].message, "Struct Point has no field z");
+
+    let errors = compile_errors("struct Point { x: float }\nlet p: Point;\nlet x: float = p;\n");
+    assert_eq!(
+        errors[0].message,
+        "Data type Struct(\"Point\") cannot be assigned to a variable Float."
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_block_scopes() {
+    let data = test_file("block_scopes.mo");
+    assert_eq!(data.get("x"), Item::Int(1));
+    assert_eq!(data.get("inner"), Item::Int(10));
+    assert_eq!(data.get("total"), Item::Int(7));
+
+    let errors = compile_errors("if (true) {\n    let b: int = 1;\n}\nprint(b);\n");
+    assert_eq!(errors[0].message, "id b is not defined");
+
+    let errors = compile_errors("while (true) {\n    let a: int;\n    let a: float;\n}\n");
+    assert_eq!(errors[0].message, "a was already defined!");
+}
+
+#[test]
+#[file_serial]
+fn test_closures() {
+    let data = test_file("closures.mo");
+    assert_eq!(data.get("r"), Item::Int(18));
+    assert_eq!(data.get("shown"), Item::Int(6));
+
+    let errors = compile_errors(
+        "fn f(): void {\n    let v: int[3];\n    fn g(): int {\n        return v[0];\n    }\n}\n",
+    );
+    assert_eq!(
+        errors[0].message,
+        "Array v can't be captured by a nested function, pass it as a parameter instead"
+    );
+
+    let errors = compile_errors(
+        "fn f(): void {\n    let x: int = 1;\n    fn g(): void {\n        x = 2;\n    }\n}\n",
+    );
+    assert_eq!(errors[0].message, "Variable x can't be mutated");
+}
+
+#[test]
+#[file_serial]
+fn test_lambdas() {
+    let data = test_file("lambdas.mo");
+    assert_eq!(data.get("a"), Item::Int(42));
+    assert_eq!(data.get("b"), Item::Int(10));
+    assert_eq!(data.get("c"), Item::Int(15));
+    assert_eq!(data.get("d"), Item::Float(6.0));
+    assert_eq!(
+        data.get_array("ys"),
+        vec![
+            Item::Float(1.5),
+            Item::Float(3.0),
+            Item::Float(4.5),
+            Item::Float(6.0)
+        ]
+    );
+    assert_eq!(data.get_array("kept"), vec![Item::Int(3), Item::Int(4)]);
+    assert_eq!(data.get("total"), Item::Int(10));
+    assert_eq!(data.get("e"), Item::Int(8));
+    assert_eq!(data.get_array("none"), vec![]);
+    assert_eq!(data.get("none_size"), Item::Int(0));
+    assert_eq!(data.get("none_sum"), Item::Float(0.0));
+
+    let source = "let xs: int[4] = [1, 2, 3, 4];\nlet kept: int[2] = filter(xs, |x: int| x > 1);\n";
+    assert_eq!(
+        source_runtime_error(source),
+        RuntimeErrorKind::ValueError(String::from("Can't copy 2 items, the array holds 3 items"))
+    );
+
+    let errors = compile_errors("let f: fn(int): int = print;\n");
+    assert_eq!(
+        errors[0].message,
+        "Native function print can't be used as a value"
+    );
+
+    let errors =
+        compile_errors("let xs: int[2] = [1, 2];\nlet ys: int[2] = filter(xs, |x: int| x + 1);\n");
+    assert_eq!(
+        errors[0].message,
+        "Function filter takes a function returning a bool"
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_type_inference() {
+    let data = test_file("type_inference.mo");
+    assert_eq!(data.get("total"), Item::Float(5.0));
+    assert_eq!(data.get("n"), Item::Int(3));
+    assert_eq!(
+        data.get_array("copy"),
+        vec![Item::Int(10), Item::Int(2), Item::Int(3), Item::Int(4)]
+    );
+    assert_eq!(data.get_array("arr")[0], Item::Int(1));
+    assert_eq!(data.get("first"), Item::Int(3));
+    assert_eq!(data.get("size"), Item::Int(2));
+    assert_eq!(data.get("corner"), Item::Float(3.0));
+    assert_eq!(data.get("explicit"), Item::Float(3.0));
+
+    let errors = compile_errors("let x = print(1);\n");
+    assert_eq!(
+        errors[0].message,
+        "Variable x can't be declared with a void value"
+    );
+
+    let errors = compile_errors("let n = 3;\nn = \"three\";\n");
+    assert_eq!(
+        errors[0].message,
+        "Data type String cannot be assigned to a variable Int."
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_tensors() {
+    let data = test_file("tensors.mo");
+    assert_eq!(data.get("item"), Item::Int(123));
+    assert_eq!(data.get("shape"), Item::Int(34));
+    assert_eq!(data.get("lit_total"), Item::Int(36));
+    assert_eq!(data.get("row_sum"), Item::Float(486.0));
+    assert_eq!(data.get("first_sum"), Item::Float(6.0));
+    assert_eq!(data.get("plane_sum"), Item::Float(138.0));
+    assert_eq!(data.get_array("t").len(), 24);
+    // Only the first plane is cleared
+    assert!(data.get_array("t")[..12]
+        .iter()
+        .all(|item| *item == Item::Int(0)));
+    assert_eq!(data.get_array("t")[12], Item::Int(100));
+    assert_eq!(data.get_array("t")[23], Item::Int(123));
+    assert_eq!(data.get_array("slice").len(), 8);
+    assert_eq!(data.get_array("slice")[7], Item::Float(1.5));
+    assert_eq!(data.get_array("doubled")[7], Item::Int(16));
+
+    let errors = compile_errors("let t: int[2][2][2];\nt[1][1] = 3;\n");
+    assert_eq!(
+        errors[0].message,
+        "Can't assign item t with dimensions [2] to value with dimensions []"
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_slices() {
+    let data = test_file("slices.mo");
+    assert_eq!(data.get_array("b").len(), 6);
+    assert_eq!(data.get_array("b")[0], Item::Int(2));
+    assert_eq!(data.get("size"), Item::Int(4));
+    assert_eq!(data.get("odd"), Item::Int(25));
+    assert_eq!(data.get("row_sum"), Item::Float(26.0));
+    assert_eq!(data.get("col_mean"), Item::Float(7.0));
+    assert_eq!(data.get("diagonal"), Item::Float(19.0));
+    assert_eq!(data.get("window"), Item::Int(15));
+    assert_eq!(
+        data.get_array("a"),
+        [10, 20, 30, 3, 4, 5, 6, 0, 0, 0].map(Item::Int).to_vec()
+    );
+    assert_eq!(data.get_array("m")[8], Item::Float(12.0));
+
+    let errors = compile_errors("let a: int[5];\nlet b = a[3:1];\n");
+    assert_eq!(errors[0].message, "Slice 3:1 takes no items");
+}
+
+#[test]
+#[file_serial]
+fn test_strings() {
+    let data = test_file("string_natives.mo");
+    assert_eq!(data.get("size"), Item::Int(12));
+    assert_eq!(data.get("loud"), Item::String(String::from("HELLO, WORLD")));
+    assert_eq!(data.get("word"), Item::String(String::from("World")));
+    assert_eq!(data.get("at"), Item::Int(7));
+    assert_eq!(data.get("missing"), Item::Int(-1));
+    assert_eq!(
+        data.get("renamed"),
+        Item::String(String::from("Hello, moris"))
+    );
+    assert_eq!(
+        data.get_array("parts"),
+        ["a", "b", "c"]
+            .map(|part| Item::String(String::from(part)))
+            .to_vec()
+    );
+    assert_eq!(data.get("joined"), Item::String(String::from("b-c")));
+    assert_eq!(data.get("greeting"), Item::Bool(true));
+    assert_eq!(data.get("total"), Item::Float(42.5));
+    assert_eq!(
+        data.get("summary"),
+        Item::String(String::from("World has 12 characters"))
+    );
+
+    assert_eq!(
+        source_runtime_error("let n = to_int(\"4x\");\n"),
+        RuntimeErrorKind::ValueError(String::from("Can't convert \"4x\" to an int"))
+    );
+
+    let errors = compile_errors("let s = format(\"{} and {}\", 1);\n");
+    assert_eq!(
+        errors[0].message,
+        "Template has 2 placeholders, 1 values were provided"
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_shadowed_natives() {
+    let data = test_file("shadowed_natives.mo");
+    assert_eq!(data.get("size"), Item::Int(0));
+    assert_eq!(data.get("position"), Item::Int(2));
+    assert_eq!(data.get("missing"), Item::Int(5));
+    assert_eq!(data.get("word"), Item::String(String::from("MORIS")));
+
+    let errors = compile_errors(
+        "fn f(): int {\n    fn len(): int {\n        return 0;\n    }\n    return len();\n}\n",
+    );
+    assert_eq!(
+        errors[0].message,
+        "A symbol with id len has been already defined"
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_math() {
+    let data = test_file("math.mo");
+    assert_eq!(data.get("a"), Item::Int(3));
+    assert_eq!(data.get("root"), Item::Float(4.0));
+    assert_eq!(data.get("power"), Item::Float(1024.0));
+    assert_eq!(data.get("digits"), Item::Float(3.0));
+    assert_eq!(data.get("one"), Item::Float(2.0));
+    assert_eq!(data.get("rounded"), Item::Int(2));
+    assert_eq!(data.get("low"), Item::Int(3));
+    assert_eq!(data.get("high"), Item::Float(7.5));
+    assert_eq!(data.get("bounded"), Item::Int(10));
+    assert_eq!(
+        data.get_array("roots"),
+        [1.0, 2.0, 3.0, 4.0].map(Item::Float).to_vec()
+    );
+    assert_eq!(
+        data.get_array("positive"),
+        [1, 2, 3, 4, 5, 6].map(Item::Int).to_vec()
+    );
+    assert_eq!(data.get_array("row"), [2, -2, 2].map(Item::Int).to_vec());
+    assert_eq!(data.get_array("top"), [5.0, 9.0].map(Item::Float).to_vec());
+    assert_eq!(data.get("total"), Item::Float(6.0));
+
+    assert_eq!(
+        source_runtime_error("let x = sqrt(-4);\n"),
+        RuntimeErrorKind::ValueError(String::from(
+            "Can't take the square root of negative number -4"
+        ))
+    );
+
+    let errors = compile_errors("let m: int[2][2];\nlet x = min(m, [1, 2, 3]);\n");
+    assert_eq!(
+        errors[0].message,
+        "Function min can't operate arrays with dimensions [2, 2] and [3]"
+    );
+}
+
+#[test]
+#[file_serial]
+fn test_runtime_errors() {
+    let error = runtime_error(build_file_path("runtime_errors.mo").as_str());
+
+    assert_eq!(
+        error.kind,
+        RuntimeErrorKind::IndexError {
+            index: 10,
+            size: 10
+        }
+    );
+    let stack = error
+        .stack
+        .iter()
+        .map(|frame| (frame.procedure_id.clone(), frame.line))
+        .collect::<Vec<(Option<String>, Option<usize>)>>();
+    assert_eq!(
+        stack,
+        vec![(Some(String::from("find")), Some(6)), (None, Some(13))]
+    );
+
+    // Integer overflows are reported instead of aborting the machine
+    let sources = [
+        "let x: int = 9223372036854775807;\nlet y = x + 1;\n",
+        "let x: int = 9223372036854775807;\nlet y = x * 2;\n",
+        "let x: int = -9223372036854775807 - 1;\nlet y = abs(x);\n",
+    ];
+    for source in sources {
+        assert_eq!(
+            source_runtime_error(source),
+            RuntimeErrorKind::OverflowError
+        );
+    }
+
+    // Object files with constants that don't fit their type can't be loaded
+    let mut runner = Runner::new(build_file_path("fibonacci.mo").as_str()).unwrap();
+    runner.generate().unwrap();
+    let mut meta = runner.manager.program_meta();
+    let (address, _) = meta
+        .constant_table
+        .iter()
+        .find(|(address, _)| {
+            memory::resolver::MemoryResolver::get_type_from_address(**address)
+                == Some(&memory::types::DataType::Int)
+        })
+        .unwrap();
+    meta.constant_table
+        .insert(*address, String::from("99999999999999999999"));
+    let error = moris_lang::vm::virtual_machine::VirtualMachine::new(meta).err();
+    assert!(matches!(error, Some(RuntimeErrorKind::ValueError(_))));
+}
+
+#[test]
+fn test_repl_persistence() {
+    let mut repl = moris_lang::vm::repl::Repl::new();
+    let inputs = [
+        "let x: int = 5;",
+        "fn add(n: int): int {\n    return n + x;\n}",
+        "let bad: int = missing;",
+        "let y: int = add(2);",
+    ];
+
+    for input in inputs {
+        repl.eval(parser::parse_program(input).unwrap(), input);
+    }
+
+    let globals = &repl.manager.env.entries.get("global").unwrap().symbols;
+    assert!(globals.get("bad").is_none());
+
+    let y_address = globals.get("y").unwrap().address;
+    assert_eq!(repl.vm.memory.globals.get(&y_address), Some(&Item::Int(7)));
+}
+
+#[test]
+#[file_serial]
+fn test_debugger() {
+    use moris_lang::vm::debugger::{Debugger, Resume};
+
+    let mut runner = Runner::new(build_file_path("debugger.mo").as_str()).unwrap();
+    runner.compile().unwrap();
+    let mut debugger = Debugger::new(runner.manager).unwrap();
+
+    debugger.add_breakpoint(7);
+    debugger.resume(Resume::Continue).unwrap();
+    assert_eq!(debugger.current_line(), Some(7));
+    assert_eq!(debugger.variable("target"), Some(String::from("3")));
+    assert_eq!(debugger.variable("i"), Some(String::from("0")));
+    assert_eq!(debugger.vm.stack_trace().len(), 2);
+
+    debugger.resume(Resume::Finish).unwrap();
+    assert_eq!(debugger.current_line(), Some(14));
+    assert_eq!(debugger.vm.memory.call_context.len(), 0);
+
+    debugger.resume(Resume::Next).unwrap();
+    assert_eq!(debugger.current_line(), Some(15));
+    assert_eq!(debugger.variable("r"), Some(String::from("4")));
+    assert_eq!(debugger.variable("t"), None);
+
+    // Variables of a block are visible while it runs
+    debugger.add_breakpoint(20);
+    debugger.resume(Resume::Continue).unwrap();
+    assert_eq!(debugger.variable("t"), Some(String::from("0")));
+    debugger.resume(Resume::Continue).unwrap();
+    assert_eq!(debugger.current_line(), Some(20));
+    assert_eq!(debugger.variable("t"), Some(String::from("10")));
+}
+
+#[test]
+#[file_serial]
+fn test_bytecode_object_files() {
+    use codegen::meta::LegacyProgramMeta;
+    use moris_lang::vm::virtual_machine::VirtualMachine;
+
+    let bytecode_path = std::env::temp_dir().join("moris_fibonacci.mobc");
+    let pickle_path = std::env::temp_dir().join("moris_fibonacci.o");
+
+    let mut runner = Runner::new(build_file_path("fibonacci.mo").as_str()).unwrap();
+    runner.with_output_path(bytecode_path.to_str().unwrap());
+    runner.compile().unwrap();
+
+    let bytes = std::fs::read(&bytecode_path).unwrap();
+    assert!(codegen::bytecode::is_bytecode(&bytes));
+
+    let legacy = LegacyProgramMeta::try_from(&runner.manager.program_meta()).unwrap();
+    let mut legacy_file = std::fs::File::create(&pickle_path).unwrap();
+    serde_pickle::to_writer(&mut legacy_file, &legacy, Default::default()).unwrap();
+
+    let mut from_bytecode = VirtualMachine::load(bytecode_path.to_str().unwrap()).unwrap();
+    from_bytecode.execute().unwrap();
+    let mut from_pickle = VirtualMachine::load(pickle_path.to_str().unwrap()).unwrap();
+    from_pickle.execute().unwrap();
+
+    assert_eq!(from_bytecode.memory.globals, from_pickle.memory.globals);
+    assert!(from_bytecode
+        .memory
+        .globals
+        .values()
+        .any(|item| *item == Item::Int(89)));
+
+    // Pickle written by a previous version from legacy_natives.mo, which
+    // calls natives on an array
+    let mut legacy = VirtualMachine::load(build_file_path("legacy_natives.o").as_str()).unwrap();
+    legacy.execute().unwrap();
+    let globals = legacy.memory.globals.values().collect::<Vec<&Item>>();
+    assert!(globals.contains(&&Item::Float(2.0)));
+    assert!(globals.contains(&&Item::Int(14)));
+
+    // Failing to write the object file is reported as a compile error
+    let mut runner = Runner::new(build_file_path("fibonacci.mo").as_str()).unwrap();
+    let missing_dir = std::env::temp_dir().join("moris_missing").join("out.mobc");
+    runner.with_output_path(missing_dir.to_str().unwrap());
+    let errors = runner.compile().unwrap_err();
+    assert!(errors[0].message.starts_with("Can't write"));
+}
+
+#[test]
+#[file_serial]
+fn test_disassembler() {
+    use moris_lang::vm::disassembler::{DisasmFormat, Disassembler};
+
+    let mut runner = Runner::new(build_file_path("fibonacci.mo").as_str()).unwrap();
+    runner.generate().unwrap();
+    let disassembler = Disassembler::new(&runner.manager.program_meta());
+
+    let text = disassembler.render(DisasmFormat::Text);
+    assert!(text.lines().any(|line| line == "fibonacci:"));
+    assert!(text.contains("L:int:0"));
+    assert!(text.contains("gotoFalse"));
+    let jump = disassembler.rows.iter().find_map(|row| row.target).unwrap();
+    assert!(text.contains(format!(".L{}:", jump).as_str()));
+
+    let markdown = disassembler.render(DisasmFormat::Markdown);
+    assert!(markdown.starts_with("| # | label | operator |"));
+    assert_eq!(markdown.lines().count(), disassembler.rows.len() + 2);
+
+    let json = disassembler.render(DisasmFormat::Json);
+    assert!(json.starts_with('['));
+    assert!(json.contains("\"labels\": [\"fibonacci\"]"));
+}
+
+#[test]
+#[file_serial]
+fn test_optimizer() {
+    use moris_lang::vm::virtual_machine::VirtualMachine;
+
+    let examples = [
+        "expressions.mo",
+        "fibonacci.mo",
+        "fibonacci_memo.mo",
+        "for_loop.mo",
+        "multi_function.mo",
+        "pipe.mo",
+        "strings.mo",
+        "while.mo",
+    ];
+
+    for example in examples {
+        let run = |level: usize| {
+            let mut runner = Runner::new(build_file_path(example).as_str()).unwrap();
+            runner.with_optimization(level).generate().unwrap();
+
+            let mut vm = VirtualMachine::new(runner.manager.program_meta()).unwrap();
+            vm.execute().unwrap();
+            (runner.manager, vm)
+        };
+
+        let (manager, plain) = run(0);
+        let (optimized_manager, optimized) = run(1);
+
+        assert!(
+            optimized_manager.quadruples.len() <= manager.quadruples.len(),
+            "{} got longer",
+            example
+        );
+
+        // Named variables keep the same addresses and values
+        for symbol in manager.env.entries.get("global").unwrap().symbols.values() {
+            assert_eq!(
+                plain.memory.globals.get(&symbol.address),
+                optimized.memory.globals.get(&symbol.address),
+                "{} differs in {}",
+                symbol.id,
+                example
+            );
+        }
+    }
+}